@@ -9,6 +9,13 @@ pub enum Align {
 
     /// Align at the end of the axis.
     End,
+
+    /// Align the text baselines of the contents of a row.
+    ///
+    /// Contents without a baseline, and contents aligned on any other axis,
+    /// are aligned at the start. Columns and containers report the baseline
+    /// of their first line, so they can be aligned in a row too.
+    Baseline,
 }
 
 /// The horizontal alignment of some resource.
@@ -121,6 +121,72 @@ impl Color {
         ]
     }
 
+    /// Creates a [`Color`] from its linear values.
+    ///
+    /// This is the inverse of [`into_linear`].
+    ///
+    /// [`Color`]: struct.Color.html
+    /// [`into_linear`]: #method.into_linear
+    pub fn from_linear(r: f32, g: f32, b: f32, a: f32) -> Color {
+        // As described in:
+        // https://en.wikipedia.org/wiki/SRGB#The_forward_transformation_(CIE_XYZ_to_sRGB)
+        fn gamma_component(u: f32) -> f32 {
+            if u < 0.0031308 {
+                u * 12.92
+            } else {
+                1.055 * u.powf(1.0 / 2.4) - 0.055
+            }
+        }
+
+        Color::new(
+            gamma_component(r),
+            gamma_component(g),
+            gamma_component(b),
+            a,
+        )
+    }
+
+    /// Linearly interpolates between the [`Color`] and another one.
+    ///
+    /// A `t` of `0.0` produces the original [`Color`] and a `t` of `1.0`
+    /// produces the `other` one. `t` is clamped to that range.
+    ///
+    /// The sRGB components are interpolated as-is. This is cheap, but
+    /// blends between very different colors can look darker than expected.
+    /// Use [`lerp_linear`] for perceptually smoother transitions.
+    ///
+    /// [`Color`]: struct.Color.html
+    /// [`lerp_linear`]: #method.lerp_linear
+    pub fn lerp(self, other: Color, t: f32) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: f32, b: f32| a + (b - a) * t;
+
+        Color::new(
+            mix(self.r, other.r),
+            mix(self.g, other.g),
+            mix(self.b, other.b),
+            mix(self.a, other.a),
+        )
+    }
+
+    /// Interpolates between the [`Color`] and another one in linear space.
+    ///
+    /// Both colors are converted into their linear values, interpolated,
+    /// and converted back into sRGB. The alpha is interpolated linearly.
+    /// Like [`lerp`], `t` is clamped to the `0.0..=1.0` range.
+    ///
+    /// [`Color`]: struct.Color.html
+    /// [`lerp`]: #method.lerp
+    pub fn lerp_linear(self, other: Color, t: f32) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: f32, b: f32| a + (b - a) * t;
+
+        let [r1, g1, b1, a1] = self.into_linear();
+        let [r2, g2, b2, a2] = other.into_linear();
+
+        Color::from_linear(mix(r1, r2), mix(g1, g2), mix(b1, b2), mix(a1, a2))
+    }
+
     /// Inverts the [`Color`] in-place.
     ///
     /// [`Color`]: struct.Color.html
@@ -191,12 +257,11 @@ impl From<Color> for Srgb {
     }
 }
 
-#[cfg(feature = "palette")]
 #[cfg(test)]
 mod tests {
     use super::*;
-    use palette::Blend;
 
+    #[cfg(feature = "palette")]
     #[test]
     fn srgba_traits() {
         let c = Color::from_rgb(0.5, 0.4, 0.3);
@@ -206,8 +271,11 @@ mod tests {
         assert_eq!(c, r);
     }
 
+    #[cfg(feature = "palette")]
     #[test]
     fn color_manipulation() {
+        use palette::Blend;
+
         let c1 = Color::from_rgb(0.5, 0.4, 0.3);
         let c2 = Color::from_rgb(0.2, 0.5, 0.3);
 
@@ -230,4 +298,27 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn lerp_interpolates_components() {
+        let c1 = Color::new(0.0, 0.2, 1.0, 1.0);
+        let c2 = Color::new(1.0, 0.4, 0.0, 0.0);
+
+        assert_eq!(c1.lerp(c2, 0.5), Color::new(0.5, 0.3, 0.5, 0.5));
+        assert_eq!(c1.lerp(c2, -1.0), c1);
+        assert_eq!(c1.lerp(c2, 2.0), c2);
+    }
+
+    #[test]
+    fn lerp_linear_interpolates_in_linear_space() {
+        let c1 = Color::from_rgb(0.9, 0.1, 0.2);
+        let c2 = Color::from_rgb(0.1, 0.8, 0.4);
+
+        let expected = Color::from_rgb(0.793_617, 0.434_668, 0.267_223);
+        let r = c1.lerp_linear(c2, 0.25);
+
+        assert!((r.r - expected.r).abs() < 1e-4);
+        assert!((r.g - expected.g).abs() < 1e-4);
+        assert!((r.b - expected.b).abs() < 1e-4);
+    }
 }
@@ -0,0 +1,278 @@
+//! Pick colors from some well-known preset palettes.
+//!
+//! The [`tailwind`] palette offers a range of [`Shades`] for every hue, while
+//! the [`material`] palette names the primary color of every hue.
+//!
+//! ```
+//! use iced_core::color_palette::{material, tailwind};
+//!
+//! let accent = material::DEEP_PURPLE;
+//! let background = tailwind::GRAY.get(50).unwrap();
+//! let text = tailwind::GRAY.darkest();
+//! ```
+//!
+//! [`tailwind`]: tailwind/index.html
+//! [`material`]: material/index.html
+//! [`Shades`]: struct.Shades.html
+use crate::Color;
+
+/// Creates a [`Color`] from its hexadecimal RGB representation, in a
+/// constant context.
+///
+/// [`Color`]: ../struct.Color.html
+macro_rules! rgb {
+    ($hex:expr) => {
+        Color {
+            r: (($hex >> 16) & 0xff) as f32 / 255.0,
+            g: (($hex >> 8) & 0xff) as f32 / 255.0,
+            b: ($hex & 0xff) as f32 / 255.0,
+            a: 1.0,
+        }
+    };
+}
+
+/// The shades of a single hue, from the lightest to the darkest.
+///
+/// Each shade is identified by its weight, one of the [`WEIGHTS`].
+///
+/// [`WEIGHTS`]: #associatedconstant.WEIGHTS
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shades([Color; 10]);
+
+impl Shades {
+    /// The weights of the [`Shades`], from the lightest to the darkest.
+    ///
+    /// [`Shades`]: struct.Shades.html
+    pub const WEIGHTS: [u16; 10] =
+        [50, 100, 200, 300, 400, 500, 600, 700, 800, 900];
+
+    /// Returns the shade with the given weight, if it is one of the
+    /// [`WEIGHTS`].
+    ///
+    /// [`WEIGHTS`]: #associatedconstant.WEIGHTS
+    pub fn get(&self, weight: u16) -> Option<Color> {
+        Self::WEIGHTS
+            .iter()
+            .position(|&w| w == weight)
+            .map(|index| self.0[index])
+    }
+
+    /// Returns the lightest shade, with a weight of `50`.
+    pub fn lightest(&self) -> Color {
+        self.0[0]
+    }
+
+    /// Returns the darkest shade, with a weight of `900`.
+    pub fn darkest(&self) -> Color {
+        self.0[9]
+    }
+
+    /// Returns an iterator over the weights and colors of the [`Shades`],
+    /// from the lightest to the darkest.
+    ///
+    /// [`Shades`]: struct.Shades.html
+    pub fn iter(&self) -> impl Iterator<Item = (u16, Color)> + '_ {
+        Self::WEIGHTS.iter().copied().zip(self.0.iter().copied())
+    }
+}
+
+pub mod tailwind {
+    //! The default palette of [Tailwind CSS] v2.
+    //!
+    //! [Tailwind CSS]: https://v2.tailwindcss.com/docs/customizing-colors
+    use super::Shades;
+    use crate::Color;
+
+    /// The gray shades.
+    pub const GRAY: Shades = Shades([
+        rgb!(0xf9fafb),
+        rgb!(0xf3f4f6),
+        rgb!(0xe5e7eb),
+        rgb!(0xd1d5db),
+        rgb!(0x9ca3af),
+        rgb!(0x6b7280),
+        rgb!(0x4b5563),
+        rgb!(0x374151),
+        rgb!(0x1f2937),
+        rgb!(0x111827),
+    ]);
+
+    /// The red shades.
+    pub const RED: Shades = Shades([
+        rgb!(0xfef2f2),
+        rgb!(0xfee2e2),
+        rgb!(0xfecaca),
+        rgb!(0xfca5a5),
+        rgb!(0xf87171),
+        rgb!(0xef4444),
+        rgb!(0xdc2626),
+        rgb!(0xb91c1c),
+        rgb!(0x991b1b),
+        rgb!(0x7f1d1d),
+    ]);
+
+    /// The yellow shades.
+    pub const YELLOW: Shades = Shades([
+        rgb!(0xfffbeb),
+        rgb!(0xfef3c7),
+        rgb!(0xfde68a),
+        rgb!(0xfcd34d),
+        rgb!(0xfbbf24),
+        rgb!(0xf59e0b),
+        rgb!(0xd97706),
+        rgb!(0xb45309),
+        rgb!(0x92400e),
+        rgb!(0x78350f),
+    ]);
+
+    /// The green shades.
+    pub const GREEN: Shades = Shades([
+        rgb!(0xecfdf5),
+        rgb!(0xd1fae5),
+        rgb!(0xa7f3d0),
+        rgb!(0x6ee7b7),
+        rgb!(0x34d399),
+        rgb!(0x10b981),
+        rgb!(0x059669),
+        rgb!(0x047857),
+        rgb!(0x065f46),
+        rgb!(0x064e3b),
+    ]);
+
+    /// The blue shades.
+    pub const BLUE: Shades = Shades([
+        rgb!(0xeff6ff),
+        rgb!(0xdbeafe),
+        rgb!(0xbfdbfe),
+        rgb!(0x93c5fd),
+        rgb!(0x60a5fa),
+        rgb!(0x3b82f6),
+        rgb!(0x2563eb),
+        rgb!(0x1d4ed8),
+        rgb!(0x1e40af),
+        rgb!(0x1e3a8a),
+    ]);
+
+    /// The indigo shades.
+    pub const INDIGO: Shades = Shades([
+        rgb!(0xeef2ff),
+        rgb!(0xe0e7ff),
+        rgb!(0xc7d2fe),
+        rgb!(0xa5b4fc),
+        rgb!(0x818cf8),
+        rgb!(0x6366f1),
+        rgb!(0x4f46e5),
+        rgb!(0x4338ca),
+        rgb!(0x3730a3),
+        rgb!(0x312e81),
+    ]);
+
+    /// The purple shades.
+    pub const PURPLE: Shades = Shades([
+        rgb!(0xf5f3ff),
+        rgb!(0xede9fe),
+        rgb!(0xddd6fe),
+        rgb!(0xc4b5fd),
+        rgb!(0xa78bfa),
+        rgb!(0x8b5cf6),
+        rgb!(0x7c3aed),
+        rgb!(0x6d28d9),
+        rgb!(0x5b21b6),
+        rgb!(0x4c1d95),
+    ]);
+
+    /// The pink shades.
+    pub const PINK: Shades = Shades([
+        rgb!(0xfdf2f8),
+        rgb!(0xfce7f3),
+        rgb!(0xfbcfe8),
+        rgb!(0xf9a8d4),
+        rgb!(0xf472b6),
+        rgb!(0xec4899),
+        rgb!(0xdb2777),
+        rgb!(0xbe185d),
+        rgb!(0x9d174d),
+        rgb!(0x831843),
+    ]);
+}
+
+pub mod material {
+    //! The primary colors of the [Material Design] palette.
+    //!
+    //! [Material Design]: https://material.io/design/color/the-color-system.html
+    use crate::Color;
+
+    /// The red primary color.
+    pub const RED: Color = rgb!(0xf44336);
+
+    /// The pink primary color.
+    pub const PINK: Color = rgb!(0xe91e63);
+
+    /// The purple primary color.
+    pub const PURPLE: Color = rgb!(0x9c27b0);
+
+    /// The deep purple primary color.
+    pub const DEEP_PURPLE: Color = rgb!(0x673ab7);
+
+    /// The indigo primary color.
+    pub const INDIGO: Color = rgb!(0x3f51b5);
+
+    /// The blue primary color.
+    pub const BLUE: Color = rgb!(0x2196f3);
+
+    /// The light blue primary color.
+    pub const LIGHT_BLUE: Color = rgb!(0x03a9f4);
+
+    /// The cyan primary color.
+    pub const CYAN: Color = rgb!(0x00bcd4);
+
+    /// The teal primary color.
+    pub const TEAL: Color = rgb!(0x009688);
+
+    /// The green primary color.
+    pub const GREEN: Color = rgb!(0x4caf50);
+
+    /// The light green primary color.
+    pub const LIGHT_GREEN: Color = rgb!(0x8bc34a);
+
+    /// The lime primary color.
+    pub const LIME: Color = rgb!(0xcddc39);
+
+    /// The yellow primary color.
+    pub const YELLOW: Color = rgb!(0xffeb3b);
+
+    /// The amber primary color.
+    pub const AMBER: Color = rgb!(0xffc107);
+
+    /// The orange primary color.
+    pub const ORANGE: Color = rgb!(0xff9800);
+
+    /// The deep orange primary color.
+    pub const DEEP_ORANGE: Color = rgb!(0xff5722);
+
+    /// The brown primary color.
+    pub const BROWN: Color = rgb!(0x795548);
+
+    /// The grey primary color.
+    pub const GREY: Color = rgb!(0x9e9e9e);
+
+    /// The blue grey primary color.
+    pub const BLUE_GREY: Color = rgb!(0x607d8b);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shades_are_found_by_weight() {
+        assert_eq!(
+            tailwind::BLUE.get(500),
+            Some(Color::from_rgb8(59, 130, 246))
+        );
+        assert_eq!(tailwind::BLUE.get(50), Some(tailwind::BLUE.lightest()));
+        assert_eq!(tailwind::BLUE.get(900), Some(tailwind::BLUE.darkest()));
+        assert_eq!(tailwind::BLUE.get(550), None);
+        assert_eq!(tailwind::BLUE.iter().count(), Shades::WEIGHTS.len());
+    }
+}
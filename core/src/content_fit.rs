@@ -0,0 +1,79 @@
+use crate::Size;
+
+/// The strategy used to fit some content, like an image, into some bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContentFit {
+    /// The content is stretched to fill the bounds exactly, ignoring its
+    /// aspect ratio.
+    Fill,
+
+    /// The content is scaled, keeping its aspect ratio, to be as big as
+    /// possible while being fully visible inside the bounds.
+    Contain,
+
+    /// The content is scaled, keeping its aspect ratio, to be as small as
+    /// possible while covering the bounds completely. Parts of it may
+    /// overflow the bounds.
+    Cover,
+
+    /// The content keeps its original size.
+    None,
+}
+
+impl ContentFit {
+    /// Returns the size of some content of the given size once fitted into
+    /// the given bounds.
+    ///
+    /// Content with no width or height cannot be scaled, so its size is
+    /// returned unchanged.
+    pub fn fit(self, content: Size, bounds: Size) -> Size {
+        if content.width == 0.0 || content.height == 0.0 {
+            return content;
+        }
+
+        let width_ratio = bounds.width / content.width;
+        let height_ratio = bounds.height / content.height;
+
+        let scale = match self {
+            ContentFit::Fill => return bounds,
+            ContentFit::None => return content,
+            ContentFit::Contain => width_ratio.min(height_ratio),
+            ContentFit::Cover => width_ratio.max(height_ratio),
+        };
+
+        Size::new(content.width * scale, content.height * scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENT: Size = Size::new(200.0, 100.0);
+    const BOUNDS: Size = Size::new(100.0, 100.0);
+
+    #[test]
+    fn contain_fits_inside_bounds() {
+        assert_eq!(
+            ContentFit::Contain.fit(CONTENT, BOUNDS),
+            Size::new(100.0, 50.0)
+        );
+    }
+
+    #[test]
+    fn cover_fills_bounds() {
+        assert_eq!(
+            ContentFit::Cover.fit(CONTENT, BOUNDS),
+            Size::new(200.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn empty_content_is_not_scaled() {
+        let empty = Size::new(0.0, 100.0);
+
+        assert_eq!(ContentFit::Contain.fit(empty, BOUNDS), empty);
+        assert_eq!(ContentFit::Cover.fit(empty, BOUNDS), empty);
+        assert_eq!(ContentFit::Fill.fit(Size::ZERO, BOUNDS), Size::ZERO);
+    }
+}
@@ -0,0 +1,79 @@
+/// The direction in which some text flows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Direction {
+    /// Text flows from left to right (e.g. English).
+    #[default]
+    LeftToRight,
+
+    /// Text flows from right to left (e.g. Arabic or Hebrew).
+    RightToLeft,
+
+    /// The direction is detected from the first strongly directional
+    /// character of the text.
+    Auto,
+}
+
+impl Direction {
+    /// Resolves the [`Direction`] for the given text.
+    ///
+    /// An [`Auto`] direction becomes [`RightToLeft`] if the first strongly
+    /// directional character of the text belongs to a right-to-left script
+    /// and [`LeftToRight`] otherwise. Any other [`Direction`] is returned
+    /// unchanged.
+    ///
+    /// [`Direction`]: enum.Direction.html
+    /// [`Auto`]: #variant.Auto
+    /// [`RightToLeft`]: #variant.RightToLeft
+    /// [`LeftToRight`]: #variant.LeftToRight
+    pub fn resolve(self, text: &str) -> Direction {
+        match self {
+            Direction::Auto => text
+                .chars()
+                .find(|c| is_right_to_left(*c) || c.is_alphabetic())
+                .filter(|c| is_right_to_left(*c))
+                .map(|_| Direction::RightToLeft)
+                .unwrap_or(Direction::LeftToRight),
+            direction => direction,
+        }
+    }
+
+    /// Returns whether the [`Direction`] is [`RightToLeft`].
+    ///
+    /// [`Direction`]: enum.Direction.html
+    /// [`RightToLeft`]: #variant.RightToLeft
+    pub fn is_right_to_left(self) -> bool {
+        self == Direction::RightToLeft
+    }
+}
+
+fn is_right_to_left(c: char) -> bool {
+    match c as u32 {
+        // Hebrew, Arabic, Syriac, Arabic Supplement, Thaana, NKo, Samaritan,
+        // Mandaic, Syriac Supplement and Arabic Extended
+        0x0590..=0x08FF => true,
+        // Hebrew and Arabic presentation forms
+        0xFB1D..=0xFDFF | 0xFE70..=0xFEFF => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auto_detects_right_to_left() {
+        assert_eq!(Direction::Auto.resolve("שלום"), Direction::RightToLeft);
+        assert_eq!(
+            Direction::Auto.resolve("123 مرحبا"),
+            Direction::RightToLeft
+        );
+    }
+
+    #[test]
+    fn auto_defaults_to_left_to_right() {
+        assert_eq!(Direction::Auto.resolve("hello"), Direction::LeftToRight);
+        assert_eq!(Direction::Auto.resolve("hi שלום"), Direction::LeftToRight);
+        assert_eq!(Direction::Auto.resolve(""), Direction::LeftToRight);
+    }
+}
@@ -15,6 +15,13 @@ pub enum Event {
 
         /// The state of the modifier keys
         modifiers: ModifiersState,
+
+        /// Whether the event was produced by the operating system repeating
+        /// a key that is held down, instead of by a new press of the key.
+        ///
+        /// Navigation usually honors repeats, while actions that should only
+        /// happen once per press can ignore them.
+        is_repeat: bool,
     },
 
     /// A keyboard key was released.
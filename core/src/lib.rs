@@ -14,25 +14,33 @@
 #![deny(unused_results)]
 #![forbid(unsafe_code)]
 #![forbid(rust_2018_idioms)]
+pub mod color_palette;
 pub mod keyboard;
 pub mod mouse;
+pub mod touch;
 
 mod align;
 mod background;
 mod color;
+mod content_fit;
+mod direction;
 mod font;
 mod length;
 mod point;
 mod rectangle;
 mod size;
+mod spacing;
 mod vector;
 
 pub use align::{Align, HorizontalAlignment, VerticalAlignment};
 pub use background::Background;
 pub use color::Color;
+pub use content_fit::ContentFit;
+pub use direction::Direction;
 pub use font::Font;
 pub use length::Length;
 pub use point::Point;
 pub use rectangle::Rectangle;
 pub use size::Size;
+pub use spacing::Spacing;
 pub use vector::Vector;
@@ -0,0 +1,75 @@
+use std::hash::{Hash, Hasher};
+
+/// An amount of padding or spacing.
+///
+/// A relative [`Spacing`] scales with the space available to the widget using
+/// it, which is useful for responsive layouts.
+///
+/// [`Spacing`]: enum.Spacing.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Spacing {
+    /// A fixed amount of units.
+    Units(u16),
+
+    /// A fraction of the available space (e.g. `0.05` for 5%).
+    ///
+    /// Padding is relative to the available width, while spacing is relative
+    /// to the available space along the axis of its widget. When the
+    /// available space is unbounded, it resolves to zero.
+    Relative(f32),
+}
+
+impl Spacing {
+    /// Resolves the [`Spacing`] to an amount of units, given the available
+    /// space.
+    ///
+    /// [`Spacing`]: enum.Spacing.html
+    pub fn resolve(self, available: f32) -> f32 {
+        match self {
+            Spacing::Units(units) => f32::from(units),
+            Spacing::Relative(ratio) if available.is_finite() => {
+                (available * ratio).max(0.0)
+            }
+            Spacing::Relative(_) => 0.0,
+        }
+    }
+}
+
+impl Default for Spacing {
+    fn default() -> Self {
+        Spacing::Units(0)
+    }
+}
+
+impl From<u16> for Spacing {
+    fn from(units: u16) -> Self {
+        Spacing::Units(units)
+    }
+}
+
+impl Hash for Spacing {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Spacing::Units(units) => {
+                0.hash(state);
+                units.hash(state);
+            }
+            Spacing::Relative(ratio) => {
+                1.hash(state);
+                ratio.to_bits().hash(state);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relative_spacing_scales_with_available_space() {
+        assert_eq!(Spacing::Relative(0.1).resolve(300.0), 30.0);
+        assert_eq!(Spacing::Relative(0.1).resolve(f32::INFINITY), 0.0);
+        assert_eq!(Spacing::Units(10).resolve(300.0), 10.0);
+    }
+}
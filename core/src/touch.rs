@@ -0,0 +1,79 @@
+//! Reuse basic touch types.
+use crate::Point;
+
+/// A touch interaction.
+///
+/// Every finger touching the screen is identified by a [`Finger`] from the
+/// moment it is pressed until it is lifted or lost.
+///
+/// [`Finger`]: struct.Finger.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Event {
+    /// A touch interaction was started.
+    FingerPressed {
+        /// The finger touching the screen.
+        id: Finger,
+
+        /// The position of the finger.
+        position: Point,
+    },
+
+    /// An on-going touch interaction was moved.
+    FingerMoved {
+        /// The finger touching the screen.
+        id: Finger,
+
+        /// The new position of the finger.
+        position: Point,
+    },
+
+    /// A touch interaction was ended.
+    FingerLifted {
+        /// The finger that was lifted.
+        id: Finger,
+
+        /// The last position of the finger.
+        position: Point,
+    },
+
+    /// A touch interaction was canceled, usually by the system.
+    FingerLost {
+        /// The finger that was lost.
+        id: Finger,
+
+        /// The last position of the finger.
+        position: Point,
+    },
+}
+
+impl Event {
+    /// Returns the [`Finger`] of the [`Event`].
+    ///
+    /// [`Finger`]: struct.Finger.html
+    /// [`Event`]: enum.Event.html
+    pub fn finger(&self) -> Finger {
+        match self {
+            Event::FingerPressed { id, .. }
+            | Event::FingerMoved { id, .. }
+            | Event::FingerLifted { id, .. }
+            | Event::FingerLost { id, .. } => *id,
+        }
+    }
+
+    /// Returns the position of the finger of the [`Event`].
+    ///
+    /// [`Event`]: enum.Event.html
+    pub fn position(&self) -> Point {
+        match self {
+            Event::FingerPressed { position, .. }
+            | Event::FingerMoved { position, .. }
+            | Event::FingerLifted { position, .. }
+            | Event::FingerLost { position, .. } => *position,
+        }
+    }
+}
+
+/// A unique identifier of a finger touching the screen, only valid for the
+/// duration of a single touch interaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Finger(pub u64);
@@ -1,6 +1,6 @@
 use iced::{
-    executor, Align, Application, Checkbox, Column, Command, Container,
-    Element, Length, Settings, Subscription, Text,
+    checkbox, executor, Align, Application, Checkbox, Column, Command,
+    Container, Element, Length, Settings, Subscription, Text,
 };
 
 pub fn main() -> iced::Result {
@@ -11,6 +11,7 @@ pub fn main() -> iced::Result {
 struct Events {
     last: Vec<iced_native::Event>,
     enabled: bool,
+    toggle: checkbox::State,
 }
 
 #[derive(Debug, Clone)]
@@ -66,6 +67,7 @@ impl Application for Events {
         );
 
         let toggle = Checkbox::new(
+            &mut self.toggle,
             self.enabled,
             "Listen to runtime events",
             Message::Toggled,
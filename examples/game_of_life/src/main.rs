@@ -5,13 +5,14 @@ mod style;
 
 use grid::Grid;
 use iced::button::{self, Button};
+use iced::checkbox::{self, Checkbox};
 use iced::executor;
 use iced::pick_list::{self, PickList};
 use iced::slider::{self, Slider};
 use iced::time;
 use iced::{
-    Align, Application, Checkbox, Column, Command, Container, Element, Length,
-    Row, Settings, Subscription, Text,
+    Align, Application, Column, Command, Container, Element, Length, Row,
+    Settings, Subscription, Text,
 };
 use preset::Preset;
 use std::time::{Duration, Instant};
@@ -794,6 +795,7 @@ struct Controls {
     next_button: button::State,
     clear_button: button::State,
     speed_slider: slider::State,
+    grid_checkbox: checkbox::State,
     preset_list: pick_list::State<Preset>,
 }
 
@@ -843,10 +845,15 @@ impl Controls {
             .push(playback_controls)
             .push(speed_controls)
             .push(
-                Checkbox::new(is_grid_enabled, "Grid", Message::ToggleGrid)
-                    .size(16)
-                    .spacing(5)
-                    .text_size(16),
+                Checkbox::new(
+                    &mut self.grid_checkbox,
+                    is_grid_enabled,
+                    "Grid",
+                    Message::ToggleGrid,
+                )
+                .size(16)
+                .spacing(5)
+                .text_size(16),
             )
             .push(
                 PickList::new(
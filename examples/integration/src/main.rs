@@ -8,6 +8,7 @@ use iced_wgpu::{wgpu, Backend, Renderer, Settings, Viewport};
 use iced_winit::{conversion, futures, program, winit, Debug, Size};
 
 use futures::task::SpawnExt;
+use std::collections::HashSet;
 use winit::{
     dpi::PhysicalPosition,
     event::{Event, ModifiersState, WindowEvent},
@@ -28,6 +29,7 @@ pub fn main() {
     );
     let mut cursor_position = PhysicalPosition::new(-1.0, -1.0);
     let mut modifiers = ModifiersState::default();
+    let mut pressed_keys = HashSet::new();
 
     // Initialize wgpu
     let instance = wgpu::Instance::new(wgpu::BackendBit::PRIMARY);
@@ -127,6 +129,7 @@ pub fn main() {
                     &event,
                     window.scale_factor(),
                     modifiers,
+                    &mut pressed_keys,
                 ) {
                     state.queue_event(event);
                 }
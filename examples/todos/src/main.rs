@@ -1,7 +1,7 @@
 use iced::{
-    button, scrollable, text_input, Align, Application, Button, Checkbox,
-    Column, Command, Container, Element, Font, HorizontalAlignment, Length,
-    Row, Scrollable, Settings, Text, TextInput,
+    button, checkbox, scrollable, text_input, Align, Application, Button,
+    Checkbox, Column, Command, Container, Element, Font, HorizontalAlignment,
+    Length, Row, Scrollable, Settings, Text, TextInput,
 };
 use serde::{Deserialize, Serialize};
 
@@ -219,6 +219,7 @@ struct Task {
 #[derive(Debug, Clone)]
 pub enum TaskState {
     Idle {
+        checkbox: checkbox::State,
         edit_button: button::State,
     },
     Editing {
@@ -230,6 +231,7 @@ pub enum TaskState {
 impl Default for TaskState {
     fn default() -> Self {
         TaskState::Idle {
+            checkbox: checkbox::State::new(),
             edit_button: button::State::new(),
         }
     }
@@ -250,6 +252,7 @@ impl Task {
             description,
             completed: false,
             state: TaskState::Idle {
+                checkbox: checkbox::State::new(),
                 edit_button: button::State::new(),
             },
         }
@@ -272,6 +275,7 @@ impl Task {
             TaskMessage::FinishEdition => {
                 if !self.description.is_empty() {
                     self.state = TaskState::Idle {
+                        checkbox: checkbox::State::new(),
                         edit_button: button::State::new(),
                     }
                 }
@@ -282,8 +286,12 @@ impl Task {
 
     fn view(&mut self) -> Element<TaskMessage> {
         match &mut self.state {
-            TaskState::Idle { edit_button } => {
+            TaskState::Idle {
+                checkbox,
+                edit_button,
+            } => {
                 let checkbox = Checkbox::new(
+                    checkbox,
                     self.completed,
                     &self.description,
                     TaskMessage::Completed,
@@ -1,6 +1,6 @@
 use iced::{
-    button, scrollable, slider, text_input, Button, Checkbox, Color, Column,
-    Container, Element, HorizontalAlignment, Image, Length, Radio, Row,
+    button, checkbox, scrollable, slider, text_input, Button, Checkbox, Color,
+    Column, Container, Element, HorizontalAlignment, Image, Length, Radio, Row,
     Sandbox, Scrollable, Settings, Slider, Space, Text, TextInput,
 };
 
@@ -144,8 +144,11 @@ impl Steps {
                     value: String::new(),
                     is_secure: false,
                     state: text_input::State::new(),
+                    toggle: checkbox::State::new(),
+                },
+                Step::Debugger {
+                    toggle: checkbox::State::new(),
                 },
-                Step::Debugger,
                 Step::End,
             ],
             current: 0,
@@ -215,8 +218,11 @@ enum Step {
         value: String,
         is_secure: bool,
         state: text_input::State,
+        toggle: checkbox::State,
+    },
+    Debugger {
+        toggle: checkbox::State,
     },
-    Debugger,
     End,
 }
 
@@ -238,7 +244,7 @@ impl<'a> Step {
     fn update(&mut self, msg: StepMessage, debug: &mut bool) {
         match msg {
             StepMessage::DebugToggled(value) => {
-                if let Step::Debugger = self {
+                if let Step::Debugger { .. } = self {
                     *debug = value;
                 }
             }
@@ -300,7 +306,7 @@ impl<'a> Step {
             Step::RowsAndColumns { .. } => "Rows and columns",
             Step::Scrollable => "Scrollable",
             Step::TextInput { .. } => "Text input",
-            Step::Debugger => "Debugger",
+            Step::Debugger { .. } => "Debugger",
             Step::End => "End",
         }
     }
@@ -315,7 +321,7 @@ impl<'a> Step {
             Step::RowsAndColumns { .. } => true,
             Step::Scrollable => true,
             Step::TextInput { value, .. } => !value.is_empty(),
-            Step::Debugger => true,
+            Step::Debugger { .. } => true,
             Step::End => false,
         }
     }
@@ -342,8 +348,9 @@ impl<'a> Step {
                 value,
                 is_secure,
                 state,
-            } => Self::text_input(value, *is_secure, state),
-            Step::Debugger => Self::debugger(debug),
+                toggle,
+            } => Self::text_input(value, *is_secure, state, toggle),
+            Step::Debugger { toggle } => Self::debugger(debug, toggle),
             Step::End => Self::end(),
         }
         .into()
@@ -598,6 +605,7 @@ impl<'a> Step {
         value: &str,
         is_secure: bool,
         state: &'a mut text_input::State,
+        toggle: &'a mut checkbox::State,
     ) -> Column<'a, StepMessage> {
         let text_input = TextInput::new(
             state,
@@ -617,6 +625,7 @@ impl<'a> Step {
                 text_input
             })
             .push(Checkbox::new(
+                toggle,
                 is_secure,
                 "Enable password mode",
                 StepMessage::ToggleSecureInput,
@@ -636,7 +645,10 @@ impl<'a> Step {
             )
     }
 
-    fn debugger(debug: bool) -> Column<'a, StepMessage> {
+    fn debugger(
+        debug: bool,
+        toggle: &'a mut checkbox::State,
+    ) -> Column<'a, StepMessage> {
         Self::container("Debugger")
             .push(Text::new(
                 "You can ask Iced to visually explain the layouting of the \
@@ -654,6 +666,7 @@ impl<'a> Step {
                 )
             } else {
                 Element::new(Checkbox::new(
+                    toggle,
                     debug,
                     "Explain layout",
                     StepMessage::DebugToggled,
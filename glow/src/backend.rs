@@ -2,11 +2,13 @@ use crate::quad;
 use crate::text;
 use crate::triangle;
 use crate::{Settings, Transformation, Viewport};
+use glow::HasContext;
 use iced_graphics::backend;
 use iced_graphics::font;
-use iced_graphics::Layer;
 use iced_graphics::Primitive;
+use iced_graphics::{BlendMode, Layer};
 use iced_native::mouse;
+use iced_native::rich_text;
 use iced_native::{Font, HorizontalAlignment, Size, VerticalAlignment};
 
 /// A [`glow`] graphics backend for [`iced`].
@@ -26,7 +28,11 @@ impl Backend {
     ///
     /// [`Backend`]: struct.Backend.html
     pub fn new(gl: &glow::Context, settings: Settings) -> Self {
-        let text_pipeline = text::Pipeline::new(gl, settings.default_font);
+        let text_pipeline = text::Pipeline::new(
+            gl,
+            settings.default_font,
+            settings.text_shaper,
+        );
         let quad_pipeline = quad::Pipeline::new(gl);
         let triangle_pipeline = triangle::Pipeline::new(gl);
 
@@ -80,6 +86,10 @@ impl Backend {
         let mut bounds = (layer.bounds * scale_factor).snap();
         bounds.height = bounds.height.min(target_height);
 
+        if layer.blend_mode != BlendMode::Normal {
+            set_blend_mode(gl, layer.blend_mode);
+        }
+
         if !layer.quads.is_empty() {
             self.quad_pipeline.draw(
                 gl,
@@ -104,8 +114,17 @@ impl Backend {
             );
         }
 
-        if !layer.text.is_empty() {
+        // Text is always drawn with normal blending
+        if layer.blend_mode != BlendMode::Normal {
+            set_blend_mode(gl, BlendMode::Normal);
+        }
+
+        if !layer.text.is_empty() || !layer.rich_text.is_empty() {
             for text in layer.text.iter() {
+                if self.text_pipeline.queue_shaped(text, scale_factor) {
+                    continue;
+                }
+
                 // Target physical coordinates directly to avoid blurry text
                 let text = glow_glyph::Section {
                     // TODO: We `round` here to avoid rerasterizing text when
@@ -141,30 +160,56 @@ impl Backend {
                             z: 0.0,
                         },
                     }],
-                    layout: glow_glyph::Layout::default()
-                        .h_align(match text.horizontal_alignment {
-                            HorizontalAlignment::Left => {
-                                glow_glyph::HorizontalAlign::Left
-                            }
-                            HorizontalAlignment::Center => {
-                                glow_glyph::HorizontalAlign::Center
-                            }
-                            HorizontalAlignment::Right => {
-                                glow_glyph::HorizontalAlign::Right
+                    layout: layout(
+                        text.horizontal_alignment,
+                        text.vertical_alignment,
+                    ),
+                    ..Default::default()
+                };
+
+                self.text_pipeline.queue(text);
+            }
+
+            for text in layer.rich_text.iter() {
+                let text = glow_glyph::Section {
+                    screen_position: (
+                        (text.bounds.x * scale_factor).round(),
+                        (text.bounds.y * scale_factor).round(),
+                    ),
+                    bounds: (
+                        (text.bounds.width * scale_factor).ceil(),
+                        (text.bounds.height * scale_factor).ceil(),
+                    ),
+                    text: text
+                        .spans
+                        .iter()
+                        .map(|span| {
+                            let size =
+                                span.size.map(f32::from).unwrap_or(text.size);
+
+                            glow_glyph::Text {
+                                text: &span.content,
+                                scale: glow_glyph::ab_glyph::PxScale {
+                                    x: size * scale_factor,
+                                    y: size * scale_factor,
+                                },
+                                font_id: self
+                                    .text_pipeline
+                                    .find_font(span.font),
+                                extra: glow_glyph::Extra {
+                                    color: span
+                                        .color
+                                        .map(|color| color.into_linear())
+                                        .unwrap_or(text.color),
+                                    z: 0.0,
+                                },
                             }
                         })
-                        .v_align(match text.vertical_alignment {
-                            VerticalAlignment::Top => {
-                                glow_glyph::VerticalAlign::Top
-                            }
-                            VerticalAlignment::Center => {
-                                glow_glyph::VerticalAlign::Center
-                            }
-                            VerticalAlignment::Bottom => {
-                                glow_glyph::VerticalAlign::Bottom
-                            }
-                        }),
-                    ..Default::default()
+                        .collect(),
+                    layout: layout(
+                        text.horizontal_alignment,
+                        text.vertical_alignment,
+                    ),
                 };
 
                 self.text_pipeline.queue(text);
@@ -184,6 +229,45 @@ impl Backend {
     }
 }
 
+fn set_blend_mode(gl: &glow::Context, mode: BlendMode) {
+    let factors = match mode {
+        BlendMode::Normal => None,
+        BlendMode::Multiply => Some((glow::DST_COLOR, glow::ZERO)),
+        BlendMode::Additive => Some((glow::SRC_ALPHA, glow::ONE)),
+        BlendMode::Screen => Some((glow::ONE_MINUS_DST_COLOR, glow::ONE)),
+    };
+
+    unsafe {
+        match factors {
+            Some((src, dst)) => gl.blend_func_separate(
+                src,
+                dst,
+                glow::ONE,
+                glow::ONE_MINUS_SRC_ALPHA,
+            ),
+            // Normal blending restores the setup of the compositor
+            None => gl.blend_func(glow::SRC_ALPHA, glow::ONE_MINUS_SRC_ALPHA),
+        }
+    }
+}
+
+fn layout(
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+) -> glow_glyph::Layout<glow_glyph::BuiltInLineBreaker> {
+    glow_glyph::Layout::default()
+        .h_align(match horizontal_alignment {
+            HorizontalAlignment::Left => glow_glyph::HorizontalAlign::Left,
+            HorizontalAlignment::Center => glow_glyph::HorizontalAlign::Center,
+            HorizontalAlignment::Right => glow_glyph::HorizontalAlign::Right,
+        })
+        .v_align(match vertical_alignment {
+            VerticalAlignment::Top => glow_glyph::VerticalAlign::Top,
+            VerticalAlignment::Center => glow_glyph::VerticalAlign::Center,
+            VerticalAlignment::Bottom => glow_glyph::VerticalAlign::Bottom,
+        })
+}
+
 impl iced_graphics::Backend for Backend {
     fn trim_measurements(&mut self) {
         self.text_pipeline.trim_measurement_cache()
@@ -208,6 +292,19 @@ impl backend::Text for Backend {
     ) -> (f32, f32) {
         self.text_pipeline.measure(contents, size, font, bounds)
     }
+
+    fn ascent(&self, size: f32, font: Font) -> f32 {
+        self.text_pipeline.ascent(size, font)
+    }
+
+    fn measure_spans(
+        &self,
+        spans: &[rich_text::Span<Font>],
+        size: f32,
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.text_pipeline.measure_spans(spans, size, bounds)
+    }
 }
 
 #[cfg(feature = "image")]
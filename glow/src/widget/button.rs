@@ -7,9 +7,9 @@
 use crate::Renderer;
 
 pub use iced_graphics::button::{Style, StyleSheet};
-pub use iced_native::button::State;
+pub use iced_native::button::{Border, State, SPINNER_PERIOD};
 
 /// A widget that produces a message when clicked.
 ///
-/// This is an alias of an `iced_native` button with an `iced_wgpu::Renderer`.
+/// This is an alias of an `iced_native` button with an `iced_glow::Renderer`.
 pub type Button<'a, Message> = iced_native::Button<'a, Message, Renderer>;
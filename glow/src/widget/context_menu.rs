@@ -0,0 +1,7 @@
+//! Show a menu of actions when some content is right-clicked.
+pub use iced_graphics::context_menu::{State, Style};
+
+/// An element that opens a menu of actions at the cursor when its content
+/// is right-clicked.
+pub type ContextMenu<'a, T, Message> =
+    iced_native::ContextMenu<'a, T, Message, crate::Renderer>;
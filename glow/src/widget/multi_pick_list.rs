@@ -0,0 +1,7 @@
+//! Display a dropdown list of values to select any number of.
+pub use iced_graphics::multi_pick_list::{MenuWidth, State, Style, StyleSheet};
+
+/// A widget allowing the selection of any number of values from a list of
+/// options.
+pub type MultiPickList<'a, T, Message> =
+    iced_native::MultiPickList<'a, T, Message, crate::Renderer>;
@@ -20,7 +20,7 @@ pub use iced_native::pane_grid::{
 ///
 /// [![Pane grid - Iced](https://thumbs.gfycat.com/MixedFlatJellyfish-small.gif)](https://gfycat.com/mixedflatjellyfish)
 ///
-/// This is an alias of an `iced_native` pane grid with an `iced_wgpu::Renderer`.
+/// This is an alias of an `iced_native` pane grid with an `iced_glow::Renderer`.
 pub type PaneGrid<'a, Message> = iced_native::PaneGrid<'a, Message, Renderer>;
 
 /// The content of a [`Pane`].
@@ -11,5 +11,5 @@ pub use iced_graphics::progress_bar::{Style, StyleSheet};
 /// A bar that displays progress.
 ///
 /// This is an alias of an `iced_native` progress bar with an
-/// `iced_wgpu::Renderer`.
+/// `iced_glow::Renderer`.
 pub type ProgressBar = iced_native::ProgressBar<Renderer>;
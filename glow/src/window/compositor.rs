@@ -1,3 +1,4 @@
+use crate::window::screenshot;
 use crate::{Backend, Color, Error, Renderer, Settings, Viewport};
 
 use core::ffi::c_void;
@@ -9,6 +10,7 @@ use iced_native::mouse;
 #[allow(missing_debug_implementations)]
 pub struct Compositor {
     gl: glow::Context,
+    viewport_size: Size<u32>,
 }
 
 impl iced_graphics::window::GLCompositor for Compositor {
@@ -33,7 +35,13 @@ impl iced_graphics::window::GLCompositor for Compositor {
 
         let renderer = Renderer::new(Backend::new(&gl, settings));
 
-        Ok((Self { gl }, renderer))
+        Ok((
+            Self {
+                gl,
+                viewport_size: Size::new(0, 0),
+            },
+            renderer,
+        ))
     }
 
     fn sample_count(settings: &Settings) -> u32 {
@@ -44,6 +52,8 @@ impl iced_graphics::window::GLCompositor for Compositor {
     }
 
     fn resize_viewport(&mut self, physical_size: Size<u32>) {
+        self.viewport_size = physical_size;
+
         unsafe {
             self.gl.viewport(
                 0,
@@ -73,4 +83,126 @@ impl iced_graphics::window::GLCompositor for Compositor {
 
         renderer.backend_mut().draw(gl, viewport, output, overlay)
     }
+
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        color: Color,
+        output: &<Self::Renderer as iced_native::Renderer>::Output,
+        overlay: &[T],
+    ) -> Result<Vec<u8>, screenshot::Error> {
+        let size = viewport.physical_size();
+
+        if size.width == 0 || size.height == 0 {
+            return Err(screenshot::Error::EmptyViewport);
+        }
+
+        let (width, height) = (size.width as i32, size.height as i32);
+        let row_length = 4 * size.width as usize;
+
+        let mut pixels = vec![0; row_length * size.height as usize];
+
+        // The output is drawn to an offscreen framebuffer, so the window
+        // surface is left untouched
+        let (framebuffer, renderbuffer) = unsafe {
+            let gl = &self.gl;
+
+            let framebuffer = gl
+                .create_framebuffer()
+                .map_err(|_| screenshot::Error::ReadFailed)?;
+
+            let renderbuffer = match gl.create_renderbuffer() {
+                Ok(renderbuffer) => renderbuffer,
+                Err(_) => {
+                    gl.delete_framebuffer(framebuffer);
+
+                    return Err(screenshot::Error::ReadFailed);
+                }
+            };
+
+            gl.bind_renderbuffer(glow::RENDERBUFFER, Some(renderbuffer));
+            gl.renderbuffer_storage(
+                glow::RENDERBUFFER,
+                glow::SRGB8_ALPHA8,
+                width,
+                height,
+            );
+            gl.bind_renderbuffer(glow::RENDERBUFFER, None);
+
+            gl.bind_framebuffer(glow::FRAMEBUFFER, Some(framebuffer));
+            gl.framebuffer_renderbuffer(
+                glow::FRAMEBUFFER,
+                glow::COLOR_ATTACHMENT0,
+                glow::RENDERBUFFER,
+                Some(renderbuffer),
+            );
+            gl.viewport(0, 0, width, height);
+
+            (framebuffer, renderbuffer)
+        };
+
+        let is_complete = unsafe {
+            self.gl.check_framebuffer_status(glow::FRAMEBUFFER)
+                == glow::FRAMEBUFFER_COMPLETE
+        };
+
+        if is_complete {
+            let _ = self.draw(renderer, viewport, color, output, overlay);
+
+            unsafe {
+                self.gl.read_pixels(
+                    0,
+                    0,
+                    width,
+                    height,
+                    glow::RGBA,
+                    glow::UNSIGNED_BYTE,
+                    glow::PixelPackData::Slice(&mut pixels),
+                );
+            }
+        }
+
+        unsafe {
+            let gl = &self.gl;
+
+            gl.bind_framebuffer(glow::FRAMEBUFFER, None);
+            gl.delete_framebuffer(framebuffer);
+            gl.delete_renderbuffer(renderbuffer);
+        }
+
+        self.resize_viewport(self.viewport_size);
+
+        if is_complete {
+            Ok(flip_rows(&pixels, row_length))
+        } else {
+            Err(screenshot::Error::ReadFailed)
+        }
+    }
+}
+
+/// Reverses the order of the given rows of pixels, since OpenGL reads them
+/// from the bottom.
+fn flip_rows(pixels: &[u8], row_length: usize) -> Vec<u8> {
+    pixels
+        .chunks_exact(row_length)
+        .rev()
+        .flatten()
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows_are_flipped() {
+        let pixels = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+
+        assert_eq!(
+            flip_rows(&pixels, 4),
+            vec![9, 10, 11, 12, 5, 6, 7, 8, 1, 2, 3, 4]
+        );
+    }
 }
@@ -6,6 +6,8 @@ use iced_winit::application;
 use iced_winit::conversion;
 use iced_winit::{Clipboard, Debug, Proxy, Settings};
 
+use std::collections::HashSet;
+
 pub use iced_winit::Application;
 pub use iced_winit::{program, Program};
 
@@ -22,11 +24,7 @@ where
     E: Executor + 'static,
     C: window::GLCompositor<Renderer = A::Renderer> + 'static,
 {
-    use glutin::{
-        event,
-        event_loop::{ControlFlow, EventLoop},
-        ContextBuilder,
-    };
+    use glutin::{event, event_loop::EventLoop, ContextBuilder};
 
     let mut debug = Debug::new();
     debug.startup_started();
@@ -83,6 +81,7 @@ where
     let mut cursor_position = glutin::dpi::PhysicalPosition::new(-1.0, -1.0);
     let mut mouse_interaction = mouse::Interaction::default();
     let mut modifiers = glutin::event::ModifiersState::default();
+    let mut pressed_keys = HashSet::new();
 
     let physical_size = context.window().inner_size();
     let mut viewport = Viewport::with_physical_size(
@@ -109,7 +108,7 @@ where
 
     event_loop.run(move |event, _, control_flow| match event {
         event::Event::MainEventsCleared => {
-            if state.is_queue_empty() {
+            if !state.needs_update() {
                 return;
             }
 
@@ -231,9 +230,6 @@ where
 
                 mouse_interaction = new_mouse_interaction;
             }
-
-            // TODO: Handle animations!
-            // Maybe we can use `ControlFlow::WaitUntil` for this.
         }
         event::Event::WindowEvent {
             event: window_event,
@@ -255,13 +251,14 @@ where
                 &window_event,
                 viewport.scale_factor(),
                 modifiers,
+                &mut pressed_keys,
             ) {
                 state.queue_event(event.clone());
                 runtime.broadcast(event);
             }
         }
         _ => {
-            *control_flow = ControlFlow::Wait;
+            *control_flow = application::control_flow(&state);
         }
     })
 }
@@ -1,5 +1,6 @@
 //! Write a graphics backend.
 use iced_native::image;
+use iced_native::rich_text;
 use iced_native::svg;
 use iced_native::{Font, Size};
 
@@ -13,6 +14,17 @@ pub trait Backend {
     /// `iced_wgpu` and `iced_glow` because of limitations in the text rendering
     /// pipeline. It will be removed in the future.
     fn trim_measurements(&mut self) {}
+
+    /// Returns the dimensions of the image with the given handle, if the
+    /// [`Backend`] supports drawing images.
+    ///
+    /// It is used to fit images drawn as part of other widgets, like the
+    /// background image of a container.
+    ///
+    /// [`Backend`]: trait.Backend.html
+    fn image_dimensions(&self, _handle: &image::Handle) -> Option<(u32, u32)> {
+        None
+    }
 }
 
 /// A graphics backend that supports text rendering.
@@ -43,6 +55,20 @@ pub trait Text {
         font: Font,
         bounds: Size,
     ) -> (f32, f32);
+
+    /// Returns the distance from the top of a line of text to its baseline,
+    /// using the given size and font.
+    fn ascent(&self, size: f32, font: Font) -> f32;
+
+    /// Measures the given spans laid out together, using the given size for
+    /// any span without a specific size, and returns the size of a laid out
+    /// paragraph that fits in the provided bounds.
+    fn measure_spans(
+        &self,
+        spans: &[rich_text::Span<Font>],
+        size: f32,
+        bounds: Size,
+    ) -> (f32, f32);
 }
 
 /// A graphics backend that supports image rendering.
@@ -0,0 +1,71 @@
+/// The way the colors of some primitives are combined with the colors
+/// already drawn behind them.
+///
+/// Blend modes apply to quads and meshes. Text and images are always drawn
+/// with [`Normal`] blending.
+///
+/// [`Normal`]: #variant.Normal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlendMode {
+    /// Draws colors over the background, weighted by their alpha.
+    Normal = 0,
+
+    /// Multiplies colors with the background, darkening it.
+    ///
+    /// The alpha of the drawn colors is ignored.
+    Multiply = 1,
+
+    /// Adds colors to the background, weighted by their alpha, brightening
+    /// it.
+    Additive = 2,
+
+    /// Multiplies the inverse of colors with the inverse of the background,
+    /// brightening it.
+    ///
+    /// The alpha of the drawn colors is ignored.
+    Screen = 3,
+}
+
+impl BlendMode {
+    /// All the supported blend modes.
+    pub const ALL: [BlendMode; 4] = [
+        BlendMode::Normal,
+        BlendMode::Multiply,
+        BlendMode::Additive,
+        BlendMode::Screen,
+    ];
+
+    /// Returns the position of the [`BlendMode`] in [`ALL`].
+    ///
+    /// Backends can use it to index their resources for each mode.
+    ///
+    /// [`BlendMode`]: enum.BlendMode.html
+    /// [`ALL`]: #associatedconstant.ALL
+    pub fn index(self) -> usize {
+        match self {
+            BlendMode::Normal => 0,
+            BlendMode::Multiply => 1,
+            BlendMode::Additive => 2,
+            BlendMode::Screen => 3,
+        }
+    }
+}
+
+impl Default for BlendMode {
+    fn default() -> BlendMode {
+        BlendMode::Normal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_matches_position_in_all() {
+        for (index, mode) in BlendMode::ALL.iter().enumerate() {
+            assert_eq!(mode.index(), index);
+            assert_eq!(*mode as usize, index);
+        }
+    }
+}
@@ -1,13 +1,14 @@
 //! Organize rendering primitives into a flattened list of layers.
 use crate::image;
+use crate::rich_text;
 use crate::svg;
 use crate::triangle;
 use crate::{
-    Background, Font, HorizontalAlignment, Point, Primitive, Rectangle, Size,
-    Vector, VerticalAlignment, Viewport,
+    Background, BlendMode, Font, HorizontalAlignment, Point, Primitive,
+    Rectangle, Size, Vector, VerticalAlignment, Viewport,
 };
 
-/// A group of primitives that should be clipped together.
+/// A group of primitives that should be clipped and blended together.
 #[derive(Debug, Clone)]
 pub struct Layer<'a> {
     /// The clipping bounds of the [`Layer`].
@@ -15,6 +16,12 @@ pub struct Layer<'a> {
     /// [`Layer`]: struct.Layer.html
     pub bounds: Rectangle,
 
+    /// The [`BlendMode`] of the quads and meshes of the [`Layer`].
+    ///
+    /// [`BlendMode`]: ../enum.BlendMode.html
+    /// [`Layer`]: struct.Layer.html
+    pub blend_mode: BlendMode,
+
     /// The quads of the [`Layer`].
     ///
     /// [`Layer`]: struct.Layer.html
@@ -30,6 +37,11 @@ pub struct Layer<'a> {
     /// [`Layer`]: struct.Layer.html
     pub text: Vec<Text<'a>>,
 
+    /// The rich text of the [`Layer`].
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub rich_text: Vec<RichText<'a>>,
+
     /// The images of the [`Layer`].
     ///
     /// [`Layer`]: struct.Layer.html
@@ -37,15 +49,19 @@ pub struct Layer<'a> {
 }
 
 impl<'a> Layer<'a> {
-    /// Creates a new [`Layer`] with the given clipping bounds.
+    /// Creates a new [`Layer`] with the given clipping bounds and
+    /// [`BlendMode::Normal`].
     ///
     /// [`Layer`]: struct.Layer.html
+    /// [`BlendMode::Normal`]: ../enum.BlendMode.html#variant.Normal
     pub fn new(bounds: Rectangle) -> Self {
         Self {
             bounds,
+            blend_mode: BlendMode::Normal,
             quads: Vec::new(),
             meshes: Vec::new(),
             text: Vec::new(),
+            rich_text: Vec::new(),
             images: Vec::new(),
         }
     }
@@ -137,6 +153,25 @@ impl<'a> Layer<'a> {
                     vertical_alignment: *vertical_alignment,
                 });
             }
+            Primitive::RichText {
+                spans,
+                bounds,
+                color,
+                size,
+                horizontal_alignment,
+                vertical_alignment,
+            } => {
+                let layer = layers.last_mut().unwrap();
+
+                layer.rich_text.push(RichText {
+                    spans,
+                    bounds: *bounds + translation,
+                    color: color.into_linear(),
+                    size: *size,
+                    horizontal_alignment: *horizontal_alignment,
+                    vertical_alignment: *vertical_alignment,
+                });
+            }
             Primitive::Quad {
                 bounds,
                 background,
@@ -190,8 +225,14 @@ impl<'a> Layer<'a> {
                 if let Some(clip_bounds) =
                     layer.bounds.intersection(&translated_bounds)
                 {
-                    let clip_layer = Layer::new(clip_bounds);
-                    let new_layer = Layer::new(layer.bounds);
+                    let clip_layer = Layer {
+                        blend_mode: layer.blend_mode,
+                        ..Layer::new(clip_bounds)
+                    };
+                    let new_layer = Layer {
+                        blend_mode: layer.blend_mode,
+                        ..Layer::new(layer.bounds)
+                    };
 
                     layers.push(clip_layer);
                     Self::process_primitive(
@@ -203,6 +244,22 @@ impl<'a> Layer<'a> {
                     layers.push(new_layer);
                 }
             }
+            Primitive::Blend { mode, content } => {
+                let layer = layers.last_mut().unwrap();
+
+                let blend_layer = Layer {
+                    blend_mode: *mode,
+                    ..Layer::new(layer.bounds)
+                };
+                let new_layer = Layer {
+                    blend_mode: layer.blend_mode,
+                    ..Layer::new(layer.bounds)
+                };
+
+                layers.push(blend_layer);
+                Self::process_primitive(layers, translation, content);
+                layers.push(new_layer);
+            }
             Primitive::Translate {
                 translation: new_translation,
                 content,
@@ -216,12 +273,17 @@ impl<'a> Layer<'a> {
             Primitive::Cached { cache } => {
                 Self::process_primitive(layers, translation, &cache);
             }
-            Primitive::Image { handle, bounds } => {
+            Primitive::Image {
+                handle,
+                bounds,
+                border_radius,
+            } => {
                 let layer = layers.last_mut().unwrap();
 
                 layer.images.push(Image::Raster {
                     handle: handle.clone(),
                     bounds: *bounds + translation,
+                    border_radius: f32::from(*border_radius),
                 });
             }
             Primitive::Svg { handle, bounds } => {
@@ -331,6 +393,40 @@ pub struct Text<'a> {
     pub vertical_alignment: VerticalAlignment,
 }
 
+/// A paragraph of text made of multiple spans.
+#[derive(Debug, Clone, Copy)]
+pub struct RichText<'a> {
+    /// The spans of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub spans: &'a [rich_text::Span<Font>],
+
+    /// The layout bounds of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub bounds: Rectangle,
+
+    /// The color of the spans without a specific color, in __linear RGB__.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub color: [f32; 4],
+
+    /// The size of the spans without a specific size.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub size: f32,
+
+    /// The horizontal alignment of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub horizontal_alignment: HorizontalAlignment,
+
+    /// The vertical alignment of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub vertical_alignment: VerticalAlignment,
+}
+
 /// A raster or vector image.
 #[derive(Debug, Clone)]
 pub enum Image {
@@ -341,6 +437,9 @@ pub enum Image {
 
         /// The bounds of the image.
         bounds: Rectangle,
+
+        /// The border radius of the image.
+        border_radius: f32,
     },
     /// A vector image.
     Vector {
@@ -9,6 +9,7 @@
 #![forbid(rust_2018_idioms)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 mod antialiasing;
+mod blend_mode;
 mod error;
 mod primitive;
 mod renderer;
@@ -20,6 +21,7 @@ pub mod defaults;
 pub mod font;
 pub mod layer;
 pub mod overlay;
+pub mod shaping;
 pub mod triangle;
 pub mod widget;
 pub mod window;
@@ -29,8 +31,10 @@ pub use widget::*;
 
 pub use antialiasing::Antialiasing;
 pub use backend::Backend;
+pub use blend_mode::BlendMode;
 pub use defaults::Defaults;
 pub use error::Error;
+pub use iced_style::theme::Theme;
 pub use layer::Layer;
 pub use primitive::Primitive;
 pub use renderer::Renderer;
@@ -38,6 +42,6 @@ pub use transformation::Transformation;
 pub use viewport::Viewport;
 
 pub use iced_native::{
-    Background, Color, Font, HorizontalAlignment, Point, Rectangle, Size,
-    Vector, VerticalAlignment,
+    Background, Color, ContentFit, Direction, Font, HorizontalAlignment, Point,
+    Rectangle, Size, Spacing, Vector, VerticalAlignment,
 };
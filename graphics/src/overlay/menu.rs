@@ -1,9 +1,11 @@
 //! Build and show dropdown menus.
 use crate::backend::{self, Backend};
 use crate::{Primitive, Renderer};
+use iced_native::overlay::menu;
+use iced_native::rich_text::Span;
 use iced_native::{
-    mouse, overlay, Color, Font, HorizontalAlignment, Point, Rectangle,
-    VerticalAlignment,
+    mouse, overlay, Background, Color, Font, HorizontalAlignment, Point,
+    Rectangle, VerticalAlignment,
 };
 
 pub use iced_style::menu::Style;
@@ -38,11 +40,17 @@ where
         )
     }
 
-    fn draw<T: ToString>(
+    fn draw<T>(
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
+        rows: &[Rectangle],
         options: &[T],
+        format: &dyn Fn(&T) -> String,
+        highlight: Option<&str>,
+        checked: Option<&[bool]>,
+        leading: Option<&dyn Fn(&T) -> String>,
+        selected: Option<usize>,
         hovered_option: Option<usize>,
         padding: u16,
         text_size: u16,
@@ -55,17 +63,9 @@ where
 
         let mut primitives = Vec::new();
 
-        for (i, option) in options.iter().enumerate() {
+        for (i, (option, &bounds)) in options.iter().zip(rows).enumerate() {
             let is_selected = hovered_option == Some(i);
 
-            let bounds = Rectangle {
-                x: bounds.x,
-                y: bounds.y
-                    + ((text_size as usize + padding as usize * 2) * i) as f32,
-                width: bounds.width,
-                height: f32::from(text_size + padding * 2),
-            };
-
             if is_selected {
                 primitives.push(Primitive::Quad {
                     bounds,
@@ -76,24 +76,133 @@ where
                 });
             }
 
-            primitives.push(Primitive::Text {
-                content: option.to_string(),
-                bounds: Rectangle {
-                    x: bounds.x + f32::from(padding),
-                    y: bounds.center_y(),
-                    width: f32::INFINITY,
-                    ..bounds
-                },
-                size: f32::from(text_size),
-                font,
-                color: if is_selected {
-                    style.selected_text_color
-                } else {
-                    style.text_color
-                },
-                horizontal_alignment: HorizontalAlignment::Left,
-                vertical_alignment: VerticalAlignment::Center,
-            });
+            let label = format(option);
+
+            let color = if is_selected {
+                style.selected_text_color
+            } else {
+                style.text_color
+            };
+
+            let mut text_x = bounds.x + f32::from(padding);
+
+            if let Some(checked) = checked {
+                let checkbox_bounds = Rectangle {
+                    x: text_x,
+                    y: bounds.y + f32::from(padding),
+                    width: f32::from(text_size),
+                    height: f32::from(text_size),
+                };
+
+                primitives.push(Primitive::Quad {
+                    bounds: checkbox_bounds,
+                    background: Background::Color(Color::TRANSPARENT),
+                    border_color: color,
+                    border_width: 1,
+                    border_radius: 2,
+                });
+
+                if checked.get(i).copied().unwrap_or(false) {
+                    primitives.push(Primitive::Text {
+                        content: B::CHECKMARK_ICON.to_string(),
+                        font: B::ICON_FONT,
+                        size: checkbox_bounds.height * 0.7,
+                        bounds: Rectangle {
+                            x: checkbox_bounds.center_x(),
+                            y: checkbox_bounds.center_y(),
+                            ..checkbox_bounds
+                        },
+                        color,
+                        horizontal_alignment: HorizontalAlignment::Center,
+                        vertical_alignment: VerticalAlignment::Center,
+                    });
+                }
+
+                text_x += f32::from(text_size + padding);
+            }
+
+            if let Some(leading) = leading {
+                primitives.push(Primitive::Text {
+                    content: leading(option),
+                    bounds: Rectangle {
+                        x: text_x,
+                        y: bounds.center_y(),
+                        width: f32::from(text_size),
+                        ..bounds
+                    },
+                    size: f32::from(text_size),
+                    font,
+                    color,
+                    horizontal_alignment: HorizontalAlignment::Left,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+
+                text_x += f32::from(text_size + padding);
+            }
+
+            if selected == Some(i) {
+                primitives.push(Primitive::Text {
+                    content: B::CHECKMARK_ICON.to_string(),
+                    font: B::ICON_FONT,
+                    size: f32::from(text_size) * 0.7,
+                    bounds: Rectangle {
+                        x: bounds.x + bounds.width
+                            - f32::from(padding)
+                            - f32::from(text_size) / 2.0,
+                        y: bounds.center_y(),
+                        ..bounds
+                    },
+                    color,
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+            }
+
+            let text_bounds = Rectangle {
+                x: text_x,
+                y: bounds.center_y(),
+                width: f32::INFINITY,
+                ..bounds
+            };
+
+            let highlighted = highlight
+                .filter(|_| !is_selected)
+                .and_then(|query| menu::highlight_range(&label, query));
+
+            if let Some(range) = highlighted {
+                let span = |content: &str| Span {
+                    content: content.to_string(),
+                    size: None,
+                    color: None,
+                    font,
+                };
+
+                primitives.push(Primitive::RichText {
+                    spans: vec![
+                        span(&label[..range.start]),
+                        Span {
+                            color: Some(style.highlighted_text_color()),
+                            ..span(&label[range.clone()])
+                        },
+                        span(&label[range.end..]),
+                    ],
+                    bounds: text_bounds,
+                    size: f32::from(text_size),
+                    color,
+                    horizontal_alignment: HorizontalAlignment::Left,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+            } else {
+                primitives.push(Primitive::Text {
+                    content: label,
+                    bounds: text_bounds,
+                    size: f32::from(text_size),
+                    font,
+                    color,
+                    horizontal_alignment: HorizontalAlignment::Left,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+            }
         }
 
         (
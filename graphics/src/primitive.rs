@@ -1,9 +1,10 @@
 use iced_native::{
-    image, svg, Background, Color, Font, HorizontalAlignment, Rectangle, Size,
-    Vector, VerticalAlignment,
+    image, rich_text, svg, Background, Color, Font, HorizontalAlignment,
+    Rectangle, Size, Vector, VerticalAlignment,
 };
 
 use crate::triangle;
+use crate::BlendMode;
 use std::sync::Arc;
 
 /// A rendering primitive.
@@ -33,6 +34,21 @@ pub enum Primitive {
         /// The vertical alignment of the text
         vertical_alignment: VerticalAlignment,
     },
+    /// A rich text primitive
+    RichText {
+        /// The spans of the text
+        spans: Vec<rich_text::Span<Font>>,
+        /// The bounds of the text
+        bounds: Rectangle,
+        /// The color of the spans without a specific color
+        color: Color,
+        /// The size of the spans without a specific size
+        size: f32,
+        /// The horizontal alignment of the text
+        horizontal_alignment: HorizontalAlignment,
+        /// The vertical alignment of the text
+        vertical_alignment: VerticalAlignment,
+    },
     /// A quad primitive
     Quad {
         /// The bounds of the quad
@@ -52,6 +68,8 @@ pub enum Primitive {
         handle: image::Handle,
         /// The bounds of the image
         bounds: Rectangle,
+        /// The border radius of the image
+        border_radius: u16,
     },
     /// An SVG primitive
     Svg {
@@ -70,6 +88,15 @@ pub enum Primitive {
         /// The content of the clip
         content: Box<Primitive>,
     },
+    /// A primitive that draws its content with a [`BlendMode`]
+    ///
+    /// [`BlendMode`]: enum.BlendMode.html
+    Blend {
+        /// The blend mode of the content
+        mode: BlendMode,
+        /// The content to blend
+        content: Box<Primitive>,
+    },
     /// A primitive that applies a translation
     Translate {
         /// The translation vector
@@ -1,14 +1,19 @@
-use crate::{Backend, Defaults, Primitive};
+use crate::{Backend, Defaults, Primitive, Theme};
 use iced_native::layout::{self, Layout};
 use iced_native::mouse;
 use iced_native::{
     Background, Color, Element, Point, Rectangle, Vector, Widget,
 };
 
+use std::time::Instant;
+
 /// A backend-agnostic renderer that supports all the built-in widgets.
 #[derive(Debug)]
 pub struct Renderer<B: Backend> {
     backend: B,
+    theme: Theme,
+    is_redraw_requested: bool,
+    redraw_deadline: Option<Instant>,
 }
 
 impl<B: Backend> Renderer<B> {
@@ -17,7 +22,12 @@ impl<B: Backend> Renderer<B> {
     /// [`Renderer`]: struct.Renderer.html
     /// [`Backend`]: backend/trait.Backend.html
     pub fn new(backend: B) -> Self {
-        Self { backend }
+        Self {
+            backend,
+            theme: Theme::default(),
+            is_redraw_requested: false,
+            redraw_deadline: None,
+        }
     }
 
     /// Returns a reference to the [`Backend`] of the [`Renderer`].
@@ -35,6 +45,21 @@ impl<B: Backend> Renderer<B> {
     pub fn backend_mut(&mut self) -> &mut B {
         &mut self.backend
     }
+
+    /// Returns the current [`Theme`] of the [`Renderer`].
+    ///
+    /// [`Renderer`]: struct.Renderer.html
+    /// [`Theme`]: struct.Theme.html
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Sets the [`Theme`] used to draw the widgets without an explicit style.
+    ///
+    /// [`Theme`]: struct.Theme.html
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
 }
 
 impl<B> iced_native::Renderer for Renderer<B>
@@ -56,6 +81,25 @@ where
         layout
     }
 
+    fn request_redraw(&mut self) {
+        self.is_redraw_requested = true;
+    }
+
+    fn take_redraw_request(&mut self) -> bool {
+        std::mem::replace(&mut self.is_redraw_requested, false)
+    }
+
+    fn request_redraw_at(&mut self, at: Instant) {
+        self.redraw_deadline = Some(match self.redraw_deadline {
+            Some(deadline) => deadline.min(at),
+            None => at,
+        });
+    }
+
+    fn take_redraw_deadline(&mut self) -> Option<Instant> {
+        self.redraw_deadline.take()
+    }
+
     fn overlay(
         &mut self,
         (base_primitive, base_cursor): (Primitive, mouse::Interaction),
@@ -0,0 +1,502 @@
+//! Plug a text shaping engine into a renderer.
+//!
+//! By default, backends lay out text glyph by glyph, which is enough for
+//! simple scripts but breaks ligatures and complex scripts. A [`Shaper`] can
+//! replace that layout with the glyphs produced by a proper shaping engine,
+//! like HarfBuzz.
+//!
+//! [`Shaper`]: trait.Shaper.html
+use crate::layer;
+use glyph_brush::ab_glyph;
+use iced_native::{
+    HorizontalAlignment, Point, Rectangle, Size, Vector, VerticalAlignment,
+};
+
+use std::borrow::Cow;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+/// A text shaping engine.
+pub trait Shaper: std::fmt::Debug {
+    /// Shapes a single line of text using the font with the given bytes,
+    /// scaled to the given size in pixels.
+    ///
+    /// The returned glyphs must be in visual order, from left to right.
+    fn shape(&self, font: &[u8], line: &str, size: f32) -> Vec<Glyph>;
+}
+
+/// Two shapers are equal only if they are the same instance.
+impl PartialEq for dyn Shaper {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(
+            self as *const dyn Shaper as *const u8,
+            other as *const dyn Shaper as *const u8,
+        )
+    }
+}
+
+impl Eq for dyn Shaper {}
+
+/// A glyph produced by a [`Shaper`].
+///
+/// [`Shaper`]: trait.Shaper.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Glyph {
+    /// The index of the glyph in the font.
+    pub id: u16,
+
+    /// The byte index of the first character of the line represented by the
+    /// glyph.
+    pub cluster: usize,
+
+    /// The horizontal distance that the pen moves after drawing the glyph.
+    pub advance: f32,
+
+    /// The offset of the glyph from the pen, with `y` growing downwards.
+    pub offset: Vector,
+}
+
+/// The vertical metrics of a font at a specific size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Metrics {
+    /// The distance from the top of a line to its baseline.
+    pub ascent: f32,
+
+    /// The distance between the tops of two consecutive lines.
+    pub line_height: f32,
+}
+
+impl Metrics {
+    /// Returns the [`Metrics`] of the given font at the given size.
+    ///
+    /// [`Metrics`]: struct.Metrics.html
+    pub fn of(font: &impl ab_glyph::Font, size: f32) -> Metrics {
+        use ab_glyph::ScaleFont;
+
+        let font = font.as_scaled(size);
+
+        Metrics {
+            ascent: font.ascent(),
+            line_height: font.ascent() - font.descent() + font.line_gap(),
+        }
+    }
+}
+
+/// Shapes text with a [`Shaper`] for the backends built on top of
+/// `glyph_brush`.
+///
+/// Every [`Paragraph`] is cached until it is not used between two calls to
+/// [`trim`]. Fonts are identified by the order they are added in, like the
+/// fonts of a `GlyphBrush`.
+///
+/// [`Shaper`]: trait.Shaper.html
+/// [`Paragraph`]: struct.Paragraph.html
+/// [`trim`]: #method.trim
+#[derive(Debug)]
+pub struct Engine {
+    shaper: &'static dyn Shaper,
+    fonts: Vec<(Cow<'static, [u8]>, ab_glyph::FontArc)>,
+    paragraphs: HashMap<u64, Paragraph>,
+    recently_used: HashSet<u64>,
+}
+
+impl Engine {
+    /// Creates a new [`Engine`] with the given [`Shaper`] and no fonts.
+    ///
+    /// [`Engine`]: struct.Engine.html
+    /// [`Shaper`]: trait.Shaper.html
+    pub fn new(shaper: &'static dyn Shaper) -> Engine {
+        Engine {
+            shaper,
+            fonts: Vec::new(),
+            paragraphs: HashMap::new(),
+            recently_used: HashSet::new(),
+        }
+    }
+
+    /// Adds a font with the given bytes to the [`Engine`].
+    ///
+    /// [`Engine`]: struct.Engine.html
+    pub fn add_font(
+        &mut self,
+        bytes: Cow<'static, [u8]>,
+        font: ab_glyph::FontArc,
+    ) {
+        self.fonts.push((bytes, font));
+    }
+
+    /// Returns the size of the given text, shaped with the font with the
+    /// given index.
+    pub fn measure(&mut self, content: &str, size: f32, font: usize) -> Size {
+        self.paragraph(content, size, font).size()
+    }
+
+    /// Shapes the given text of a layer and places its glyphs in physical
+    /// coordinates, ready to be queued in a `GlyphBrush` with the bounds
+    /// they are clipped to.
+    pub fn section_glyphs(
+        &mut self,
+        text: &layer::Text<'_>,
+        scale_factor: f32,
+        font: usize,
+    ) -> (Vec<glyph_brush::SectionGlyph>, ab_glyph::Rect) {
+        let size = text.size * scale_factor;
+
+        // Target physical coordinates directly to avoid blurry text
+        let anchor = Point::new(
+            (text.bounds.x * scale_factor).round(),
+            (text.bounds.y * scale_factor).round(),
+        );
+
+        let area = Paragraph::area(
+            Rectangle {
+                x: anchor.x,
+                y: anchor.y,
+                width: (text.bounds.width * scale_factor).ceil(),
+                height: (text.bounds.height * scale_factor).ceil(),
+            },
+            text.horizontal_alignment,
+            text.vertical_alignment,
+        );
+
+        let glyphs = self
+            .paragraph(text.content, size, font)
+            .glyphs(anchor, text.horizontal_alignment, text.vertical_alignment)
+            .into_iter()
+            .map(|glyph| glyph_brush::SectionGlyph {
+                section_index: 0,
+                byte_index: glyph.byte_index,
+                glyph: ab_glyph::Glyph {
+                    id: ab_glyph::GlyphId(glyph.id),
+                    scale: size.into(),
+                    position: ab_glyph::point(
+                        glyph.position.x,
+                        glyph.position.y,
+                    ),
+                },
+                font_id: glyph_brush::FontId(font),
+            })
+            .collect();
+
+        let bounds = ab_glyph::Rect {
+            min: ab_glyph::point(area.x, area.y),
+            max: ab_glyph::point(area.x + area.width, area.y + area.height),
+        };
+
+        (glyphs, bounds)
+    }
+
+    /// Drops every cached [`Paragraph`] that has not been used since the
+    /// last call.
+    ///
+    /// [`Paragraph`]: struct.Paragraph.html
+    pub fn trim(&mut self) {
+        let recently_used = &self.recently_used;
+
+        self.paragraphs.retain(|key, _| recently_used.contains(key));
+        self.recently_used.clear();
+    }
+
+    fn paragraph(
+        &mut self,
+        content: &str,
+        size: f32,
+        font: usize,
+    ) -> &Paragraph {
+        let key = {
+            let mut hasher = DefaultHasher::new();
+
+            content.hash(&mut hasher);
+            size.to_bits().hash(&mut hasher);
+            font.hash(&mut hasher);
+
+            hasher.finish()
+        };
+
+        let _ = self.recently_used.insert(key);
+
+        let shaper = self.shaper;
+        let (bytes, font) = &self.fonts[font];
+
+        self.paragraphs.entry(key).or_insert_with(|| {
+            Paragraph::new(
+                shaper,
+                bytes,
+                content,
+                size,
+                Metrics::of(font, size),
+            )
+        })
+    }
+}
+
+/// Some text shaped line by line.
+///
+/// Shaped text is never wrapped: only explicit line breaks start new lines.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Paragraph {
+    lines: Vec<Line>,
+    metrics: Metrics,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Line {
+    start: usize,
+    glyphs: Vec<Glyph>,
+    width: f32,
+}
+
+/// A glyph of a [`Paragraph`] placed on the screen.
+///
+/// [`Paragraph`]: struct.Paragraph.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Positioned {
+    /// The index of the glyph in the font.
+    pub id: u16,
+
+    /// The byte index of the first character of the whole text represented
+    /// by the glyph.
+    pub byte_index: usize,
+
+    /// The position of the glyph on its baseline.
+    pub position: Point,
+}
+
+impl Paragraph {
+    /// Shapes the given text with a [`Shaper`].
+    ///
+    /// [`Shaper`]: trait.Shaper.html
+    pub fn new(
+        shaper: &dyn Shaper,
+        font: &[u8],
+        content: &str,
+        size: f32,
+        metrics: Metrics,
+    ) -> Paragraph {
+        let mut start = 0;
+
+        let lines = content
+            .split('\n')
+            .map(|line| {
+                let glyphs =
+                    shaper.shape(font, line.trim_end_matches('\r'), size);
+                let width = glyphs.iter().map(|glyph| glyph.advance).sum();
+
+                let shaped = Line {
+                    start,
+                    glyphs,
+                    width,
+                };
+
+                start += line.len() + 1;
+                shaped
+            })
+            .collect();
+
+        Paragraph { lines, metrics }
+    }
+
+    /// Returns the size of the [`Paragraph`].
+    ///
+    /// [`Paragraph`]: struct.Paragraph.html
+    pub fn size(&self) -> Size {
+        Size::new(
+            self.lines.iter().map(|line| line.width).fold(0.0, f32::max),
+            self.lines.len() as f32 * self.metrics.line_height,
+        )
+    }
+
+    /// Returns the area covered by a [`Paragraph`] anchored at the given
+    /// bounds, in the same way as the text primitives.
+    ///
+    /// The `x` and `y` of the bounds are the anchor of the text, which is
+    /// aligned around it.
+    ///
+    /// [`Paragraph`]: struct.Paragraph.html
+    pub fn area(
+        bounds: Rectangle,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+    ) -> Rectangle {
+        let x = match horizontal_alignment {
+            HorizontalAlignment::Left => bounds.x,
+            HorizontalAlignment::Center => bounds.x - bounds.width / 2.0,
+            HorizontalAlignment::Right => bounds.x - bounds.width,
+        };
+
+        let y = match vertical_alignment {
+            VerticalAlignment::Top => bounds.y,
+            VerticalAlignment::Center => bounds.y - bounds.height / 2.0,
+            VerticalAlignment::Bottom => bounds.y - bounds.height,
+        };
+
+        Rectangle { x, y, ..bounds }
+    }
+
+    /// Places the glyphs of the [`Paragraph`] around the given anchor.
+    ///
+    /// [`Paragraph`]: struct.Paragraph.html
+    pub fn glyphs(
+        &self,
+        anchor: Point,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+    ) -> Vec<Positioned> {
+        let height = self.size().height;
+
+        let top = match vertical_alignment {
+            VerticalAlignment::Top => anchor.y,
+            VerticalAlignment::Center => anchor.y - height / 2.0,
+            VerticalAlignment::Bottom => anchor.y - height,
+        };
+
+        let mut glyphs = Vec::new();
+
+        for (i, line) in self.lines.iter().enumerate() {
+            let baseline =
+                top + i as f32 * self.metrics.line_height + self.metrics.ascent;
+
+            let mut pen = match horizontal_alignment {
+                HorizontalAlignment::Left => anchor.x,
+                HorizontalAlignment::Center => anchor.x - line.width / 2.0,
+                HorizontalAlignment::Right => anchor.x - line.width,
+            };
+
+            for glyph in &line.glyphs {
+                glyphs.push(Positioned {
+                    id: glyph.id,
+                    byte_index: line.start + glyph.cluster,
+                    position: Point::new(pen, baseline) + glyph.offset,
+                });
+
+                pen += glyph.advance;
+            }
+        }
+
+        glyphs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Shapes every character into a glyph 10 units wide, turning `fi` into
+    /// a single ligature.
+    #[derive(Debug)]
+    struct Ligatures;
+
+    impl Shaper for Ligatures {
+        fn shape(&self, _font: &[u8], line: &str, _size: f32) -> Vec<Glyph> {
+            let mut glyphs = Vec::new();
+            let mut chars = line.char_indices().peekable();
+
+            while let Some((cluster, c)) = chars.next() {
+                let id =
+                    if c == 'f' && chars.peek().map(|(_, c)| *c) == Some('i') {
+                        let _ = chars.next();
+                        0
+                    } else {
+                        c as u16
+                    };
+
+                glyphs.push(Glyph {
+                    id,
+                    cluster,
+                    advance: 10.0,
+                    offset: Vector::new(0.0, 0.0),
+                });
+            }
+
+            glyphs
+        }
+    }
+
+    const METRICS: Metrics = Metrics {
+        ascent: 15.0,
+        line_height: 20.0,
+    };
+
+    /// Counts how many lines it shapes.
+    #[derive(Debug, Default)]
+    struct Counter(std::sync::atomic::AtomicUsize);
+
+    impl Shaper for Counter {
+        fn shape(&self, font: &[u8], line: &str, size: f32) -> Vec<Glyph> {
+            let _ = self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+
+            Ligatures.shape(font, line, size)
+        }
+    }
+
+    #[test]
+    fn paragraphs_are_cached_until_unused() {
+        static COUNTER: Counter =
+            Counter(std::sync::atomic::AtomicUsize::new(0));
+
+        let shaped = || COUNTER.0.load(std::sync::atomic::Ordering::SeqCst);
+        let bytes = include_bytes!("../fonts/Lato-Regular.ttf");
+        let font = ab_glyph::FontArc::try_from_slice(bytes).unwrap();
+
+        let mut engine = Engine::new(&COUNTER);
+        engine.add_font(Cow::Borrowed(bytes), font);
+
+        assert_eq!(engine.measure("fit", 20.0, 0).width, 20.0);
+        assert_eq!(engine.measure("fit", 20.0, 0).width, 20.0);
+        assert_eq!(shaped(), 1);
+
+        let _ = engine.measure("fit", 30.0, 0);
+        assert_eq!(shaped(), 2);
+
+        // Paragraphs used since the last trim survive it
+        engine.trim();
+        let _ = engine.measure("fit", 20.0, 0);
+        assert_eq!(shaped(), 2);
+
+        engine.trim();
+        engine.trim();
+        let _ = engine.measure("fit", 20.0, 0);
+        assert_eq!(shaped(), 3);
+    }
+
+    #[test]
+    fn shapers_are_compared_by_identity() {
+        // Zero-sized shapers may share their address, so counters are used
+        static A: Counter = Counter(std::sync::atomic::AtomicUsize::new(0));
+        static B: Counter = Counter(std::sync::atomic::AtomicUsize::new(0));
+
+        let a: Option<&'static dyn Shaper> = Some(&A);
+        let b: Option<&'static dyn Shaper> = Some(&B);
+
+        assert!(a == a);
+        assert!(a != b);
+    }
+
+    #[test]
+    fn lines_are_shaped_and_aligned_independently() {
+        let paragraph =
+            Paragraph::new(&Ligatures, &[], "fit\nab", 20.0, METRICS);
+
+        assert_eq!(paragraph.size(), Size::new(20.0, 40.0));
+
+        let glyphs = paragraph.glyphs(
+            Point::new(100.0, 100.0),
+            HorizontalAlignment::Right,
+            VerticalAlignment::Bottom,
+        );
+
+        assert_eq!(
+            glyphs
+                .iter()
+                .map(|glyph| (glyph.id, glyph.byte_index, glyph.position))
+                .collect::<Vec<_>>(),
+            vec![
+                (0, 0, Point::new(80.0, 75.0)),
+                (u16::from(b't'), 2, Point::new(90.0, 75.0)),
+                (u16::from(b'a'), 4, Point::new(80.0, 95.0)),
+                (u16::from(b'b'), 5, Point::new(90.0, 95.0)),
+            ]
+        );
+    }
+}
@@ -10,21 +10,31 @@
 pub mod button;
 pub mod checkbox;
 pub mod container;
+pub mod context_menu;
 pub mod image;
+pub mod modal;
+pub mod multi_pick_list;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod rich_text;
 pub mod rule;
 pub mod scrollable;
 pub mod slider;
 pub mod svg;
 pub mod text_input;
+pub mod tooltip;
 
 mod column;
+mod focus;
+mod grid;
+mod linked_text;
 mod row;
 mod space;
+mod stack;
 mod text;
+mod wrap;
 
 #[doc(no_inline)]
 pub use button::Button;
@@ -33,13 +43,21 @@ pub use checkbox::Checkbox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use context_menu::ContextMenu;
+#[doc(no_inline)]
+pub use modal::Modal;
+#[doc(no_inline)]
+pub use multi_pick_list::MultiPickList;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
 #[doc(no_inline)]
 pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
-pub use radio::Radio;
+pub use radio::{Radio, RadioGroup};
+#[doc(no_inline)]
+pub use rich_text::RichText;
 #[doc(no_inline)]
 pub use rule::Rule;
 #[doc(no_inline)]
@@ -48,13 +66,18 @@ pub use scrollable::Scrollable;
 pub use slider::Slider;
 #[doc(no_inline)]
 pub use text_input::TextInput;
+#[doc(no_inline)]
+pub use tooltip::Tooltip;
 
 pub use column::Column;
+pub use grid::Grid;
 pub use image::Image;
 pub use row::Row;
 pub use space::Space;
+pub use stack::Stack;
 pub use svg::Svg;
 pub use text::Text;
+pub use wrap::Wrap;
 
 #[cfg(feature = "canvas")]
 #[cfg_attr(docsrs, doc(cfg(feature = "canvas")))]
@@ -6,12 +6,14 @@
 //! [`State`]: struct.State.html
 use crate::defaults::{self, Defaults};
 use crate::{Backend, Primitive, Renderer};
-use iced_native::mouse;
+use iced_native::{animation, mouse};
 use iced_native::{
     Background, Color, Element, Layout, Point, Rectangle, Vector,
 };
 
-pub use iced_native::button::State;
+pub use iced_native::button::{
+    Border, Ripple, State, RIPPLE_DURATION, SPINNER_PERIOD,
+};
 pub use iced_style::button::{Style, StyleSheet};
 
 /// A widget that produces a message when clicked.
@@ -35,34 +37,74 @@ where
         cursor_position: Point,
         is_disabled: bool,
         is_pressed: bool,
+        is_toggled: bool,
+        spinner: Option<f32>,
+        ripple: Option<Ripple>,
         style: &Box<dyn StyleSheet>,
+        border: Border,
         content: &Element<'_, Message, Self>,
         content_layout: Layout<'_>,
     ) -> Self::Output {
-        let is_mouse_over = bounds.contains(cursor_position);
+        let style = if style.follows_theme() {
+            self.theme().button
+        } else {
+            &**style
+        };
+
+        // A loading button is not interactive
+        let is_mouse_over =
+            spinner.is_none() && bounds.contains(cursor_position);
 
         let styling = if is_disabled {
             style.disabled()
+        } else if is_mouse_over && is_pressed {
+            style.pressed()
+        } else if is_toggled {
+            style.toggled()
         } else if is_mouse_over {
-            if is_pressed {
-                style.pressed()
-            } else {
-                style.hovered()
-            }
+            style.hovered()
         } else {
             style.active()
         };
 
-        let (content, _) = content.draw(
-            self,
-            &Defaults {
-                text: defaults::Text {
-                    color: styling.text_color,
-                },
+        let styling = Style {
+            border_radius: border.radius.unwrap_or(styling.border_radius),
+            border_width: border.width.unwrap_or(styling.border_width),
+            border_color: border.color.unwrap_or(styling.border_color),
+            ..styling
+        };
+
+        let content = match spinner {
+            Some(progress) => draw_spinner(
+                content_layout.bounds(),
+                progress,
+                styling.text_color,
+            ),
+            None => {
+                content
+                    .draw(
+                        self,
+                        &Defaults {
+                            text: defaults::Text {
+                                color: styling.text_color,
+                            },
+                        },
+                        content_layout,
+                        cursor_position,
+                    )
+                    .0
+            }
+        };
+
+        let content = match ripple {
+            Some(ripple) => Primitive::Group {
+                primitives: vec![
+                    draw_ripple(bounds, ripple, styling.text_color),
+                    content,
+                ],
             },
-            content_layout,
-            cursor_position,
-        );
+            None => content,
+        };
 
         (
             if styling.background.is_some() || styling.border_width > 0 {
@@ -111,3 +153,87 @@ where
         )
     }
 }
+
+/// The opacity of a [`Ripple`] when it starts spreading.
+///
+/// [`Ripple`]: struct.Ripple.html
+const RIPPLE_OPACITY: f32 = 0.3;
+
+/// Draws a circle spreading from the center of the ripple until it covers the
+/// given bounds, fading out as it grows.
+fn draw_ripple(bounds: Rectangle, ripple: Ripple, color: Color) -> Primitive {
+    let center = ripple.center;
+
+    // The distance to the farthest corner of the bounds
+    let max_radius = (center.x - bounds.x)
+        .abs()
+        .max((bounds.x + bounds.width - center.x).abs())
+        .hypot(
+            (center.y - bounds.y)
+                .abs()
+                .max((bounds.y + bounds.height - center.y).abs()),
+        );
+
+    let radius = max_radius * animation::ease_out(ripple.progress);
+
+    Primitive::Clip {
+        bounds,
+        offset: Vector::new(0, 0),
+        content: Box::new(Primitive::Quad {
+            bounds: Rectangle {
+                x: center.x - radius,
+                y: center.y - radius,
+                width: radius * 2.0,
+                height: radius * 2.0,
+            },
+            background: Background::Color(Color {
+                a: color.a * RIPPLE_OPACITY * (1.0 - ripple.progress),
+                ..color
+            }),
+            border_radius: radius as u16,
+            border_width: 0,
+            border_color: Color::TRANSPARENT,
+        }),
+    }
+}
+
+/// The amount of dots of the spinner of a loading [`Button`].
+///
+/// [`Button`]: type.Button.html
+const SPINNER_DOTS: usize = 8;
+
+/// Draws a ring of dots fading behind the one leading the given progress.
+fn draw_spinner(bounds: Rectangle, progress: f32, color: Color) -> Primitive {
+    let size = bounds.width.min(bounds.height);
+    let dot = (size / 4.0).max(1.0);
+    let radius = (size - dot) / 2.0;
+    let center = bounds.center();
+
+    let primitives = (0..SPINNER_DOTS)
+        .map(|i| {
+            let offset = i as f32 / SPINNER_DOTS as f32;
+            let angle = 2.0 * std::f32::consts::PI * offset;
+
+            // How far behind the leading dot this one is, from 0.0 to 1.0
+            let lag = (progress - offset).rem_euclid(1.0);
+
+            Primitive::Quad {
+                bounds: Rectangle {
+                    x: center.x + radius * angle.sin() - dot / 2.0,
+                    y: center.y - radius * angle.cos() - dot / 2.0,
+                    width: dot,
+                    height: dot,
+                },
+                background: Background::Color(Color {
+                    a: color.a * (1.0 - lag),
+                    ..color
+                }),
+                border_radius: (dot / 2.0) as u16,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            }
+        })
+        .collect();
+
+    Primitive::Group { primitives }
+}
@@ -8,20 +8,22 @@
 //! [`Frame`]: struct.Frame.html
 use crate::{Backend, Defaults, Primitive, Renderer};
 use iced_native::{
-    layout, mouse, Clipboard, Element, Hasher, Layout, Length, Point, Size,
-    Vector, Widget,
+    layout, mouse, Clipboard, Element, Hasher, Layout, Length, Point,
+    Rectangle, Size, Vector, Widget,
 };
 use std::hash::Hash;
 use std::marker::PhantomData;
 
 pub mod path;
 
+pub mod event;
+
 mod cache;
 mod cursor;
-mod event;
 mod fill;
 mod frame;
 mod geometry;
+mod gradient;
 mod program;
 mod stroke;
 mod text;
@@ -32,11 +34,14 @@ pub use event::Event;
 pub use fill::{Fill, FillRule};
 pub use frame::Frame;
 pub use geometry::Geometry;
+pub use gradient::{ColorStop, Gradient, MAX_STOPS};
 pub use path::Path;
 pub use program::Program;
 pub use stroke::{LineCap, LineJoin, Stroke};
 pub use text::Text;
 
+pub use crate::BlendMode;
+
 /// A widget capable of drawing 2D graphics.
 ///
 /// [`Canvas`]: struct.Canvas.html
@@ -166,40 +171,44 @@ where
         messages: &mut Vec<Message>,
         _renderer: &Renderer<B>,
         _clipboard: Option<&dyn Clipboard>,
-    ) {
+    ) -> event::Status {
         let bounds = layout.bounds();
-
-        let canvas_event = match event {
-            iced_native::Event::Mouse(mouse_event) => {
-                Some(Event::Mouse(mouse_event))
-            }
-            iced_native::Event::Keyboard(keyboard_event) => {
-                Some(Event::Keyboard(keyboard_event))
-            }
-            _ => None,
-        };
-
-        let cursor = Cursor::from_window_position(cursor_position);
+        let canvas_event = Event::from_native(event, bounds.position());
+        let cursor = Cursor::from_window_position(cursor_position)
+            .relative_to(bounds.position());
 
         if let Some(canvas_event) = canvas_event {
-            if let Some(message) =
-                self.program.update(canvas_event, bounds, cursor)
-            {
+            let (status, message) = self.program.on_event(
+                canvas_event,
+                Rectangle::with_size(bounds.size()),
+                cursor,
+            );
+
+            if let Some(message) = message {
                 messages.push(message);
             }
+
+            return status;
         }
+
+        event::Status::Ignored
     }
 
     fn draw(
         &self,
-        _renderer: &mut Renderer<B>,
+        renderer: &mut Renderer<B>,
         _defaults: &Defaults,
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> (Primitive, mouse::Interaction) {
-        let bounds = layout.bounds();
-        let translation = Vector::new(bounds.x, bounds.y);
-        let cursor = Cursor::from_window_position(cursor_position);
+        let translation = Vector::new(layout.bounds().x, layout.bounds().y);
+        let bounds = Rectangle::with_size(layout.bounds().size());
+        let cursor = Cursor::from_window_position(cursor_position)
+            .relative_to(layout.position());
+
+        if self.program.is_animating() {
+            iced_native::Renderer::request_redraw(renderer);
+        }
 
         (
             Primitive::Translate {
@@ -237,3 +246,169 @@ where
         Element::new(canvas)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_native::{Cache, Stack, UserInterface};
+
+    struct Headless;
+
+    impl Backend for Headless {}
+
+    struct Layer {
+        id: usize,
+        is_capturing: bool,
+        is_animating: bool,
+    }
+
+    impl Program<usize> for Layer {
+        fn on_event(
+            &mut self,
+            event: Event,
+            bounds: Rectangle,
+            cursor: Cursor,
+        ) -> (event::Status, Option<usize>) {
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(_))
+                    if cursor.is_over(&bounds) =>
+                {
+                    let status = if self.is_capturing {
+                        event::Status::Captured
+                    } else {
+                        event::Status::Ignored
+                    };
+
+                    (status, Some(self.id))
+                }
+                _ => (event::Status::Ignored, None),
+            }
+        }
+
+        fn draw(&self, _bounds: Rectangle, _cursor: Cursor) -> Vec<Geometry> {
+            Vec::new()
+        }
+
+        fn is_animating(&self) -> bool {
+            self.is_animating
+        }
+    }
+
+    fn layer(id: usize, is_capturing: bool) -> Canvas<usize, Layer> {
+        Canvas::new(Layer {
+            id,
+            is_capturing,
+            is_animating: false,
+        })
+    }
+
+    fn click(is_capturing: bool) -> Vec<usize> {
+        let mut renderer = Renderer::new(Headless);
+
+        let stack = Stack::new()
+            .push(layer(0, true))
+            .push(layer(1, is_capturing));
+
+        let mut user_interface = UserInterface::build(
+            stack,
+            Size::new(200.0, 200.0),
+            Cache::new(),
+            &mut renderer,
+        );
+
+        user_interface.update(
+            &[iced_native::Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Left,
+            ))],
+            Point::new(50.0, 50.0),
+            None,
+            &renderer,
+        )
+    }
+
+    #[test]
+    fn captured_events_do_not_reach_other_widgets() {
+        assert_eq!(click(true), vec![1]);
+        assert_eq!(click(false), vec![1, 0]);
+    }
+
+    struct Tracker;
+
+    impl Program<(Rectangle, Option<Point>)> for Tracker {
+        fn update(
+            &mut self,
+            _event: Event,
+            bounds: Rectangle,
+            cursor: Cursor,
+        ) -> Option<(Rectangle, Option<Point>)> {
+            Some((bounds, cursor.position()))
+        }
+
+        fn draw(&self, _bounds: Rectangle, _cursor: Cursor) -> Vec<Geometry> {
+            Vec::new()
+        }
+    }
+
+    #[test]
+    fn programs_receive_canvas_coordinates() {
+        let mut renderer = Renderer::new(Headless);
+
+        let column = iced_native::Column::new().padding(20).push(
+            Canvas::new(Tracker)
+                .width(Length::Units(50))
+                .height(Length::Units(50)),
+        );
+
+        let mut user_interface = UserInterface::build(
+            column,
+            Size::new(200.0, 200.0),
+            Cache::new(),
+            &mut renderer,
+        );
+
+        let messages = user_interface.update(
+            &[iced_native::Event::Mouse(mouse::Event::CursorMoved {
+                x: 30.0,
+                y: 25.0,
+            })],
+            Point::new(30.0, 25.0),
+            None,
+            &renderer,
+        );
+
+        assert_eq!(
+            messages,
+            vec![(
+                Rectangle::with_size(Size::new(50.0, 50.0)),
+                Some(Point::new(10.0, 5.0))
+            )]
+        );
+    }
+
+    #[test]
+    fn animating_programs_request_redraws() {
+        let mut renderer = Renderer::new(Headless);
+
+        for &is_animating in &[false, true] {
+            let canvas = Canvas::new(Layer {
+                id: 0,
+                is_capturing: false,
+                is_animating,
+            });
+
+            let mut user_interface = UserInterface::build(
+                canvas,
+                Size::new(200.0, 200.0),
+                Cache::new(),
+                &mut renderer,
+            );
+
+            let _ = user_interface.draw(&mut renderer, Point::ORIGIN);
+
+            assert_eq!(
+                iced_native::Renderer::take_redraw_request(&mut renderer),
+                is_animating
+            );
+        }
+    }
+}
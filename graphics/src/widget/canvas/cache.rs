@@ -24,7 +24,10 @@ impl Default for State {
 /// A [`Cache`] will not redraw its geometry unless the dimensions of its layer
 /// change or it is explicitly cleared.
 ///
-/// [`Layer`]: ../trait.Layer.html
+/// A [`Program`] can use a different [`Cache`] for every layer it draws, in
+/// order to clear them independently.
+///
+/// [`Program`]: trait.Program.html
 /// [`Cache`]: struct.Cache.html
 /// [`Geometry`]: struct.Geometry.html
 #[derive(Debug, Default)]
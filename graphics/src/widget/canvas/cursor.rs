@@ -21,7 +21,22 @@ impl Cursor {
         }
     }
 
-    /// Returns the absolute position of the [`Cursor`], if available.
+    /// Moves the [`Cursor`] into the coordinate space starting at the given
+    /// origin.
+    ///
+    /// [`Cursor`]: enum.Cursor.html
+    pub(crate) fn relative_to(self, origin: Point) -> Self {
+        match self {
+            Cursor::Available(position) => Cursor::Available(Point::new(
+                position.x - origin.x,
+                position.y - origin.y,
+            )),
+            Cursor::Unavailable => Cursor::Unavailable,
+        }
+    }
+
+    /// Returns the position of the [`Cursor`] in canvas coordinates, if
+    /// available.
     ///
     /// [`Cursor`]: enum.Cursor.html
     pub fn position(&self) -> Option<Point> {
@@ -1,9 +1,20 @@
+//! Handle events of a canvas.
 use iced_native::keyboard;
 use iced_native::mouse;
+use iced_native::touch;
+use iced_native::Point;
+
+pub use iced_native::event::Status;
 
 /// A [`Canvas`] event.
 ///
-/// [`Canvas`]: struct.Event.html
+/// Any position carried by an [`Event`] is relative to the top-left corner of
+/// the [`Canvas`], like the bounds and the [`Cursor`] given to a [`Program`].
+///
+/// [`Canvas`]: ../struct.Canvas.html
+/// [`Event`]: enum.Event.html
+/// [`Cursor`]: ../enum.Cursor.html
+/// [`Program`]: ../trait.Program.html
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Event {
     /// A mouse event.
@@ -11,4 +22,119 @@ pub enum Event {
 
     /// A keyboard event.
     Keyboard(keyboard::Event),
+
+    /// A touch event.
+    ///
+    /// Some [`Gestures`] can recognize pans and pinches out of them.
+    ///
+    /// [`Gestures`]: ../../../iced_native/touch/struct.Gestures.html
+    Touch(touch::Event),
+}
+
+impl Event {
+    /// Converts a runtime event into a [`Canvas`] event, translating its
+    /// positions so they are relative to the given origin.
+    ///
+    /// [`Canvas`]: ../struct.Canvas.html
+    pub(crate) fn from_native(
+        event: iced_native::Event,
+        origin: Point,
+    ) -> Option<Event> {
+        let translate = |position: Point| {
+            Point::new(position.x - origin.x, position.y - origin.y)
+        };
+
+        match event {
+            iced_native::Event::Mouse(mouse::Event::CursorMoved { x, y }) => {
+                let position = translate(Point::new(x, y));
+
+                Some(Event::Mouse(mouse::Event::CursorMoved {
+                    x: position.x,
+                    y: position.y,
+                }))
+            }
+            iced_native::Event::Mouse(mouse_event) => {
+                Some(Event::Mouse(mouse_event))
+            }
+            iced_native::Event::Keyboard(keyboard_event) => {
+                Some(Event::Keyboard(keyboard_event))
+            }
+            iced_native::Event::Touch(touch_event) => {
+                Some(Event::Touch(match touch_event {
+                    touch::Event::FingerPressed { id, position } => {
+                        touch::Event::FingerPressed {
+                            id,
+                            position: translate(position),
+                        }
+                    }
+                    touch::Event::FingerMoved { id, position } => {
+                        touch::Event::FingerMoved {
+                            id,
+                            position: translate(position),
+                        }
+                    }
+                    touch::Event::FingerLifted { id, position } => {
+                        touch::Event::FingerLifted {
+                            id,
+                            position: translate(position),
+                        }
+                    }
+                    touch::Event::FingerLost { id, position } => {
+                        touch::Event::FingerLost {
+                            id,
+                            position: translate(position),
+                        }
+                    }
+                }))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions_are_translated_into_canvas_coordinates() {
+        let origin = Point::new(10.0, 20.0);
+
+        assert_eq!(
+            Event::from_native(
+                iced_native::Event::Mouse(mouse::Event::CursorMoved {
+                    x: 15.0,
+                    y: 25.0,
+                }),
+                origin,
+            ),
+            Some(Event::Mouse(mouse::Event::CursorMoved { x: 5.0, y: 5.0 }))
+        );
+
+        assert_eq!(
+            Event::from_native(
+                iced_native::Event::Touch(touch::Event::FingerMoved {
+                    id: touch::Finger(0),
+                    position: Point::new(10.0, 50.0),
+                }),
+                origin,
+            ),
+            Some(Event::Touch(touch::Event::FingerMoved {
+                id: touch::Finger(0),
+                position: Point::new(0.0, 30.0),
+            }))
+        );
+
+        assert_eq!(
+            Event::from_native(
+                iced_native::Event::Mouse(mouse::Event::ButtonPressed(
+                    mouse::Button::Left
+                )),
+                origin,
+            ),
+            Some(Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Left
+            )))
+        );
+    }
 }
@@ -1,8 +1,8 @@
-use iced_native::{Point, Rectangle, Size, Vector};
+use iced_native::{image, Point, Rectangle, Size, Vector};
 
 use crate::{
-    canvas::{Fill, Geometry, Path, Stroke, Text},
-    triangle, Primitive,
+    canvas::{Fill, FillRule, Geometry, Gradient, Path, Stroke, Text},
+    triangle, BlendMode, Primitive,
 };
 
 /// The frame of a [`Canvas`].
@@ -150,6 +150,84 @@ impl Frame {
         .expect("Fill rectangle");
     }
 
+    /// Draws the given [`Path`] on the [`Frame`] by filling it with the
+    /// provided [`Gradient`], following the given [`FillRule`].
+    ///
+    /// Gradients are defined in the coordinate system of the [`Frame`] at the
+    /// time of drawing.
+    ///
+    /// [`Path`]: path/struct.Path.html
+    /// [`Frame`]: struct.Frame.html
+    /// [`Gradient`]: enum.Gradient.html
+    /// [`FillRule`]: enum.FillRule.html
+    pub fn fill_gradient(
+        &mut self,
+        path: &Path,
+        gradient: &Gradient,
+        rule: FillRule,
+    ) {
+        use lyon::tessellation::{
+            BuffersBuilder, FillOptions, FillTessellator, VertexBuffers,
+        };
+
+        let mut local = VertexBuffers::new();
+
+        let _ = FillTessellator::new()
+            .tessellate_path(
+                path.raw(),
+                &FillOptions::default().with_fill_rule(rule.into()),
+                &mut BuffersBuilder::new(&mut local, LocalVertex),
+            )
+            .expect("Tessellate path");
+
+        self.push_gradient(local, gradient);
+    }
+
+    /// Adds the given triangles, in local coordinates, to the [`Frame`] with
+    /// the colors of a [`Gradient`] at their vertices.
+    ///
+    /// Vertex colors are interpolated linearly, so triangles are subdivided
+    /// wherever that interpolation would visibly deviate from the
+    /// [`Gradient`] (e.g. around color stops or in radial gradients).
+    ///
+    /// [`Frame`]: struct.Frame.html
+    /// [`Gradient`]: enum.Gradient.html
+    fn push_gradient(
+        &mut self,
+        local: lyon::tessellation::VertexBuffers<lyon::math::Point, u32>,
+        gradient: &Gradient,
+    ) {
+        let transform = self.transforms.current.raw;
+        let buffers = &mut self.buffers;
+
+        let mut push_triangle = |triangle: [lyon::math::Point; 3]| {
+            for point in triangle.iter() {
+                let position = transform.transform_point(*point);
+
+                buffers.indices.push(buffers.vertices.len() as u32);
+                buffers.vertices.push(triangle::Vertex2D {
+                    position: [position.x, position.y],
+                    color: gradient
+                        .color_at(iced_native::Point::new(point.x, point.y))
+                        .into_linear(),
+                });
+            }
+        };
+
+        for indices in local.indices.chunks_exact(3) {
+            subdivide(
+                [
+                    local.vertices[indices[0] as usize],
+                    local.vertices[indices[1] as usize],
+                    local.vertices[indices[2] as usize],
+                ],
+                gradient,
+                0,
+                &mut push_triangle,
+            );
+        }
+    }
+
     /// Draws the stroke of the given [`Path`] on the [`Frame`] with the
     /// provided style.
     ///
@@ -235,6 +313,96 @@ impl Frame {
         });
     }
 
+    /// Draws the image of the given [`Handle`] on the [`Frame`], stretched to
+    /// fill the given bounds.
+    ///
+    /// __Warning:__ like text, images are drawn on top of the geometry of the
+    /// [`Frame`] and they can only be translated and scaled. Rotations are not
+    /// supported: a rotated image is drawn unrotated, filling the bounding box
+    /// of its rotated corners.
+    ///
+    /// [`Handle`]: ../image/struct.Handle.html
+    /// [`Frame`]: struct.Frame.html
+    pub fn draw_image(&mut self, handle: image::Handle, bounds: Rectangle) {
+        let bounds = self.transform_rectangle(bounds);
+
+        self.primitives.push(Primitive::Image {
+            handle,
+            bounds,
+            border_radius: 0,
+        });
+    }
+
+    /// Draws a region of the image of the given [`Handle`] on the [`Frame`],
+    /// stretched to fill the given bounds.
+    ///
+    /// The region is given in fractions of the size of the image, from `0.0`
+    /// to `1.0`. For instance, the second sprite of a sprite sheet with four
+    /// sprites in a row is at `x: 0.25` with a `width` of `0.25`. Drawing an
+    /// image this way has the same limitations as [`draw_image`].
+    ///
+    /// [`Handle`]: ../image/struct.Handle.html
+    /// [`Frame`]: struct.Frame.html
+    /// [`draw_image`]: #method.draw_image
+    pub fn draw_image_region(
+        &mut self,
+        handle: image::Handle,
+        region: Rectangle,
+        bounds: Rectangle,
+    ) {
+        if !(region.width > 0.0 && region.height > 0.0) {
+            return;
+        }
+
+        let bounds = self.transform_rectangle(bounds);
+
+        // The whole image is scaled so the region fills the bounds, and
+        // clipped to them
+        let width = bounds.width / region.width;
+        let height = bounds.height / region.height;
+
+        self.primitives.push(Primitive::Clip {
+            bounds,
+            offset: Vector::new(0, 0),
+            content: Box::new(Primitive::Image {
+                handle,
+                bounds: Rectangle {
+                    x: bounds.x - region.x * width,
+                    y: bounds.y - region.y * height,
+                    width,
+                    height,
+                },
+                border_radius: 0,
+            }),
+        });
+    }
+
+    fn transform_rectangle(&self, rectangle: Rectangle) -> Rectangle {
+        if self.transforms.current.is_identity {
+            return rectangle;
+        }
+
+        let transform = |x, y| {
+            self.transforms
+                .current
+                .raw
+                .transform_point(lyon::math::Point::new(x, y))
+        };
+
+        let top_left = transform(rectangle.x, rectangle.y);
+        let bottom_right = transform(
+            rectangle.x + rectangle.width,
+            rectangle.y + rectangle.height,
+        );
+
+        Rectangle {
+            x: top_left.x.min(bottom_right.x),
+            y: top_left.y.min(bottom_right.y),
+            width: (bottom_right.x - top_left.x).abs(),
+            height: (bottom_right.y - top_left.y).abs(),
+        }
+    }
+
     /// Stores the current transform of the [`Frame`] and executes the given
     /// drawing operations, restoring the transform afterwards.
     ///
@@ -251,6 +419,33 @@ impl Frame {
         self.transforms.current = self.transforms.previous.pop().unwrap();
     }
 
+    /// Executes the given drawing operations, blending them with everything
+    /// drawn before using the given [`BlendMode`].
+    ///
+    /// The drawing operations start with the current transform of the
+    /// [`Frame`].
+    ///
+    /// [`Frame`]: struct.Frame.html
+    /// [`BlendMode`]: enum.BlendMode.html
+    pub fn with_blend_mode(
+        &mut self,
+        mode: BlendMode,
+        f: impl FnOnce(&mut Frame),
+    ) {
+        // Keep the order of the meshes drawn before
+        self.flush_buffers();
+
+        let mut frame = Frame::new(self.size);
+        frame.transforms.current = self.transforms.current;
+
+        f(&mut frame);
+
+        self.primitives.push(Primitive::Blend {
+            mode,
+            content: Box::new(frame.into_geometry().into_primitive()),
+        });
+    }
+
     /// Applies a translation to the current transform of the [`Frame`].
     ///
     /// [`Frame`]: struct.Frame.html
@@ -269,7 +464,10 @@ impl Frame {
 
     /// Applies a rotation to the current transform of the [`Frame`].
     ///
+    /// The rotation is not applied to the images drawn with [`draw_image`].
+    ///
     /// [`Frame`]: struct.Frame.html
+    /// [`draw_image`]: #method.draw_image
     #[inline]
     pub fn rotate(&mut self, angle: f32) {
         self.transforms.current.raw = self
@@ -295,19 +493,122 @@ impl Frame {
     /// [`Frame`]: struct.Frame.html
     /// [`Geometry`]: struct.Geometry.html
     pub fn into_geometry(mut self) -> Geometry {
+        self.flush_buffers();
+
+        Geometry::from_primitive(Primitive::Group {
+            primitives: self.primitives,
+        })
+    }
+
+    fn flush_buffers(&mut self) {
         if !self.buffers.indices.is_empty() {
+            let buffers = std::mem::replace(
+                &mut self.buffers,
+                lyon::tessellation::VertexBuffers::new(),
+            );
+
             self.primitives.push(Primitive::Mesh2D {
                 buffers: triangle::Mesh2D {
-                    vertices: self.buffers.vertices,
-                    indices: self.buffers.indices,
+                    vertices: buffers.vertices,
+                    indices: buffers.indices,
                 },
                 size: self.size,
             });
         }
+    }
+}
 
-        Geometry::from_primitive(Primitive::Group {
-            primitives: self.primitives,
-        })
+/// The maximum difference allowed per color channel between a [`Gradient`]
+/// and the linear interpolation of the colors at the vertices of a triangle.
+///
+/// [`Gradient`]: enum.Gradient.html
+const GRADIENT_TOLERANCE: f32 = 1.0 / 255.0;
+
+/// The maximum amount of times a triangle can be halved to follow a
+/// [`Gradient`].
+///
+/// [`Gradient`]: enum.Gradient.html
+const MAX_SUBDIVISIONS: u32 = 10;
+
+fn subdivide(
+    triangle: [lyon::math::Point; 3],
+    gradient: &Gradient,
+    depth: u32,
+    push: &mut impl FnMut([lyon::math::Point; 3]),
+) {
+    let color_at = |point: lyon::math::Point| {
+        gradient
+            .color_at(iced_native::Point::new(point.x, point.y))
+            .into_linear()
+    };
+
+    // Split the longest edge, to avoid producing thin triangles
+    let [a, b, c] = {
+        let [a, b, c] = triangle;
+
+        let (ab, bc, ca) = (
+            (b - a).square_length(),
+            (c - b).square_length(),
+            (a - c).square_length(),
+        );
+
+        if ab >= bc && ab >= ca {
+            [a, b, c]
+        } else if bc >= ca {
+            [b, c, a]
+        } else {
+            [c, a, b]
+        }
+    };
+
+    let midpoint = a.lerp(b, 0.5);
+    let centroid = lyon::math::Point::new(
+        (a.x + b.x + c.x) / 3.0,
+        (a.y + b.y + c.y) / 3.0,
+    );
+
+    let (a_color, b_color, c_color) = (color_at(a), color_at(b), color_at(c));
+    let (midpoint_color, centroid_color) =
+        (color_at(midpoint), color_at(centroid));
+
+    let is_accurate = (0..4).all(|i| {
+        let midpoint_error =
+            (a_color[i] + b_color[i]) / 2.0 - midpoint_color[i];
+
+        let centroid_error =
+            (a_color[i] + b_color[i] + c_color[i]) / 3.0 - centroid_color[i];
+
+        midpoint_error.abs() <= GRADIENT_TOLERANCE
+            && centroid_error.abs() <= GRADIENT_TOLERANCE
+    });
+
+    if is_accurate || depth >= MAX_SUBDIVISIONS {
+        push([a, b, c]);
+    } else {
+        subdivide([a, midpoint, c], gradient, depth + 1, push);
+        subdivide([midpoint, b, c], gradient, depth + 1, push);
+    }
+}
+
+struct LocalVertex;
+
+impl lyon::tessellation::BasicVertexConstructor<lyon::math::Point>
+    for LocalVertex
+{
+    fn new_vertex(&mut self, position: lyon::math::Point) -> lyon::math::Point {
+        position
+    }
+}
+
+impl lyon::tessellation::FillVertexConstructor<lyon::math::Point>
+    for LocalVertex
+{
+    fn new_vertex(
+        &mut self,
+        position: lyon::math::Point,
+        _attributes: lyon::tessellation::FillAttributes<'_>,
+    ) -> lyon::math::Point {
+        position
     }
 }
 
@@ -358,3 +659,91 @@ impl lyon::tessellation::StrokeVertexConstructor<triangle::Vertex2D>
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Color;
+
+    #[test]
+    fn image_regions_follow_translation_and_scale() {
+        let sprite_sheet =
+            image::Handle::from_pixels(64, 64, vec![0; 64 * 64 * 4]);
+        let second_sprite = Rectangle {
+            x: 0.5,
+            y: 0.0,
+            width: 0.5,
+            height: 0.5,
+        };
+
+        let mut frame = Frame::new(Size::new(200.0, 200.0));
+        frame.translate(Vector::new(10.0, 20.0));
+        frame.scale(2.0);
+        frame.draw_image_region(
+            sprite_sheet,
+            second_sprite,
+            Rectangle {
+                x: 5.0,
+                y: 5.0,
+                width: 32.0,
+                height: 32.0,
+            },
+        );
+
+        match frame.into_geometry().into_primitive() {
+            Primitive::Group { primitives } => match primitives.as_slice() {
+                [Primitive::Clip {
+                    bounds, content, ..
+                }] => {
+                    assert_eq!(
+                        *bounds,
+                        Rectangle {
+                            x: 20.0,
+                            y: 30.0,
+                            width: 64.0,
+                            height: 64.0,
+                        }
+                    );
+
+                    match content.as_ref() {
+                        Primitive::Image { bounds, .. } => assert_eq!(
+                            *bounds,
+                            Rectangle {
+                                x: -44.0,
+                                y: 30.0,
+                                width: 128.0,
+                                height: 128.0,
+                            }
+                        ),
+                        content => panic!("unexpected content: {:?}", content),
+                    }
+                }
+                primitives => panic!("unexpected primitives: {:?}", primitives),
+            },
+            primitive => panic!("unexpected primitive: {:?}", primitive),
+        }
+    }
+
+    #[test]
+    fn blended_drawings_keep_their_order() {
+        let square = Path::rectangle(Point::ORIGIN, Size::new(10.0, 10.0));
+
+        let mut frame = Frame::new(Size::new(100.0, 100.0));
+        frame.fill(&square, Color::BLACK);
+        frame.with_blend_mode(BlendMode::Multiply, |frame| {
+            frame.fill(&square, Color::WHITE);
+        });
+        frame.fill(&square, Color::BLACK);
+
+        match frame.into_geometry().into_primitive() {
+            Primitive::Group { primitives } => match primitives.as_slice() {
+                [Primitive::Mesh2D { .. }, Primitive::Blend {
+                    mode: BlendMode::Multiply,
+                    ..
+                }, Primitive::Mesh2D { .. }] => {}
+                primitives => panic!("unexpected primitives: {:?}", primitives),
+            },
+            primitive => panic!("unexpected primitive: {:?}", primitive),
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use iced_native::{Color, Point};
+
+/// The maximum amount of color stops of a [`Gradient`].
+///
+/// [`Gradient`]: enum.Gradient.html
+pub const MAX_STOPS: usize = 8;
+
+/// A fill that smoothly transitions between multiple colors.
+///
+/// # Example
+///
+/// ```
+/// # use iced_graphics::canvas::Gradient;
+/// # use iced_native::{Color, Point};
+/// #
+/// let sunset = Gradient::linear(Point::ORIGIN, Point::new(0.0, 100.0))
+///     .add_stop(0.0, Color::from_rgb(1.0, 0.6, 0.2))
+///     .add_stop(1.0, Color::from_rgb(0.4, 0.1, 0.5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gradient {
+    /// A gradient that progresses along the line from `start` to `end`.
+    Linear {
+        /// The point where the gradient starts.
+        start: Point,
+
+        /// The point where the gradient ends.
+        end: Point,
+
+        /// The color stops of the gradient, sorted by offset.
+        stops: [Option<ColorStop>; MAX_STOPS],
+    },
+
+    /// A gradient that progresses outwards from a `center` until a `radius`.
+    Radial {
+        /// The center of the gradient.
+        center: Point,
+
+        /// The distance from the `center` where the gradient ends.
+        radius: f32,
+
+        /// The color stops of the gradient, sorted by offset.
+        stops: [Option<ColorStop>; MAX_STOPS],
+    },
+}
+
+/// A color at a specific offset of a [`Gradient`].
+///
+/// [`Gradient`]: enum.Gradient.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorStop {
+    /// The offset of the stop, from `0.0` (start) to `1.0` (end).
+    pub offset: f32,
+
+    /// The color of the stop.
+    pub color: Color,
+}
+
+impl Gradient {
+    /// Creates a new linear [`Gradient`] from `start` to `end` without any
+    /// color stops.
+    ///
+    /// [`Gradient`]: enum.Gradient.html
+    pub fn linear(start: Point, end: Point) -> Gradient {
+        Gradient::Linear {
+            start,
+            end,
+            stops: [None; MAX_STOPS],
+        }
+    }
+
+    /// Creates a new radial [`Gradient`] with the given `center` and `radius`
+    /// without any color stops.
+    ///
+    /// [`Gradient`]: enum.Gradient.html
+    pub fn radial(center: Point, radius: f32) -> Gradient {
+        Gradient::Radial {
+            center,
+            radius,
+            stops: [None; MAX_STOPS],
+        }
+    }
+
+    /// Adds a [`ColorStop`] with the given offset and color to the
+    /// [`Gradient`].
+    ///
+    /// The offset is clamped between `0.0` and `1.0`. Stops beyond the
+    /// [`MAX_STOPS`] are ignored.
+    ///
+    /// [`ColorStop`]: struct.ColorStop.html
+    /// [`Gradient`]: enum.Gradient.html
+    /// [`MAX_STOPS`]: constant.MAX_STOPS.html
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Gradient {
+        let stops = match &mut self {
+            Gradient::Linear { stops, .. } | Gradient::Radial { stops, .. } => {
+                stops
+            }
+        };
+
+        let stop = ColorStop {
+            offset: offset.clamp(0.0, 1.0),
+            color,
+        };
+
+        if let Some(free) = stops.iter().position(Option::is_none) {
+            stops[free] = Some(stop);
+
+            // Keep stops with equal offsets in insertion order
+            stops[..=free].sort_by(|a, b| {
+                let (a, b) = (a.unwrap().offset, b.unwrap().offset);
+
+                a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal)
+            });
+        }
+
+        self
+    }
+
+    /// Returns the color of the [`Gradient`] at the given point.
+    ///
+    /// Colors between stops are interpolated in sRGB space. Points before
+    /// the first stop or after the last one take the color of that stop.
+    ///
+    /// [`Gradient`]: enum.Gradient.html
+    pub fn color_at(&self, point: Point) -> Color {
+        let (t, stops) = match self {
+            Gradient::Linear { start, end, stops } => {
+                let direction = *end - *start;
+                let length_squared =
+                    direction.x * direction.x + direction.y * direction.y;
+
+                let t = if length_squared > 0.0 {
+                    let offset = point - *start;
+
+                    (offset.x * direction.x + offset.y * direction.y)
+                        / length_squared
+                } else {
+                    0.0
+                };
+
+                (t, stops)
+            }
+            Gradient::Radial {
+                center,
+                radius,
+                stops,
+            } => {
+                let t = if *radius > 0.0 {
+                    point.distance(*center) / radius
+                } else {
+                    0.0
+                };
+
+                (t, stops)
+            }
+        };
+
+        let mut stops = stops.iter().flatten();
+
+        let mut previous = match stops.next() {
+            Some(first) => first,
+            None => return Color::TRANSPARENT,
+        };
+
+        if t <= previous.offset {
+            return previous.color;
+        }
+
+        for stop in stops {
+            if t <= stop.offset {
+                let span = stop.offset - previous.offset;
+
+                return if span > 0.0 {
+                    previous
+                        .color
+                        .lerp(stop.color, (t - previous.offset) / span)
+                } else {
+                    stop.color
+                };
+            }
+
+            previous = stop;
+        }
+
+        previous.color
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Color = Color::from_rgb(1.0, 0.0, 0.0);
+    const BLUE: Color = Color::from_rgb(0.0, 0.0, 1.0);
+
+    #[test]
+    fn linear_interpolates_between_stops() {
+        let gradient = Gradient::linear(Point::ORIGIN, Point::new(100.0, 0.0))
+            .add_stop(1.0, BLUE)
+            .add_stop(0.0, RED);
+
+        assert_eq!(gradient.color_at(Point::new(-10.0, 0.0)), RED);
+        assert_eq!(
+            gradient.color_at(Point::new(50.0, 30.0)),
+            RED.lerp(BLUE, 0.5)
+        );
+        assert_eq!(gradient.color_at(Point::new(120.0, 0.0)), BLUE);
+    }
+
+    #[test]
+    fn radial_progresses_outwards() {
+        let gradient = Gradient::radial(Point::new(50.0, 50.0), 10.0)
+            .add_stop(0.0, RED)
+            .add_stop(0.5, BLUE);
+
+        assert_eq!(gradient.color_at(Point::new(50.0, 50.0)), RED);
+        assert_eq!(gradient.color_at(Point::new(55.0, 50.0)), BLUE);
+        assert_eq!(gradient.color_at(Point::new(50.0, 90.0)), BLUE);
+    }
+}
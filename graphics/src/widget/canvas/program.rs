@@ -1,4 +1,5 @@
-use crate::canvas::{Cursor, Event, Geometry};
+use crate::canvas::event::{self, Event};
+use crate::canvas::{Cursor, Geometry};
 use iced_native::{mouse, Rectangle};
 
 /// The state and logic of a [`Canvas`].
@@ -14,6 +15,9 @@ pub trait Program<Message> {
     /// When a [`Program`] is used in a [`Canvas`], the runtime will call this
     /// method for each [`Event`].
     ///
+    /// The positions carried by the [`Event`] and the [`Cursor`] are relative
+    /// to the top-left corner of the [`Canvas`], and so are the `bounds`.
+    ///
     /// This method can optionally return a `Message` to notify an application
     /// of any meaningful interactions.
     ///
@@ -22,6 +26,7 @@ pub trait Program<Message> {
     /// [`Program`]: trait.Program.html
     /// [`Canvas`]: struct.Canvas.html
     /// [`Event`]: enum.Event.html
+    /// [`Cursor`]: enum.Cursor.html
     fn update(
         &mut self,
         _event: Event,
@@ -31,11 +36,71 @@ pub trait Program<Message> {
         None
     }
 
+    /// Processes an [`Event`] and returns whether it was captured.
+    ///
+    /// A captured [`Event`] is not handled by any other widget. Implement
+    /// this method instead of [`update`] when the [`Program`] needs to
+    /// capture events.
+    ///
+    /// By default, this method calls [`update`] and ignores the [`Event`].
+    ///
+    /// [`Event`]: enum.Event.html
+    /// [`update`]: #method.update
+    /// [`Program`]: trait.Program.html
+    fn on_event(
+        &mut self,
+        event: Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> (event::Status, Option<Message>) {
+        (event::Status::Ignored, self.update(event, bounds, cursor))
+    }
+
     /// Draws the state of the [`Program`], producing a bunch of [`Geometry`].
     ///
     /// [`Geometry`] can be easily generated with a [`Frame`] or stored in a
     /// [`Cache`].
     ///
+    /// Every [`Geometry`] returned is a layer, drawn on top of the previous
+    /// ones. Each layer can be stored in its own [`Cache`], so that only the
+    /// layers that change are tessellated again:
+    ///
+    /// ```no_run
+    /// # mod iced {
+    /// #     pub use iced_graphics::canvas;
+    /// #     pub use iced_native::{Color, Point, Rectangle};
+    /// # }
+    /// use iced::canvas::{Cache, Cursor, Geometry, Path, Program};
+    /// use iced::{Color, Point, Rectangle};
+    ///
+    /// struct Chart {
+    ///     // Only cleared when the axes change
+    ///     grid: Cache,
+    ///     // Cleared every time a new point is added
+    ///     data: Cache,
+    ///     points: Vec<Point>,
+    /// }
+    ///
+    /// impl Program<()> for Chart {
+    ///     fn draw(&self, bounds: Rectangle, _cursor: Cursor) -> Vec<Geometry> {
+    ///         let grid = self.grid.draw(bounds.size(), |frame| {
+    ///             let background = Path::rectangle(Point::ORIGIN, frame.size());
+    ///
+    ///             frame.fill(&background, Color::WHITE);
+    ///         });
+    ///
+    ///         let data = self.data.draw(bounds.size(), |frame| {
+    ///             for point in &self.points {
+    ///                 frame.fill(&Path::circle(*point, 2.0), Color::BLACK);
+    ///             }
+    ///         });
+    ///
+    ///         // The data is drawn on top of the grid
+    ///         vec![grid, data]
+    ///     }
+    /// }
+    /// ```
+    ///
     /// [`Program`]: trait.Program.html
     /// [`Geometry`]: struct.Geometry.html
     /// [`Frame`]: struct.Frame.html
@@ -56,6 +121,18 @@ pub trait Program<Message> {
     ) -> mouse::Interaction {
         mouse::Interaction::default()
     }
+
+    /// Returns whether the [`Program`] is animating.
+    ///
+    /// While animating, the [`Program`] is drawn again on the next frame even
+    /// if no event arrives.
+    ///
+    /// By default, a [`Program`] is never animating.
+    ///
+    /// [`Program`]: trait.Program.html
+    fn is_animating(&self) -> bool {
+        false
+    }
 }
 
 impl<T, Message> Program<Message> for &mut T
@@ -71,6 +148,15 @@ where
         T::update(self, event, bounds, cursor)
     }
 
+    fn on_event(
+        &mut self,
+        event: Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> (event::Status, Option<Message>) {
+        T::on_event(self, event, bounds, cursor)
+    }
+
     fn draw(&self, bounds: Rectangle, cursor: Cursor) -> Vec<Geometry> {
         T::draw(self, bounds, cursor)
     }
@@ -82,4 +168,8 @@ where
     ) -> mouse::Interaction {
         T::mouse_interaction(self, bounds, cursor)
     }
+
+    fn is_animating(&self) -> bool {
+        T::is_animating(self)
+    }
 }
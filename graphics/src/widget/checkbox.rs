@@ -1,17 +1,20 @@
 //! Show toggle controls using checkboxes.
 use crate::backend::{self, Backend};
+use crate::widget::focus;
 use crate::{Primitive, Renderer};
+use iced_native::animation;
 use iced_native::checkbox;
 use iced_native::mouse;
-use iced_native::{HorizontalAlignment, Rectangle, VerticalAlignment};
+use iced_native::{Font, HorizontalAlignment, Rectangle, VerticalAlignment};
 
+pub use iced_native::checkbox::{Icon, State};
 pub use iced_style::checkbox::{Style, StyleSheet};
 
 /// A box that can be checked.
 ///
 /// This is an alias of an `iced_native` checkbox with an `iced_wgpu::Renderer`.
-pub type Checkbox<Message, Backend> =
-    iced_native::Checkbox<Message, Renderer<Backend>>;
+pub type Checkbox<'a, Message, Backend> =
+    iced_native::Checkbox<'a, Message, Renderer<Backend>>;
 
 impl<B> checkbox::Renderer for Renderer<B>
 where
@@ -26,16 +29,28 @@ where
         &mut self,
         bounds: Rectangle,
         is_checked: bool,
+        transition: f32,
         is_mouse_over: bool,
+        is_focused: bool,
+        icon: Option<&checkbox::Icon>,
         (label, _): Self::Output,
         style_sheet: &Self::Style,
     ) -> Self::Output {
+        let style_sheet = if style_sheet.follows_theme() {
+            self.theme().checkbox
+        } else {
+            &**style_sheet
+        };
+
         let style = if is_mouse_over {
             style_sheet.hovered(is_checked)
         } else {
             style_sheet.active(is_checked)
         };
 
+        let checkmark = (B::CHECKMARK_ICON, B::ICON_FONT);
+        let glyph = |is_checked| glyph(icon, is_checked, checkmark);
+
         let checkbox = Primitive::Quad {
             bounds,
             background: style.background,
@@ -44,28 +59,48 @@ where
             border_color: style.border_color,
         };
 
+        let checkbox = if is_focused {
+            focus::ring(
+                checkbox,
+                bounds,
+                style.border_radius,
+                style_sheet.focus_ring_color(),
+            )
+        } else {
+            checkbox
+        };
+
+        // The glyph of the previous state shrinks while the current one grows
+        let scale = animation::ease_out(transition);
+        let glyphs = [
+            (glyph(!is_checked), 1.0 - scale),
+            (glyph(is_checked), scale),
+        ];
+
+        let mut primitives = vec![checkbox];
+
+        for (glyph, scale) in glyphs.iter().filter(|(_, scale)| *scale > 0.0) {
+            if let Some((glyph, font)) = glyph {
+                primitives.push(Primitive::Text {
+                    content: glyph.to_string(),
+                    font: *font,
+                    size: bounds.height * 0.7 * scale,
+                    bounds: Rectangle {
+                        x: bounds.center_x(),
+                        y: bounds.center_y(),
+                        ..bounds
+                    },
+                    color: style.checkmark_color,
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+            }
+        }
+
+        primitives.push(label);
+
         (
-            Primitive::Group {
-                primitives: if is_checked {
-                    let check = Primitive::Text {
-                        content: B::CHECKMARK_ICON.to_string(),
-                        font: B::ICON_FONT,
-                        size: bounds.height * 0.7,
-                        bounds: Rectangle {
-                            x: bounds.center_x(),
-                            y: bounds.center_y(),
-                            ..bounds
-                        },
-                        color: style.checkmark_color,
-                        horizontal_alignment: HorizontalAlignment::Center,
-                        vertical_alignment: VerticalAlignment::Center,
-                    };
-
-                    vec![checkbox, check, label]
-                } else {
-                    vec![checkbox, label]
-                },
-            },
+            Primitive::Group { primitives },
             if is_mouse_over {
                 mouse::Interaction::Pointer
             } else {
@@ -74,3 +109,56 @@ where
         )
     }
 }
+
+/// Returns the glyph displayed inside a checkbox with the given [`Icon`], if
+/// any, falling back to the given checkmark.
+///
+/// [`Icon`]: struct.Icon.html
+fn glyph(
+    icon: Option<&Icon>,
+    is_checked: bool,
+    checkmark: (char, Font),
+) -> Option<(char, Font)> {
+    match icon {
+        Some(icon) if is_checked => Some((icon.checked, icon.font)),
+        Some(icon) => icon.unchecked.map(|glyph| (glyph, icon.font)),
+        None if is_checked => Some(checkmark),
+        None => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHECKMARK: (char, Font) = ('✓', Font::Default);
+
+    fn glyph_char(icon: Option<&Icon>, is_checked: bool) -> Option<char> {
+        glyph(icon, is_checked, CHECKMARK).map(|(glyph, _)| glyph)
+    }
+
+    #[test]
+    fn icon_glyphs_replace_the_checkmark() {
+        let icon = Icon {
+            font: Font::Default,
+            checked: '●',
+            unchecked: Some('○'),
+        };
+
+        assert_eq!(glyph_char(Some(&icon), true), Some('●'));
+        assert_eq!(glyph_char(Some(&icon), false), Some('○'));
+    }
+
+    #[test]
+    fn unchecked_glyph_is_optional() {
+        let icon = Icon {
+            font: Font::Default,
+            checked: '●',
+            unchecked: None,
+        };
+
+        assert_eq!(glyph_char(Some(&icon), false), None);
+        assert_eq!(glyph_char(None, false), None);
+        assert_eq!(glyph_char(None, true), Some('✓'));
+    }
+}
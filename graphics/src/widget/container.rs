@@ -2,7 +2,11 @@
 use crate::container;
 use crate::defaults::{self, Defaults};
 use crate::{Backend, Primitive, Renderer};
-use iced_native::{Background, Color, Element, Layout, Point, Rectangle};
+use iced_native::image;
+use iced_native::{
+    Background, Color, ContentFit, Element, Layout, Point, Rectangle, Size,
+    Vector,
+};
 
 pub use iced_style::container::{Style, StyleSheet};
 
@@ -25,9 +29,17 @@ where
         bounds: Rectangle,
         cursor_position: Point,
         style_sheet: &Self::Style,
+        background_image: Option<(&image::Handle, ContentFit)>,
+        clip: bool,
         content: &Element<'_, Message, Self>,
         content_layout: Layout<'_>,
     ) -> Self::Output {
+        let style_sheet = if style_sheet.follows_theme() {
+            self.theme().container
+        } else {
+            &**style_sheet
+        };
+
         let style = style_sheet.style();
 
         let defaults = Defaults {
@@ -39,15 +51,50 @@ where
         let (content, mouse_interaction) =
             content.draw(self, &defaults, content_layout, cursor_position);
 
-        if let Some(background) = background(bounds, &style) {
-            (
-                Primitive::Group {
-                    primitives: vec![background, content],
-                },
-                mouse_interaction,
-            )
+        let content = if clip {
+            Primitive::Clip {
+                bounds,
+                offset: Vector::new(0, 0),
+                content: Box::new(content),
+            }
         } else {
+            content
+        };
+
+        let background_image = background_image.map(|(handle, fit)| {
+            let size = match self.backend().image_dimensions(handle) {
+                Some((width, height)) => fit
+                    .fit(Size::new(width as f32, height as f32), bounds.size()),
+                None => bounds.size(),
+            };
+
+            Primitive::Clip {
+                bounds,
+                offset: Vector::new(0, 0),
+                content: Box::new(Primitive::Image {
+                    handle: handle.clone(),
+                    bounds: Rectangle {
+                        x: bounds.x + (bounds.width - size.width) / 2.0,
+                        y: bounds.y + (bounds.height - size.height) / 2.0,
+                        width: size.width,
+                        height: size.height,
+                    },
+                    border_radius: style.border_radius,
+                }),
+            }
+        });
+
+        let mut primitives: Vec<_> = background(bounds, &style)
+            .into_iter()
+            .chain(background_image)
+            .collect();
+
+        if primitives.is_empty() {
             (content, mouse_interaction)
+        } else {
+            primitives.push(content);
+
+            (Primitive::Group { primitives }, mouse_interaction)
         }
     }
 }
@@ -56,8 +103,10 @@ pub(crate) fn background(
     bounds: Rectangle,
     style: &container::Style,
 ) -> Option<Primitive> {
-    if style.background.is_some() || style.border_width > 0 {
-        Some(Primitive::Quad {
+    let has_shadow = style.shadow_color.a > 0.0;
+
+    if style.background.is_some() || style.border_width > 0 || has_shadow {
+        let background = Primitive::Quad {
             bounds,
             background: style
                 .background
@@ -65,8 +114,142 @@ pub(crate) fn background(
             border_radius: style.border_radius,
             border_width: style.border_width,
             border_color: style.border_color,
-        })
+        };
+
+        if has_shadow {
+            Some(Primitive::Group {
+                primitives: vec![shadow(bounds, style), background],
+            })
+        } else {
+            Some(background)
+        }
     } else {
         None
     }
 }
+
+/// The maximum amount of quads used to draw the shadow of a [`Container`].
+///
+/// [`Container`]: type.Container.html
+const MAX_SHADOW_LAYERS: u16 = 8;
+
+/// Produces a soft shadow for the given bounds by stacking translucent quads
+/// that shrink towards the shadow center.
+///
+/// The quads are evenly spread over the blur radius, and their alpha is split
+/// so that the center of the shadow, where all of them overlap, ends up with
+/// the alpha of the shadow color.
+fn shadow(bounds: Rectangle, style: &container::Style) -> Primitive {
+    let blur_radius = f32::from(style.shadow_blur_radius);
+    let layers = (style.shadow_blur_radius + 1).min(MAX_SHADOW_LAYERS);
+
+    let color = Color {
+        a: layer_alpha(style.shadow_color.a, layers),
+        ..style.shadow_color
+    };
+
+    let offset_bounds = bounds + style.shadow_offset;
+
+    let primitives = (0..layers)
+        .map(|layer| {
+            let expansion = if layers > 1 {
+                blur_radius * f32::from(layers - 1 - layer)
+                    / f32::from(layers - 1)
+            } else {
+                0.0
+            };
+
+            Primitive::Quad {
+                bounds: Rectangle {
+                    x: offset_bounds.x - expansion,
+                    y: offset_bounds.y - expansion,
+                    width: offset_bounds.width + expansion * 2.0,
+                    height: offset_bounds.height + expansion * 2.0,
+                },
+                background: Background::Color(color),
+                border_radius: style.border_radius + expansion.round() as u16,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            }
+        })
+        .collect();
+
+    Primitive::Group { primitives }
+}
+
+/// Returns the alpha of each of the given amount of stacked layers, so that
+/// blending all of them produces the given alpha.
+fn layer_alpha(alpha: f32, layers: u16) -> f32 {
+    1.0 - (1.0 - alpha).powf(1.0 / f32::from(layers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_native::Vector;
+
+    fn quads(primitive: Primitive) -> Vec<(Rectangle, Color)> {
+        match primitive {
+            Primitive::Group { primitives } => primitives
+                .into_iter()
+                .map(|primitive| match primitive {
+                    Primitive::Quad {
+                        bounds,
+                        background: Background::Color(color),
+                        ..
+                    } => (bounds, color),
+                    _ => panic!("expected a quad"),
+                })
+                .collect(),
+            _ => panic!("expected a group"),
+        }
+    }
+
+    fn style(blur_radius: u16) -> container::Style {
+        container::Style {
+            shadow_offset: Vector::new(0.0, 2.0),
+            shadow_blur_radius: blur_radius,
+            shadow_color: Color::from_rgba(0.0, 0.0, 0.0, 0.5),
+            ..container::Style::default()
+        }
+    }
+
+    #[test]
+    fn shadow_layers_are_limited() {
+        let bounds = Rectangle {
+            x: 100.0,
+            y: 100.0,
+            width: 50.0,
+            height: 50.0,
+        };
+
+        let quads = quads(shadow(bounds, &style(1000)));
+
+        assert_eq!(quads.len(), usize::from(MAX_SHADOW_LAYERS));
+
+        // The outermost layer still covers the whole blur radius
+        assert_eq!(quads[0].0.x, -900.0);
+        assert_eq!(quads[0].0.y, -898.0);
+        assert_eq!(quads[quads.len() - 1].0, bounds + Vector::new(0.0, 2.0));
+    }
+
+    #[test]
+    fn shadow_center_has_the_shadow_alpha() {
+        let bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 10.0,
+            height: 10.0,
+        };
+
+        for blur_radius in &[0, 3, 20] {
+            let center = quads(shadow(bounds, &style(*blur_radius)))
+                .into_iter()
+                .fold(0.0, |alpha, (_, color)| {
+                    color.a + alpha * (1.0 - color.a)
+                });
+
+            assert!((center - 0.5).abs() < 1e-5, "{}", center);
+        }
+    }
+}
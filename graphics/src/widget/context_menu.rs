@@ -0,0 +1,10 @@
+//! Show a menu of actions when some content is right-clicked.
+use crate::Renderer;
+
+pub use iced_native::context_menu::State;
+pub use iced_style::menu::Style;
+
+/// An element that opens a menu of actions at the cursor when its content
+/// is right-clicked.
+pub type ContextMenu<'a, T, Message, Backend> =
+    iced_native::ContextMenu<'a, T, Message, Renderer<Backend>>;
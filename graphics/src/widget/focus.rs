@@ -0,0 +1,32 @@
+//! Draw the focus ring around focused widgets.
+use crate::Primitive;
+use iced_native::{Background, Color, Rectangle};
+
+/// The width of a focus ring.
+const WIDTH: f32 = 2.0;
+
+/// Surrounds the given primitive with a focus ring around its bounds,
+/// following its border radius.
+pub(crate) fn ring(
+    primitive: Primitive,
+    bounds: Rectangle,
+    border_radius: u16,
+    color: Color,
+) -> Primitive {
+    let ring = Primitive::Quad {
+        bounds: Rectangle {
+            x: bounds.x - WIDTH,
+            y: bounds.y - WIDTH,
+            width: bounds.width + WIDTH * 2.0,
+            height: bounds.height + WIDTH * 2.0,
+        },
+        background: Background::Color(Color::TRANSPARENT),
+        border_radius: border_radius + WIDTH as u16,
+        border_width: WIDTH as u16,
+        border_color: color,
+    };
+
+    Primitive::Group {
+        primitives: vec![ring, primitive],
+    }
+}
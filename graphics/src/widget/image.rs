@@ -5,7 +5,7 @@ use iced_native::image;
 use iced_native::mouse;
 use iced_native::Layout;
 
-pub use iced_native::image::{Handle, Image};
+pub use iced_native::image::{Handle, Image, Status};
 
 impl<B> image::Renderer for Renderer<B>
 where
@@ -18,12 +18,14 @@ where
     fn draw(
         &mut self,
         handle: image::Handle,
+        border_radius: u16,
         layout: Layout<'_>,
     ) -> Self::Output {
         (
             Primitive::Image {
                 handle,
                 bounds: layout.bounds(),
+                border_radius,
             },
             mouse::Interaction::default(),
         )
@@ -0,0 +1,44 @@
+//! Write paragraphs of text with clickable links.
+use crate::backend::{self, Backend};
+use crate::Renderer;
+use iced_native::linked_text;
+use iced_native::mouse;
+use iced_native::rich_text::{self, Span};
+use iced_native::{Font, HorizontalAlignment, Rectangle, VerticalAlignment};
+
+impl<B> linked_text::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    fn draw(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        spans: &[Span<Font>],
+        size: u16,
+        is_link_hovered: bool,
+    ) -> Self::Output {
+        // The lines are already wrapped, so the backend must not wrap them
+        let (primitive, _) = rich_text::Renderer::draw(
+            self,
+            defaults,
+            Rectangle {
+                width: f32::INFINITY,
+                ..bounds
+            },
+            spans,
+            size,
+            HorizontalAlignment::Left,
+            VerticalAlignment::Top,
+        );
+
+        (
+            primitive,
+            if is_link_hovered {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
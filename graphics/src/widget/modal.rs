@@ -0,0 +1,55 @@
+//! Display content on top of a dimmed backdrop, capturing all input.
+use crate::defaults::Defaults;
+use crate::{Backend, Primitive, Renderer};
+use iced_native::{Color, Element, Layout, Point, Rectangle};
+
+pub use iced_style::modal::{Style, StyleSheet};
+
+/// An element that can show some content centered in the viewport, on top of
+/// a backdrop covering everything else.
+///
+/// This is an alias of an `iced_native` modal with a default `Renderer`.
+pub type Modal<'a, Message, Backend> =
+    iced_native::Modal<'a, Message, Renderer<Backend>>;
+
+impl<B> iced_native::modal::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw<Message>(
+        &mut self,
+        defaults: &Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        style_sheet: &Self::Style,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output {
+        let style = if style_sheet.follows_theme() {
+            self.theme().modal.style()
+        } else {
+            style_sheet.style()
+        };
+
+        let (content, mouse_interaction) =
+            content.draw(self, defaults, content_layout, cursor_position);
+
+        (
+            Primitive::Group {
+                primitives: vec![
+                    Primitive::Quad {
+                        bounds,
+                        background: style.backdrop,
+                        border_radius: 0,
+                        border_width: 0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    content,
+                ],
+            },
+            mouse_interaction,
+        )
+    }
+}
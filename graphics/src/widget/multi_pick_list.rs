@@ -0,0 +1,11 @@
+//! Display a dropdown list of values to select any number of.
+use crate::Renderer;
+
+pub use iced_native::multi_pick_list::State;
+pub use iced_native::pick_list::MenuWidth;
+pub use iced_style::pick_list::{Style, StyleSheet};
+
+/// A widget allowing the selection of any number of values from a list of
+/// options.
+pub type MultiPickList<'a, T, Message, Backend> =
+    iced_native::MultiPickList<'a, T, Message, Renderer<Backend>>;
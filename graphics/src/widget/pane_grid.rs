@@ -133,6 +133,12 @@ where
         body: (&Element<'_, Message, Self>, Layout<'_>),
         cursor_position: Point,
     ) -> Self::Output {
+        let style_sheet = if style_sheet.follows_theme() {
+            self.theme().container
+        } else {
+            &**style_sheet
+        };
+
         let style = style_sheet.style();
         let (body, body_layout) = body;
 
@@ -196,6 +202,12 @@ where
         controls: Option<(&Element<'_, Message, Self>, Layout<'_>)>,
         cursor_position: Point,
     ) -> Self::Output {
+        let style_sheet = if style_sheet.follows_theme() {
+            self.theme().container
+        } else {
+            &**style_sheet
+        };
+
         let style = style_sheet.style();
 
         let defaults = Self::Defaults {
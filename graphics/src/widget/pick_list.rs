@@ -6,7 +6,7 @@ use iced_native::{
 };
 use iced_style::menu;
 
-pub use iced_native::pick_list::State;
+pub use iced_native::pick_list::{Group, Icon, MenuWidth, State};
 pub use iced_style::pick_list::{Style, StyleSheet};
 
 /// A widget allowing the selection of a single value from a list of options.
@@ -33,14 +33,21 @@ where
         padding: u16,
         text_size: u16,
         font: Font,
+        icon: Option<&Icon<Font>>,
         style: &Box<dyn StyleSheet>,
     ) -> Self::Output {
+        let sheet = if style.follows_theme() {
+            self.theme().pick_list
+        } else {
+            &**style
+        };
+
         let is_mouse_over = bounds.contains(cursor_position);
 
         let style = if is_mouse_over {
-            style.hovered()
+            sheet.hovered()
         } else {
-            style.active()
+            sheet.active()
         };
 
         let background = Primitive::Quad {
@@ -51,19 +58,12 @@ where
             border_radius: style.border_radius,
         };
 
-        let arrow_down = Primitive::Text {
-            content: B::ARROW_DOWN_ICON.to_string(),
+        let icon = icon.copied().unwrap_or(Icon {
             font: B::ICON_FONT,
-            size: bounds.height * style.icon_size,
-            bounds: Rectangle {
-                x: bounds.x + bounds.width - f32::from(padding) * 2.0,
-                y: bounds.center_y(),
-                ..bounds
-            },
-            color: style.text_color,
-            horizontal_alignment: HorizontalAlignment::Right,
-            vertical_alignment: VerticalAlignment::Center,
-        };
+            code_point: B::ARROW_DOWN_ICON,
+        });
+
+        let arrow_down = arrow_down(bounds, padding, icon, sheet, &style);
 
         (
             Primitive::Group {
@@ -95,3 +95,86 @@ where
         )
     }
 }
+
+/// Produces the dropdown arrow of a pick list with the given [`Icon`].
+///
+/// [`Icon`]: struct.Icon.html
+fn arrow_down(
+    bounds: Rectangle,
+    padding: u16,
+    icon: Icon<Font>,
+    sheet: &dyn StyleSheet,
+    style: &Style,
+) -> Primitive {
+    Primitive::Text {
+        content: icon.code_point.to_string(),
+        font: icon.font,
+        size: bounds.height * style.icon_size,
+        bounds: Rectangle {
+            x: bounds.x + bounds.width - f32::from(padding) * 2.0,
+            y: bounds.center_y(),
+            ..bounds
+        },
+        color: sheet.icon_color(style),
+        horizontal_alignment: HorizontalAlignment::Right,
+        vertical_alignment: VerticalAlignment::Center,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_native::Color;
+
+    struct Highlighted;
+
+    impl StyleSheet for Highlighted {
+        fn menu(&self) -> menu::Style {
+            menu::Style::default()
+        }
+
+        fn active(&self) -> Style {
+            Style::default()
+        }
+
+        fn hovered(&self) -> Style {
+            Style::default()
+        }
+
+        fn icon_color(&self, _style: &Style) -> Color {
+            Color::WHITE
+        }
+    }
+
+    #[test]
+    fn arrow_uses_the_icon_and_its_color() {
+        let icon = Icon {
+            font: Font::Default,
+            code_point: '▾',
+        };
+        let bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 100.0,
+            height: 20.0,
+        };
+
+        match arrow_down(bounds, 5, icon, &Highlighted, &Style::default()) {
+            Primitive::Text { content, color, .. } => {
+                assert_eq!(content, "▾");
+                assert_eq!(color, Color::WHITE);
+            }
+            _ => panic!("expected a text"),
+        }
+
+        // The arrow has the color of the text by default
+        let sheet: Box<dyn StyleSheet> = Default::default();
+
+        match arrow_down(bounds, 5, icon, &*sheet, &Style::default()) {
+            Primitive::Text { color, .. } => {
+                assert_eq!(color, Style::default().text_color);
+            }
+            _ => panic!("expected a text"),
+        }
+    }
+}
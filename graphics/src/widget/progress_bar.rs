@@ -17,6 +17,8 @@ pub use iced_style::progress_bar::{Style, StyleSheet};
 /// `iced_wgpu::Renderer`.
 pub type ProgressBar<Backend> = iced_native::ProgressBar<Renderer<Backend>>;
 
+const SEGMENT_GAP: f32 = 2.0;
+
 impl<B> progress_bar::Renderer for Renderer<B>
 where
     B: Backend,
@@ -30,13 +32,29 @@ where
         bounds: Rectangle,
         range: std::ops::RangeInclusive<f32>,
         value: f32,
+        segments: Option<u16>,
         style_sheet: &Self::Style,
     ) -> Self::Output {
+        let style_sheet = if style_sheet.follows_theme() {
+            self.theme().progress_bar
+        } else {
+            &**style_sheet
+        };
+
         let style = style_sheet.style();
 
         let (range_start, range_end) = range.into_inner();
-        let active_progress_width = bounds.width
-            * ((value - range_start) / (range_end - range_start).max(1.0));
+        let amount_filled =
+            (value - range_start) / (range_end - range_start).max(1.0);
+
+        if let Some(segments) = segments {
+            return (
+                segmented(bounds, amount_filled, segments, &style),
+                mouse::Interaction::default(),
+            );
+        }
+
+        let active_progress_width = bounds.width * amount_filled;
 
         let background = Primitive::Group {
             primitives: vec![Primitive::Quad {
@@ -71,3 +89,84 @@ where
         )
     }
 }
+
+/// Produces the given amount of segments of a progress bar, filling the
+/// ones that are completely covered by the filled amount.
+fn segmented(
+    bounds: Rectangle,
+    amount_filled: f32,
+    segments: u16,
+    style: &Style,
+) -> Primitive {
+    let segment_width = (bounds.width - SEGMENT_GAP * f32::from(segments - 1))
+        / f32::from(segments);
+
+    let filled_segments = (amount_filled * f32::from(segments)).floor() as u16;
+
+    let primitives = (0..segments)
+        .map(|i| Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds.x + f32::from(i) * (segment_width + SEGMENT_GAP),
+                width: segment_width,
+                ..bounds
+            },
+            background: if i < filled_segments {
+                style.bar
+            } else {
+                style.background
+            },
+            border_radius: style.border_radius,
+            border_width: 0,
+            border_color: Color::TRANSPARENT,
+        })
+        .collect();
+
+    Primitive::Group { primitives }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_native::Background;
+
+    #[test]
+    fn whole_segments_are_filled() {
+        let style = Style {
+            background: Background::Color(Color::WHITE),
+            bar: Background::Color(Color::BLACK),
+            border_radius: 0,
+        };
+        let bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 108.0,
+            height: 10.0,
+        };
+
+        let segments = match segmented(bounds, 0.5, 5, &style) {
+            Primitive::Group { primitives } => primitives,
+            _ => panic!("expected a group"),
+        };
+
+        let filled: Vec<(f32, bool)> = segments
+            .into_iter()
+            .map(|segment| match segment {
+                Primitive::Quad {
+                    bounds, background, ..
+                } => (bounds.x, background == style.bar),
+                _ => panic!("expected a quad"),
+            })
+            .collect();
+
+        assert_eq!(
+            filled,
+            vec![
+                (0.0, true),
+                (22.0, true),
+                (44.0, false),
+                (66.0, false),
+                (88.0, false)
+            ]
+        );
+    }
+}
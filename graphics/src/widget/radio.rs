@@ -1,9 +1,12 @@
 //! Create choices using radio buttons.
+use crate::widget::focus;
 use crate::{Backend, Primitive, Renderer};
+use iced_native::animation;
 use iced_native::mouse;
 use iced_native::radio;
 use iced_native::{Background, Color, Rectangle};
 
+pub use iced_native::radio::{Orientation, State};
 pub use iced_style::radio::{Style, StyleSheet};
 
 /// A circular button representing a choice.
@@ -13,6 +16,13 @@ pub use iced_style::radio::{Style, StyleSheet};
 pub type Radio<Message, Backend> =
     iced_native::Radio<Message, Renderer<Backend>>;
 
+/// A group of radio buttons that can be navigated with the arrow keys.
+///
+/// This is an alias of an `iced_native` radio group with an
+/// `iced_wgpu::Renderer`.
+pub type RadioGroup<'a, Message, Backend> =
+    iced_native::RadioGroup<'a, Message, Renderer<Backend>>;
+
 impl<B> radio::Renderer for Renderer<B>
 where
     B: Backend,
@@ -26,10 +36,18 @@ where
         &mut self,
         bounds: Rectangle,
         is_selected: bool,
+        transition: f32,
         is_mouse_over: bool,
+        is_focused: bool,
         (label, _): Self::Output,
         style_sheet: &Self::Style,
     ) -> Self::Output {
+        let style_sheet = if style_sheet.follows_theme() {
+            self.theme().radio
+        } else {
+            &**style_sheet
+        };
+
         let style = if is_mouse_over {
             style_sheet.hovered()
         } else {
@@ -47,15 +65,32 @@ where
             border_color: style.border_color,
         };
 
+        let radio = if is_focused {
+            focus::ring(
+                radio,
+                bounds,
+                (size / 2.0) as u16,
+                style_sheet.focus_ring_color(),
+            )
+        } else {
+            radio
+        };
+
+        // The dot grows when selected and shrinks when deselected
+        let scale = animation::ease_out(transition);
+        let scale = if is_selected { scale } else { 1.0 - scale };
+
         (
             Primitive::Group {
-                primitives: if is_selected {
+                primitives: if scale > 0.0 {
+                    let dot_size = dot_size * scale;
+
                     let radio_circle = Primitive::Quad {
                         bounds: Rectangle {
-                            x: bounds.x + dot_size / 2.0,
-                            y: bounds.y + dot_size / 2.0,
-                            width: bounds.width - dot_size,
-                            height: bounds.height - dot_size,
+                            x: bounds.center_x() - dot_size / 2.0,
+                            y: bounds.center_y() - dot_size / 2.0,
+                            width: dot_size,
+                            height: dot_size,
                         },
                         background: Background::Color(style.dot_color),
                         border_radius: (dot_size / 2.0) as u16,
@@ -75,4 +110,24 @@ where
             },
         )
     }
+
+    fn draw_group(&mut self, radios: Vec<Self::Output>) -> Self::Output {
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        (
+            Primitive::Group {
+                primitives: radios
+                    .into_iter()
+                    .map(|(primitive, new_mouse_interaction)| {
+                        if new_mouse_interaction > mouse_interaction {
+                            mouse_interaction = new_mouse_interaction;
+                        }
+
+                        primitive
+                    })
+                    .collect(),
+            },
+            mouse_interaction,
+        )
+    }
 }
@@ -0,0 +1,65 @@
+//! Write paragraphs of text made of differently styled spans.
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::rich_text;
+use iced_native::{
+    Font, HorizontalAlignment, Rectangle, Size, VerticalAlignment,
+};
+
+pub use iced_native::rich_text::Span;
+
+/// A paragraph of text made of multiple [`Span`]s.
+///
+/// This is an alias of an `iced_native` rich text with an `iced_wgpu::Renderer`.
+///
+/// [`Span`]: struct.Span.html
+pub type RichText<Backend> = iced_native::RichText<Renderer<Backend>>;
+
+impl<B> rich_text::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    fn measure_spans(
+        &self,
+        spans: &[Span<Font>],
+        size: u16,
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.backend().measure_spans(spans, f32::from(size), bounds)
+    }
+
+    fn draw(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        spans: &[Span<Font>],
+        size: u16,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+    ) -> Self::Output {
+        let x = match horizontal_alignment {
+            HorizontalAlignment::Left => bounds.x,
+            HorizontalAlignment::Center => bounds.center_x(),
+            HorizontalAlignment::Right => bounds.x + bounds.width,
+        };
+
+        let y = match vertical_alignment {
+            VerticalAlignment::Top => bounds.y,
+            VerticalAlignment::Center => bounds.center_y(),
+            VerticalAlignment::Bottom => bounds.y + bounds.height,
+        };
+
+        (
+            Primitive::RichText {
+                spans: spans.to_vec(),
+                bounds: Rectangle { x, y, ..bounds },
+                color: defaults.text.color,
+                size: f32::from(size),
+                horizontal_alignment,
+                vertical_alignment,
+            },
+            mouse::Interaction::default(),
+        )
+    }
+}
@@ -24,6 +24,12 @@ where
         style_sheet: &Self::Style,
         is_horizontal: bool,
     ) -> Self::Output {
+        let style_sheet = if style_sheet.follows_theme() {
+            self.theme().rule
+        } else {
+            &**style_sheet
+        };
+
         let style = style_sheet.style();
 
         let line = if is_horizontal {
@@ -4,7 +4,7 @@ use iced_native::mouse;
 use iced_native::scrollable;
 use iced_native::{Background, Color, Rectangle, Vector};
 
-pub use iced_native::scrollable::State;
+pub use iced_native::scrollable::{AutoHide, Side, State};
 pub use iced_style::scrollable::{Scrollbar, Scroller, StyleSheet};
 
 /// A widget that can vertically display an infinite amount of content
@@ -69,10 +69,17 @@ where
         is_mouse_over: bool,
         is_mouse_over_scrollbar: bool,
         scrollbar: Option<scrollable::Scrollbar>,
+        scrollbar_alpha: f32,
         offset: u32,
         style_sheet: &Self::Style,
         (content, mouse_interaction): Self::Output,
     ) -> Self::Output {
+        let style_sheet = if style_sheet.follows_theme() {
+            self.theme().scrollable
+        } else {
+            &**style_sheet
+        };
+
         (
             if let Some(scrollbar) = scrollbar {
                 let clip = Primitive::Clip {
@@ -92,7 +99,11 @@ where
                 let is_scrollbar_visible =
                     style.background.is_some() || style.border_width > 0;
 
-                let scroller = if is_mouse_over
+                let style = fade(style, scrollbar_alpha);
+
+                let scroller = if scrollbar_alpha <= 0.0 {
+                    Primitive::None
+                } else if is_mouse_over
                     || state.is_scroller_grabbed()
                     || is_scrollbar_visible
                 {
@@ -107,7 +118,8 @@ where
                     Primitive::None
                 };
 
-                let scrollbar = if is_scrollbar_visible {
+                let scrollbar = if is_scrollbar_visible && scrollbar_alpha > 0.0
+                {
                     Primitive::Quad {
                         bounds: Rectangle {
                             x: scrollbar.bounds.x + f32::from(SCROLLBAR_MARGIN),
@@ -140,3 +152,27 @@ where
         )
     }
 }
+
+fn fade(style: Scrollbar, alpha: f32) -> Scrollbar {
+    if alpha >= 1.0 {
+        return style;
+    }
+
+    let fade_color = |color: Color| Color {
+        a: color.a * alpha,
+        ..color
+    };
+
+    Scrollbar {
+        background: style.background.map(|background| match background {
+            Background::Color(color) => Background::Color(fade_color(color)),
+        }),
+        border_color: fade_color(style.border_color),
+        scroller: Scroller {
+            color: fade_color(style.scroller.color),
+            border_color: fade_color(style.scroller.border_color),
+            ..style.scroller
+        },
+        ..style
+    }
+}
@@ -9,7 +9,7 @@ use iced_native::mouse;
 use iced_native::slider;
 use iced_native::{Background, Color, Point, Rectangle};
 
-pub use iced_native::slider::State;
+pub use iced_native::slider::{Scale, State};
 pub use iced_style::slider::{Handle, HandleShape, Style, StyleSheet};
 
 /// An horizontal bar and a handle that selects a single value from a range of
@@ -36,6 +36,12 @@ where
         is_dragging: bool,
         style_sheet: &Self::Style,
     ) -> Self::Output {
+        let style_sheet = if style_sheet.follows_theme() {
+            self.theme().slider
+        } else {
+            &**style_sheet
+        };
+
         let is_mouse_over = bounds.contains(cursor_position);
 
         let style = if is_dragging {
@@ -77,18 +83,8 @@ where
 
         let (range_start, range_end) = range.into_inner();
 
-        let (handle_width, handle_height, handle_border_radius) = match style
-            .handle
-            .shape
-        {
-            HandleShape::Circle { radius } => {
-                (f32::from(radius * 2), f32::from(radius * 2), radius)
-            }
-            HandleShape::Rectangle {
-                width,
-                border_radius,
-            } => (f32::from(width), f32::from(bounds.height), border_radius),
-        };
+        let (handle_width, handle_height, handle_border_radius) =
+            handle_dimensions(style.handle.shape, bounds.height);
 
         let handle_offset = (bounds.width - handle_width)
             * ((value - range_start) / (range_end - range_start).max(1.0));
@@ -120,3 +116,45 @@ where
         )
     }
 }
+
+/// Returns the width, height, and border radius of a handle with the given
+/// [`HandleShape`] in a slider of the given height.
+///
+/// [`HandleShape`]: enum.HandleShape.html
+fn handle_dimensions(shape: HandleShape, height: f32) -> (f32, f32, u16) {
+    match shape {
+        HandleShape::Circle { radius } => {
+            (f32::from(radius * 2), f32::from(radius * 2), radius)
+        }
+        HandleShape::Rectangle {
+            width,
+            border_radius,
+        } => (f32::from(width), height, border_radius),
+        HandleShape::Custom {
+            width,
+            height,
+            border_radius,
+        } => (f32::from(width), f32::from(height), border_radius),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_handles_keep_their_own_height() {
+        let custom = HandleShape::Custom {
+            width: 24,
+            height: 12,
+            border_radius: 6,
+        };
+        let rectangle = HandleShape::Rectangle {
+            width: 8,
+            border_radius: 2,
+        };
+
+        assert_eq!(handle_dimensions(custom, 22.0), (24.0, 12.0, 6));
+        assert_eq!(handle_dimensions(rectangle, 22.0), (8.0, 22.0, 2));
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{Backend, Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::stack;
+use iced_native::{Element, Layout, Point};
+
+/// A container that places its contents on top of each other.
+pub type Stack<'a, Message, Backend> =
+    iced_native::Stack<'a, Message, Renderer<Backend>>;
+
+impl<B> stack::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        content: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Self::Output {
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        (
+            Primitive::Group {
+                primitives: content
+                    .iter()
+                    .zip(layout.children())
+                    .map(|(child, layout)| {
+                        let (primitive, new_mouse_interaction) =
+                            child.draw(self, defaults, layout, cursor_position);
+
+                        // The top-most interaction wins
+                        if new_mouse_interaction
+                            != mouse::Interaction::default()
+                        {
+                            mouse_interaction = new_mouse_interaction;
+                        }
+
+                        primitive
+                    })
+                    .collect(),
+            },
+            mouse_interaction,
+        )
+    }
+}
@@ -3,7 +3,7 @@ use crate::backend::{self, Backend};
 use crate::{Primitive, Renderer};
 use iced_native::{mouse, svg, Layout};
 
-pub use iced_native::svg::{Handle, Svg};
+pub use iced_native::svg::{Elements, Handle, Svg};
 
 impl<B> svg::Renderer for Renderer<B>
 where
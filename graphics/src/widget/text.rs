@@ -35,6 +35,10 @@ where
             .measure(content, f32::from(size), font, bounds)
     }
 
+    fn ascent(&self, size: u16, font: Font) -> f32 {
+        self.backend().ascent(f32::from(size), font)
+    }
+
     fn draw(
         &mut self,
         defaults: &Self::Defaults,
@@ -9,8 +9,8 @@ use crate::{Primitive, Renderer};
 use iced_native::mouse;
 use iced_native::text_input::{self, cursor};
 use iced_native::{
-    Background, Color, Font, HorizontalAlignment, Point, Rectangle, Size,
-    Vector, VerticalAlignment,
+    Background, Color, Direction, Font, HorizontalAlignment, Point, Rectangle,
+    Size, Vector, VerticalAlignment,
 };
 use std::f32;
 
@@ -23,6 +23,14 @@ pub use iced_style::text_input::{Style, StyleSheet};
 pub type TextInput<'a, Message, Backend> =
     iced_native::TextInput<'a, Message, Renderer<Backend>>;
 
+/// A [`TextInput`] showing a menu of suggestions while it is being edited.
+///
+/// This is an alias of `iced_native` suggestions with an `iced_wgpu::Renderer`.
+///
+/// [`TextInput`]: type.TextInput.html
+pub type Suggestions<'a, Message, Backend> =
+    iced_native::text_input::Suggestions<'a, Message, Renderer<Backend>>;
+
 impl<B> text_input::Renderer for Renderer<B>
 where
     B: Backend + backend::Text,
@@ -73,17 +81,29 @@ where
         &mut self,
         bounds: Rectangle,
         text_bounds: Rectangle,
+        clear_bounds: Option<Rectangle>,
+        footer: Option<text_input::Footer<'_>>,
         cursor_position: Point,
         font: Font,
         size: u16,
         placeholder: &str,
         value: &text_input::Value,
         state: &text_input::State,
+        is_cursor_visible: bool,
+        direction: Direction,
         style_sheet: &Self::Style,
     ) -> Self::Output {
+        let style_sheet = if style_sheet.follows_theme() {
+            self.theme().text_input
+        } else {
+            &**style_sheet
+        };
+
         let is_mouse_over = bounds.contains(cursor_position);
 
-        let style = if state.is_focused() {
+        let style = if matches!(footer, Some(footer) if footer.is_error) {
+            style_sheet.error()
+        } else if state.is_focused() {
             style_sheet.focused()
         } else if is_mouse_over {
             style_sheet.hovered()
@@ -100,13 +120,18 @@ where
         };
 
         let text = value.to_string();
+        let is_right_to_left = direction.is_right_to_left();
+
+        // Glyphs are laid out from left to right, so the contents are
+        // reordered visually
+        let content = if text.is_empty() {
+            text_input::Value::new(placeholder)
+        } else {
+            value.clone()
+        };
 
         let text_value = Primitive::Text {
-            content: if text.is_empty() {
-                placeholder.to_string()
-            } else {
-                text.clone()
-            },
+            content: content.visual(direction),
             color: if text.is_empty() {
                 style_sheet.placeholder_color()
             } else {
@@ -114,12 +139,21 @@ where
             },
             font,
             bounds: Rectangle {
+                x: if is_right_to_left {
+                    text_bounds.x + text_bounds.width
+                } else {
+                    text_bounds.x
+                },
                 y: text_bounds.center_y(),
                 width: f32::INFINITY,
                 ..text_bounds
             },
             size: f32::from(size),
-            horizontal_alignment: HorizontalAlignment::Left,
+            horizontal_alignment: if is_right_to_left {
+                HorizontalAlignment::Right
+            } else {
+                HorizontalAlignment::Left
+            },
             vertical_alignment: VerticalAlignment::Center,
         };
 
@@ -139,19 +173,28 @@ where
                         );
 
                     (
-                        Primitive::Quad {
-                            bounds: Rectangle {
-                                x: text_bounds.x + text_value_width,
-                                y: text_bounds.y,
-                                width: 1.0,
-                                height: text_bounds.height,
-                            },
-                            background: Background::Color(
-                                style_sheet.value_color(),
-                            ),
-                            border_radius: 0,
-                            border_width: 0,
-                            border_color: Color::TRANSPARENT,
+                        if is_cursor_visible {
+                            Primitive::Quad {
+                                bounds: Rectangle {
+                                    x: if is_right_to_left {
+                                        text_bounds.x + text_bounds.width
+                                            - text_value_width
+                                    } else {
+                                        text_bounds.x + text_value_width
+                                    },
+                                    y: text_bounds.y,
+                                    width: 1.0,
+                                    height: text_bounds.height,
+                                },
+                                background: Background::Color(
+                                    style_sheet.value_color(),
+                                ),
+                                border_radius: 0,
+                                border_width: 0,
+                                border_color: Color::TRANSPARENT,
+                            }
+                        } else {
+                            Primitive::None
                         },
                         offset,
                     )
@@ -185,7 +228,12 @@ where
                     (
                         Primitive::Quad {
                             bounds: Rectangle {
-                                x: text_bounds.x + left_position,
+                                x: if is_right_to_left {
+                                    text_bounds.x + text_bounds.width
+                                        - right_position
+                                } else {
+                                    text_bounds.x + left_position
+                                },
                                 y: text_bounds.y,
                                 width,
                                 height: text_bounds.height,
@@ -210,10 +258,10 @@ where
                 Primitive::Group {
                     primitives: vec![cursor_primitive, text_value],
                 },
-                Vector::new(offset as u32, 0),
+                offset,
             )
         } else {
-            (text_value, Vector::new(0, 0))
+            (text_value, 0.0)
         };
 
         let text_width = self.measure_value(
@@ -223,20 +271,123 @@ where
         );
 
         let contents = if text_width > text_bounds.width {
-            Primitive::Clip {
-                bounds: text_bounds,
-                offset,
-                content: Box::new(contents_primitive),
+            // Right-to-left contents overflow to the left, so they scroll
+            // in the opposite direction
+            if is_right_to_left {
+                Primitive::Clip {
+                    bounds: text_bounds,
+                    offset: Vector::new(0, 0),
+                    content: Box::new(Primitive::Translate {
+                        translation: Vector::new(offset, 0.0),
+                        content: Box::new(contents_primitive),
+                    }),
+                }
+            } else {
+                Primitive::Clip {
+                    bounds: text_bounds,
+                    offset: Vector::new(offset as u32, 0),
+                    content: Box::new(contents_primitive),
+                }
             }
         } else {
             contents_primitive
         };
 
+        let is_mouse_over_clear = matches!(
+            clear_bounds,
+            Some(bounds) if bounds.contains(cursor_position)
+        );
+
+        let mut primitives = vec![input, contents];
+
+        if let Some(clear_bounds) = clear_bounds {
+            primitives.push(Primitive::Text {
+                content: String::from("×"),
+                color: if is_mouse_over_clear {
+                    style_sheet.value_color()
+                } else {
+                    style_sheet.placeholder_color()
+                },
+                font,
+                bounds: Rectangle {
+                    x: clear_bounds.center_x(),
+                    y: clear_bounds.center_y(),
+                    ..clear_bounds
+                },
+                size: f32::from(size),
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        if let Some(footer) = footer {
+            let color = if footer.is_error {
+                style_sheet.error_color()
+            } else {
+                style_sheet.helper_color()
+            };
+
+            // The text starts at the leading edge and the counter sits at
+            // the trailing one
+            let (leading_x, trailing_x, leading, trailing) = if is_right_to_left
+            {
+                (
+                    footer.bounds.x + footer.bounds.width,
+                    footer.bounds.x,
+                    HorizontalAlignment::Right,
+                    HorizontalAlignment::Left,
+                )
+            } else {
+                (
+                    footer.bounds.x,
+                    footer.bounds.x + footer.bounds.width,
+                    HorizontalAlignment::Left,
+                    HorizontalAlignment::Right,
+                )
+            };
+
+            if let Some(text) = footer.text {
+                primitives.push(Primitive::Text {
+                    content: text.to_string(),
+                    color,
+                    font,
+                    bounds: Rectangle {
+                        x: leading_x,
+                        y: footer.bounds.center_y(),
+                        ..footer.bounds
+                    },
+                    size: f32::from(footer.size),
+                    horizontal_alignment: leading,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+            }
+
+            if let Some((count, limit)) = footer.counter {
+                primitives.push(Primitive::Text {
+                    content: format!("{}/{}", count, limit),
+                    color: if count > limit {
+                        style_sheet.error_color()
+                    } else {
+                        style_sheet.helper_color()
+                    },
+                    font,
+                    bounds: Rectangle {
+                        x: trailing_x,
+                        y: footer.bounds.center_y(),
+                        ..footer.bounds
+                    },
+                    size: f32::from(footer.size),
+                    horizontal_alignment: trailing,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+            }
+        }
+
         (
-            Primitive::Group {
-                primitives: vec![input, contents],
-            },
-            if is_mouse_over {
+            Primitive::Group { primitives },
+            if is_mouse_over_clear {
+                mouse::Interaction::Pointer
+            } else if is_mouse_over {
                 mouse::Interaction::Text
             } else {
                 mouse::Interaction::default()
@@ -0,0 +1,12 @@
+//! Display a floating tip next to some content while it is hovered.
+use crate::Renderer;
+
+pub use iced_native::tooltip::{Position, State};
+pub use iced_style::container::{Style, StyleSheet};
+
+/// An element that shows a tip next to its content while the content is
+/// hovered.
+///
+/// This is an alias of an `iced_native` tooltip with a default `Renderer`.
+pub type Tooltip<'a, Message, Backend> =
+    iced_native::Tooltip<'a, Message, Renderer<Backend>>;
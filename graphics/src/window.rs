@@ -1,6 +1,8 @@
 //! Draw graphics to window surfaces.
 mod compositor;
 
+pub mod screenshot;
+
 #[cfg(feature = "opengl")]
 mod gl_compositor;
 
@@ -1,3 +1,4 @@
+use crate::window::screenshot;
 use crate::{Color, Error, Viewport};
 use iced_native::mouse;
 use raw_window_handle::HasRawWindowHandle;
@@ -53,4 +54,30 @@ pub trait Compositor: Sized {
         output: &<Self::Renderer as iced_native::Renderer>::Output,
         overlay: &[T],
     ) -> mouse::Interaction;
+
+    /// Draws the output primitives to an offscreen image and returns its
+    /// pixels.
+    ///
+    /// The image has the physical size of the given [`Viewport`], so its
+    /// contents are scaled by the scale factor of the [`Viewport`]. The
+    /// pixels are returned row by row, from the top-left corner, with 4 bytes
+    /// per pixel in RGBA order. Colors are encoded in sRGB.
+    ///
+    /// This is useful to take screenshots of an application or to compare
+    /// its output in visual tests.
+    ///
+    /// By default, this returns [`screenshot::Error::Unsupported`].
+    ///
+    /// [`Viewport`]: ../struct.Viewport.html
+    /// [`screenshot::Error::Unsupported`]: screenshot/enum.Error.html#variant.Unsupported
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        _renderer: &mut Self::Renderer,
+        _viewport: &Viewport,
+        _background_color: Color,
+        _output: &<Self::Renderer as iced_native::Renderer>::Output,
+        _overlay: &[T],
+    ) -> Result<Vec<u8>, screenshot::Error> {
+        Err(screenshot::Error::Unsupported)
+    }
 }
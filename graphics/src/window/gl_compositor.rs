@@ -1,3 +1,4 @@
+use crate::window::screenshot;
 use crate::{Color, Error, Size, Viewport};
 use iced_native::mouse;
 
@@ -65,4 +66,27 @@ pub trait GLCompositor: Sized {
         output: &<Self::Renderer as iced_native::Renderer>::Output,
         overlay: &[T],
     ) -> mouse::Interaction;
+
+    /// Draws the provided output with the given [`Renderer`] to an offscreen
+    /// target and reads the resulting pixels back.
+    ///
+    /// The target has the physical size of the given [`Viewport`]. The
+    /// pixels are returned row by row, from the top-left corner, with 4 bytes
+    /// per pixel in RGBA order. Colors are encoded in sRGB.
+    ///
+    /// By default, this returns [`screenshot::Error::Unsupported`].
+    ///
+    /// [`Renderer`]: #associatedtype.Renderer
+    /// [`Viewport`]: ../struct.Viewport.html
+    /// [`screenshot::Error::Unsupported`]: screenshot/enum.Error.html#variant.Unsupported
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        _renderer: &mut Self::Renderer,
+        _viewport: &Viewport,
+        _background_color: Color,
+        _output: &<Self::Renderer as iced_native::Renderer>::Output,
+        _overlay: &[T],
+    ) -> Result<Vec<u8>, screenshot::Error> {
+        Err(screenshot::Error::Unsupported)
+    }
 }
@@ -0,0 +1,17 @@
+//! Read the pixels drawn by a compositor.
+
+/// An error that occurred while taking a screenshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// The compositor does not support taking screenshots
+    #[error("the compositor does not support taking screenshots")]
+    Unsupported,
+
+    /// The viewport to capture has no pixels
+    #[error("the viewport to capture has no pixels")]
+    EmptyViewport,
+
+    /// The drawn pixels could not be read back
+    #[error("the drawn pixels could not be read back")]
+    ReadFailed,
+}
@@ -0,0 +1,194 @@
+//! Describe widgets to assistive technologies.
+//!
+//! Widgets can describe themselves with some [`Accessibility`] metadata: a
+//! [`Role`] and an optional accessible name. The metadata is stored in the
+//! layout of a user interface, where it can be collected into a [`tree`] by
+//! an accessibility backend or a test.
+//!
+//! [`Accessibility`]: struct.Accessibility.html
+//! [`Role`]: enum.Role.html
+//! [`tree`]: fn.tree.html
+use crate::{layout, Rectangle, Vector};
+
+/// The accessibility metadata of a widget.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Accessibility {
+    /// The [`Role`] of the widget.
+    ///
+    /// [`Role`]: enum.Role.html
+    pub role: Role,
+
+    /// The name announced for the widget, if any.
+    pub name: Option<String>,
+}
+
+/// What a widget is, for assistive technologies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// A control that performs an action when pressed.
+    Button,
+
+    /// A control that can be checked and unchecked.
+    Checkbox,
+
+    /// A field where text can be entered.
+    TextInput,
+}
+
+/// A widget described by its [`Accessibility`] metadata, placed on the
+/// screen.
+///
+/// [`Accessibility`]: struct.Accessibility.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    /// The [`Accessibility`] metadata of the widget.
+    ///
+    /// [`Accessibility`]: struct.Accessibility.html
+    pub accessibility: Accessibility,
+
+    /// The absolute bounds of the widget.
+    pub bounds: Rectangle,
+
+    /// The described widgets contained in the widget, like the content of a
+    /// button.
+    pub children: Vec<Node>,
+}
+
+/// Collects the widgets described in the given layout into a tree of
+/// [`Node`]s.
+///
+/// Widgets without [`Accessibility`] metadata are skipped, so their
+/// described children are collected in their place.
+///
+/// [`Node`]: struct.Node.html
+/// [`Accessibility`]: struct.Accessibility.html
+pub fn tree(layout: &layout::Node) -> Vec<Node> {
+    let mut nodes = Vec::new();
+
+    collect(layout, Vector::new(0.0, 0.0), &mut nodes);
+
+    nodes
+}
+
+fn collect(layout: &layout::Node, offset: Vector, nodes: &mut Vec<Node>) {
+    let bounds = layout.bounds() + offset;
+    let offset = Vector::new(bounds.x, bounds.y);
+
+    match layout.accessibility() {
+        Some(accessibility) => {
+            let mut children = Vec::new();
+
+            for child in layout.children() {
+                collect(child, offset, &mut children);
+            }
+
+            nodes.push(Node {
+                accessibility: accessibility.clone(),
+                bounds,
+                children,
+            });
+        }
+        None => {
+            for child in layout.children() {
+                collect(child, offset, nodes);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::testing::Harness;
+    use crate::{
+        button, checkbox, text_input, Button, Cache, Checkbox, Column, Length,
+        Size, Text, TextInput, UserInterface,
+    };
+
+    #[test]
+    fn described_widgets_are_collected_with_their_bounds() {
+        let mut button = button::State::new();
+        let mut checkbox = checkbox::State::new();
+        let mut text_input = text_input::State::new();
+
+        let form: Column<'_, (), Null> = Column::new()
+            .push(TextInput::new(&mut text_input, "Name", "", |_| ()))
+            .push(Checkbox::new(&mut checkbox, false, "Remember me", |_| ()))
+            .push(
+                Button::new(&mut button, Text::new("Ok"))
+                    .width(Length::Units(50))
+                    .accessible_name("Save"),
+            );
+
+        let harness = Harness::new(form, Size::new(100.0, 100.0));
+
+        let described: Vec<_> = harness
+            .accessibility_tree()
+            .into_iter()
+            .map(|node| {
+                (
+                    node.accessibility.role,
+                    node.accessibility.name,
+                    node.bounds.y,
+                    node.children.len(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            described,
+            vec![
+                (Role::TextInput, Some(String::from("Name")), 0.0, 0),
+                (Role::Checkbox, Some(String::from("Remember me")), 20.0, 0),
+                (Role::Button, Some(String::from("Save")), 40.0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn changed_names_are_not_cached() {
+        fn names(
+            cache: Cache,
+            name: &str,
+            placeholder: &str,
+        ) -> (Cache, Vec<String>) {
+            let mut button = button::State::new();
+            let mut checkbox = checkbox::State::new();
+            let mut text_input = text_input::State::new();
+            let mut renderer = Null::new();
+
+            let form: Column<'_, (), Null> = Column::new()
+                .push(TextInput::new(&mut text_input, placeholder, "", |_| ()))
+                .push(
+                    Checkbox::new(&mut checkbox, false, "", |_| ())
+                        .accessible_name(name),
+                )
+                .push(
+                    Button::new(&mut button, Text::new(""))
+                        .accessible_name(name),
+                );
+
+            let user_interface = UserInterface::build(
+                form,
+                Size::new(100.0, 100.0),
+                cache,
+                &mut renderer,
+            );
+
+            let names = user_interface
+                .accessibility_tree()
+                .into_iter()
+                .filter_map(|node| node.accessibility.name)
+                .collect();
+
+            (user_interface.into_cache(), names)
+        }
+
+        let (cache, before) = names(Cache::new(), "Save", "Name");
+        let (_, after) = names(cache, "Submit", "Email");
+
+        assert_eq!(before, vec!["Name", "Save", "Save"]);
+        assert_eq!(after, vec!["Email", "Submit", "Submit"]);
+    }
+}
@@ -0,0 +1,105 @@
+//! Animate widgets over time.
+//!
+//! There is no clock ticking in the background. Instead, a widget keeps an
+//! [`Animation`] in its local state and measures its progress whenever it is
+//! drawn. While the [`Animation`] is in progress, the widget calls
+//! [`Renderer::request_redraw`], which makes the shell draw the user
+//! interface again on the next frame.
+//!
+//! [`Animation`]: struct.Animation.html
+//! [`Renderer::request_redraw`]: ../renderer/trait.Renderer.html#method.request_redraw
+use std::time::{Duration, Instant};
+
+/// A transition lasting a fixed amount of time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Animation {
+    started_at: Instant,
+    duration: Duration,
+}
+
+impl Animation {
+    /// Creates a new [`Animation`] starting now and lasting the given
+    /// duration.
+    ///
+    /// [`Animation`]: struct.Animation.html
+    pub fn new(duration: Duration) -> Self {
+        Self::starting_at(Instant::now(), duration)
+    }
+
+    /// Creates a new [`Animation`] starting at the given instant and lasting
+    /// the given duration.
+    ///
+    /// [`Animation`]: struct.Animation.html
+    pub fn starting_at(started_at: Instant, duration: Duration) -> Self {
+        Animation {
+            started_at,
+            duration,
+        }
+    }
+
+    /// Returns the current progress of the [`Animation`], from `0.0` to
+    /// `1.0`.
+    ///
+    /// [`Animation`]: struct.Animation.html
+    pub fn progress(&self) -> f32 {
+        self.progress_at(Instant::now())
+    }
+
+    /// Returns the progress of the [`Animation`] at the given instant, from
+    /// `0.0` to `1.0`.
+    ///
+    /// [`Animation`]: struct.Animation.html
+    pub fn progress_at(&self, now: Instant) -> f32 {
+        if self.duration == Duration::from_secs(0) {
+            return 1.0;
+        }
+
+        let elapsed = now.saturating_duration_since(self.started_at);
+
+        (elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+    }
+
+    /// Returns whether the [`Animation`] has finished.
+    ///
+    /// [`Animation`]: struct.Animation.html
+    pub fn is_finished(&self) -> bool {
+        self.progress() >= 1.0
+    }
+}
+
+/// Eases the progress of an [`Animation`], starting fast and slowing down
+/// towards the end.
+///
+/// [`Animation`]: struct.Animation.html
+pub fn ease_out(progress: f32) -> f32 {
+    1.0 - (1.0 - progress.clamp(0.0, 1.0)).powi(3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn progress_is_measured_from_the_start() {
+        let start = Instant::now();
+        let animation =
+            Animation::starting_at(start, Duration::from_millis(200));
+
+        assert_eq!(animation.progress_at(start), 0.0);
+        assert_eq!(
+            animation.progress_at(start + Duration::from_millis(50)),
+            0.25
+        );
+        assert_eq!(
+            animation.progress_at(start + Duration::from_millis(400)),
+            1.0
+        );
+    }
+
+    #[test]
+    fn easing_keeps_the_ends() {
+        assert_eq!(ease_out(0.0), 0.0);
+        assert_eq!(ease_out(1.0), 1.0);
+        assert!(ease_out(0.5) > 0.5);
+    }
+}
@@ -1,8 +1,12 @@
+use crate::accessibility::Accessibility;
+use crate::focus::Focusable;
 use crate::{
-    layout, overlay, Clipboard, Color, Event, Hasher, Layout, Length, Point,
-    Widget,
+    event, layout, overlay, Clipboard, Color, Event, Hasher, Layout, Length,
+    Point, Rectangle, Size, Widget,
 };
 
+use std::cell::Cell;
+
 /// A generic [`Widget`].
 ///
 /// It is useful to build composable user interfaces that do not leak
@@ -17,6 +21,9 @@ use crate::{
 #[allow(missing_debug_implementations)]
 pub struct Element<'a, Message, Renderer> {
     pub(crate) widget: Box<dyn Widget<Message, Renderer> + 'a>,
+    min_size: Cell<Option<Size>>,
+    #[cfg(any(test, feature = "testing"))]
+    tag: &'static str,
 }
 
 impl<'a, Message, Renderer> Element<'a, Message, Renderer>
@@ -31,7 +38,10 @@ where
         widget: impl Widget<Message, Renderer> + 'a,
     ) -> Element<'a, Message, Renderer> {
         Element {
+            #[cfg(any(test, feature = "testing"))]
+            tag: tag_of(&widget),
             widget: Box::new(widget),
+            min_size: Cell::new(None),
         }
     }
 
@@ -178,7 +188,10 @@ where
         F: 'static + Fn(Message) -> B,
     {
         Element {
+            #[cfg(any(test, feature = "testing"))]
+            tag: self.tag,
             widget: Box::new(Map::new(self.widget, f)),
+            min_size: self.min_size,
         }
     }
 
@@ -198,7 +211,10 @@ where
         Renderer: 'a + layout::Debugger,
     {
         Element {
+            #[cfg(any(test, feature = "testing"))]
+            tag: self.tag,
             widget: Box::new(Explain::new(self, color.into())),
+            min_size: Cell::new(None),
         }
     }
 
@@ -216,6 +232,30 @@ where
         self.widget.height()
     }
 
+    /// Returns the minimum size of the contents of the [`Element`].
+    ///
+    /// The minimum size is only computed once per [`Element`], as nested
+    /// flex layouts ask for it on every level.
+    ///
+    /// [`Element`]: struct.Element.html
+    pub fn min_size(&self, renderer: &Renderer) -> Size {
+        if let Some(min_size) = self.min_size.get() {
+            return min_size;
+        }
+
+        let min_size = self.widget.min_size(renderer);
+        self.min_size.set(Some(min_size));
+
+        min_size
+    }
+
+    /// Returns the maximum size of the [`Element`].
+    ///
+    /// [`Element`]: struct.Element.html
+    pub fn max_size(&self) -> Size {
+        self.widget.max_size()
+    }
+
     /// Computes the layout of the [`Element`] in the given [`Limits`].
     ///
     /// [`Element`]: struct.Element.html
@@ -225,7 +265,27 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        self.widget.layout(renderer, limits)
+        let mut node = self.widget.layout(renderer, limits);
+
+        // Invalid sizes are usually caused by bad math in the layout of a
+        // custom widget
+        #[cfg(debug_assertions)]
+        {
+            if node.has_invalid_size() {
+                log::warn!("Invalid layout size read as zero");
+            }
+        }
+
+        if let Some(accessibility) = self.widget.accessibility() {
+            node.set_accessibility(accessibility);
+        }
+
+        // Tagging consumes the node, so its mutability does not depend on
+        // the enabled features
+        #[cfg(any(test, feature = "testing"))]
+        let node = node.tagged(self.tag);
+
+        node
     }
 
     /// Processes a runtime [`Event`].
@@ -239,7 +299,7 @@ where
         messages: &mut Vec<Message>,
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>,
-    ) {
+    ) -> event::Status {
         self.widget.on_event(
             event,
             layout,
@@ -247,7 +307,19 @@ where
             messages,
             renderer,
             clipboard,
-        );
+        )
+    }
+
+    /// Notifies the [`Element`] that the layout of the user interface was
+    /// recomputed.
+    ///
+    /// [`Element`]: struct.Element.html
+    pub fn layout_changed(
+        &mut self,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+    ) {
+        self.widget.layout_changed(layout, messages)
     }
 
     /// Draws the [`Element`] and its children using the given [`Layout`].
@@ -281,6 +353,34 @@ where
     ) -> Option<overlay::Element<'b, Message, Renderer>> {
         self.widget.overlay(layout)
     }
+
+    /// Returns the bounds of the focused widget of the [`Element`], if any.
+    ///
+    /// [`Element`]: struct.Element.html
+    pub fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.widget.focused_bounds(layout)
+    }
+
+    /// Visits the focusable states of the [`Element`] and its children.
+    ///
+    /// [`Element`]: struct.Element.html
+    pub fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        messages: &mut Vec<Message>,
+    ) {
+        self.widget.focusables(visit, messages)
+    }
+}
+
+/// Returns the name of the type of the given widget, without its module path
+/// nor its generic parameters (e.g. `Button`).
+#[cfg(any(test, feature = "testing"))]
+fn tag_of<T>(_widget: &T) -> &'static str {
+    let name = std::any::type_name::<T>();
+    let name = name.split('<').next().unwrap_or(name);
+
+    name.rsplit("::").next().unwrap_or(name)
 }
 
 struct Map<'a, A, B, Renderer> {
@@ -317,6 +417,14 @@ where
         self.widget.height()
     }
 
+    fn min_size(&self, renderer: &Renderer) -> Size {
+        self.widget.min_size(renderer)
+    }
+
+    fn max_size(&self) -> Size {
+        self.widget.max_size()
+    }
+
     fn layout(
         &self,
         renderer: &Renderer,
@@ -333,10 +441,10 @@ where
         messages: &mut Vec<B>,
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>,
-    ) {
+    ) -> event::Status {
         let mut original_messages = Vec::new();
 
-        self.widget.on_event(
+        let status = self.widget.on_event(
             event,
             layout,
             cursor_position,
@@ -345,6 +453,18 @@ where
             clipboard,
         );
 
+        original_messages
+            .drain(..)
+            .for_each(|message| messages.push((self.mapper)(message)));
+
+        status
+    }
+
+    fn layout_changed(&mut self, layout: Layout<'_>, messages: &mut Vec<B>) {
+        let mut original_messages = Vec::new();
+
+        self.widget.layout_changed(layout, &mut original_messages);
+
         original_messages
             .drain(..)
             .for_each(|message| messages.push((self.mapper)(message)));
@@ -375,6 +495,28 @@ where
             .overlay(layout)
             .map(move |overlay| overlay.map(mapper))
     }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.widget.focused_bounds(layout)
+    }
+
+    fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        messages: &mut Vec<B>,
+    ) {
+        let mut original_messages = Vec::new();
+
+        self.widget.focusables(visit, &mut original_messages);
+
+        original_messages
+            .drain(..)
+            .for_each(|message| messages.push((self.mapper)(message)));
+    }
+
+    fn accessibility(&self) -> Option<Accessibility> {
+        self.widget.accessibility()
+    }
 }
 
 struct Explain<'a, Message, Renderer: crate::Renderer> {
@@ -404,6 +546,14 @@ where
         self.element.widget.height()
     }
 
+    fn min_size(&self, renderer: &Renderer) -> Size {
+        self.element.min_size(renderer)
+    }
+
+    fn max_size(&self) -> Size {
+        self.element.max_size()
+    }
+
     fn layout(
         &self,
         renderer: &Renderer,
@@ -420,7 +570,7 @@ where
         messages: &mut Vec<Message>,
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>,
-    ) {
+    ) -> event::Status {
         self.element.widget.on_event(
             event,
             layout,
@@ -431,6 +581,14 @@ where
         )
     }
 
+    fn layout_changed(
+        &mut self,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+    ) {
+        self.element.layout_changed(layout, messages)
+    }
+
     fn draw(
         &self,
         renderer: &mut Renderer,
@@ -457,4 +615,122 @@ where
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         self.element.overlay(layout)
     }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.element.focused_bounds(layout)
+    }
+
+    fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        messages: &mut Vec<Message>,
+    ) {
+        self.element.focusables(visit, messages)
+    }
+
+    fn accessibility(&self) -> Option<Accessibility> {
+        self.element.widget.accessibility()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::{Column, Container, Length};
+
+    struct Measured<'a> {
+        measurements: &'a Cell<usize>,
+        layouts: &'a Cell<usize>,
+    }
+
+    impl<'a> Widget<(), Null> for Measured<'a> {
+        fn width(&self) -> Length {
+            Length::Fill
+        }
+
+        fn height(&self) -> Length {
+            Length::Fill
+        }
+
+        fn min_size(&self, _renderer: &Null) -> Size {
+            self.measurements.set(self.measurements.get() + 1);
+
+            Size::new(10.0, 10.0)
+        }
+
+        fn layout(
+            &self,
+            _renderer: &Null,
+            limits: &layout::Limits,
+        ) -> layout::Node {
+            self.layouts.set(self.layouts.get() + 1);
+
+            layout::Node::new(limits.max())
+        }
+
+        fn draw(
+            &self,
+            _renderer: &mut Null,
+            _defaults: &(),
+            _layout: Layout<'_>,
+            _cursor_position: Point,
+        ) {
+        }
+
+        fn hash_layout(&self, _state: &mut Hasher) {}
+    }
+
+    #[test]
+    fn min_size_is_measured_once_in_nested_layouts() {
+        let measurements = Cell::new(0);
+        let layouts = Cell::new(0);
+
+        let nested = (0..4).fold(
+            Element::new(Measured {
+                measurements: &measurements,
+                layouts: &layouts,
+            }),
+            |content, _| {
+                Column::new()
+                    .height(Length::Fill)
+                    .push(Container::new(content).height(Length::Fill))
+                    .into()
+            },
+        );
+
+        let _ = nested.layout(
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        assert_eq!(measurements.get(), 1);
+    }
+
+    #[test]
+    fn leaves_are_laid_out_once_in_nested_layouts() {
+        let measurements = Cell::new(0);
+        let layouts = Cell::new(0);
+
+        let nested = (0..12).fold(
+            Element::new(Measured {
+                measurements: &measurements,
+                layouts: &layouts,
+            }),
+            |content, _| {
+                Column::new()
+                    .height(Length::Fill)
+                    .push(content)
+                    .push(Container::new(Column::new()).height(Length::Fill))
+                    .into()
+            },
+        );
+
+        let _ = nested.layout(
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        assert_eq!(layouts.get(), 1);
+    }
 }
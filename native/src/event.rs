@@ -1,4 +1,5 @@
-use crate::{keyboard, mouse, window};
+//! Handle events of a user interface.
+use crate::{keyboard, mouse, touch, window};
 
 /// A user interface event.
 ///
@@ -16,4 +17,49 @@ pub enum Event {
 
     /// A window event
     Window(window::Event),
+
+    /// A touch event
+    Touch(touch::Event),
+}
+
+/// The status of an [`Event`] after being processed.
+///
+/// A widget captures an [`Event`] when it handles it in a way that should
+/// prevent other widgets from handling it too, like a nested scrollable
+/// consuming a scroll.
+///
+/// [`Event`]: enum.Event.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Event`] was **NOT** handled by any widget.
+    ///
+    /// [`Event`]: enum.Event.html
+    Ignored,
+
+    /// The [`Event`] was handled and processed by a widget.
+    ///
+    /// [`Event`]: enum.Event.html
+    Captured,
+}
+
+impl Status {
+    /// Merges two [`Status`] into one.
+    ///
+    /// `Captured` takes precedence over `Ignored`:
+    ///
+    /// ```
+    /// use iced_native::event::Status;
+    ///
+    /// assert_eq!(Status::Ignored.merge(Status::Ignored), Status::Ignored);
+    /// assert_eq!(Status::Ignored.merge(Status::Captured), Status::Captured);
+    /// assert_eq!(Status::Captured.merge(Status::Ignored), Status::Captured);
+    /// ```
+    ///
+    /// [`Status`]: enum.Status.html
+    pub fn merge(self, other: Self) -> Self {
+        match self {
+            Status::Ignored => other,
+            Status::Captured => Status::Captured,
+        }
+    }
 }
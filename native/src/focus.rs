@@ -0,0 +1,122 @@
+//! Move the keyboard focus between widgets.
+//!
+//! Widgets that can be focused let their state be visited as a [`Focusable`]
+//! through [`Widget::focusables`], in the order the focus moves through
+//! them. A [`Modal`] uses it to cycle the focus inside its content with `Tab`.
+//!
+//! [`Focusable`]: trait.Focusable.html
+//! [`Widget::focusables`]: ../widget/trait.Widget.html#method.focusables
+//! [`Modal`]: ../widget/modal/struct.Modal.html
+
+/// The state of a widget that can be focused with the keyboard.
+pub trait Focusable {
+    /// Returns whether the widget is focused.
+    fn is_focused(&self) -> bool;
+
+    /// Focuses the widget.
+    fn focus(&mut self);
+
+    /// Removes the focus from the widget.
+    fn unfocus(&mut self);
+}
+
+/// Moves the focus to the next of the focusables visited by the given
+/// function, or to the previous one if `backwards` is set, wrapping around at
+/// the ends.
+///
+/// If none of them is focused, the first one is focused, or the last one when
+/// moving backwards.
+pub fn cycle(
+    mut focusables: impl FnMut(&mut dyn FnMut(&mut dyn Focusable)),
+    backwards: bool,
+) {
+    let mut count = 0;
+    let mut focused = None;
+
+    focusables(&mut |focusable| {
+        if focused.is_none() && focusable.is_focused() {
+            focused = Some(count);
+        }
+
+        count += 1;
+    });
+
+    if count == 0 {
+        return;
+    }
+
+    let next = match (focused, backwards) {
+        (None, false) => 0,
+        (None, true) => count - 1,
+        (Some(index), false) => (index + 1) % count,
+        (Some(index), true) => (index + count - 1) % count,
+    };
+
+    let mut index = 0;
+
+    focusables(&mut |focusable| {
+        if index == next {
+            focusable.focus();
+        } else {
+            focusable.unfocus();
+        }
+
+        index += 1;
+    });
+}
+
+/// Removes the focus from all the focusables visited by the given function.
+pub fn clear(mut focusables: impl FnMut(&mut dyn FnMut(&mut dyn Focusable))) {
+    focusables(&mut |focusable| focusable.unfocus());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Field(bool);
+
+    impl Focusable for Field {
+        fn is_focused(&self) -> bool {
+            self.0
+        }
+
+        fn focus(&mut self) {
+            self.0 = true;
+        }
+
+        fn unfocus(&mut self) {
+            self.0 = false;
+        }
+    }
+
+    fn focused(fields: &[Field]) -> Vec<bool> {
+        fields.iter().map(|field| field.0).collect()
+    }
+
+    fn cycle_fields(fields: &mut [Field], backwards: bool) {
+        cycle(
+            |visit| {
+                for field in fields.iter_mut() {
+                    visit(field);
+                }
+            },
+            backwards,
+        );
+    }
+
+    #[test]
+    fn focus_cycles_and_wraps_around() {
+        let mut fields = [Field::default(), Field::default(), Field::default()];
+
+        cycle_fields(&mut fields, false);
+        assert_eq!(focused(&fields), vec![true, false, false]);
+
+        cycle_fields(&mut fields, true);
+        assert_eq!(focused(&fields), vec![false, false, true]);
+
+        cycle_fields(&mut fields, false);
+        assert_eq!(focused(&fields), vec![true, false, false]);
+    }
+}
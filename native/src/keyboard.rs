@@ -1,2 +1,92 @@
 //! Track keyboard events.
 pub use iced_core::keyboard::*;
+
+use crate::subscription::{EventStream, Recipe, Subscription};
+use crate::Hasher;
+use iced_futures::futures::future;
+use iced_futures::futures::StreamExt;
+use iced_futures::BoxStream;
+
+/// Returns a [`Subscription`] that maps keyboard shortcuts to messages.
+///
+/// The provided function is called every time a key is pressed, with its
+/// [`KeyCode`] and the current [`ModifiersState`]. If it returns
+/// `Some(message)`, the `message` will be produced by the [`Subscription`].
+///
+/// This is useful to handle application-wide shortcuts in a single place,
+/// independently of any widget.
+///
+/// # Example
+///
+/// ```
+/// use iced_native::keyboard::{self, KeyCode, ModifiersState};
+///
+/// enum Message {
+///     Save,
+///     Quit,
+/// }
+///
+/// fn shortcut(key_code: KeyCode, modifiers: ModifiersState) -> Option<Message> {
+///     if !modifiers.control {
+///         return None;
+///     }
+///
+///     match key_code {
+///         KeyCode::S => Some(Message::Save),
+///         KeyCode::Q => Some(Message::Quit),
+///         _ => None,
+///     }
+/// }
+///
+/// let shortcuts = keyboard::shortcuts(shortcut);
+/// ```
+///
+/// [`Subscription`]: ../subscription/type.Subscription.html
+/// [`KeyCode`]: enum.KeyCode.html
+/// [`ModifiersState`]: struct.ModifiersState.html
+pub fn shortcuts<Message>(
+    f: fn(KeyCode, ModifiersState) -> Option<Message>,
+) -> Subscription<Message>
+where
+    Message: 'static + Send,
+{
+    Subscription::from_recipe(Shortcuts { f })
+}
+
+struct Shortcuts<Message> {
+    f: fn(KeyCode, ModifiersState) -> Option<Message>,
+}
+
+impl<Message> Recipe<Hasher, crate::Event> for Shortcuts<Message>
+where
+    Message: 'static + Send,
+{
+    type Output = Message;
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        (self.f as usize).hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        event_stream: EventStream,
+    ) -> BoxStream<Self::Output> {
+        let f = self.f;
+
+        event_stream
+            .filter_map(move |event| {
+                future::ready(match event {
+                    crate::Event::Keyboard(Event::KeyPressed {
+                        key_code,
+                        modifiers,
+                        ..
+                    }) => f(key_code, modifiers),
+                    _ => None,
+                })
+            })
+            .boxed()
+    }
+}
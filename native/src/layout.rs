@@ -9,7 +9,7 @@ pub use debugger::Debugger;
 pub use limits::Limits;
 pub use node::Node;
 
-use crate::{Point, Rectangle, Vector};
+use crate::{Point, Rectangle, Size, Vector};
 
 /// The bounds of a [`Node`] and its children, using absolute coordinates.
 ///
@@ -34,6 +34,10 @@ impl<'a> Layout<'a> {
         }
     }
 
+    pub(crate) fn node(&self) -> &'a Node {
+        self.node
+    }
+
     /// Returns the position of the [`Layout`].
     ///
     /// [`Layout`]: struct.Layout.html
@@ -73,3 +77,19 @@ impl<'a> Layout<'a> {
         })
     }
 }
+
+/// Replaces the NaN and negative dimensions of the given [`Size`] with zero.
+///
+/// [`Size`]: ../struct.Size.html
+fn sanitize(size: Size) -> Size {
+    Size::new(valid(size.width), valid(size.height))
+}
+
+/// Replaces the given dimension with zero if it is NaN or negative.
+fn valid(value: f32) -> f32 {
+    if value >= 0.0 {
+        value
+    } else {
+        0.0
+    }
+}
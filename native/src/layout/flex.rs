@@ -18,7 +18,7 @@
 // limitations under the License.
 use crate::{
     layout::{Limits, Node},
-    Align, Element, Point, Size,
+    Align, Element, Length, Point, Size,
 };
 
 /// The main axis of a flex layout.
@@ -52,6 +52,66 @@ impl Axis {
             Axis::Vertical => (cross, main),
         }
     }
+
+    fn length<Message, Renderer>(
+        &self,
+        element: &Element<'_, Message, Renderer>,
+    ) -> Length
+    where
+        Renderer: crate::Renderer,
+    {
+        match self {
+            Axis::Horizontal => element.width(),
+            Axis::Vertical => element.height(),
+        }
+    }
+}
+
+/// Returns the minimum size of a [`Length`], ignoring the contents.
+///
+/// [`Length`]: ../../enum.Length.html
+fn min_length(length: Length) -> f32 {
+    match length {
+        Length::Units(units) => f32::from(units),
+        _ => 0.0,
+    }
+}
+
+/// Computes the minimum size of the contents of a flex layout with the given
+/// axis, padding and spacing.
+///
+/// The items are placed next to each other at their minimum size, which is
+/// the largest of the minimum size of their contents and their [`Length`].
+///
+/// [`Length`]: ../../enum.Length.html
+pub fn min_size<Message, Renderer>(
+    axis: Axis,
+    renderer: &Renderer,
+    padding: f32,
+    spacing: f32,
+    items: &[Element<'_, Message, Renderer>],
+) -> Size
+where
+    Renderer: crate::Renderer,
+{
+    let total_spacing = spacing * items.len().saturating_sub(1) as f32;
+
+    let (main, cross) = items.iter().fold((0.0, 0.0), |(main, cross), item| {
+        let min_size = item.min_size(renderer);
+
+        let (width, height) = (
+            min_size.width.max(min_length(item.width())),
+            min_size.height.max(min_length(item.height())),
+        );
+
+        let size = Size::new(width, height);
+
+        (main + axis.main(size), f32::max(cross, axis.cross(size)))
+    });
+
+    let (width, height) = axis.pack(main + total_spacing, cross);
+
+    Size::new(width, height).pad(padding)
 }
 
 /// Computes the flex layout with the given axis and limits, applying spacing,
@@ -108,19 +168,70 @@ where
         }
     }
 
-    let remaining = available.max(0.0);
+    let mut remaining = available.max(0.0);
 
-    for (i, child) in items.iter().enumerate() {
-        let fill_factor = match axis {
-            Axis::Horizontal => child.width(),
-            Axis::Vertical => child.height(),
+    // Flexible items are never shrunk below the minimum size of their contents
+    let min_mains: Vec<f32> = items
+        .iter()
+        .map(|child| {
+            if axis.length(child).fill_factor() == 0 {
+                0.0
+            } else {
+                axis.main(child.min_size(renderer))
+            }
+        })
+        .collect();
+
+    // The share of the flexible items that do not fit in it, either because
+    // of the minimum size of their contents or because of their own maximum
+    // size, is frozen to their size, and the remaining space is distributed
+    // again among the others until every share fits
+    let mut frozen: Vec<Option<f32>> = vec![None; items.len()];
+
+    loop {
+        let mut is_settled = true;
+
+        for (i, child) in items.iter().enumerate() {
+            let fill_factor = axis.length(child).fill_factor();
+
+            if fill_factor == 0 || frozen[i].is_some() {
+                continue;
+            }
+
+            let share = remaining * fill_factor as f32 / fill_sum as f32;
+
+            let size = if share < min_mains[i] {
+                Some(min_mains[i])
+            } else {
+                // Items with a maximum size, like a `Container` with a
+                // `max_width`, stay below their share
+                Some(axis.main(child.max_size()).max(min_mains[i]))
+                    .filter(|size| *size < share)
+            };
+
+            if let Some(share) = size {
+                frozen[i] = Some(share);
+                remaining = (remaining - share).max(0.0);
+                fill_sum -= fill_factor;
+                is_settled = false;
+            }
         }
-        .fill_factor();
+
+        if is_settled || fill_sum == 0 {
+            break;
+        }
+    }
+
+    for (i, child) in items.iter().enumerate() {
+        let length = axis.length(child);
+        let fill_factor = length.fill_factor();
 
         if fill_factor != 0 {
-            let max_main = remaining * fill_factor as f32 / fill_sum as f32;
+            let max_main = frozen[i].unwrap_or_else(|| {
+                remaining * fill_factor as f32 / fill_sum as f32
+            });
             let min_main = if max_main.is_infinite() {
-                0.0
+                min_mains[i]
             } else {
                 max_main
             };
@@ -143,6 +254,23 @@ where
         }
     }
 
+    // Only rows can align the baselines of their contents
+    let baseline = match (&axis, align_items) {
+        (Axis::Horizontal, Align::Baseline) => {
+            nodes.iter().filter_map(Node::baseline).reduce(f32::max)
+        }
+        _ => None,
+    };
+
+    if let Some(baseline) = baseline {
+        for node in &nodes {
+            if let Some(node_baseline) = node.baseline() {
+                cross =
+                    cross.max(baseline - node_baseline + node.size().height);
+            }
+        }
+    }
+
     let mut main = padding;
 
     for (i, node) in nodes.iter_mut().enumerate() {
@@ -155,9 +283,18 @@ where
         node.move_to(Point::new(x, y));
 
         match axis {
-            Axis::Horizontal => {
-                node.align(Align::Start, align_items, Size::new(0.0, cross));
-            }
+            Axis::Horizontal => match (baseline, node.baseline()) {
+                (Some(baseline), Some(node_baseline)) => {
+                    node.move_to(Point::new(x, y + baseline - node_baseline));
+                }
+                _ => {
+                    node.align(
+                        Align::Start,
+                        align_items,
+                        Size::new(0.0, cross),
+                    );
+                }
+            },
             Axis::Vertical => {
                 node.align(align_items, Align::Start, Size::new(cross, 0.0));
             }
@@ -171,5 +308,35 @@ where
     let (width, height) = axis.pack(main - padding, cross);
     let size = limits.resolve(Size::new(width, height));
 
-    Node::with_children(size.pad(padding), nodes)
+    // The contents are never clipped, so they overflow silently unless the
+    // `debug` feature reports it
+    #[cfg(feature = "debug")]
+    {
+        let overflow = main - padding - axis.main(size);
+
+        if overflow > 0.5 {
+            log::warn!(
+                "{:?} flex layout overflows its limits by {} units, consider \
+                 placing it in a `Scrollable`",
+                axis,
+                overflow
+            );
+        }
+    }
+
+    // Columns report the baseline of their first line
+    let baseline = match axis {
+        Axis::Horizontal => baseline.map(|baseline| padding + baseline),
+        Axis::Vertical => nodes.first().and_then(|node| {
+            node.baseline().map(|baseline| node.bounds().y + baseline)
+        }),
+    };
+
+    let mut node = Node::with_children(size.pad(padding), nodes);
+
+    if let Some(baseline) = baseline {
+        node.set_baseline(baseline);
+    }
+
+    node
 }
@@ -18,6 +18,8 @@ impl Limits {
 
     /// Creates new [`Limits`] with the given minimum and maximum [`Size`].
     ///
+    /// NaN and negative dimensions are read as zero.
+    ///
     /// [`Limits`]: struct.Limits.html
     /// [`Size`]: ../struct.Size.html
     pub const fn new(min: Size, max: Size) -> Limits {
@@ -33,7 +35,7 @@ impl Limits {
     /// [`Limits`]: struct.Limits.html
     /// [`Size`]: ../struct.Size.html
     pub fn min(&self) -> Size {
-        self.min
+        super::sanitize(self.min)
     }
 
     /// Returns the maximum [`Size`] of the [`Limits`].
@@ -41,7 +43,7 @@ impl Limits {
     /// [`Limits`]: struct.Limits.html
     /// [`Size`]: ../struct.Size.html
     pub fn max(&self) -> Size {
-        self.max
+        super::sanitize(self.max)
     }
 
     /// Returns the fill [`Size`] of the [`Limits`].
@@ -184,9 +186,13 @@ impl Limits {
     /// Computes the resulting [`Size`] that fits the [`Limits`] given the
     /// intrinsic size of some content.
     ///
+    /// NaN and negative intrinsic dimensions are treated as zero.
+    ///
     /// [`Limits`]: struct.Limits.html
     pub fn resolve(&self, intrinsic_size: Size) -> Size {
-        Size::new(
+        let intrinsic_size = super::sanitize(intrinsic_size);
+
+        super::sanitize(Size::new(
             intrinsic_size
                 .width
                 .min(self.max.width)
@@ -195,6 +201,37 @@ impl Limits {
                 .height
                 .min(self.max.height)
                 .max(self.fill.height),
-        )
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_limits_are_replaced_with_zero() {
+        let limits =
+            Limits::new(Size::new(-10.0, f32::NAN), Size::new(f32::NAN, 100.0));
+
+        assert_eq!(limits.min(), Size::ZERO);
+        assert_eq!(limits.max(), Size::new(0.0, 100.0));
+    }
+
+    #[test]
+    fn invalid_intrinsic_sizes_resolve_to_the_minimum() {
+        let limits =
+            Limits::new(Size::new(10.0, 10.0), Size::new(100.0, 100.0))
+                .width(Length::Shrink)
+                .height(Length::Shrink);
+
+        assert_eq!(
+            limits.resolve(Size::new(f32::NAN, -50.0)),
+            Size::new(10.0, 10.0)
+        );
+        assert_eq!(
+            limits.resolve(Size::new(f32::INFINITY, 50.0)),
+            Size::new(100.0, 50.0)
+        );
     }
 }
@@ -1,3 +1,4 @@
+use crate::accessibility::Accessibility;
 use crate::{Align, Point, Rectangle, Size};
 
 /// The bounds of an element and its children.
@@ -5,6 +6,10 @@ use crate::{Align, Point, Rectangle, Size};
 pub struct Node {
     bounds: Rectangle,
     children: Vec<Node>,
+    baseline: Option<f32>,
+    accessibility: Option<Accessibility>,
+    #[cfg(any(test, feature = "testing"))]
+    tag: Option<&'static str>,
 }
 
 impl Node {
@@ -18,6 +23,8 @@ impl Node {
 
     /// Creates a new [`Node`] with the given [`Size`] and children.
     ///
+    /// NaN and negative dimensions are read as zero.
+    ///
     /// [`Node`]: struct.Node.html
     /// [`Size`]: ../struct.Size.html
     pub const fn with_children(size: Size, children: Vec<Node>) -> Self {
@@ -29,6 +36,10 @@ impl Node {
                 height: size.height,
             },
             children,
+            baseline: None,
+            accessibility: None,
+            #[cfg(any(test, feature = "testing"))]
+            tag: None,
         }
     }
 
@@ -37,14 +48,14 @@ impl Node {
     /// [`Node`]: struct.Node.html
     /// [`Size`]: ../struct.Size.html
     pub fn size(&self) -> Size {
-        Size::new(self.bounds.width, self.bounds.height)
+        super::sanitize(Size::new(self.bounds.width, self.bounds.height))
     }
 
     /// Returns the bounds of the [`Node`].
     ///
     /// [`Node`]: struct.Node.html
     pub fn bounds(&self) -> Rectangle {
-        self.bounds
+        Rectangle::new(self.bounds.position(), self.size())
     }
 
     /// Returns the children of the [`Node`].
@@ -54,6 +65,43 @@ impl Node {
         &self.children
     }
 
+    /// Returns the distance from the top of the [`Node`] to the baseline of
+    /// its text, if it has any.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn baseline(&self) -> Option<f32> {
+        self.baseline
+    }
+
+    /// Sets the distance from the top of the [`Node`] to the baseline of its
+    /// text.
+    ///
+    /// Rows use it to align their contents when using [`Align::Baseline`].
+    ///
+    /// [`Node`]: struct.Node.html
+    /// [`Align::Baseline`]: ../enum.Align.html#variant.Baseline
+    pub fn set_baseline(&mut self, baseline: f32) {
+        self.baseline = Some(baseline);
+    }
+
+    /// Returns the [`Accessibility`] metadata of the widget that produced the
+    /// [`Node`], if any.
+    ///
+    /// [`Accessibility`]: ../accessibility/struct.Accessibility.html
+    /// [`Node`]: struct.Node.html
+    pub fn accessibility(&self) -> Option<&Accessibility> {
+        self.accessibility.as_ref()
+    }
+
+    /// Sets the [`Accessibility`] metadata of the widget that produced the
+    /// [`Node`].
+    ///
+    /// [`Accessibility`]: ../accessibility/struct.Accessibility.html
+    /// [`Node`]: struct.Node.html
+    pub fn set_accessibility(&mut self, accessibility: Accessibility) {
+        self.accessibility = Some(accessibility);
+    }
+
     /// Aligns the [`Node`] in the given space.
     ///
     /// [`Node`]: struct.Node.html
@@ -64,7 +112,7 @@ impl Node {
         space: Size,
     ) {
         match horizontal_alignment {
-            Align::Start => {}
+            Align::Start | Align::Baseline => {}
             Align::Center => {
                 self.bounds.x += (space.width - self.bounds.width) / 2.0;
             }
@@ -74,7 +122,7 @@ impl Node {
         }
 
         match vertical_alignment {
-            Align::Start => {}
+            Align::Start | Align::Baseline => {}
             Align::Center => {
                 self.bounds.y += (space.height - self.bounds.height) / 2.0;
             }
@@ -86,9 +134,71 @@ impl Node {
 
     /// Moves the [`Node`] to the given position.
     ///
+    /// NaN coordinates are replaced with zero.
+    ///
     /// [`Node`]: struct.Node.html
     pub fn move_to(&mut self, position: Point) {
-        self.bounds.x = position.x;
-        self.bounds.y = position.y;
+        self.bounds.x = if position.x.is_nan() { 0.0 } else { position.x };
+        self.bounds.y = if position.y.is_nan() { 0.0 } else { position.y };
+    }
+
+    /// Returns whether the [`Node`] was created with a NaN or negative
+    /// dimension.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub(crate) fn has_invalid_size(&self) -> bool {
+        self.size() != Size::new(self.bounds.width, self.bounds.height)
+    }
+
+    /// Returns the type name of the widget that produced the [`Node`], if
+    /// known.
+    ///
+    /// [`Node`]: struct.Node.html
+    #[cfg(any(test, feature = "testing"))]
+    pub(crate) fn tag(&self) -> Option<&'static str> {
+        self.tag
+    }
+
+    /// Returns the [`Node`] tagged with the type name of the widget that
+    /// produced it.
+    ///
+    /// [`Node`]: struct.Node.html
+    #[cfg(any(test, feature = "testing"))]
+    pub(crate) fn tagged(self, tag: &'static str) -> Self {
+        Node {
+            tag: Some(tag),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_sizes_are_replaced_with_zero() {
+        let pathological = [
+            (Size::new(f32::NAN, 10.0), Size::new(0.0, 10.0)),
+            (Size::new(-5.0, f32::NAN), Size::new(0.0, 0.0)),
+            (Size::new(f32::NEG_INFINITY, -0.5), Size::new(0.0, 0.0)),
+            (Size::new(20.0, 30.0), Size::new(20.0, 30.0)),
+        ];
+
+        for (size, expected) in pathological.iter() {
+            assert_eq!(Node::new(*size).size(), *expected);
+        }
+    }
+
+    #[test]
+    fn invalid_positions_are_replaced_with_zero() {
+        let mut node = Node::new(Size::new(10.0, 10.0));
+
+        node.move_to(Point::new(f32::NAN, -5.0));
+
+        assert_eq!(
+            node.bounds(),
+            Rectangle::new(Point::new(0.0, -5.0), node.size())
+        );
     }
 }
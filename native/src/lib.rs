@@ -35,6 +35,10 @@
 #![deny(unused_results)]
 #![forbid(unsafe_code)]
 #![forbid(rust_2018_idioms)]
+pub mod accessibility;
+pub mod animation;
+pub mod event;
+pub mod focus;
 pub mod keyboard;
 pub mod layout;
 pub mod mouse;
@@ -42,12 +46,14 @@ pub mod overlay;
 pub mod program;
 pub mod renderer;
 pub mod subscription;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod touch;
 pub mod widget;
 pub mod window;
 
 mod clipboard;
 mod element;
-mod event;
 mod hasher;
 mod runtime;
 mod user_interface;
@@ -62,8 +68,9 @@ mod debug;
 mod debug;
 
 pub use iced_core::{
-    Align, Background, Color, Font, HorizontalAlignment, Length, Point,
-    Rectangle, Size, Vector, VerticalAlignment,
+    color_palette, Align, Background, Color, ContentFit, Direction, Font,
+    HorizontalAlignment, Length, Point, Rectangle, Size, Spacing, Vector,
+    VerticalAlignment,
 };
 pub use iced_futures::{executor, futures, Command};
 
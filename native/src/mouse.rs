@@ -1,6 +1,8 @@
 //! Track mouse events.
 
 pub mod click;
+pub mod drag;
 
 pub use click::Click;
+pub use drag::Drag;
 pub use iced_core::mouse::*;
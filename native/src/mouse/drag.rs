@@ -0,0 +1,233 @@
+//! Tell mouse drags apart from clicks.
+use crate::mouse::{self, Button};
+use crate::{Point, Vector};
+
+/// The distance the mouse needs to move while pressed for a [`Drag`] to
+/// start, in logical pixels.
+///
+/// [`Drag`]: struct.Drag.html
+pub const THRESHOLD: f32 = 4.0;
+
+/// The tracker of the drags of the left mouse button.
+///
+/// A widget keeps a [`Drag`] in its local state and feeds it the mouse events
+/// it receives in `on_event`. Pressing the left button only arms the
+/// [`Drag`]: it starts once the mouse moves past a threshold, so releasing
+/// the button before that can be handled as a click.
+///
+/// [`Drag`]: struct.Drag.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Drag {
+    threshold: f32,
+    origin: Option<Point>,
+    last_position: Option<Point>,
+}
+
+/// A change in a [`Drag`].
+///
+/// [`Drag`]: struct.Drag.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Event {
+    /// The mouse moved past the threshold while pressed.
+    Started {
+        /// The position where the left button was pressed.
+        origin: Point,
+
+        /// The current position of the mouse.
+        position: Point,
+    },
+
+    /// The mouse moved during a drag.
+    Moved {
+        /// The current position of the mouse.
+        position: Point,
+
+        /// The movement since the last [`Event`] of the drag.
+        ///
+        /// [`Event`]: enum.Event.html
+        delta: Vector,
+    },
+
+    /// The left button was released, ending a drag.
+    Ended {
+        /// The position where the left button was released.
+        position: Point,
+    },
+}
+
+impl Drag {
+    /// Creates a new [`Drag`] using the default [`THRESHOLD`].
+    ///
+    /// [`Drag`]: struct.Drag.html
+    /// [`THRESHOLD`]: constant.THRESHOLD.html
+    pub fn new() -> Self {
+        Self::with_threshold(THRESHOLD)
+    }
+
+    /// Creates a new [`Drag`] starting once the mouse moves the given
+    /// distance while pressed.
+    ///
+    /// [`Drag`]: struct.Drag.html
+    pub fn with_threshold(threshold: f32) -> Self {
+        Drag {
+            threshold,
+            origin: None,
+            last_position: None,
+        }
+    }
+
+    /// Processes a mouse event, given the current position of the cursor.
+    ///
+    /// A press of the left button only arms the [`Drag`] if `can_start` is
+    /// true, which usually means that the cursor is over the widget.
+    ///
+    /// [`Drag`]: struct.Drag.html
+    pub fn update(
+        &mut self,
+        event: mouse::Event,
+        cursor_position: Point,
+        can_start: bool,
+    ) -> Option<Event> {
+        match event {
+            mouse::Event::ButtonPressed(Button::Left) if can_start => {
+                self.origin = Some(cursor_position);
+                self.last_position = None;
+
+                None
+            }
+            mouse::Event::CursorMoved { x, y } => {
+                let origin = self.origin?;
+                let position = Point::new(x, y);
+
+                match self.last_position {
+                    Some(last_position) => {
+                        self.last_position = Some(position);
+
+                        Some(Event::Moved {
+                            position,
+                            delta: position - last_position,
+                        })
+                    }
+                    None if position.distance(origin) >= self.threshold => {
+                        self.last_position = Some(position);
+
+                        Some(Event::Started { origin, position })
+                    }
+                    None => None,
+                }
+            }
+            mouse::Event::ButtonReleased(Button::Left) => {
+                let was_dragging = self.is_dragging();
+
+                self.origin = None;
+                self.last_position = None;
+
+                if was_dragging {
+                    Some(Event::Ended {
+                        position: cursor_position,
+                    })
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns whether the left button is pressed, even if the [`Drag`] has
+    /// not started yet.
+    ///
+    /// [`Drag`]: struct.Drag.html
+    pub fn is_pressed(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    /// Returns whether the [`Drag`] has started and is still in progress.
+    ///
+    /// [`Drag`]: struct.Drag.html
+    pub fn is_dragging(&self) -> bool {
+        self.last_position.is_some()
+    }
+}
+
+impl Default for Drag {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn moved(x: f32, y: f32) -> mouse::Event {
+        mouse::Event::CursorMoved { x, y }
+    }
+
+    #[test]
+    fn drag_starts_past_the_threshold() {
+        let mut drag = Drag::new();
+        let origin = Point::new(10.0, 10.0);
+
+        let events: Vec<_> = vec![
+            (mouse::Event::ButtonPressed(Button::Left), origin),
+            (moved(12.0, 10.0), Point::new(12.0, 10.0)),
+            (moved(14.0, 10.0), Point::new(14.0, 10.0)),
+            (moved(20.0, 15.0), Point::new(20.0, 15.0)),
+            (
+                mouse::Event::ButtonReleased(Button::Left),
+                Point::new(20.0, 15.0),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(event, position)| drag.update(event, position, true))
+        .collect();
+
+        assert_eq!(
+            events,
+            vec![
+                Event::Started {
+                    origin,
+                    position: Point::new(14.0, 10.0),
+                },
+                Event::Moved {
+                    position: Point::new(20.0, 15.0),
+                    delta: Vector::new(6.0, 5.0),
+                },
+                Event::Ended {
+                    position: Point::new(20.0, 15.0),
+                },
+            ]
+        );
+        assert!(!drag.is_pressed());
+    }
+
+    #[test]
+    fn release_before_the_threshold_is_not_a_drag() {
+        let mut drag = Drag::new();
+        let origin = Point::new(10.0, 10.0);
+
+        assert_eq!(
+            drag.update(
+                mouse::Event::ButtonPressed(Button::Left),
+                origin,
+                true
+            ),
+            None
+        );
+        assert_eq!(
+            drag.update(moved(11.0, 11.0), Point::new(11.0, 11.0), true),
+            None
+        );
+        assert!(drag.is_pressed());
+        assert!(!drag.is_dragging());
+        assert_eq!(
+            drag.update(
+                mouse::Event::ButtonReleased(Button::Left),
+                Point::new(11.0, 11.0),
+                true,
+            ),
+            None
+        );
+    }
+}
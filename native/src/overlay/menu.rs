@@ -1,18 +1,36 @@
 //! Build and show dropdown menus.
 use crate::{
-    container, layout, mouse, overlay, scrollable, text, Clipboard, Container,
-    Element, Event, Hasher, Layout, Length, Point, Rectangle, Scrollable, Size,
-    Vector, Widget,
+    container, event, keyboard, layout, mouse, overlay, scrollable, text,
+    Clipboard, Container, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Scrollable, Size, Vector, Widget,
 };
 
+use std::hash::Hash;
+use std::ops::Range;
+use std::time::{Duration, Instant};
+
+/// The time after which the characters typed in an open [`Menu`] start a new
+/// type-ahead search.
+///
+/// [`Menu`]: struct.Menu.html
+pub const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_millis(1000);
+
 /// A list of selectable options.
 #[allow(missing_debug_implementations)]
 pub struct Menu<'a, T, Renderer: self::Renderer> {
     state: &'a mut State,
     options: &'a [T],
     hovered_option: &'a mut Option<usize>,
-    last_selection: &'a mut Option<T>,
+    last_selection: &'a mut Option<usize>,
+    format: &'a dyn Fn(&T) -> String,
+    highlight: Option<&'a str>,
+    checked: Option<&'a [bool]>,
+    leading: Option<&'a dyn Fn(&T) -> String>,
+    selected: Option<usize>,
+    marks_selected: bool,
     width: u16,
+    fit_content: bool,
+    avoids_overflow: bool,
     padding: u16,
     text_size: Option<u16>,
     font: Renderer::Font,
@@ -21,26 +39,37 @@ pub struct Menu<'a, T, Renderer: self::Renderer> {
 
 impl<'a, T, Renderer> Menu<'a, T, Renderer>
 where
-    T: ToString + Clone,
+    T: ToString,
     Renderer: self::Renderer + 'a,
 {
     /// Creates a new [`Menu`] with the given [`State`], a list of options, and
     /// the message to produced when an option is selected.
     ///
+    /// When an option is clicked, its index in the list is stored in
+    /// `last_selection`.
+    ///
     /// [`Menu`]: struct.Menu.html
     /// [`State`]: struct.State.html
     pub fn new(
         state: &'a mut State,
         options: &'a [T],
         hovered_option: &'a mut Option<usize>,
-        last_selection: &'a mut Option<T>,
+        last_selection: &'a mut Option<usize>,
     ) -> Self {
         Menu {
             state,
             options,
             hovered_option,
             last_selection,
+            format: &ToString::to_string,
+            highlight: None,
+            checked: None,
+            leading: None,
+            selected: None,
+            marks_selected: false,
             width: 0,
+            fit_content: false,
+            avoids_overflow: false,
             padding: 0,
             text_size: None,
             font: Default::default(),
@@ -48,6 +77,66 @@ where
         }
     }
 
+    /// Sets the function used to format the options of the [`Menu`].
+    ///
+    /// By default, options are displayed using their `ToString`
+    /// implementation.
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub fn format(mut self, format: &'a dyn Fn(&T) -> String) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets the query to highlight in the options of the [`Menu`].
+    ///
+    /// The first case-insensitive match of the query in each label will be
+    /// highlighted. See [`highlight_range`] for the details.
+    ///
+    /// [`Menu`]: struct.Menu.html
+    /// [`highlight_range`]: fn.highlight_range.html
+    pub fn highlight(mut self, query: &'a str) -> Self {
+        self.highlight = Some(query);
+        self
+    }
+
+    /// Displays a checkbox next to each option of the [`Menu`], checked
+    /// according to the given flags.
+    ///
+    /// The flags are matched with the options by index. Options without a
+    /// flag are displayed unchecked.
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub fn checked(mut self, checked: &'a [bool]) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    /// Sets the function producing the text displayed before the label of
+    /// each option of the [`Menu`], like an icon.
+    ///
+    /// The texts are displayed in their own column, so the labels stay
+    /// aligned even if some options produce an empty text.
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub fn leading(mut self, leading: &'a dyn Fn(&T) -> String) -> Self {
+        self.leading = Some(leading);
+        self
+    }
+
+    /// Displays a checkmark after the label of the selected option of the
+    /// [`Menu`], if any.
+    ///
+    /// The checkmarks are displayed in their own column at the end of the
+    /// options.
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub fn mark_selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self.marks_selected = true;
+        self
+    }
+
     /// Sets the width of the [`Menu`].
     ///
     /// [`Menu`]: struct.Menu.html
@@ -56,6 +145,28 @@ where
         self
     }
 
+    /// Makes the [`Menu`] as wide as its widest option, ignoring any
+    /// [`width`] set.
+    ///
+    /// [`Menu`]: struct.Menu.html
+    /// [`width`]: #method.width
+    pub fn fit_content(mut self) -> Self {
+        self.fit_content = true;
+        self
+    }
+
+    /// Moves the [`Menu`] to the left when it would overflow the right edge
+    /// of the screen otherwise.
+    ///
+    /// By default, the [`Menu`] is aligned with its target and shrunk to fit
+    /// the space to the right of it instead.
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub fn avoid_overflow(mut self) -> Self {
+        self.avoids_overflow = true;
+        self
+    }
+
     /// Sets the padding of the [`Menu`].
     ///
     /// [`Menu`]: struct.Menu.html
@@ -117,6 +228,17 @@ where
 #[derive(Debug, Clone, Default)]
 pub struct State {
     scrollable: scrollable::State,
+    type_ahead: TypeAhead,
+    reveals_hovered: bool,
+}
+
+/// The characters typed in an open [`Menu`] to jump to an option.
+///
+/// [`Menu`]: struct.Menu.html
+#[derive(Debug, Clone, Default)]
+struct TypeAhead {
+    query: String,
+    last_typed: Option<Instant>,
 }
 
 impl State {
@@ -127,11 +249,32 @@ impl State {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Scrolls a [`Menu`] that is about to open from the top, until its
+    /// hovered option is visible.
+    ///
+    /// The hovered option is followed until the mouse moves over the
+    /// [`Menu`]. This is useful to reveal the selected option of a long
+    /// list.
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub fn reveal_hovered(&mut self) {
+        self.scrollable = scrollable::State::new();
+        self.reveals_hovered = true;
+    }
 }
 
 struct Overlay<'a, Message, Renderer: self::Renderer> {
     container: Container<'a, Message, Renderer>,
     width: u16,
+    labels: Option<Vec<String>>,
+    is_checkable: bool,
+    has_leading: bool,
+    marks_selected: bool,
+    avoids_overflow: bool,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
     target_height: f32,
     style: <Renderer as self::Renderer>::Style,
 }
@@ -143,35 +286,65 @@ where
 {
     pub fn new<T>(menu: Menu<'a, T, Renderer>, target_height: f32) -> Self
     where
-        T: Clone + ToString,
+        T: ToString,
     {
         let Menu {
             state,
             options,
             hovered_option,
             last_selection,
+            format,
+            highlight,
+            checked,
+            leading,
+            selected,
+            marks_selected,
             width,
+            fit_content,
+            avoids_overflow,
             padding,
             font,
             text_size,
             style,
         } = menu;
 
-        let container =
-            Container::new(Scrollable::new(&mut state.scrollable).push(List {
-                options,
-                hovered_option,
-                last_selection,
-                font,
-                text_size,
-                padding,
-                style: style.clone(),
-            }))
-            .padding(1);
+        // The labels are formatted once, both to lay out the options and to
+        // hash their layout
+        let labels: Vec<String> = options.iter().map(format).collect();
+
+        let scrollable =
+            Scrollable::new(&mut state.scrollable).scroll_to_focus(true);
+
+        let container = Container::new(scrollable.push(List {
+            type_ahead: &mut state.type_ahead,
+            reveals_hovered: &mut state.reveals_hovered,
+            options,
+            labels: labels.clone(),
+            hovered_option,
+            last_selection,
+            format,
+            highlight,
+            checked,
+            leading,
+            selected: selected.filter(|_| marks_selected),
+            font,
+            text_size,
+            padding,
+            style: style.clone(),
+        }))
+        .padding(1);
 
         Self {
             container,
-            width: width,
+            width,
+            labels: if fit_content { Some(labels) } else { None },
+            is_checkable: checked.is_some(),
+            has_leading: leading.is_some(),
+            marks_selected,
+            avoids_overflow,
+            padding,
+            text_size,
+            font,
             target_height,
             style: style,
         }
@@ -192,10 +365,53 @@ where
         let space_below = bounds.height - (position.y + self.target_height);
         let space_above = position.y;
 
+        let width = match &self.labels {
+            Some(labels) => {
+                let text_size =
+                    self.text_size.unwrap_or(renderer.default_size());
+
+                let max_width = labels
+                    .iter()
+                    .map(|label| {
+                        let (width, _) =
+                            renderer.measure_text(label, text_size, self.font);
+
+                        width.round() as u16
+                    })
+                    .max()
+                    .unwrap_or(0);
+
+                // Leave room for the checkboxes, the leading column and the
+                // checkmarks, if any
+                let columns =
+                    [self.is_checkable, self.has_leading, self.marks_selected]
+                        .iter()
+                        .filter(|&&column| column)
+                        .count() as u32;
+
+                let columns_width =
+                    columns * (u32::from(text_size) + u32::from(self.padding));
+
+                // Account for the padding of the options and the border of
+                // the menu, which may not fit in a `u16` for huge values
+                let width = u32::from(max_width)
+                    + columns_width
+                    + u32::from(self.padding) * 2
+                    + 2;
+
+                width.min(u32::from(u16::MAX)) as u16
+            }
+            None => self.width,
+        };
+
         let limits = layout::Limits::new(
             Size::ZERO,
             Size::new(
-                bounds.width - position.x,
+                if self.avoids_overflow {
+                    bounds.width
+                } else {
+                    bounds.width - position.x
+                },
                 if space_below > space_above {
                     space_below
                 } else {
@@ -203,27 +419,39 @@ where
                 },
             ),
         )
-        .width(Length::Units(self.width));
+        .width(Length::Units(width));
 
         let mut node = self.container.layout(renderer, &limits);
 
-        node.move_to(if space_below > space_above {
+        let mut position = if space_below > space_above {
             position + Vector::new(0.0, self.target_height)
         } else {
             position - Vector::new(0.0, node.size().height)
-        });
+        };
+
+        if self.avoids_overflow {
+            let overflow = position.x + node.size().width - bounds.width;
+
+            if overflow > 0.0 {
+                position.x = (position.x - overflow).max(0.0);
+            }
+        }
+
+        node.move_to(position);
 
         node
     }
 
     fn hash_layout(&self, state: &mut Hasher, position: Point) {
-        use std::hash::Hash;
-
         struct Marker;
         std::any::TypeId::of::<Marker>().hash(state);
 
         (position.x as u32).hash(state);
         (position.y as u32).hash(state);
+        self.labels.hash(state);
+        self.is_checkable.hash(state);
+        self.has_leading.hash(state);
+        self.marks_selected.hash(state);
         self.container.hash_layout(state);
     }
 
@@ -236,7 +464,7 @@ where
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>,
     ) {
-        self.container.on_event(
+        let _ = self.container.on_event(
             event.clone(),
             layout,
             cursor_position,
@@ -267,9 +495,17 @@ where
 }
 
 struct List<'a, T, Renderer: self::Renderer> {
+    type_ahead: &'a mut TypeAhead,
+    reveals_hovered: &'a mut bool,
     options: &'a [T],
+    labels: Vec<String>,
     hovered_option: &'a mut Option<usize>,
-    last_selection: &'a mut Option<T>,
+    last_selection: &'a mut Option<usize>,
+    format: &'a dyn Fn(&T) -> String,
+    highlight: Option<&'a str>,
+    checked: Option<&'a [bool]>,
+    leading: Option<&'a dyn Fn(&T) -> String>,
+    selected: Option<usize>,
     padding: u16,
     text_size: Option<u16>,
     font: Renderer::Font,
@@ -279,7 +515,7 @@ struct List<'a, T, Renderer: self::Renderer> {
 impl<'a, T, Message, Renderer: self::Renderer> Widget<Message, Renderer>
     for List<'a, T, Renderer>
 where
-    T: Clone + ToString,
+    T: ToString,
     Renderer: self::Renderer,
 {
     fn width(&self) -> Length {
@@ -299,29 +535,61 @@ where
 
         let limits = limits.width(Length::Fill).height(Length::Shrink);
         let text_size = self.text_size.unwrap_or(renderer.default_size());
+        let padding = f32::from(self.padding) * 2.0;
+
+        let labels = &self.labels;
 
-        let size = {
+        // Rows of single-line labels are all equally tall, so they do not
+        // need to be measured nor laid out one by one
+        if labels.iter().all(|label| !label.contains('\n')) {
             let intrinsic = Size::new(
                 0.0,
-                f32::from(text_size + self.padding * 2)
-                    * self.options.len() as f32,
+                (f32::from(text_size) + padding) * labels.len() as f32,
             );
 
-            limits.resolve(intrinsic)
-        };
+            return layout::Node::new(limits.resolve(intrinsic));
+        }
+
+        let heights = row_heights(labels, text_size, padding, |label| {
+            renderer.measure_text(label, text_size, self.font).1
+        });
+
+        let size = limits.resolve(Size::new(0.0, heights.iter().sum()));
+        let mut y = 0.0;
+
+        let rows = heights
+            .into_iter()
+            .map(|height| {
+                let mut row = layout::Node::new(Size::new(size.width, height));
+                row.move_to(Point::new(0.0, y));
+
+                y += height;
+                row
+            })
+            .collect();
 
-        layout::Node::new(size)
+        layout::Node::with_children(size, rows)
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
-        use std::hash::Hash as _;
-
         struct Marker;
         std::any::TypeId::of::<Marker>().hash(state);
 
-        self.options.len().hash(state);
         self.text_size.hash(state);
         self.padding.hash(state);
+        self.labels.hash(state);
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        // The option hovered with the keyboard, or revealed when opening, has
+        // the focus of the menu until the mouse moves over it
+        if !*self.reveals_hovered {
+            return None;
+        }
+
+        rows(layout, self.options.len())
+            .into_iter()
+            .nth((*self.hovered_option)?)
     }
 
     fn on_event(
@@ -330,36 +598,69 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
         _messages: &mut Vec<Message>,
-        renderer: &Renderer,
+        _renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
-    ) {
+    ) -> event::Status {
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 let bounds = layout.bounds();
 
                 if bounds.contains(cursor_position) {
                     if let Some(index) = *self.hovered_option {
-                        if let Some(option) = self.options.get(index) {
-                            *self.last_selection = Some(option.clone());
+                        if index < self.options.len() {
+                            *self.last_selection = Some(index);
                         }
                     }
                 }
             }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if !c.is_control() =>
+            {
+                let now = Instant::now();
+                let type_ahead = &mut *self.type_ahead;
+
+                let is_idle =
+                    type_ahead.last_typed.map_or(true, |last_typed| {
+                        now.duration_since(last_typed) > TYPE_AHEAD_TIMEOUT
+                    });
+
+                if is_idle {
+                    type_ahead.query.clear();
+                }
+
+                type_ahead.query.extend(c.to_lowercase());
+                type_ahead.last_typed = Some(now);
+
+                if let Some(index) = type_ahead_match(
+                    self.options,
+                    self.format,
+                    *self.hovered_option,
+                    &type_ahead.query,
+                ) {
+                    *self.hovered_option = Some(index);
+                    *self.reveals_hovered = true;
+                }
+
+                return event::Status::Captured;
+            }
             Event::Mouse(mouse::Event::CursorMoved { .. }) => {
                 let bounds = layout.bounds();
-                let text_size =
-                    self.text_size.unwrap_or(renderer.default_size());
 
                 if bounds.contains(cursor_position) {
-                    *self.hovered_option = Some(
-                        ((cursor_position.y - bounds.y)
-                            / f32::from(text_size + self.padding * 2))
-                            as usize,
-                    );
+                    // The mouse takes over, so the next character starts a
+                    // new search
+                    self.type_ahead.last_typed = None;
+                    *self.reveals_hovered = false;
+
+                    *self.hovered_option = rows(layout, self.options.len())
+                        .iter()
+                        .position(|row| cursor_position.y < row.y + row.height);
                 }
             }
             _ => {}
         }
+
+        event::Status::Ignored
     }
 
     fn draw(
@@ -373,7 +674,13 @@ where
             renderer,
             layout.bounds(),
             cursor_position,
+            &rows(layout, self.options.len()),
             self.options,
+            self.format,
+            self.highlight,
+            self.checked,
+            self.leading,
+            self.selected,
             *self.hovered_option,
             self.padding,
             self.text_size.unwrap_or(renderer.default_size()),
@@ -413,12 +720,35 @@ pub trait Renderer:
 
     /// Draws the list of options of a [`Menu`].
     ///
+    /// The `format` function produces the label of each option. If a
+    /// `highlight` query is provided, the range of each label returned by
+    /// [`highlight_range`] should be displayed differently.
+    ///
+    /// Each option is displayed as a row with the following columns:
+    /// - a checkbox, if `checked` flags are provided
+    /// - the text produced by the `leading` function, if provided
+    /// - the label
+    /// - a checkmark, only for the `selected` option
+    ///
+    /// Every column before the label is as wide as the text size plus the
+    /// padding, so labels stay aligned.
+    ///
+    /// The `rows` contain the bounds of every option, which may have
+    /// different heights when labels span multiple lines.
+    ///
     /// [`Menu`]: struct.Menu.html
-    fn draw<T: ToString>(
+    /// [`highlight_range`]: fn.highlight_range.html
+    fn draw<T>(
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
+        rows: &[Rectangle],
         options: &[T],
+        format: &dyn Fn(&T) -> String,
+        highlight: Option<&str>,
+        checked: Option<&[bool]>,
+        leading: Option<&dyn Fn(&T) -> String>,
+        selected: Option<usize>,
         hovered_option: Option<usize>,
         padding: u16,
         text_size: u16,
@@ -427,10 +757,116 @@ pub trait Renderer:
     ) -> Self::Output;
 }
 
+/// Returns the bounds of the rows of the options of a [`List`] with the given
+/// layout.
+///
+/// Rows of variable height are laid out as children of the [`List`], while
+/// rows of uniform height split its bounds equally.
+///
+/// [`List`]: struct.List.html
+fn rows(layout: Layout<'_>, count: usize) -> Vec<Rectangle> {
+    let bounds = layout.bounds();
+
+    if layout.children().next().is_some() {
+        return layout.children().map(|row| row.bounds()).collect();
+    }
+
+    let height = bounds.height / count.max(1) as f32;
+
+    (0..count)
+        .map(|i| Rectangle {
+            y: bounds.y + height * i as f32,
+            height,
+            ..bounds
+        })
+        .collect()
+}
+
+/// Returns the byte range of the first case-insensitive match of the `query`
+/// in the given `label`, if any.
+///
+/// An empty `query` never matches.
+pub fn highlight_range(label: &str, query: &str) -> Option<Range<usize>> {
+    let query: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+
+    if query.is_empty() {
+        return None;
+    }
+
+    'candidates: for (start, _) in label.char_indices() {
+        let mut remaining = query.iter();
+
+        for (offset, c) in label[start..].char_indices() {
+            for lowercase in c.to_lowercase() {
+                match remaining.next() {
+                    Some(&expected) if expected == lowercase => {}
+                    _ => continue 'candidates,
+                }
+            }
+
+            if remaining.len() == 0 {
+                return Some(start..start + offset + c.len_utf8());
+            }
+        }
+
+        break;
+    }
+
+    None
+}
+
+/// Returns the index of the option to hover after typing the given `query`
+/// in a [`Menu`], if any option matches.
+///
+/// Options match when their label starts with the query, ignoring case. The
+/// search starts at the `hovered` option, so typing more characters refines
+/// the current match. When the query repeats a single character, the search
+/// starts after the `hovered` option instead, cycling through the options
+/// starting with that character.
+///
+/// Labels are only produced for the options visited by the search.
+///
+/// [`Menu`]: struct.Menu.html
+fn type_ahead_match<T>(
+    options: &[T],
+    format: &dyn Fn(&T) -> String,
+    hovered: Option<usize>,
+    query: &str,
+) -> Option<usize> {
+    let mut chars = query.chars();
+    let first = chars.next()?;
+
+    let (prefix, start) = if chars.all(|c| c == first) {
+        (first.to_string(), hovered.map_or(0, |index| index + 1))
+    } else {
+        (query.to_string(), hovered.unwrap_or(0))
+    };
+
+    let start = start.min(options.len());
+
+    (start..options.len()).chain(0..start).find(|&index| {
+        format(&options[index]).to_lowercase().starts_with(&prefix)
+    })
+}
+
+/// Returns the heights of the rows of the given labels, which fit their
+/// measured height and are never shorter than a line.
+fn row_heights(
+    labels: &[String],
+    text_size: u16,
+    padding: f32,
+    measure: impl Fn(&str) -> f32,
+) -> Vec<f32> {
+    labels
+        .iter()
+        .map(|label| measure(label).max(f32::from(text_size)) + padding)
+        .collect()
+}
+
 impl<'a, T, Message, Renderer> Into<Element<'a, Message, Renderer>>
     for List<'a, T, Renderer>
 where
-    T: ToString + Clone,
+    T: ToString,
     Message: 'a,
     Renderer: 'a + self::Renderer,
 {
@@ -438,3 +874,194 @@ where
         Element::new(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        highlight_range, row_heights, type_ahead_match, Menu, Overlay, State,
+    };
+    use crate::renderer::Null;
+    use crate::{mouse, Event, Hasher, Layout, Overlay as _, Point, Size};
+
+    fn fit_content_width(
+        padding: u16,
+        leading: Option<&dyn Fn(&&str) -> String>,
+        marks_selected: bool,
+    ) -> f32 {
+        let mut state = State::new();
+        let mut hovered_option = None;
+        let mut last_selection = None;
+        let options = ["Rust", "Elm"];
+
+        let mut menu = Menu::<_, Null>::new(
+            &mut state,
+            &options,
+            &mut hovered_option,
+            &mut last_selection,
+        )
+        .padding(padding)
+        .fit_content();
+
+        if let Some(leading) = leading {
+            menu = menu.leading(leading);
+        }
+
+        if marks_selected {
+            menu = menu.mark_selected(Some(1));
+        }
+
+        let overlay: Overlay<'_, (), Null> = Overlay::new(menu, 0.0);
+
+        overlay
+            .layout(&Null, Size::new(400.0, 400.0), Point::ORIGIN)
+            .size()
+            .width
+    }
+
+    #[test]
+    fn leading_and_selected_columns_are_reserved() {
+        let icon = |_: &&str| String::from("*");
+
+        // The null renderer measures every label as empty, and the default
+        // text size is 20
+        assert_eq!(fit_content_width(5, None, false), 12.0);
+        assert_eq!(fit_content_width(5, Some(&icon), false), 37.0);
+        assert_eq!(fit_content_width(5, Some(&icon), true), 62.0);
+    }
+
+    #[test]
+    fn huge_paddings_do_not_overflow_the_width() {
+        let icon = |_: &&str| String::from("*");
+
+        assert_eq!(fit_content_width(u16::MAX, Some(&icon), true), 400.0);
+    }
+
+    #[test]
+    fn rows_fit_multi_line_labels() {
+        let labels: Vec<String> = vec!["One", "Two\nlines", "", "Three"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        let heights = row_heights(&labels, 20, 10.0, |label| {
+            20.0 * label.lines().count() as f32
+        });
+
+        assert_eq!(heights, vec![30.0, 50.0, 30.0, 30.0]);
+    }
+
+    #[test]
+    fn rows_of_multi_line_labels_are_hovered() {
+        let mut state = State::new();
+        let mut hovered_option = None;
+        let mut last_selection = None;
+        let options = ["One", "Two\nlines", "Three"];
+
+        {
+            let menu = Menu::<_, Null>::new(
+                &mut state,
+                &options,
+                &mut hovered_option,
+                &mut last_selection,
+            )
+            .width(100);
+
+            let mut overlay: Overlay<'_, (), Null> = Overlay::new(menu, 0.0);
+            let layout =
+                overlay.layout(&Null, Size::new(400.0, 400.0), Point::ORIGIN);
+
+            // The null renderer measures every label as a single line
+            assert_eq!(layout.size().height, 20.0 * 3.0 + 2.0);
+
+            overlay.on_event(
+                Event::Mouse(mouse::Event::CursorMoved { x: 10.0, y: 35.0 }),
+                Layout::new(&layout),
+                Point::new(10.0, 35.0),
+                &mut Vec::new(),
+                &Null,
+                None,
+            );
+        }
+
+        assert_eq!(hovered_option, Some(1));
+    }
+
+    #[test]
+    fn layout_hash_follows_the_labels() {
+        // The options do not need to be hashable
+        struct Count(usize);
+
+        impl ToString for Count {
+            fn to_string(&self) -> String {
+                self.0.to_string()
+            }
+        }
+
+        let options = [Count(1), Count(2)];
+
+        let hash = |format: &dyn Fn(&Count) -> String| {
+            use std::hash::Hasher as _;
+
+            let mut state = State::new();
+            let mut hovered_option = None;
+            let mut last_selection = None;
+
+            let menu = Menu::<_, Null>::new(
+                &mut state,
+                &options,
+                &mut hovered_option,
+                &mut last_selection,
+            )
+            .format(format);
+
+            let overlay: Overlay<'_, (), Null> = Overlay::new(menu, 0.0);
+            let mut hasher = Hasher::default();
+            overlay.hash_layout(&mut hasher, Point::ORIGIN);
+
+            hasher.finish()
+        };
+
+        let single_line = |count: &Count| count.0.to_string();
+        let multi_line = |count: &Count| "\n".repeat(count.0);
+
+        assert_eq!(hash(&single_line), hash(&single_line));
+        assert_ne!(hash(&single_line), hash(&multi_line));
+    }
+
+    #[test]
+    fn highlight_range_finds_first_case_insensitive_match() {
+        assert_eq!(highlight_range("Rust", "rust"), Some(0..4));
+        assert_eq!(highlight_range("Elm and elm", "ELM"), Some(0..3));
+        assert_eq!(highlight_range("Haskell", "kel"), Some(3..6));
+        assert_eq!(highlight_range("Über", "üb"), Some(0..3));
+    }
+
+    #[test]
+    fn type_ahead_cycles_through_options_with_the_same_initial() {
+        let labels = ["Elm", "Rust", "Ruby", "Go", "Racket"];
+        let matching = |hovered, query| {
+            type_ahead_match(
+                &labels,
+                &|label| label.to_string(),
+                hovered,
+                query,
+            )
+        };
+
+        assert_eq!(matching(None, "r"), Some(1));
+        assert_eq!(matching(Some(1), "r"), Some(2));
+        assert_eq!(matching(Some(2), "rr"), Some(4));
+        assert_eq!(matching(Some(4), "rrr"), Some(1));
+        assert_eq!(matching(Some(1), "ru"), Some(1));
+        assert_eq!(matching(Some(1), "rub"), Some(2));
+        assert_eq!(matching(Some(2), "java"), None);
+        assert_eq!(matching(Some(2), ""), None);
+    }
+
+    #[test]
+    fn highlight_range_without_match() {
+        assert_eq!(highlight_range("Rust", ""), None);
+        assert_eq!(highlight_range("Rust", "rusty"), None);
+        assert_eq!(highlight_range("Go", "elm"), None);
+    }
+}
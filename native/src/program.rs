@@ -36,4 +36,16 @@ pub trait Program: Sized {
     ///
     /// [`Program`]: trait.Program.html
     fn view(&mut self) -> Element<'_, Self::Message, Self::Renderer>;
+
+    /// Prepares the [`Renderer`] before the widgets of the [`Program`] are
+    /// laid out and drawn.
+    ///
+    /// This can be used to configure renderer-wide settings that depend on
+    /// the state of the [`Program`], like a theme.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Renderer`]: #associatedtype.Renderer
+    /// [`Program`]: trait.Program.html
+    fn prepare(&self, _renderer: &mut Self::Renderer) {}
 }
@@ -3,6 +3,8 @@ use crate::{
     UserInterface,
 };
 
+use std::time::Instant;
+
 /// The execution state of a [`Program`]. It leverages caching, event
 /// processing, and rendering primitive storage.
 ///
@@ -17,6 +19,8 @@ where
     primitive: <P::Renderer as Renderer>::Output,
     queued_events: Vec<Event>,
     queued_messages: Vec<P::Message>,
+    is_animating: bool,
+    redraw_at: Option<Instant>,
 }
 
 impl<P> State<P>
@@ -43,6 +47,11 @@ where
             debug,
         );
 
+        // The first layout is reported right away, instead of on the first
+        // event
+        let queued_messages =
+            user_interface.update(&[], cursor_position, None, renderer);
+
         debug.draw_started();
         let primitive = user_interface.draw(renderer, cursor_position);
         debug.draw_finished();
@@ -54,7 +63,9 @@ where
             cache,
             primitive,
             queued_events: Vec::new(),
-            queued_messages: Vec::new(),
+            queued_messages,
+            is_animating: renderer.take_redraw_request(),
+            redraw_at: renderer.take_redraw_deadline(),
         }
     }
 
@@ -96,6 +107,41 @@ where
         self.queued_events.is_empty() && self.queued_messages.is_empty()
     }
 
+    /// Returns whether the widgets of the [`State`] requested to be drawn
+    /// again on the next frame, because they are animating.
+    ///
+    /// An animating [`State`] needs to be updated on every frame, even if its
+    /// queue is empty.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn is_animating(&self) -> bool {
+        self.is_animating
+    }
+
+    /// Returns the instant at which the widgets of the [`State`] requested to
+    /// be drawn again, if any.
+    ///
+    /// Once it is reached, the [`State`] needs to be updated, even if its
+    /// queue is empty.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn redraw_at(&self) -> Option<Instant> {
+        self.redraw_at
+    }
+
+    /// Returns whether the [`State`] needs to be updated, because its queue
+    /// is not empty, it is animating or a requested redraw is due.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn needs_update(&self) -> bool {
+        !self.is_queue_empty()
+            || self.is_animating
+            || self
+                .redraw_at
+                .map(|redraw_at| redraw_at <= Instant::now())
+                .unwrap_or(false)
+    }
+
     /// Processes all the queued events and messages, rebuilding and redrawing
     /// the widgets of the linked [`Program`] if necessary.
     ///
@@ -136,6 +182,8 @@ where
             self.primitive = user_interface.draw(renderer, cursor_position);
             debug.draw_finished();
 
+            self.is_animating = renderer.take_redraw_request();
+            self.redraw_at = renderer.take_redraw_deadline();
             self.cache = Some(user_interface.into_cache());
 
             None
@@ -163,10 +211,21 @@ where
                 debug,
             );
 
+            // The messages produced by the new layout are processed on the
+            // next update
+            self.queued_messages.extend(user_interface.update(
+                &[],
+                cursor_position,
+                clipboard,
+                renderer,
+            ));
+
             debug.draw_started();
             self.primitive = user_interface.draw(renderer, cursor_position);
             debug.draw_finished();
 
+            self.is_animating = renderer.take_redraw_request();
+            self.redraw_at = renderer.take_redraw_deadline();
             self.cache = Some(user_interface.into_cache());
 
             Some(commands)
@@ -181,6 +240,8 @@ fn build_user_interface<'a, P: Program>(
     size: Size,
     debug: &mut Debug,
 ) -> UserInterface<'a, P::Message, P::Renderer> {
+    program.prepare(renderer);
+
     debug.view_started();
     let view = program.view();
     debug.view_finished();
@@ -191,3 +252,97 @@ fn build_user_interface<'a, P: Program>(
 
     user_interface
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::{
+        on_layout, Column, Element, Length, OnLayout, Rectangle, Text,
+    };
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Message {
+        Grow,
+        LaidOut(Rectangle),
+    }
+
+    struct Observer {
+        state: on_layout::State,
+        height: u16,
+        reported: Vec<Rectangle>,
+    }
+
+    impl Program for Observer {
+        type Renderer = Null;
+        type Message = Message;
+
+        fn update(&mut self, message: Message) -> Command<Message> {
+            match message {
+                Message::Grow => self.height += 10,
+                Message::LaidOut(bounds) => self.reported.push(bounds),
+            }
+
+            Command::none()
+        }
+
+        fn view(&mut self) -> Element<'_, Message, Null> {
+            Column::new()
+                .push(OnLayout::new(
+                    &mut self.state,
+                    Text::new("")
+                        .width(Length::Units(30))
+                        .height(Length::Units(self.height)),
+                    Message::LaidOut,
+                ))
+                .into()
+        }
+    }
+
+    fn update(state: &mut State<Observer>) {
+        let _ = state.update(
+            Size::new(100.0, 100.0),
+            Point::ORIGIN,
+            None,
+            &mut Null,
+            &mut Debug::new(),
+        );
+    }
+
+    #[test]
+    fn layout_changes_are_reported_without_events() {
+        let mut state = State::new(
+            Observer {
+                state: on_layout::State::new(),
+                height: 10,
+                reported: Vec::new(),
+            },
+            Size::new(100.0, 100.0),
+            Point::ORIGIN,
+            &mut Null,
+            &mut Debug::new(),
+        );
+
+        assert!(state.needs_update());
+
+        update(&mut state);
+
+        assert!(!state.needs_update());
+
+        state.queue_message(Message::Grow);
+        update(&mut state);
+
+        assert!(state.needs_update());
+
+        update(&mut state);
+
+        assert!(!state.needs_update());
+        assert_eq!(
+            state.program().reported,
+            vec![
+                Rectangle::new(Point::ORIGIN, Size::new(30.0, 10.0)),
+                Rectangle::new(Point::ORIGIN, Size::new(30.0, 20.0)),
+            ]
+        );
+    }
+}
@@ -27,6 +27,8 @@ pub use null::Null;
 
 use crate::{layout, Element, Rectangle};
 
+use std::time::Instant;
+
 /// A component that can take the state of a user interface and produce an
 /// output for its users.
 pub trait Renderer: Sized {
@@ -65,4 +67,40 @@ pub trait Renderer: Sized {
         overlay: Self::Output,
         overlay_bounds: Rectangle,
     ) -> Self::Output;
+
+    /// Requests the user interface to be drawn again on the next frame.
+    ///
+    /// Widgets call this while drawing an [`Animation`] in progress. By
+    /// default, the request is ignored.
+    ///
+    /// [`Animation`]: ../animation/struct.Animation.html
+    fn request_redraw(&mut self) {}
+
+    /// Returns whether a redraw has been requested since the last call,
+    /// clearing the request.
+    ///
+    /// Shells call this after drawing the user interface to decide whether
+    /// to keep drawing frames.
+    fn take_redraw_request(&mut self) -> bool {
+        false
+    }
+
+    /// Requests the user interface to be drawn again once the given instant
+    /// is reached.
+    ///
+    /// Widgets call this to wake up only when their appearance changes next,
+    /// like a blinking cursor. By default, a redraw is requested on the next
+    /// frame instead.
+    fn request_redraw_at(&mut self, _at: Instant) {
+        self.request_redraw();
+    }
+
+    /// Returns the earliest instant a redraw has been requested at since the
+    /// last call, clearing the request.
+    ///
+    /// Shells call this after drawing the user interface to decide when to
+    /// wake up next.
+    fn take_redraw_deadline(&mut self) -> Option<Instant> {
+        None
+    }
 }
@@ -1,8 +1,10 @@
+use crate::overlay::menu;
 use crate::{
-    button, checkbox, column, container, pane_grid, progress_bar, radio, row,
-    scrollable, slider, text, text_input, Color, Element, Font,
-    HorizontalAlignment, Layout, Point, Rectangle, Renderer, Size,
-    VerticalAlignment,
+    button, checkbox, column, container, grid, image, linked_text, modal,
+    pane_grid, pick_list, progress_bar, radio, rich_text, row, scrollable,
+    slider, stack, text, text_input, wrap, Color, ContentFit, Direction,
+    Element, Font, HorizontalAlignment, Layout, Point, Rectangle, Renderer,
+    Size, VerticalAlignment,
 };
 
 /// A renderer that does nothing.
@@ -39,6 +41,39 @@ impl column::Renderer for Null {
     }
 }
 
+impl grid::Renderer for Null {
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _content: &[Element<'_, Message, Self>],
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+    ) {
+    }
+}
+
+impl wrap::Renderer for Null {
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _content: &[Element<'_, Message, Self>],
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+    ) {
+    }
+}
+
+impl stack::Renderer for Null {
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _content: &[Element<'_, Message, Self>],
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+    ) {
+    }
+}
+
 impl row::Renderer for Null {
     fn draw<Message>(
         &mut self,
@@ -81,6 +116,40 @@ impl text::Renderer for Null {
     }
 }
 
+impl rich_text::Renderer for Null {
+    fn measure_spans(
+        &self,
+        _spans: &[rich_text::Span<Font>],
+        _size: u16,
+        _bounds: Size,
+    ) -> (f32, f32) {
+        (0.0, 20.0)
+    }
+
+    fn draw(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _spans: &[rich_text::Span<Font>],
+        _size: u16,
+        _horizontal_alignment: HorizontalAlignment,
+        _vertical_alignment: VerticalAlignment,
+    ) {
+    }
+}
+
+impl linked_text::Renderer for Null {
+    fn draw(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _spans: &[rich_text::Span<Font>],
+        _size: u16,
+        _is_link_hovered: bool,
+    ) {
+    }
+}
+
 impl scrollable::Renderer for Null {
     type Style = ();
 
@@ -101,6 +170,7 @@ impl scrollable::Renderer for Null {
         _is_mouse_over: bool,
         _is_mouse_over_scrollbar: bool,
         _scrollbar: Option<scrollable::Scrollbar>,
+        _scrollbar_alpha: f32,
         _offset: u32,
         _style: &Self::Style,
         _content: Self::Output,
@@ -130,12 +200,16 @@ impl text_input::Renderer for Null {
         &mut self,
         _bounds: Rectangle,
         _text_bounds: Rectangle,
+        _clear_bounds: Option<Rectangle>,
+        _footer: Option<text_input::Footer<'_>>,
         _cursor_position: Point,
         _font: Font,
         _size: u16,
         _placeholder: &str,
         _value: &text_input::Value,
         _state: &text_input::State,
+        _is_cursor_visible: bool,
+        _direction: Direction,
         _style: &Self::Style,
     ) -> Self::Output {
     }
@@ -153,7 +227,11 @@ impl button::Renderer for Null {
         _cursor_position: Point,
         _is_disabled: bool,
         _is_pressed: bool,
+        _is_toggled: bool,
+        _spinner: Option<f32>,
+        _ripple: Option<button::Ripple>,
         _style: &Self::Style,
+        _border: button::Border,
         _content: &Element<'_, Message, Self>,
         _content_layout: Layout<'_>,
     ) -> Self::Output {
@@ -170,11 +248,15 @@ impl radio::Renderer for Null {
         &mut self,
         _bounds: Rectangle,
         _is_selected: bool,
+        _transition: f32,
         _is_mouse_over: bool,
+        _is_focused: bool,
         _label: Self::Output,
         _style: &Self::Style,
     ) {
     }
+
+    fn draw_group(&mut self, _radios: Vec<Self::Output>) {}
 }
 
 impl checkbox::Renderer for Null {
@@ -187,7 +269,10 @@ impl checkbox::Renderer for Null {
         &mut self,
         _bounds: Rectangle,
         _is_checked: bool,
+        _transition: f32,
         _is_mouse_over: bool,
+        _is_focused: bool,
+        _icon: Option<&checkbox::Icon>,
         _label: Self::Output,
         _style: &Self::Style,
     ) {
@@ -221,6 +306,7 @@ impl progress_bar::Renderer for Null {
         _bounds: Rectangle,
         _range: std::ops::RangeInclusive<f32>,
         _value: f32,
+        _segments: Option<u16>,
         _style: &Self::Style,
     ) {
     }
@@ -235,6 +321,8 @@ impl container::Renderer for Null {
         _bounds: Rectangle,
         _cursor_position: Point,
         _style: &Self::Style,
+        _background_image: Option<(&image::Handle, ContentFit)>,
+        _clip: bool,
         _content: &Element<'_, Message, Self>,
         _content_layout: Layout<'_>,
     ) {
@@ -281,3 +369,71 @@ impl pane_grid::Renderer for Null {
     ) {
     }
 }
+
+impl menu::Renderer for Null {
+    type Style = ();
+
+    fn decorate(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _style: &(),
+        _primitive: (),
+    ) {
+    }
+
+    fn draw<T>(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _rows: &[Rectangle],
+        _options: &[T],
+        _format: &dyn Fn(&T) -> String,
+        _highlight: Option<&str>,
+        _checked: Option<&[bool]>,
+        _leading: Option<&dyn Fn(&T) -> String>,
+        _selected: Option<usize>,
+        _hovered_option: Option<usize>,
+        _padding: u16,
+        _text_size: u16,
+        _font: Font,
+        _style: &(),
+    ) {
+    }
+}
+
+impl pick_list::Renderer for Null {
+    const DEFAULT_PADDING: u16 = 0;
+
+    type Style = ();
+
+    fn menu_style(_style: &()) {}
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _selected: Option<String>,
+        _padding: u16,
+        _text_size: u16,
+        _font: Font,
+        _icon: Option<&pick_list::Icon<Font>>,
+        _style: &(),
+    ) {
+    }
+}
+
+impl modal::Renderer for Null {
+    type Style = ();
+
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _style: &(),
+        _content: &Element<'_, Message, Self>,
+        _content_layout: Layout<'_>,
+    ) {
+    }
+}
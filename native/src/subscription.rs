@@ -33,7 +33,7 @@ pub use iced_futures::subscription::Recipe;
 
 mod events;
 
-use events::Events;
+use events::{Events, EventsWith};
 
 /// Returns a [`Subscription`] to all the runtime events.
 ///
@@ -45,3 +45,21 @@ use events::Events;
 pub fn events() -> Subscription<Event> {
     Subscription::from_recipe(Events)
 }
+
+/// Returns a [`Subscription`] that filters all the runtime events with the
+/// provided function, producing messages accordingly.
+///
+/// This subscription will call the provided function for every [`Event`]
+/// handled by the runtime. If the function returns `Some(message)`, the
+/// `message` will be produced by the [`Subscription`].
+///
+/// [`Subscription`]: type.Subscription.html
+/// [`Event`]: ../enum.Event.html
+pub fn events_with<Message>(
+    f: fn(Event) -> Option<Message>,
+) -> Subscription<Message>
+where
+    Message: 'static + Send,
+{
+    Subscription::from_recipe(EventsWith::new(f))
+}
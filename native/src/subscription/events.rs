@@ -2,6 +2,8 @@ use crate::{
     subscription::{EventStream, Recipe},
     Event, Hasher,
 };
+use iced_futures::futures::future;
+use iced_futures::futures::StreamExt;
 use iced_futures::BoxStream;
 
 pub struct Events;
@@ -22,3 +24,38 @@ impl Recipe<Hasher, Event> for Events {
         event_stream
     }
 }
+
+pub struct EventsWith<Message> {
+    f: fn(Event) -> Option<Message>,
+}
+
+impl<Message> EventsWith<Message> {
+    pub fn new(f: fn(Event) -> Option<Message>) -> Self {
+        Self { f }
+    }
+}
+
+impl<Message> Recipe<Hasher, Event> for EventsWith<Message>
+where
+    Message: 'static + Send,
+{
+    type Output = Message;
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        (self.f as usize).hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        event_stream: EventStream,
+    ) -> BoxStream<Self::Output> {
+        let f = self.f;
+
+        event_stream
+            .filter_map(move |event| future::ready(f(event)))
+            .boxed()
+    }
+}
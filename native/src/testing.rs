@@ -0,0 +1,391 @@
+//! Test widgets by replaying events and asserting the resulting messages.
+//!
+//! This module is only available with the `testing` feature enabled.
+use crate::accessibility;
+use crate::keyboard::{self, KeyCode, ModifiersState};
+use crate::mouse;
+use crate::renderer::Null;
+use crate::{
+    layout, Clipboard, Element, Event, Layout, Point, Renderer, Size, Vector,
+};
+
+use std::fmt::Write;
+
+/// A harness that lays out an [`Element`] with a [`Null`] renderer and
+/// replays events through it, recording every produced message.
+///
+/// Widgets borrow their local state for as long as the [`Harness`] lives.
+/// Drop it (e.g. by calling [`into_messages`]) before asserting on that
+/// state.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{button, Button, Length, Point, Size, Text};
+/// # use iced_native::testing::Harness;
+/// #
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Message {
+///     Pressed,
+/// }
+///
+/// let mut state = button::State::new();
+/// let button = Button::new(&mut state, Text::new("Press me!"))
+///     .width(Length::Units(100))
+///     .on_press(Message::Pressed);
+///
+/// let mut harness = Harness::new(button, Size::new(200.0, 100.0));
+/// harness.click(Point::new(5.0, 5.0));
+///
+/// assert_eq!(harness.messages(), &[Message::Pressed]);
+/// ```
+///
+/// [`Element`]: ../struct.Element.html
+/// [`Null`]: ../renderer/struct.Null.html
+/// [`Harness`]: struct.Harness.html
+/// [`into_messages`]: #method.into_messages
+#[allow(missing_debug_implementations)]
+pub struct Harness<'a, Message> {
+    root: Element<'a, Message, Null>,
+    bounds: Size,
+    layout: layout::Node,
+    renderer: Null,
+    cursor_position: Point,
+    modifiers: ModifiersState,
+    clipboard: Option<&'a dyn Clipboard>,
+    messages: Vec<Message>,
+}
+
+impl<'a, Message> Harness<'a, Message> {
+    /// Creates a new [`Harness`] and lays out the given root [`Element`] in
+    /// the given bounds.
+    ///
+    /// [`Harness`]: struct.Harness.html
+    /// [`Element`]: ../struct.Element.html
+    pub fn new(
+        root: impl Into<Element<'a, Message, Null>>,
+        bounds: Size,
+    ) -> Self {
+        let root = root.into();
+        let mut renderer = Null::new();
+        let layout =
+            renderer.layout(&root, &layout::Limits::new(Size::ZERO, bounds));
+
+        Harness {
+            root,
+            bounds,
+            layout,
+            renderer,
+            cursor_position: Point::new(-1.0, -1.0),
+            modifiers: ModifiersState::default(),
+            clipboard: None,
+            messages: Vec::new(),
+        }
+    }
+
+    /// Sets the [`Clipboard`] given to the widgets when handling events.
+    ///
+    /// [`Clipboard`]: ../trait.Clipboard.html
+    pub fn clipboard(mut self, clipboard: &'a dyn Clipboard) -> Self {
+        self.clipboard = Some(clipboard);
+        self
+    }
+
+    /// Returns the [`Layout`] of the root [`Element`].
+    ///
+    /// [`Layout`]: ../struct.Layout.html
+    /// [`Element`]: ../struct.Element.html
+    pub fn layout(&self) -> Layout<'_> {
+        Layout::new(&self.layout)
+    }
+
+    /// Returns a [`snapshot`] of the current layout of the root [`Element`].
+    ///
+    /// [`snapshot`]: fn.snapshot.html
+    /// [`Element`]: ../struct.Element.html
+    pub fn snapshot(&self) -> String {
+        snapshot(&self.layout)
+    }
+
+    /// Returns the [`accessibility::tree`] of the current layout of the root
+    /// [`Element`].
+    ///
+    /// [`accessibility::tree`]: ../accessibility/fn.tree.html
+    /// [`Element`]: ../struct.Element.html
+    pub fn accessibility_tree(&self) -> Vec<accessibility::Node> {
+        accessibility::tree(&self.layout)
+    }
+
+    /// Returns the current position of the cursor.
+    pub fn cursor_position(&self) -> Point {
+        self.cursor_position
+    }
+
+    /// Dispatches an [`Event`] to the widgets and their overlay, if any.
+    ///
+    /// A [`CursorMoved`] event updates the position of the cursor before
+    /// being dispatched.
+    ///
+    /// [`Event`]: ../enum.Event.html
+    /// [`CursorMoved`]: ../mouse/enum.Event.html#variant.CursorMoved
+    pub fn event(&mut self, event: Event) {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { x, y }) => {
+                self.cursor_position = Point::new(x, y);
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                self.modifiers = modifiers;
+            }
+            _ => {}
+        }
+
+        let base_cursor = if let Some(mut overlay) =
+            self.root.overlay(Layout::new(&self.layout))
+        {
+            let layout = overlay.layout(&self.renderer, self.bounds);
+
+            overlay.on_event(
+                event.clone(),
+                Layout::new(&layout),
+                self.cursor_position,
+                &mut self.messages,
+                &self.renderer,
+                self.clipboard,
+            );
+
+            if layout.bounds().contains(self.cursor_position) {
+                Point::new(-1.0, -1.0)
+            } else {
+                self.cursor_position
+            }
+        } else {
+            self.cursor_position
+        };
+
+        let _ = self.root.on_event(
+            event,
+            Layout::new(&self.layout),
+            base_cursor,
+            &mut self.messages,
+            &self.renderer,
+            self.clipboard,
+        );
+
+        // Widgets may change their layout after handling an event
+        self.layout = self
+            .renderer
+            .layout(&self.root, &layout::Limits::new(Size::ZERO, self.bounds));
+    }
+
+    /// Dispatches a sequence of events to the widgets, one by one.
+    pub fn events(&mut self, events: impl IntoIterator<Item = Event>) {
+        for event in events {
+            self.event(event);
+        }
+    }
+
+    /// Notifies the widgets that the layout changed, like the runtime does
+    /// after every new layout.
+    pub fn layout_changed(&mut self) {
+        self.root
+            .widget
+            .layout_changed(Layout::new(&self.layout), &mut self.messages);
+    }
+
+    /// Moves the cursor to the given position.
+    pub fn move_cursor_to(&mut self, position: Point) {
+        self.event(Event::Mouse(mouse::Event::CursorMoved {
+            x: position.x,
+            y: position.y,
+        }));
+    }
+
+    /// Moves the cursor to the given position and clicks the left mouse
+    /// button.
+    pub fn click(&mut self, position: Point) {
+        self.move_cursor_to(position);
+
+        self.events(vec![
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+    }
+
+    /// Presses and releases the given key with the current modifiers.
+    pub fn press_key(&mut self, key_code: KeyCode) {
+        let modifiers = self.modifiers;
+
+        self.events(vec![
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+                is_repeat: false,
+            }),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code,
+                modifiers,
+            }),
+        ]);
+    }
+
+    /// Types the given text, one character at a time.
+    pub fn type_text(&mut self, text: &str) {
+        self.events(
+            text.chars().map(|c| {
+                Event::Keyboard(keyboard::Event::CharacterReceived(c))
+            }),
+        );
+    }
+
+    /// Returns all the messages produced so far.
+    pub fn messages(&self) -> &[Message] {
+        &self.messages
+    }
+
+    /// Returns all the messages produced so far, leaving none behind.
+    pub fn take_messages(&mut self) -> Vec<Message> {
+        std::mem::take(&mut self.messages)
+    }
+
+    /// Consumes the [`Harness`] and returns all the messages it produced,
+    /// releasing the local state of the widgets.
+    ///
+    /// [`Harness`]: struct.Harness.html
+    pub fn into_messages(self) -> Vec<Message> {
+        self.messages
+    }
+}
+
+/// Produces a deterministic textual representation of a layout tree, useful
+/// for golden tests.
+///
+/// Every [`Node`] is written on its own line, indented by its depth, with the
+/// type of the widget that produced it and its absolute bounds. Nodes that
+/// were not produced by an [`Element`] (e.g. the inner nodes of a widget) are
+/// written as `Node`.
+///
+/// ```text
+/// Column (0, 0) 100x40
+///   Text (0, 0) 100x20
+///   Text (0, 20) 100x20
+/// ```
+///
+/// [`Node`]: ../layout/struct.Node.html
+/// [`Element`]: ../struct.Element.html
+pub fn snapshot(node: &layout::Node) -> String {
+    let mut output = String::new();
+
+    write_node(&mut output, node, Vector::new(0.0, 0.0), 0);
+
+    output
+}
+
+fn write_node(
+    output: &mut String,
+    node: &layout::Node,
+    offset: Vector,
+    depth: usize,
+) {
+    let bounds = node.bounds();
+    let position = Point::new(bounds.x, bounds.y) + offset;
+
+    // Writing to a `String` never fails
+    let _ = writeln!(
+        output,
+        "{:indent$}{} ({}, {}) {}x{}",
+        "",
+        node.tag().unwrap_or("Node"),
+        position.x,
+        position.y,
+        bounds.width,
+        bounds.height,
+        indent = depth * 2,
+    );
+
+    for child in node.children() {
+        write_node(
+            output,
+            child,
+            Vector::new(position.x, position.y),
+            depth + 1,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        checkbox, text_input, Checkbox, Column, Length, Text, TextInput,
+    };
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Toggled(bool),
+        Changed(String),
+    }
+
+    #[test]
+    fn checkbox_toggles_with_keyboard_once_focused() {
+        let mut state = checkbox::State::new();
+        let checkbox =
+            Checkbox::new(&mut state, false, "Check me!", Message::Toggled);
+
+        let mut harness = Harness::new(checkbox, Size::new(200.0, 100.0));
+        harness.press_key(KeyCode::Space);
+        assert!(harness.messages().is_empty());
+
+        harness.click(Point::new(5.0, 5.0));
+        harness.press_key(KeyCode::Space);
+
+        assert_eq!(
+            harness.into_messages(),
+            vec![Message::Toggled(true), Message::Toggled(true)]
+        );
+        assert!(state.is_focused());
+    }
+
+    #[test]
+    fn text_input_receives_typed_text_once_focused() {
+        let mut state = text_input::State::new();
+        let text_input =
+            TextInput::new(&mut state, "Type something...", "", |value| {
+                Message::Changed(value)
+            });
+
+        let mut harness = Harness::new(text_input, Size::new(200.0, 100.0));
+        harness.type_text("a");
+        assert!(harness.take_messages().is_empty());
+
+        harness.click(Point::new(5.0, 5.0));
+        harness.type_text("ab");
+
+        assert_eq!(
+            harness.into_messages(),
+            vec![
+                Message::Changed(String::from("a")),
+                Message::Changed(String::from("ab"))
+            ]
+        );
+    }
+
+    #[test]
+    fn snapshot_lists_widgets_with_absolute_bounds() {
+        let column: Column<'_, Message, Null> = Column::new()
+            .padding(10)
+            .spacing(5)
+            .push(Text::new("First").width(Length::Units(50)))
+            .push(Text::new("Second").width(Length::Units(80)));
+
+        let harness = Harness::new(column, Size::new(200.0, 100.0));
+
+        assert_eq!(
+            harness.snapshot().lines().collect::<Vec<_>>(),
+            vec![
+                "Column (0, 0) 100x65",
+                "  Text (10, 10) 50x20",
+                "  Text (10, 35) 80x20",
+            ]
+        );
+    }
+}
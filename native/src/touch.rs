@@ -0,0 +1,5 @@
+//! Track touch events.
+pub mod gesture;
+
+pub use gesture::{Gesture, Gestures};
+pub use iced_core::touch::*;
@@ -0,0 +1,298 @@
+//! Recognize taps, pans and pinches out of touch events.
+use crate::touch::{self, Finger};
+use crate::{Point, Vector};
+
+/// The distance a finger needs to move for a touch to stop being a tap and
+/// start panning, in logical pixels.
+pub const THRESHOLD: f32 = 8.0;
+
+/// A gesture recognized by [`Gestures`].
+///
+/// [`Gestures`]: struct.Gestures.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gesture {
+    /// A finger was pressed and lifted without moving, while no other finger
+    /// was touching the screen.
+    Tap {
+        /// The position of the finger.
+        position: Point,
+    },
+
+    /// A single finger moved.
+    Pan {
+        /// The current position of the finger.
+        position: Point,
+
+        /// The movement since the last [`Gesture`].
+        ///
+        /// [`Gesture`]: enum.Gesture.html
+        delta: Vector,
+    },
+
+    /// One of two fingers moved.
+    Pinch {
+        /// The point halfway between both fingers.
+        center: Point,
+
+        /// The movement of the center since the last [`Gesture`].
+        ///
+        /// [`Gesture`]: enum.Gesture.html
+        delta: Vector,
+
+        /// The change of the distance between both fingers since the last
+        /// [`Gesture`], as a factor.
+        ///
+        /// [`Gesture`]: enum.Gesture.html
+        scale: f32,
+    },
+}
+
+/// The tracker of the gestures of the fingers touching a widget.
+///
+/// A widget keeps some [`Gestures`] in its local state and feeds them the
+/// touch events it receives in `on_event`.
+///
+/// The first finger pressed pans, once it moves past a threshold. Pressing a
+/// second finger turns the pan into a pinch of both fingers, and any other
+/// finger is ignored. When one of the pinching fingers is lifted, the other
+/// one pans again. Fingers are told apart by their [`Finger`] identifier, so
+/// the events of ignored fingers do not disturb the tracked ones.
+///
+/// [`Gestures`]: struct.Gestures.html
+/// [`Finger`]: ../struct.Finger.html
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Gestures {
+    fingers: [Option<Touch>; 2],
+    is_panning: bool,
+    is_tap: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Touch {
+    id: Finger,
+    position: Point,
+}
+
+impl Gestures {
+    /// Creates some new [`Gestures`] without any finger touching.
+    ///
+    /// [`Gestures`]: struct.Gestures.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Processes a touch event.
+    ///
+    /// A new finger is only tracked if `can_start` is true, which usually
+    /// means that the finger is over the widget.
+    pub fn update(
+        &mut self,
+        event: touch::Event,
+        can_start: bool,
+    ) -> Option<Gesture> {
+        match event {
+            touch::Event::FingerPressed { id, position } if can_start => {
+                let slot =
+                    self.fingers.iter_mut().find(|slot| slot.is_none())?;
+                *slot = Some(Touch { id, position });
+
+                self.is_tap = self.fingers().count() == 1;
+
+                None
+            }
+            touch::Event::FingerMoved { id, position } => {
+                let index = self.index_of(id)?;
+
+                match self.fingers {
+                    [Some(a), Some(b)] => {
+                        let (before, after) = if a.id == id {
+                            ((a.position, b.position), (position, b.position))
+                        } else {
+                            ((a.position, b.position), (a.position, position))
+                        };
+
+                        self.fingers[index] = Some(Touch { id, position });
+                        self.is_tap = false;
+
+                        let before_distance = before.0.distance(before.1);
+                        let center = midpoint(after.0, after.1);
+
+                        Some(Gesture::Pinch {
+                            center,
+                            delta: center - midpoint(before.0, before.1),
+                            scale: if before_distance > 0.0 {
+                                after.0.distance(after.1) / before_distance
+                            } else {
+                                1.0
+                            },
+                        })
+                    }
+                    [Some(touch), None] | [None, Some(touch)] => {
+                        // The finger keeps its initial position until it
+                        // starts panning, so no movement is lost
+                        if !self.is_panning
+                            && position.distance(touch.position) < THRESHOLD
+                        {
+                            return None;
+                        }
+
+                        self.fingers[index] = Some(Touch { id, position });
+                        self.is_panning = true;
+                        self.is_tap = false;
+
+                        Some(Gesture::Pan {
+                            position,
+                            delta: position - touch.position,
+                        })
+                    }
+                    _ => None,
+                }
+            }
+            touch::Event::FingerLifted { id, position } => {
+                let index = self.index_of(id)?;
+                self.fingers[index] = None;
+
+                let is_tap = self.is_tap;
+                self.end_touch();
+
+                if is_tap {
+                    Some(Gesture::Tap { position })
+                } else {
+                    None
+                }
+            }
+            touch::Event::FingerLost { id, .. } => {
+                let index = self.index_of(id)?;
+                self.fingers[index] = None;
+
+                self.end_touch();
+
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns whether any tracked finger is touching the screen.
+    pub fn is_touching(&self) -> bool {
+        self.fingers().next().is_some()
+    }
+
+    fn fingers(&self) -> impl Iterator<Item = Touch> + '_ {
+        self.fingers.iter().flatten().copied()
+    }
+
+    fn index_of(&self, id: Finger) -> Option<usize> {
+        self.fingers
+            .iter()
+            .position(|slot| slot.map(|touch| touch.id) == Some(id))
+    }
+
+    fn end_touch(&mut self) {
+        // The remaining finger of a pinch pans right away
+        self.is_panning = self.is_touching();
+        self.is_tap = false;
+    }
+}
+
+fn midpoint(a: Point, b: Point) -> Point {
+    Point::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pressed(id: u64, x: f32, y: f32) -> touch::Event {
+        touch::Event::FingerPressed {
+            id: Finger(id),
+            position: Point::new(x, y),
+        }
+    }
+
+    fn moved(id: u64, x: f32, y: f32) -> touch::Event {
+        touch::Event::FingerMoved {
+            id: Finger(id),
+            position: Point::new(x, y),
+        }
+    }
+
+    fn lifted(id: u64, x: f32, y: f32) -> touch::Event {
+        touch::Event::FingerLifted {
+            id: Finger(id),
+            position: Point::new(x, y),
+        }
+    }
+
+    fn recognize(events: Vec<touch::Event>) -> Vec<Gesture> {
+        let mut gestures = Gestures::new();
+
+        events
+            .into_iter()
+            .filter_map(|event| gestures.update(event, true))
+            .collect()
+    }
+
+    #[test]
+    fn a_finger_that_does_not_move_taps() {
+        assert_eq!(
+            recognize(vec![
+                pressed(1, 10.0, 10.0),
+                moved(1, 12.0, 10.0),
+                lifted(1, 12.0, 10.0),
+            ]),
+            vec![Gesture::Tap {
+                position: Point::new(12.0, 10.0),
+            }]
+        );
+    }
+
+    #[test]
+    fn a_finger_pans_past_the_threshold() {
+        assert_eq!(
+            recognize(vec![
+                pressed(1, 10.0, 10.0),
+                moved(1, 10.0, 15.0),
+                moved(1, 10.0, 30.0),
+                moved(1, 10.0, 35.0),
+                lifted(1, 10.0, 35.0),
+            ]),
+            vec![
+                Gesture::Pan {
+                    position: Point::new(10.0, 30.0),
+                    delta: Vector::new(0.0, 20.0),
+                },
+                Gesture::Pan {
+                    position: Point::new(10.0, 35.0),
+                    delta: Vector::new(0.0, 5.0),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn two_fingers_pinch_and_others_are_ignored() {
+        assert_eq!(
+            recognize(vec![
+                pressed(1, 0.0, 0.0),
+                pressed(2, 10.0, 0.0),
+                pressed(3, 50.0, 50.0),
+                moved(3, 80.0, 80.0),
+                moved(2, 20.0, 0.0),
+                lifted(1, 0.0, 0.0),
+                moved(2, 20.0, 10.0),
+            ]),
+            vec![
+                Gesture::Pinch {
+                    center: Point::new(10.0, 0.0),
+                    delta: Vector::new(5.0, 0.0),
+                    scale: 2.0,
+                },
+                Gesture::Pan {
+                    position: Point::new(20.0, 10.0),
+                    delta: Vector::new(0.0, 10.0),
+                },
+            ]
+        );
+    }
+}
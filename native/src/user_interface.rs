@@ -1,4 +1,7 @@
-use crate::{layout, overlay, Clipboard, Element, Event, Layout, Point, Size};
+use crate::{
+    accessibility, layout, overlay, Clipboard, Element, Event, Layout, Point,
+    Size,
+};
 
 use std::hash::Hasher;
 
@@ -23,6 +26,7 @@ pub struct UserInterface<'a, Message, Renderer> {
     base: Layer,
     overlay: Option<Layer>,
     bounds: Size,
+    layout_changed: bool,
 }
 
 impl<'a, Message, Renderer> UserInterface<'a, Message, Renderer>
@@ -93,6 +97,7 @@ where
         renderer: &mut Renderer,
     ) -> Self {
         let root = root.into();
+        let layout_changed;
 
         let (base, overlay) = {
             let hash = {
@@ -105,6 +110,8 @@ where
             let layout_is_cached =
                 hash == cache.base.hash && bounds == cache.bounds;
 
+            layout_changed = cache.layout_changed || !layout_is_cached;
+
             let (layout, overlay) = if layout_is_cached {
                 (cache.base.layout, cache.overlay)
             } else {
@@ -125,6 +132,7 @@ where
             base,
             overlay,
             bounds,
+            layout_changed,
         }
     }
 
@@ -203,6 +211,14 @@ where
     ) -> Vec<Message> {
         let mut messages = Vec::new();
 
+        if self.layout_changed {
+            self.layout_changed = false;
+
+            self.root
+                .widget
+                .layout_changed(Layout::new(&self.base.layout), &mut messages);
+        }
+
         let base_cursor = if let Some(mut overlay) =
             self.root.overlay(Layout::new(&self.base.layout))
         {
@@ -240,7 +256,7 @@ where
         };
 
         for event in events {
-            self.root.widget.on_event(
+            let _ = self.root.widget.on_event(
                 event.clone(),
                 Layout::new(&self.base.layout),
                 base_cursor,
@@ -382,6 +398,15 @@ where
         }
     }
 
+    /// Returns the [`accessibility::tree`] of the widgets of the
+    /// [`UserInterface`], excluding any overlay.
+    ///
+    /// [`accessibility::tree`]: accessibility/fn.tree.html
+    /// [`UserInterface`]: struct.UserInterface.html
+    pub fn accessibility_tree(&self) -> Vec<accessibility::Node> {
+        accessibility::tree(&self.base.layout)
+    }
+
     /// Extract the [`Cache`] of the [`UserInterface`], consuming it in the
     /// process.
     ///
@@ -392,6 +417,7 @@ where
             base: self.base,
             overlay: self.overlay,
             bounds: self.bounds,
+            layout_changed: self.layout_changed,
         }
     }
 
@@ -434,6 +460,7 @@ pub struct Cache {
     base: Layer,
     overlay: Option<Layer>,
     bounds: Size,
+    layout_changed: bool,
 }
 
 impl Cache {
@@ -452,6 +479,7 @@ impl Cache {
             },
             overlay: None,
             bounds: Size::ZERO,
+            layout_changed: false,
         }
     }
 }
@@ -10,6 +10,38 @@
 //! [`Widget`] trait. You can use the API of the built-in widgets as a guide or
 //! source of inspiration.
 //!
+//! # Stateful widgets
+//! Widgets do not keep any state between rebuilds of the user interface.
+//! Instead, stateful widgets borrow their local state from your application,
+//! like the [`text_input::State`] of a [`TextInput`].
+//!
+//! Therefore, elements do not need keys to keep their state when the items
+//! of a dynamic list are reordered: store the state of every widget next to
+//! the item it represents, and it will follow the item wherever it goes.
+//!
+//! ```
+//! # use iced_native::{renderer::Null, text_input, Column, TextInput};
+//! #
+//! struct Task {
+//!     description: String,
+//!     input: text_input::State,
+//! }
+//!
+//! fn view(tasks: &mut [Task]) -> Column<'_, String, Null> {
+//!     tasks.iter_mut().fold(Column::new(), |column, task| {
+//!         column.push(TextInput::new(
+//!             &mut task.input,
+//!             "Description",
+//!             &task.description,
+//!             |description| description,
+//!         ))
+//!     })
+//! }
+//! ```
+//!
+//! When the items cannot hold the state of their widgets, keep it in a
+//! [`keyed::State`] instead, which stores it by a stable key of each item.
+//!
 //! # Re-exports
 //! For convenience, the contents of this module are available at the root
 //! module. Therefore, you can directly type:
@@ -20,24 +52,45 @@
 //!
 //! [`Widget`]: trait.Widget.html
 //! [renderer]: ../renderer/index.html
+//! [`text_input::State`]: text_input/struct.State.html
+//! [`TextInput`]: text_input/struct.TextInput.html
+//! [`keyed::State`]: keyed/struct.State.html
+pub mod aspect_ratio;
 pub mod button;
 pub mod checkbox;
 pub mod column;
+pub mod component;
 pub mod container;
+pub mod context_menu;
+pub mod grid;
 pub mod image;
+pub mod keyed;
+pub mod linked_text;
+pub mod modal;
+pub mod mouse_area;
+pub mod multi_pick_list;
+pub mod on_layout;
 pub mod pane_grid;
 pub mod pick_list;
+pub mod popover;
 pub mod progress_bar;
 pub mod radio;
+pub mod responsive;
+pub mod rich_text;
 pub mod row;
 pub mod rule;
 pub mod scrollable;
 pub mod slider;
 pub mod space;
+pub mod stack;
 pub mod svg;
 pub mod text;
 pub mod text_input;
+pub mod tooltip;
+pub mod wrap;
 
+#[doc(no_inline)]
+pub use aspect_ratio::AspectRatio;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
@@ -45,17 +98,39 @@ pub use checkbox::Checkbox;
 #[doc(no_inline)]
 pub use column::Column;
 #[doc(no_inline)]
+pub use component::{Component, Stateful};
+#[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use context_menu::ContextMenu;
+#[doc(no_inline)]
+pub use grid::Grid;
+#[doc(no_inline)]
 pub use image::Image;
 #[doc(no_inline)]
+pub use linked_text::LinkedText;
+#[doc(no_inline)]
+pub use modal::Modal;
+#[doc(no_inline)]
+pub use mouse_area::MouseArea;
+#[doc(no_inline)]
+pub use multi_pick_list::MultiPickList;
+#[doc(no_inline)]
+pub use on_layout::OnLayout;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
 #[doc(no_inline)]
+pub use popover::Popover;
+#[doc(no_inline)]
 pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
-pub use radio::Radio;
+pub use radio::{Radio, RadioGroup};
+#[doc(no_inline)]
+pub use responsive::Responsive;
+#[doc(no_inline)]
+pub use rich_text::RichText;
 #[doc(no_inline)]
 pub use row::Row;
 #[doc(no_inline)]
@@ -67,13 +142,24 @@ pub use slider::Slider;
 #[doc(no_inline)]
 pub use space::Space;
 #[doc(no_inline)]
+pub use stack::Stack;
+#[doc(no_inline)]
 pub use svg::Svg;
 #[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
 pub use text_input::TextInput;
+#[doc(no_inline)]
+pub use tooltip::Tooltip;
+#[doc(no_inline)]
+pub use wrap::Wrap;
 
-use crate::{layout, overlay, Clipboard, Event, Hasher, Layout, Length, Point};
+use crate::accessibility::Accessibility;
+use crate::focus::Focusable;
+use crate::{
+    event, layout, overlay, Clipboard, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size,
+};
 
 /// A component that displays information and allows interaction.
 ///
@@ -114,6 +200,30 @@ where
     /// [`Widget`]: trait.Widget.html
     fn height(&self) -> Length;
 
+    /// Returns the minimum size of the contents of the [`Widget`].
+    ///
+    /// Containers never shrink a flexible [`Widget`] below this size, so its
+    /// contents are not squashed when space is scarce.
+    ///
+    /// By default, it returns a zero size.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    fn min_size(&self, _renderer: &Renderer) -> Size {
+        Size::ZERO
+    }
+
+    /// Returns the maximum size of the [`Widget`].
+    ///
+    /// Containers never give a flexible [`Widget`] more space than this
+    /// size, and share the space it does not take among the others.
+    ///
+    /// By default, it returns an infinite size.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    fn max_size(&self) -> Size {
+        Size::INFINITY
+    }
+
     /// Returns the [`Node`] of the [`Widget`].
     ///
     /// This [`Node`] is used by the runtime to compute the [`Layout`] of the
@@ -165,7 +275,10 @@ where
     ///   * the `Renderer`
     ///   * a [`Clipboard`], if available
     ///
-    /// By default, it does nothing.
+    /// It returns whether the [`Event`] was captured, letting the parents of
+    /// the [`Widget`] decide whether to handle it themselves.
+    ///
+    /// By default, it does nothing and ignores the [`Event`].
     ///
     /// [`Event`]: ../enum.Event.html
     /// [`Widget`]: trait.Widget.html
@@ -179,6 +292,24 @@ where
         _messages: &mut Vec<Message>,
         _renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        event::Status::Ignored
+    }
+
+    /// Notifies the [`Widget`] that the layout of the user interface was
+    /// recomputed, giving it its new [`Layout`].
+    ///
+    /// The runtime calls it once after every new layout, before processing
+    /// any events. Widgets with children should forward it to them.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    fn layout_changed(
+        &mut self,
+        _layout: Layout<'_>,
+        _messages: &mut Vec<Message>,
     ) {
     }
 
@@ -191,4 +322,57 @@ where
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         None
     }
+
+    /// Returns the bounds of the focused [`Widget`] among this [`Widget`]
+    /// and its children, if any.
+    ///
+    /// Focusable widgets should return their bounds while focused, and
+    /// widgets with children should look for a focused child. A
+    /// [`Scrollable`] uses it to reveal its focused children.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`Scrollable`]: scrollable/struct.Scrollable.html
+    fn focused_bounds(&self, _layout: Layout<'_>) -> Option<Rectangle> {
+        None
+    }
+
+    /// Visits the [`Focusable`] states of this [`Widget`] and its children,
+    /// in the order the keyboard focus moves through them.
+    ///
+    /// Focusable widgets should visit their state, and widgets with children
+    /// should visit them in order. Widgets that build their children on
+    /// demand can build them for the duration of the visit.
+    ///
+    /// The visit may change the focus of the states, so focusable widgets
+    /// should produce the messages of their focus changes afterwards, like the
+    /// `on_blur` message of a [`TextInput`].
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Focusable`]: ../focus/trait.Focusable.html
+    /// [`Widget`]: trait.Widget.html
+    /// [`TextInput`]: text_input/struct.TextInput.html
+    fn focusables(
+        &mut self,
+        _visit: &mut dyn FnMut(&mut dyn Focusable),
+        _messages: &mut Vec<Message>,
+    ) {
+    }
+
+    /// Returns the [`Accessibility`] metadata describing the [`Widget`], if
+    /// any.
+    ///
+    /// The metadata is stored in the layout of the [`Widget`], where it can
+    /// be collected by [`accessibility::tree`].
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Accessibility`]: ../accessibility/struct.Accessibility.html
+    /// [`Widget`]: trait.Widget.html
+    /// [`accessibility::tree`]: ../accessibility/fn.tree.html
+    fn accessibility(&self) -> Option<Accessibility> {
+        None
+    }
 }
@@ -0,0 +1,288 @@
+//! Keep some content at a fixed aspect ratio.
+use std::hash::Hash;
+
+use crate::focus::Focusable;
+use crate::{
+    event, layout, overlay, Clipboard, Element, Event, Hasher, Layout, Length,
+    Point, Rectangle, Size, Widget,
+};
+
+/// An element that keeps its content at a fixed aspect ratio.
+///
+/// The height of the [`AspectRatio`] is computed from its width. If there
+/// is not enough vertical space available, the width is computed from the
+/// height instead.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{renderer::Null, Length};
+/// #
+/// # type AspectRatio<'a> = iced_native::AspectRatio<'a, (), Null>;
+/// # type Text = iced_native::Text<Null>;
+/// #
+/// let thumbnail = AspectRatio::new(16.0 / 9.0, Text::new("Thumbnail"))
+///     .width(Length::Units(320));
+/// ```
+///
+/// [`AspectRatio`]: struct.AspectRatio.html
+#[allow(missing_debug_implementations)]
+pub struct AspectRatio<'a, Message, Renderer> {
+    ratio: Ratio,
+    width: Length,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> AspectRatio<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    /// Creates a new [`AspectRatio`] with the given ratio and content.
+    ///
+    /// The ratio is the width divided by the height (e.g. `16.0 / 9.0`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ratio is not positive and finite.
+    ///
+    /// [`AspectRatio`]: struct.AspectRatio.html
+    pub fn new<T>(ratio: f32, content: T) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        AspectRatio {
+            ratio: Ratio::new(ratio),
+            width: Length::Fill,
+            content: content.into(),
+        }
+    }
+
+    /// Sets the width of the [`AspectRatio`].
+    ///
+    /// [`AspectRatio`]: struct.AspectRatio.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for AspectRatio<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width);
+        let max = limits.max();
+
+        let width = match self.width {
+            Length::Shrink => {
+                self.content.layout(renderer, &limits.loose()).size().width
+            }
+            _ => max.width,
+        };
+
+        let ratio = self.ratio;
+
+        let size = if width.is_finite() && ratio.height(width) <= max.height {
+            Size::new(width, ratio.height(width))
+        } else if max.height.is_finite() {
+            Size::new(ratio.width(max.height), max.height)
+        } else {
+            let width =
+                self.content.layout(renderer, &limits.loose()).size().width;
+
+            Size::new(width, ratio.height(width))
+        };
+
+        let content = self
+            .content
+            .layout(renderer, &layout::Limits::new(size, size));
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        self.content.widget.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn layout_changed(
+        &mut self,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+    ) {
+        self.content
+            .layout_changed(layout.children().next().unwrap(), messages)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.content.draw(
+            renderer,
+            defaults,
+            layout.children().next().unwrap(),
+            cursor_position,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.ratio.hash(state);
+        self.width.hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout.children().next().unwrap())
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.content
+            .focused_bounds(layout.children().next().unwrap())
+    }
+
+    fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        messages: &mut Vec<Message>,
+    ) {
+        self.content.focusables(visit, messages)
+    }
+}
+
+impl<'a, Message, Renderer> From<AspectRatio<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        aspect_ratio: AspectRatio<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(aspect_ratio)
+    }
+}
+
+/// The width of something divided by its height.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Ratio(f32);
+
+impl Ratio {
+    /// Creates a new [`Ratio`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ratio is not positive and finite.
+    ///
+    /// [`Ratio`]: struct.Ratio.html
+    pub(crate) fn new(ratio: f32) -> Self {
+        assert!(
+            ratio > 0.0 && ratio.is_finite(),
+            "an aspect ratio must be positive and finite, but it is {}",
+            ratio
+        );
+
+        Ratio(ratio)
+    }
+
+    /// Returns the width matching the given height.
+    pub(crate) fn width(self, height: f32) -> f32 {
+        height * self.0
+    }
+
+    /// Returns the height matching the given width.
+    pub(crate) fn height(self, width: f32) -> f32 {
+        width / self.0
+    }
+}
+
+impl Hash for Ratio {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::testing::Harness;
+    use crate::Text;
+
+    fn snapshot(width: Length, bounds: Size) -> Vec<String> {
+        let aspect_ratio: AspectRatio<'_, (), Null> =
+            AspectRatio::new(2.0, Text::new("Thumbnail")).width(width);
+
+        Harness::new(aspect_ratio, bounds)
+            .snapshot()
+            .lines()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn height_follows_the_width() {
+        assert_eq!(
+            snapshot(Length::Fill, Size::new(200.0, 300.0)),
+            vec!["AspectRatio (0, 0) 200x100", "  Text (0, 0) 200x100"]
+        );
+    }
+
+    #[test]
+    fn width_follows_the_height_when_it_is_limited() {
+        assert_eq!(
+            snapshot(Length::Units(200), Size::new(300.0, 50.0)),
+            vec!["AspectRatio (0, 0) 100x50", "  Text (0, 0) 100x50"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "an aspect ratio must be positive and finite")]
+    fn ratio_must_be_positive() {
+        let _ = Ratio::new(0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "an aspect ratio must be positive and finite")]
+    fn ratio_must_be_a_number() {
+        let _ = Ratio::new(f32::NAN);
+    }
+}
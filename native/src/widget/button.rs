@@ -4,11 +4,25 @@
 //!
 //! [`Button`]: struct.Button.html
 //! [`State`]: struct.State.html
+use crate::accessibility::{Accessibility, Role};
+use crate::animation::Animation;
+use crate::focus::Focusable;
 use crate::{
-    layout, mouse, Clipboard, Element, Event, Hasher, Layout, Length, Point,
-    Rectangle, Widget,
+    event, layout, mouse, touch, Clipboard, Color, Element, Event, Hasher,
+    Layout, Length, Point, Rectangle, Size, Vector, Widget,
 };
 use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+/// The time it takes the spinner of a loading [`Button`] to complete a cycle.
+///
+/// [`Button`]: struct.Button.html
+pub const SPINNER_PERIOD: Duration = Duration::from_millis(1000);
+
+/// The time it takes the ripple of a [`Button`] to spread and fade out.
+///
+/// [`Button`]: struct.Button.html
+pub const RIPPLE_DURATION: Duration = Duration::from_millis(400);
 
 /// A generic widget that produces a message when pressed.
 ///
@@ -32,11 +46,17 @@ pub struct Button<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     content: Element<'a, Message, Renderer>,
     on_press: Option<Message>,
+    on_right_press: Option<Message>,
     width: Length,
     height: Length,
     min_width: u32,
     min_height: u32,
+    min_hit_size: Size,
     padding: u16,
+    is_toggled: bool,
+    has_ripple: bool,
+    border: Border,
+    accessible_name: Option<String>,
     style: Renderer::Style,
 }
 
@@ -58,11 +78,17 @@ where
             state,
             content: content.into(),
             on_press: None,
+            on_right_press: None,
             width: Length::Shrink,
             height: Length::Shrink,
             min_width: 0,
             min_height: 0,
+            min_hit_size: Size::ZERO,
             padding: Renderer::DEFAULT_PADDING,
+            is_toggled: false,
+            has_ripple: false,
+            border: Border::default(),
+            accessible_name: None,
             style: Renderer::Style::default(),
         }
     }
@@ -99,6 +125,18 @@ where
         self
     }
 
+    /// Sets the minimum size of the clickable area of the [`Button`].
+    ///
+    /// A [`Button`] smaller than this size is drawn centered inside an
+    /// invisible area of at least this size, which can be clicked as well.
+    /// Useful to make small icon buttons easier to press on touch screens.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn min_hit_size(mut self, min_hit_size: Size) -> Self {
+        self.min_hit_size = min_hit_size;
+        self
+    }
+
     /// Sets the padding of the [`Button`].
     ///
     /// [`Button`]: struct.Button.html
@@ -109,12 +147,94 @@ where
 
     /// Sets the message that will be produced when the [`Button`] is pressed.
     ///
+    /// The [`Button`] is pressed by clicking it or by tapping it with a
+    /// finger that does not move away.
+    ///
     /// [`Button`]: struct.Button.html
     pub fn on_press(mut self, msg: Message) -> Self {
         self.on_press = Some(msg);
         self
     }
 
+    /// Sets the message that will be produced when the [`Button`] is pressed
+    /// with the right mouse button.
+    ///
+    /// Useful to open a context menu. A [`Button`] without an [`on_press`]
+    /// message is still drawn as disabled.
+    ///
+    /// [`Button`]: struct.Button.html
+    /// [`on_press`]: #method.on_press
+    pub fn on_right_press(mut self, msg: Message) -> Self {
+        self.on_right_press = Some(msg);
+        self
+    }
+
+    /// Sets whether the [`Button`] is loading.
+    ///
+    /// A loading [`Button`] draws a spinner instead of its content and
+    /// ignores any presses until it stops loading.
+    ///
+    /// The spinner completes a cycle every [`SPINNER_PERIOD`], measured from
+    /// the moment the [`Button`] started loading. It keeps requesting
+    /// redraws while loading, so it spins without any subscription.
+    ///
+    /// [`Button`]: struct.Button.html
+    /// [`SPINNER_PERIOD`]: constant.SPINNER_PERIOD.html
+    pub fn loading(self, is_loading: bool) -> Self {
+        self.state.loading_since = if is_loading {
+            self.state.loading_since.or_else(|| Some(Instant::now()))
+        } else {
+            None
+        };
+
+        self
+    }
+
+    /// Sets whether the [`Button`] is toggled on.
+    ///
+    /// A toggled [`Button`] stays drawn with the toggled style of its style
+    /// sheet, which is useful for toolbars that switch options on and off.
+    /// The [`Button`] does not toggle itself: flip the value in the message
+    /// produced by [`on_press`] instead.
+    ///
+    /// ```
+    /// # use iced_native::{button, Text};
+    /// #
+    /// # type Button<'a, Message> =
+    /// #     iced_native::Button<'a, Message, iced_native::renderer::Null>;
+    /// #
+    /// #[derive(Clone)]
+    /// enum Message {
+    ///     BoldToggled(bool),
+    /// }
+    ///
+    /// let mut state = button::State::new();
+    /// let is_bold = true;
+    ///
+    /// let bold = Button::new(&mut state, Text::new("B"))
+    ///     .toggled(is_bold)
+    ///     .on_press(Message::BoldToggled(!is_bold));
+    /// ```
+    ///
+    /// [`Button`]: struct.Button.html
+    /// [`on_press`]: #method.on_press
+    pub fn toggled(mut self, is_toggled: bool) -> Self {
+        self.is_toggled = is_toggled;
+        self
+    }
+
+    /// Sets whether the [`Button`] draws a ripple when pressed.
+    ///
+    /// The ripple spreads from the point where the [`Button`] was pressed
+    /// and fades out over [`RIPPLE_DURATION`]. It is disabled by default.
+    ///
+    /// [`Button`]: struct.Button.html
+    /// [`RIPPLE_DURATION`]: constant.RIPPLE_DURATION.html
+    pub fn ripple(mut self, has_ripple: bool) -> Self {
+        self.has_ripple = has_ripple;
+        self
+    }
+
     /// Sets the style of the [`Button`].
     ///
     /// [`Button`]: struct.Button.html
@@ -122,6 +242,94 @@ where
         self.style = style.into();
         self
     }
+
+    /// Sets the name announced for the [`Button`] by assistive
+    /// technologies.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn accessible_name(mut self, name: impl Into<String>) -> Self {
+        self.accessible_name = Some(name.into());
+        self
+    }
+
+    /// Sets the border radius of the [`Button`], overriding the one of its
+    /// style in every state.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn border_radius(mut self, border_radius: u16) -> Self {
+        self.border.radius = Some(border_radius);
+        self
+    }
+
+    /// Sets the border width of the [`Button`], overriding the one of its
+    /// style in every state.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn border_width(mut self, border_width: u16) -> Self {
+        self.border.width = Some(border_width);
+        self
+    }
+
+    /// Sets the border color of the [`Button`], overriding the one of its
+    /// style in every state.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn border_color(mut self, border_color: impl Into<Color>) -> Self {
+        self.border.color = Some(border_color.into());
+        self
+    }
+
+    /// Starts spreading the ripple of the [`Button`] from the given point,
+    /// if it has one.
+    ///
+    /// [`Button`]: struct.Button.html
+    fn spread_ripple(&mut self, layout: Layout<'_>, position: Point) {
+        if !self.has_ripple {
+            return;
+        }
+
+        let button = layout.children().next().unwrap();
+        let offset = position - button.position();
+
+        self.state.ripple = Some(Spread {
+            x: offset.x.round() as i32,
+            y: offset.y.round() as i32,
+            animation: Animation::new(RIPPLE_DURATION),
+        });
+    }
+}
+
+/// The overrides of the border of a [`Button`].
+///
+/// The style of a [`Button`] remains the main way to customize it. These
+/// overrides are layered over the border of its style, which makes one-off
+/// tweaks easy, like a pill-shaped button.
+///
+/// [`Button`]: struct.Button.html
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Border {
+    /// The border radius, if overridden.
+    pub radius: Option<u16>,
+
+    /// The border width, if overridden.
+    pub width: Option<u16>,
+
+    /// The border color, if overridden.
+    pub color: Option<Color>,
+}
+
+/// The ripple of a pressed [`Button`].
+///
+/// [`Button`]: struct.Button.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ripple {
+    /// The point where the [`Button`] was pressed.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub center: Point,
+
+    /// The progress of the ripple, from `0.0` to `1.0`.
+    pub progress: f32,
 }
 
 /// The local state of a [`Button`].
@@ -130,6 +338,33 @@ where
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
     is_pressed: bool,
+    is_right_pressed: bool,
+    loading_since: Option<Instant>,
+    ripple: Option<Spread>,
+    tap: Option<Tap>,
+}
+
+/// A ripple spreading from where a [`Button`] was pressed.
+///
+/// The origin is kept in whole units relative to the position of the
+/// [`Button`], which is precise enough to draw the ripple.
+///
+/// [`Button`]: struct.Button.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Spread {
+    x: i32,
+    y: i32,
+    animation: Animation,
+}
+
+/// A finger tapping a [`Button`], with the point where it was pressed.
+///
+/// [`Button`]: struct.Button.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Tap {
+    finger: touch::Finger,
+    x: i32,
+    y: i32,
 }
 
 impl State {
@@ -139,6 +374,14 @@ impl State {
     pub fn new() -> State {
         State::default()
     }
+
+    /// Returns whether the [`Button`] with this [`State`] is loading.
+    ///
+    /// [`Button`]: struct.Button.html
+    /// [`State`]: struct.State.html
+    pub fn is_loading(&self) -> bool {
+        self.loading_since.is_some()
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -155,11 +398,22 @@ where
         self.height
     }
 
+    fn min_size(&self, renderer: &Renderer) -> Size {
+        let content =
+            self.content.min_size(renderer).pad(f32::from(self.padding));
+
+        Size::new(
+            content.width.max(self.min_width as f32),
+            content.height.max(self.min_height as f32),
+        )
+    }
+
     fn layout(
         &self,
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
+        let max = limits.max();
         let padding = f32::from(self.padding);
         let limits = limits
             .min_width(self.min_width)
@@ -172,8 +426,19 @@ where
         content.move_to(Point::new(padding, padding));
 
         let size = limits.resolve(content.size()).pad(padding);
+        let mut button = layout::Node::with_children(size, vec![content]);
+
+        let hit_size = Size::new(
+            size.width.max(self.min_hit_size.width.min(max.width)),
+            size.height.max(self.min_hit_size.height.min(max.height)),
+        );
 
-        layout::Node::with_children(size, vec![content])
+        button.move_to(Point::new(
+            (hit_size.width - size.width) / 2.0,
+            (hit_size.height - size.height) / 2.0,
+        ));
+
+        layout::Node::with_children(hit_size, vec![button])
     }
 
     fn on_event(
@@ -184,13 +449,78 @@ where
         messages: &mut Vec<Message>,
         _renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
-    ) {
+    ) -> event::Status {
+        if self.state.is_loading() {
+            self.state.is_pressed = false;
+            self.state.is_right_pressed = false;
+
+            return event::Status::Ignored;
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 if self.on_press.is_some() {
                     let bounds = layout.bounds();
 
                     self.state.is_pressed = bounds.contains(cursor_position);
+
+                    if self.state.is_pressed {
+                        self.spread_ripple(layout, cursor_position);
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                if self.on_press.is_some()
+                    && self.state.tap.is_none()
+                    && layout.bounds().contains(position)
+                {
+                    self.state.is_pressed = true;
+                    self.state.tap = Some(Tap {
+                        finger: id,
+                        x: position.x.round() as i32,
+                        y: position.y.round() as i32,
+                    });
+
+                    self.spread_ripple(layout, position);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                // A finger moving away is panning, not tapping
+                if let Some(tap) = self.state.tap.filter(|tap| tap.finger == id)
+                {
+                    let origin = Point::new(tap.x as f32, tap.y as f32);
+
+                    if position.distance(origin) >= touch::gesture::THRESHOLD {
+                        self.state.is_pressed = false;
+                        self.state.tap = None;
+                    }
+                }
+            }
+            Event::Touch(touch::Event::FingerLifted { id, position }) => {
+                if self.state.tap.map(|tap| tap.finger) == Some(id) {
+                    let is_tapped = self.state.is_pressed
+                        && layout.bounds().contains(position);
+
+                    self.state.is_pressed = false;
+                    self.state.tap = None;
+
+                    if let (true, Some(on_press)) =
+                        (is_tapped, self.on_press.clone())
+                    {
+                        messages.push(on_press);
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { id, .. }) => {
+                if self.state.tap.map(|tap| tap.finger) == Some(id) {
+                    self.state.is_pressed = false;
+                    self.state.tap = None;
                 }
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
@@ -204,11 +534,64 @@ where
 
                     if is_clicked {
                         messages.push(on_press);
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+                if self.on_right_press.is_some() {
+                    let bounds = layout.bounds();
+
+                    self.state.is_right_pressed =
+                        bounds.contains(cursor_position);
+
+                    if self.state.is_right_pressed {
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(
+                mouse::Button::Right,
+            )) => {
+                if let Some(on_right_press) = self.on_right_press.clone() {
+                    let bounds = layout.bounds();
+
+                    let is_clicked = self.state.is_right_pressed
+                        && bounds.contains(cursor_position);
+
+                    self.state.is_right_pressed = false;
+
+                    if is_clicked {
+                        messages.push(on_right_press);
+
+                        return event::Status::Captured;
                     }
                 }
             }
             _ => {}
         }
+
+        event::Status::Ignored
+    }
+
+    fn layout_changed(
+        &mut self,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+    ) {
+        let button = layout.children().next().unwrap();
+        let content_layout = button.children().next().unwrap();
+
+        self.content.layout_changed(content_layout, messages);
+    }
+
+    fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        messages: &mut Vec<Message>,
+    ) {
+        self.content.focusables(visit, messages)
     }
 
     fn draw(
@@ -218,23 +601,67 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> Renderer::Output {
+        let button = layout.children().next().unwrap();
+        let content_layout = button.children().next().unwrap();
+
+        let spinner = self.state.loading_since.map(|loading_since| {
+            let elapsed = loading_since.elapsed().as_secs_f32();
+
+            renderer.request_redraw();
+
+            (elapsed / SPINNER_PERIOD.as_secs_f32()).fract()
+        });
+
+        let ripple = match self.state.ripple {
+            Some(spread) if self.has_ripple => {
+                let progress = spread.animation.progress();
+
+                if progress < 1.0 {
+                    renderer.request_redraw();
+
+                    Some(Ripple {
+                        center: button.position()
+                            + Vector::new(spread.x as f32, spread.y as f32),
+                        progress,
+                    })
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+
         renderer.draw(
             defaults,
-            layout.bounds(),
+            button.bounds(),
             cursor_position,
             self.on_press.is_none(),
             self.state.is_pressed,
+            self.is_toggled,
+            spinner,
+            ripple,
             &self.style,
+            self.border,
             &self.content,
-            layout.children().next().unwrap(),
+            content_layout,
         )
     }
 
+    fn accessibility(&self) -> Option<Accessibility> {
+        Some(Accessibility {
+            role: Role::Button,
+            name: self.accessible_name.clone(),
+        })
+    }
+
     fn hash_layout(&self, state: &mut Hasher) {
         struct Marker;
         std::any::TypeId::of::<Marker>().hash(state);
 
         self.width.hash(state);
+        self.min_hit_size.width.to_bits().hash(state);
+        self.min_hit_size.height.to_bits().hash(state);
+        self.accessible_name.hash(state);
         self.content.hash_layout(state);
     }
 }
@@ -257,7 +684,22 @@ pub trait Renderer: crate::Renderer + Sized {
 
     /// Draws a [`Button`].
     ///
+    /// A loading [`Button`] provides the progress of its spinner through the
+    /// current cycle, from `0.0` to `1.0`. The spinner should be drawn
+    /// instead of the content in that case.
+    ///
+    /// A toggled [`Button`] should be drawn with a distinct style while it
+    /// is not being pressed.
+    ///
+    /// A [`Ripple`], if any, should be drawn over the background of the
+    /// [`Button`] and clipped to its bounds.
+    ///
+    /// The [`Border`] overrides must be applied over the style currently in
+    /// use.
+    ///
     /// [`Button`]: struct.Button.html
+    /// [`Border`]: struct.Border.html
+    /// [`Ripple`]: struct.Ripple.html
     fn draw<Message>(
         &mut self,
         defaults: &Self::Defaults,
@@ -265,7 +707,11 @@ pub trait Renderer: crate::Renderer + Sized {
         cursor_position: Point,
         is_disabled: bool,
         is_pressed: bool,
+        is_toggled: bool,
+        spinner: Option<f32>,
+        ripple: Option<Ripple>,
         style: &Self::Style,
+        border: Border,
         content: &Element<'_, Message, Self>,
         content_layout: Layout<'_>,
     ) -> Self::Output;
@@ -283,3 +729,153 @@ where
         Element::new(button)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+    use crate::Text;
+
+    #[test]
+    fn min_hit_size_extends_clickable_area() {
+        let mut state = State::new();
+        let button = Button::new(&mut state, Text::new("x"))
+            .min_hit_size(Size::new(40.0, 40.0))
+            .on_press(());
+
+        let mut harness = Harness::new(button, Size::new(200.0, 100.0));
+        assert_eq!(harness.layout().bounds().size(), Size::new(40.0, 40.0));
+
+        harness.click(Point::new(2.0, 38.0));
+        harness.click(Point::new(45.0, 20.0));
+
+        assert_eq!(harness.into_messages(), vec![()]);
+    }
+
+    #[test]
+    fn right_click_produces_right_press_message() {
+        let mut state = State::new();
+        let button = Button::new(&mut state, Text::new("x"))
+            .width(Length::Units(100))
+            .on_press("left")
+            .on_right_press("right");
+
+        let mut harness = Harness::new(button, Size::new(200.0, 100.0));
+        harness.move_cursor_to(Point::new(5.0, 5.0));
+        harness.events(vec![
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Right)),
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Middle)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Middle)),
+        ]);
+        harness.click(Point::new(5.0, 5.0));
+
+        assert_eq!(harness.into_messages(), vec!["right", "left"]);
+    }
+
+    #[test]
+    fn toggled_button_is_pressed_like_any_other() {
+        let mut state = State::new();
+        let is_bold = true;
+
+        let button = Button::new(&mut state, Text::new("B"))
+            .width(Length::Units(100))
+            .toggled(is_bold)
+            .on_press(!is_bold);
+
+        let mut harness = Harness::new(button, Size::new(200.0, 100.0));
+        harness.click(Point::new(5.0, 5.0));
+        harness.click(Point::new(5.0, 5.0));
+
+        // The button does not toggle itself between presses
+        assert_eq!(harness.into_messages(), vec![false, false]);
+        assert!(!state.is_pressed);
+    }
+
+    #[test]
+    fn ripple_starts_where_the_button_is_pressed() {
+        let mut state = State::new();
+
+        {
+            let button = Button::new(&mut state, Text::new("x"))
+                .width(Length::Units(100))
+                .on_press(());
+
+            let mut harness = Harness::new(button, Size::new(200.0, 100.0));
+            harness.click(Point::new(20.0, 5.0));
+        }
+
+        assert!(state.ripple.is_none());
+
+        {
+            let button = Button::new(&mut state, Text::new("x"))
+                .width(Length::Units(100))
+                .ripple(true)
+                .on_press(());
+
+            let mut harness = Harness::new(button, Size::new(200.0, 100.0));
+            harness.click(Point::new(20.0, 5.0));
+        }
+
+        let spread = state.ripple.expect("ripple");
+        assert_eq!((spread.x, spread.y), (20, 5));
+    }
+
+    #[test]
+    fn loading_button_ignores_presses() {
+        let mut state = State::new();
+
+        {
+            let button = Button::new(&mut state, Text::new("x"))
+                .width(Length::Units(100))
+                .on_press(())
+                .loading(true);
+
+            let mut harness = Harness::new(button, Size::new(200.0, 100.0));
+            harness.click(Point::new(5.0, 5.0));
+
+            assert!(harness.into_messages().is_empty());
+        }
+
+        assert!(state.is_loading());
+
+        let button = Button::new(&mut state, Text::new("x"))
+            .width(Length::Units(100))
+            .on_press(())
+            .loading(false);
+
+        let mut harness = Harness::new(button, Size::new(200.0, 100.0));
+        harness.click(Point::new(5.0, 5.0));
+
+        assert_eq!(harness.into_messages(), vec![()]);
+    }
+
+    #[test]
+    fn taps_press_the_button_unless_the_finger_pans() {
+        let mut state = State::new();
+        let button = Button::new(&mut state, Text::new("x"))
+            .width(Length::Units(100))
+            .on_press(());
+
+        let mut harness = Harness::new(button, Size::new(200.0, 100.0));
+        let tap = |to: Point| {
+            let id = touch::Finger(0);
+
+            vec![
+                touch::Event::FingerPressed {
+                    id,
+                    position: Point::new(5.0, 5.0),
+                },
+                touch::Event::FingerMoved { id, position: to },
+                touch::Event::FingerLifted { id, position: to },
+            ]
+            .into_iter()
+            .map(Event::Touch)
+        };
+
+        harness.events(tap(Point::new(7.0, 5.0)));
+        harness.events(tap(Point::new(50.0, 5.0)));
+
+        assert_eq!(harness.into_messages(), vec![()]);
+    }
+}
@@ -1,31 +1,44 @@
 //! Show toggle controls using checkboxes.
 use std::hash::Hash;
+use std::time::Duration;
 
+use crate::accessibility::{Accessibility, Role};
+use crate::animation::Animation;
+use crate::focus::Focusable;
 use crate::{
-    layout, mouse, row, text, Align, Clipboard, Element, Event, Hasher,
-    HorizontalAlignment, Layout, Length, Point, Rectangle, Row, Text,
-    VerticalAlignment, Widget,
+    event, keyboard, layout, mouse, row, text, Align, Clipboard, Element,
+    Event, Font, Hasher, HorizontalAlignment, Layout, Length, Point, Rectangle,
+    Row, Text, VerticalAlignment, Widget,
 };
 
+/// The time it takes a [`Checkbox`] to transition between its checked and
+/// unchecked states.
+///
+/// [`Checkbox`]: struct.Checkbox.html
+pub const TRANSITION_DURATION: Duration = Duration::from_millis(150);
+
 /// A box that can be checked.
 ///
 /// # Example
 ///
 /// ```
-/// # type Checkbox<Message> = iced_native::Checkbox<Message, iced_native::renderer::Null>;
+/// # use iced_native::checkbox;
+/// # type Checkbox<'a, Message> = iced_native::Checkbox<'a, Message, iced_native::renderer::Null>;
 /// #
 /// pub enum Message {
 ///     CheckboxToggled(bool),
 /// }
 ///
+/// let mut state = checkbox::State::new();
 /// let is_checked = true;
 ///
-/// Checkbox::new(is_checked, "Toggle me!", Message::CheckboxToggled);
+/// Checkbox::new(&mut state, is_checked, "Toggle me!", Message::CheckboxToggled);
 /// ```
 ///
 /// ![Checkbox drawn by `iced_wgpu`](https://github.com/hecrj/iced/blob/7760618fb112074bc40b148944521f312152012a/docs/images/checkbox.png?raw=true)
 #[allow(missing_debug_implementations)]
-pub struct Checkbox<Message, Renderer: self::Renderer + text::Renderer> {
+pub struct Checkbox<'a, Message, Renderer: self::Renderer + text::Renderer> {
+    state: &'a mut State,
     is_checked: bool,
     on_toggle: Box<dyn Fn(bool) -> Message>,
     label: String,
@@ -34,15 +47,39 @@ pub struct Checkbox<Message, Renderer: self::Renderer + text::Renderer> {
     spacing: u16,
     text_size: Option<u16>,
     font: Renderer::Font,
+    icon: Option<Icon>,
+    is_label_clickable: bool,
+    is_animated: bool,
+    accessible_name: Option<String>,
     style: Renderer::Style,
 }
 
-impl<Message, Renderer: self::Renderer + text::Renderer>
-    Checkbox<Message, Renderer>
+/// The glyphs of an icon font displayed inside a [`Checkbox`].
+///
+/// [`Checkbox`]: struct.Checkbox.html
+#[derive(Debug, Clone, Copy)]
+pub struct Icon {
+    /// The font containing the glyphs.
+    pub font: Font,
+
+    /// The glyph displayed when the [`Checkbox`] is checked.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    pub checked: char,
+
+    /// The glyph displayed when the [`Checkbox`] is not checked, if any.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    pub unchecked: Option<char>,
+}
+
+impl<'a, Message, Renderer: self::Renderer + text::Renderer>
+    Checkbox<'a, Message, Renderer>
 {
     /// Creates a new [`Checkbox`].
     ///
     /// It expects:
+    ///   * some local [`State`]
     ///   * a boolean describing whether the [`Checkbox`] is checked or not
     ///   * the label of the [`Checkbox`]
     ///   * a function that will be called when the [`Checkbox`] is toggled. It
@@ -50,11 +87,24 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
     ///     `Message`.
     ///
     /// [`Checkbox`]: struct.Checkbox.html
-    pub fn new<F>(is_checked: bool, label: impl Into<String>, f: F) -> Self
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        is_checked: bool,
+        label: impl Into<String>,
+        f: F,
+    ) -> Self
     where
         F: 'static + Fn(bool) -> Message,
     {
+        if state.is_checked == Some(!is_checked) {
+            state.transition = Some(Animation::new(TRANSITION_DURATION));
+        }
+
+        state.is_checked = Some(is_checked);
+
         Checkbox {
+            state,
             is_checked,
             on_toggle: Box::new(f),
             label: label.into(),
@@ -63,6 +113,10 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
             spacing: Renderer::DEFAULT_SPACING,
             text_size: None,
             font: Renderer::Font::default(),
+            icon: None,
+            is_label_clickable: true,
+            is_animated: true,
+            accessible_name: None,
             style: Renderer::Style::default(),
         }
     }
@@ -108,6 +162,55 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
         self
     }
 
+    /// Sets the [`Icon`] displayed inside the [`Checkbox`].
+    ///
+    /// By default, a checkmark is displayed when checked and nothing
+    /// otherwise.
+    ///
+    /// [`Icon`]: struct.Icon.html
+    /// [`Checkbox`]: struct.Checkbox.html
+    pub fn icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Sets whether clicking the label of the [`Checkbox`] toggles it.
+    ///
+    /// By default, the whole [`Checkbox`] is clickable. Disable it to only
+    /// react to clicks on the box itself, like when the label has its own
+    /// interactive content.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    pub fn label_clickable(mut self, is_label_clickable: bool) -> Self {
+        self.is_label_clickable = is_label_clickable;
+        self
+    }
+
+    /// Sets whether the [`Checkbox`] animates the transition between its
+    /// checked and unchecked states.
+    ///
+    /// By default, the transition lasts [`TRANSITION_DURATION`]. Disable it
+    /// to toggle the [`Checkbox`] instantly, like when the user prefers
+    /// reduced motion.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    /// [`TRANSITION_DURATION`]: constant.TRANSITION_DURATION.html
+    pub fn animated(mut self, is_animated: bool) -> Self {
+        self.is_animated = is_animated;
+        self
+    }
+
+    /// Returns the bounds that toggle the [`Checkbox`] when clicked.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    fn clickable_bounds(&self, layout: Layout<'_>) -> Rectangle {
+        if self.is_label_clickable {
+            layout.bounds()
+        } else {
+            layout.children().next().unwrap().bounds()
+        }
+    }
+
     /// Sets the style of the [`Checkbox`].
     ///
     /// [`Checkbox`]: struct.Checkbox.html
@@ -115,10 +218,75 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
         self.style = style.into();
         self
     }
+
+    /// Sets the name announced for the [`Checkbox`] by assistive
+    /// technologies.
+    ///
+    /// By default, the label of the [`Checkbox`] is announced.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    pub fn accessible_name(mut self, name: impl Into<String>) -> Self {
+        self.accessible_name = Some(name.into());
+        self
+    }
+}
+
+/// The local state of a [`Checkbox`].
+///
+/// [`Checkbox`]: struct.Checkbox.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    is_focused: bool,
+    is_checked: Option<bool>,
+    transition: Option<Animation>,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`Checkbox`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Checkbox`]: struct.Checkbox.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Creates a new [`State`], representing a focused [`Checkbox`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Checkbox`]: struct.Checkbox.html
+    pub fn focused() -> State {
+        State {
+            is_focused: true,
+            ..State::default()
+        }
+    }
+
+    /// Returns whether the [`Checkbox`] is currently focused or not.
+    ///
+    /// A focused [`Checkbox`] is toggled by pressing the space bar.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+}
+
+impl Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
 }
 
-impl<Message, Renderer> Widget<Message, Renderer>
-    for Checkbox<Message, Renderer>
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Checkbox<'a, Message, Renderer>
 where
     Renderer: self::Renderer + text::Renderer + row::Renderer,
 {
@@ -161,17 +329,42 @@ where
         messages: &mut Vec<Message>,
         _renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
-    ) {
+    ) -> event::Status {
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
-                let mouse_over = layout.bounds().contains(cursor_position);
+                let mouse_over =
+                    self.clickable_bounds(layout).contains(cursor_position);
+
+                self.state.is_focused = mouse_over;
 
                 if mouse_over {
                     messages.push((self.on_toggle)(!self.is_checked));
+
+                    return event::Status::Captured;
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                is_repeat,
+                ..
+            }) if self.state.is_focused => match key_code {
+                // Holding the key down toggles the checkbox only once
+                keyboard::KeyCode::Space if !is_repeat => {
+                    messages.push((self.on_toggle)(!self.is_checked));
+
+                    return event::Status::Captured;
+                }
+                keyboard::KeyCode::Escape => {
+                    self.state.is_focused = false;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
             _ => {}
         }
+
+        event::Status::Ignored
     }
 
     fn draw(
@@ -181,7 +374,6 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> Renderer::Output {
-        let bounds = layout.bounds();
         let mut children = layout.children();
 
         let checkbox_layout = children.next().unwrap();
@@ -200,23 +392,68 @@ where
             VerticalAlignment::Center,
         );
 
-        let is_mouse_over = bounds.contains(cursor_position);
+        let is_mouse_over =
+            self.clickable_bounds(layout).contains(cursor_position);
+
+        let transition = match self.state.transition {
+            Some(transition) if self.is_animated => {
+                let progress = transition.progress();
+
+                if progress < 1.0 {
+                    renderer.request_redraw();
+                }
+
+                progress
+            }
+            _ => 1.0,
+        };
 
         self::Renderer::draw(
             renderer,
             checkbox_bounds,
             self.is_checked,
+            transition,
             is_mouse_over,
+            self.state.is_focused,
+            self.icon.as_ref(),
             label,
             &self.style,
         )
     }
 
+    fn accessibility(&self) -> Option<Accessibility> {
+        Some(Accessibility {
+            role: Role::Checkbox,
+            name: self
+                .accessible_name
+                .clone()
+                .or_else(|| Some(self.label.clone()))
+                .filter(|name| !name.is_empty()),
+        })
+    }
+
     fn hash_layout(&self, state: &mut Hasher) {
         struct Marker;
         std::any::TypeId::of::<Marker>().hash(state);
 
         self.label.hash(state);
+        self.accessible_name.hash(state);
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        if self.state.is_focused {
+            Some(layout.bounds())
+        } else {
+            None
+        }
+    }
+
+    fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        _messages: &mut Vec<Message>,
+    ) {
+        visit(&mut *self.state);
     }
 }
 
@@ -246,29 +483,93 @@ pub trait Renderer: crate::Renderer {
     /// It receives:
     ///   * the bounds of the [`Checkbox`]
     ///   * whether the [`Checkbox`] is selected or not
+    ///   * the progress of the transition to the current state, from `0.0`
+    ///     to `1.0`
     ///   * whether the mouse is over the [`Checkbox`] or not
+    ///   * whether the [`Checkbox`] is focused or not
+    ///   * the custom [`Icon`] of the [`Checkbox`], if any
     ///   * the drawn label of the [`Checkbox`]
     ///
     /// [`Checkbox`]: struct.Checkbox.html
+    /// [`Icon`]: struct.Icon.html
     fn draw(
         &mut self,
         bounds: Rectangle,
         is_checked: bool,
+        transition: f32,
         is_mouse_over: bool,
+        is_focused: bool,
+        icon: Option<&Icon>,
         label: Self::Output,
         style: &Self::Style,
     ) -> Self::Output;
 }
 
-impl<'a, Message, Renderer> From<Checkbox<Message, Renderer>>
+impl<'a, Message, Renderer> From<Checkbox<'a, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
     Renderer: 'a + self::Renderer + text::Renderer + row::Renderer,
     Message: 'a,
 {
     fn from(
-        checkbox: Checkbox<Message, Renderer>,
+        checkbox: Checkbox<'a, Message, Renderer>,
     ) -> Element<'a, Message, Renderer> {
         Element::new(checkbox)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::testing::Harness;
+    use crate::Size;
+
+    fn checkbox(
+        state: &mut State,
+        is_checked: bool,
+    ) -> Checkbox<'_, bool, Null> {
+        Checkbox::new(state, is_checked, "Check", |is_checked| is_checked)
+    }
+
+    #[test]
+    fn toggling_starts_a_transition() {
+        let mut state = State::new();
+
+        let _ = checkbox(&mut state, false);
+        let _ = checkbox(&mut state, false);
+        assert_eq!(state.transition, None);
+
+        let mut harness =
+            Harness::new(checkbox(&mut state, false), Size::new(200.0, 100.0));
+        harness.click(Point::new(5.0, 5.0));
+
+        let is_checked = harness.into_messages();
+        assert_eq!(is_checked, vec![true]);
+
+        let _ = checkbox(&mut state, is_checked[0]);
+        assert!(state.transition.is_some());
+    }
+
+    #[test]
+    fn label_can_be_excluded_from_clicks() {
+        let checkbox = |state, is_label_clickable| {
+            checkbox(state, false)
+                .width(Length::Units(200))
+                .label_clickable(is_label_clickable)
+        };
+
+        let mut state = State::new();
+        let mut harness =
+            Harness::new(checkbox(&mut state, true), Size::new(200.0, 100.0));
+        harness.click(Point::new(150.0, 5.0));
+        assert_eq!(harness.into_messages(), vec![true]);
+
+        let mut state = State::new();
+        let mut harness =
+            Harness::new(checkbox(&mut state, false), Size::new(200.0, 100.0));
+        harness.click(Point::new(150.0, 5.0));
+        harness.click(Point::new(1.0, 5.0));
+        assert_eq!(harness.into_messages(), vec![true]);
+    }
+}
@@ -1,18 +1,28 @@
 //! Distribute content vertically.
 use std::hash::Hash;
 
+use crate::focus::Focusable;
 use crate::{
-    layout, overlay, Align, Clipboard, Element, Event, Hasher, Layout, Length,
-    Point, Widget,
+    event, layout, overlay, Align, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Spacing, Widget,
 };
 
 use std::u32;
 
 /// A container that distributes its contents vertically.
+///
+/// A [`Column`] never grows past the limits of its parent, even if it
+/// shrinks to fit its contents. The children that do not fit overflow the
+/// [`Column`] without being clipped, which is logged when the `debug` feature
+/// is enabled. Use [`Scrollable::with_column`] to clip the overflow and scroll
+/// it instead.
+///
+/// [`Column`]: struct.Column.html
+/// [`Scrollable::with_column`]: ../scrollable/struct.Scrollable.html#method.with_column
 #[allow(missing_debug_implementations)]
 pub struct Column<'a, Message, Renderer> {
-    spacing: u16,
-    padding: u16,
+    spacing: Spacing,
+    padding: Spacing,
     width: Length,
     height: Length,
     max_width: u32,
@@ -36,8 +46,8 @@ impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
         children: Vec<Element<'a, Message, Renderer>>,
     ) -> Self {
         Column {
-            spacing: 0,
-            padding: 0,
+            spacing: Spacing::Units(0),
+            padding: Spacing::Units(0),
             width: Length::Shrink,
             height: Length::Shrink,
             max_width: u32::MAX,
@@ -52,16 +62,23 @@ impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
     /// Custom margins per element do not exist in Iced. You should use this
     /// method instead! While less flexible, it helps you keep spacing between
     /// elements consistent.
-    pub fn spacing(mut self, units: u16) -> Self {
-        self.spacing = units;
+    ///
+    /// A [`Spacing::Relative`] amount is relative to the available height.
+    ///
+    /// [`Spacing::Relative`]: ../../enum.Spacing.html#variant.Relative
+    pub fn spacing(mut self, spacing: impl Into<Spacing>) -> Self {
+        self.spacing = spacing.into();
         self
     }
 
     /// Sets the padding of the [`Column`].
     ///
+    /// A [`Spacing::Relative`] amount is relative to the available width.
+    ///
     /// [`Column`]: struct.Column.html
-    pub fn padding(mut self, units: u16) -> Self {
-        self.padding = units;
+    /// [`Spacing::Relative`]: ../../enum.Spacing.html#variant.Relative
+    pub fn padding(mut self, padding: impl Into<Spacing>) -> Self {
+        self.padding = padding.into();
         self
     }
 
@@ -130,6 +147,16 @@ where
         self.height
     }
 
+    fn min_size(&self, renderer: &Renderer) -> Size {
+        layout::flex::min_size(
+            layout::flex::Axis::Vertical,
+            renderer,
+            self.padding.resolve(f32::INFINITY),
+            self.spacing.resolve(f32::INFINITY),
+            &self.children,
+        )
+    }
+
     fn layout(
         &self,
         renderer: &Renderer,
@@ -145,8 +172,8 @@ where
             layout::flex::Axis::Vertical,
             renderer,
             &limits,
-            self.padding as f32,
-            self.spacing as f32,
+            self.padding.resolve(limits.max().width),
+            self.spacing.resolve(limits.max().height),
             self.align_items,
             &self.children,
         )
@@ -160,9 +187,11 @@ where
         messages: &mut Vec<Message>,
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>,
-    ) {
-        self.children.iter_mut().zip(layout.children()).for_each(
-            |(child, layout)| {
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .map(|(child, layout)| {
                 child.widget.on_event(
                     event.clone(),
                     layout,
@@ -171,8 +200,18 @@ where
                     renderer,
                     clipboard,
                 )
-            },
-        );
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn layout_changed(
+        &mut self,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+    ) {
+        for (child, layout) in self.children.iter_mut().zip(layout.children()) {
+            child.layout_changed(layout, messages);
+        }
     }
 
     fn draw(
@@ -212,6 +251,23 @@ where
             .filter_map(|(child, layout)| child.widget.overlay(layout))
             .next()
     }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.children
+            .iter()
+            .zip(layout.children())
+            .find_map(|(child, layout)| child.focused_bounds(layout))
+    }
+
+    fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        messages: &mut Vec<Message>,
+    ) {
+        for child in &mut self.children {
+            child.focusables(visit, messages);
+        }
+    }
 }
 
 /// The renderer of a [`Column`].
@@ -252,3 +308,77 @@ where
         Element::new(column)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::testing::Harness;
+    use crate::{Size, Stack, Text};
+
+    #[test]
+    fn relative_padding_and_spacing_scale_with_available_space() {
+        let column: Column<'_, (), Null> = Column::new()
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(Spacing::Relative(0.1))
+            .spacing(Spacing::Relative(0.05))
+            .push(Text::new("First"))
+            .push(Text::new("Second"));
+
+        let harness = Harness::new(column, Size::new(200.0, 400.0));
+
+        // Padding is relative to the width and spacing to the height
+        assert_eq!(
+            harness.snapshot().lines().collect::<Vec<_>>(),
+            vec![
+                "Column (0, 0) 200x400",
+                "  Text (20, 20) 0x20",
+                "  Text (20, 60) 0x20",
+            ]
+        );
+    }
+
+    #[test]
+    fn flexible_children_are_not_squashed_below_their_contents() {
+        let column: Column<'_, (), Null> = Column::new()
+            .height(Length::Units(50))
+            .push(Text::new("Squashed").height(Length::Fill))
+            .push(Text::new("Expanded").height(Length::FillPortion(3)));
+
+        let harness = Harness::new(column, Size::new(100.0, 100.0));
+
+        assert_eq!(
+            harness.snapshot().lines().collect::<Vec<_>>(),
+            vec![
+                "Column (0, 0) 0x50",
+                "  Text (0, 0) 0x20",
+                "  Text (0, 20) 0x30",
+            ]
+        );
+    }
+
+    #[test]
+    fn wrappers_keep_the_minimum_size_of_their_contents() {
+        let column: Column<'_, (), Null> = Column::new()
+            .height(Length::Units(50))
+            .push(
+                Stack::new()
+                    .height(Length::Fill)
+                    .push(Text::new("Squashed")),
+            )
+            .push(Text::new("Expanded").height(Length::FillPortion(3)));
+
+        let harness = Harness::new(column, Size::new(100.0, 100.0));
+
+        assert_eq!(
+            harness.snapshot().lines().collect::<Vec<_>>(),
+            vec![
+                "Column (0, 0) 0x50",
+                "  Stack (0, 0) 0x20",
+                "    Text (0, 0) 0x20",
+                "  Text (0, 20) 0x30",
+            ]
+        );
+    }
+}
@@ -0,0 +1,596 @@
+//! Build reusable widgets out of other widgets and some local state.
+//!
+//! A [`Component`] owns its state and updates it with the events produced by
+//! its own view, like a small application. A [`Stateful`] element pairs a
+//! [`Component`] with the widgets it displays.
+//!
+//! [`Component`]: trait.Component.html
+//! [`Stateful`]: struct.Stateful.html
+use std::cell::{Cell, RefCell};
+use std::hash::{Hash, Hasher as _};
+use std::marker::PhantomData;
+
+use crate::focus::Focusable;
+use crate::{
+    event, layout, overlay, Clipboard, Element, Event, Hasher, Layout, Length,
+    Point, Rectangle, Size, Vector, Widget,
+};
+
+/// A reusable piece of user interface with its own state and events.
+///
+/// The view of a [`Component`] produces its own `Event` type, which is fed
+/// back to [`update`] to change the state of the [`Component`]. An `Event`
+/// only reaches the application when [`update`] turns it into a `Message`.
+///
+/// The state of the widgets in the view, like a [`button::State`], is kept
+/// in the [`Component`] itself, which lives in the state of the application.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{button, renderer::Null, Button, Element, Text};
+/// # use iced_native::component::{Component, Stateful};
+/// #
+/// #[derive(Default)]
+/// struct Counter {
+///     value: u32,
+///     increment: button::State,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// struct Increment;
+///
+/// impl Component<u32, Null> for Counter {
+///     type Event = Increment;
+///
+///     fn update(&mut self, _event: Increment) -> Option<u32> {
+///         self.value += 1;
+///
+///         Some(self.value)
+///     }
+///
+///     fn view(&mut self) -> Element<'_, Increment, Null> {
+///         Button::new(&mut self.increment, Text::new(self.value.to_string()))
+///             .on_press(Increment)
+///             .into()
+///     }
+/// }
+///
+/// let mut counter = Counter::default();
+///
+/// let element: Element<'_, u32, Null> = Stateful::new(&mut counter).into();
+/// ```
+///
+/// [`Component`]: trait.Component.html
+/// [`update`]: #tymethod.update
+/// [`button::State`]: ../button/struct.State.html
+pub trait Component<Message, Renderer> {
+    /// The type of the events produced by the view of the [`Component`].
+    ///
+    /// [`Component`]: trait.Component.html
+    type Event;
+
+    /// Updates the [`Component`] with an event of its view, optionally
+    /// producing a message for the application.
+    ///
+    /// [`Component`]: trait.Component.html
+    fn update(&mut self, event: Self::Event) -> Option<Message>;
+
+    /// Builds the view of the [`Component`].
+    ///
+    /// [`Component`]: trait.Component.html
+    fn view(&mut self) -> Element<'_, Self::Event, Renderer>;
+}
+
+/// An element displaying a [`Component`].
+///
+/// The view of the [`Component`] borrows its state, so it is built again to
+/// process every event and every draw. Its size and layout are kept until the
+/// view processes an event, since [`update`] may change them without
+/// producing any message.
+///
+/// [`Component`]: trait.Component.html
+/// [`update`]: trait.Component.html#tymethod.update
+#[allow(missing_debug_implementations)]
+pub struct Stateful<'a, C, Message, Renderer> {
+    component: RefCell<&'a mut C>,
+    limits: Cell<Option<layout::Limits>>,
+    content: RefCell<Option<layout::Node>>,
+    cache: RefCell<Cache>,
+    phantom: PhantomData<(Message, Renderer)>,
+}
+
+/// The parts of the view of a [`Component`] that only change when it
+/// processes an event.
+///
+/// [`Component`]: trait.Component.html
+#[derive(Debug, Clone, Copy, Default)]
+struct Cache {
+    lengths: Option<(Length, Length)>,
+    min_size: Option<Size>,
+    hash: Option<u64>,
+    is_outdated: bool,
+}
+
+impl Cache {
+    fn outdated() -> Self {
+        Cache {
+            is_outdated: true,
+            ..Cache::default()
+        }
+    }
+}
+
+impl<'a, C, Message, Renderer> Stateful<'a, C, Message, Renderer>
+where
+    C: Component<Message, Renderer>,
+    Renderer: crate::Renderer,
+{
+    /// Creates a new [`Stateful`] element displaying the given [`Component`].
+    ///
+    /// [`Stateful`]: struct.Stateful.html
+    /// [`Component`]: trait.Component.html
+    pub fn new(component: &'a mut C) -> Self {
+        Stateful {
+            component: RefCell::new(component),
+            limits: Cell::new(None),
+            content: RefCell::new(None),
+            cache: RefCell::new(Cache::default()),
+            phantom: PhantomData,
+        }
+    }
+
+    fn lengths(&self) -> (Length, Length) {
+        let mut cache = self.cache.borrow_mut();
+
+        *cache.lengths.get_or_insert_with(|| {
+            let mut component = self.component.borrow_mut();
+            let view = component.view();
+
+            (view.width(), view.height())
+        })
+    }
+
+    /// Lays out the given view again if it processed an event since its last
+    /// layout, with the limits of that layout.
+    fn update_layout(
+        &self,
+        view: &Element<'_, C::Event, Renderer>,
+        renderer: &Renderer,
+        bounds: Rectangle,
+    ) {
+        let mut cache = self.cache.borrow_mut();
+
+        if cache.is_outdated {
+            let limits = self.limits.get().unwrap_or_else(|| {
+                layout::Limits::new(Size::ZERO, bounds.size())
+            });
+
+            *self.content.borrow_mut() = Some(view.layout(renderer, &limits));
+
+            cache.is_outdated = false;
+        }
+    }
+
+    /// Calls the given function with the layout of the content, which is the
+    /// only child of the given layout unless the view was laid out again.
+    fn with_content<T>(
+        &self,
+        layout: Layout<'_>,
+        f: impl FnOnce(Layout<'_>) -> T,
+    ) -> T {
+        let bounds = layout.bounds();
+        let offset = Vector::new(bounds.x, bounds.y);
+
+        match (&*self.content.borrow(), layout.children().next()) {
+            (Some(content), _) => f(Layout::with_offset(offset, content)),
+            (None, Some(content)) => f(content),
+            (None, None) => {
+                f(Layout::with_offset(offset, &layout::Node::default()))
+            }
+        }
+    }
+}
+
+impl<'a, C, Message, Renderer> Widget<Message, Renderer>
+    for Stateful<'a, C, Message, Renderer>
+where
+    C: Component<Message, Renderer>,
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn width(&self) -> Length {
+        self.lengths().0
+    }
+
+    fn height(&self) -> Length {
+        self.lengths().1
+    }
+
+    fn min_size(&self, renderer: &Renderer) -> Size {
+        let mut cache = self.cache.borrow_mut();
+
+        *cache.min_size.get_or_insert_with(|| {
+            self.component.borrow_mut().view().min_size(renderer)
+        })
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.limits.set(Some(*limits));
+        *self.content.borrow_mut() = None;
+        self.cache.borrow_mut().is_outdated = false;
+
+        let content =
+            self.component.borrow_mut().view().layout(renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        let mut events = Vec::new();
+
+        let status = {
+            let mut component = self.component.borrow_mut();
+            let mut view = component.view();
+            self.update_layout(&view, renderer, layout.bounds());
+
+            self.with_content(layout, |layout| {
+                view.widget.on_event(
+                    event,
+                    layout,
+                    cursor_position,
+                    &mut events,
+                    renderer,
+                    clipboard,
+                )
+            })
+        };
+
+        if status == event::Status::Captured || !events.is_empty() {
+            *self.cache.get_mut() = Cache::outdated();
+        }
+
+        let component = self.component.get_mut();
+
+        for event in events {
+            messages.extend(component.update(event));
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let mut component = self.component.borrow_mut();
+        let view = component.view();
+        self.update_layout(&view, renderer, layout.bounds());
+
+        self.with_content(layout, |layout| {
+            view.draw(renderer, defaults, layout, cursor_position)
+        })
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        let mut cache = self.cache.borrow_mut();
+
+        let hash = *cache.hash.get_or_insert_with(|| {
+            let mut hasher = Hasher::default();
+
+            self.component.borrow_mut().view().hash_layout(&mut hasher);
+
+            hasher.finish()
+        });
+
+        hash.hash(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let bounds = layout.bounds();
+        let offset = Vector::new(bounds.x, bounds.y);
+
+        let content =
+            self.with_content(layout, |content| content.node().clone());
+
+        let has_overlay = self
+            .component
+            .get_mut()
+            .view()
+            .overlay(Layout::with_offset(offset, &content))
+            .is_some();
+
+        if !has_overlay {
+            return None;
+        }
+
+        Some(overlay::Element::new(
+            Point::ORIGIN,
+            Box::new(Overlay {
+                component: RefCell::new(self.component.get_mut()),
+                content,
+                offset,
+                cache: &mut self.cache,
+                phantom: PhantomData,
+            }),
+        ))
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        let mut component = self.component.borrow_mut();
+        let view = component.view();
+
+        self.with_content(layout, |layout| view.focused_bounds(layout))
+    }
+
+    fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        messages: &mut Vec<Message>,
+    ) {
+        let mut events = Vec::new();
+
+        self.component
+            .get_mut()
+            .view()
+            .focusables(visit, &mut events);
+
+        if !events.is_empty() {
+            *self.cache.get_mut() = Cache::outdated();
+        }
+
+        let component = self.component.get_mut();
+
+        for event in events {
+            messages.extend(component.update(event));
+        }
+    }
+}
+
+/// The overlay of the view of a [`Component`].
+///
+/// Like the view itself, the overlay is built again for every call.
+///
+/// [`Component`]: trait.Component.html
+struct Overlay<'a, 'b, C, Message, Renderer> {
+    component: RefCell<&'a mut &'b mut C>,
+    content: layout::Node,
+    offset: Vector,
+    cache: &'a mut RefCell<Cache>,
+    phantom: PhantomData<(Message, Renderer)>,
+}
+
+impl<'a, 'b, C, Message, Renderer> Overlay<'a, 'b, C, Message, Renderer>
+where
+    C: Component<Message, Renderer>,
+    Renderer: crate::Renderer,
+{
+    fn with_overlay<T>(
+        &self,
+        f: impl FnOnce(&mut overlay::Element<'_, C::Event, Renderer>) -> T,
+    ) -> Option<T> {
+        let mut component = self.component.borrow_mut();
+        let mut view = component.view();
+
+        let mut overlay =
+            view.overlay(Layout::with_offset(self.offset, &self.content))?;
+
+        Some(f(&mut overlay))
+    }
+}
+
+impl<'a, 'b, C, Message, Renderer> crate::Overlay<Message, Renderer>
+    for Overlay<'a, 'b, C, Message, Renderer>
+where
+    C: Component<Message, Renderer>,
+    Renderer: crate::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        _position: Point,
+    ) -> layout::Node {
+        self.with_overlay(|overlay| overlay.layout(renderer, bounds))
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        // The overlay of the view can only go away while processing an event,
+        // after which a new overlay is requested
+        self.with_overlay(|overlay| {
+            overlay.draw(renderer, defaults, layout, cursor_position)
+        })
+        .expect("Draw the overlay of the view")
+    }
+
+    fn hash_layout(&self, state: &mut Hasher, _position: Point) {
+        let _ = self.with_overlay(|overlay| overlay.hash_layout(state));
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let mut events = Vec::new();
+
+        let _ = self.with_overlay(|overlay| {
+            overlay.on_event(
+                event,
+                layout,
+                cursor_position,
+                &mut events,
+                renderer,
+                clipboard,
+            )
+        });
+
+        *self.cache.get_mut() = Cache::outdated();
+
+        let component = self.component.get_mut();
+
+        for event in events {
+            messages.extend(component.update(event));
+        }
+    }
+}
+
+impl<'a, C, Message, Renderer> From<Stateful<'a, C, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    C: Component<Message, Renderer>,
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        stateful: Stateful<'a, C, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(stateful)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::testing::Harness;
+    use crate::{button, pick_list, Button, PickList, Size, Text};
+
+    #[derive(Default)]
+    struct Counter {
+        value: u32,
+        increment: button::State,
+    }
+
+    #[derive(Debug, Clone)]
+    struct Increment;
+
+    impl Component<u32, Null> for Counter {
+        type Event = Increment;
+
+        fn update(&mut self, _event: Increment) -> Option<u32> {
+            self.value += 1;
+
+            // Only every other increment reaches the application
+            if self.value % 2 == 0 {
+                Some(self.value)
+            } else {
+                None
+            }
+        }
+
+        fn view(&mut self) -> Element<'_, Increment, Null> {
+            Button::new(&mut self.increment, Text::new(""))
+                .width(Length::Units(20 + 10 * self.value as u16))
+                .on_press(Increment)
+                .into()
+        }
+    }
+
+    #[test]
+    fn events_of_the_view_update_the_component() {
+        let mut counter = Counter::default();
+
+        {
+            let mut harness = Harness::new(
+                Stateful::new(&mut counter),
+                Size::new(100.0, 100.0),
+            );
+
+            harness.click(Point::new(10.0, 10.0));
+            harness.click(Point::new(10.0, 10.0));
+            harness.click(Point::new(10.0, 10.0));
+
+            // The button grew after the first click, without a message
+            harness.click(Point::new(45.0, 10.0));
+
+            assert_eq!(harness.into_messages(), vec![2, 4]);
+        }
+
+        assert_eq!(counter.value, 4);
+    }
+
+    const LANGUAGES: [&str; 3] = ["Rust", "Elm", "Haskell"];
+
+    #[derive(Default)]
+    struct Picker {
+        selected: Option<&'static str>,
+        languages: pick_list::State<&'static str>,
+    }
+
+    impl Component<String, Null> for Picker {
+        type Event = &'static str;
+
+        fn update(&mut self, language: &'static str) -> Option<String> {
+            self.selected = Some(language);
+
+            Some(format!("Picked {}", language))
+        }
+
+        fn view(&mut self) -> Element<'_, &'static str, Null> {
+            PickList::new(
+                &mut self.languages,
+                &LANGUAGES[..],
+                self.selected,
+                |language| language,
+            )
+            .width(Length::Units(100))
+            .into()
+        }
+    }
+
+    #[test]
+    fn overlays_of_the_view_are_displayed() {
+        let mut picker = Picker::default();
+
+        {
+            let mut harness = Harness::new(
+                Stateful::new(&mut picker),
+                Size::new(200.0, 200.0),
+            );
+
+            harness.click(Point::new(10.0, 10.0));
+
+            // Each option is 20 units tall and the menu starts below the
+            // pick list
+            harness.move_cursor_to(Point::new(10.0, 50.0));
+            harness.click(Point::new(10.0, 50.0));
+
+            assert_eq!(harness.into_messages(), vec!["Picked Elm"]);
+        }
+
+        assert_eq!(picker.selected, Some("Elm"));
+    }
+}
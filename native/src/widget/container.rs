@@ -1,9 +1,12 @@
 //! Decorate content and apply alignment.
 use std::hash::Hash;
 
+use crate::aspect_ratio::Ratio;
+use crate::focus::Focusable;
 use crate::{
-    layout, overlay, Align, Clipboard, Element, Event, Hasher, Layout, Length,
-    Point, Rectangle, Widget,
+    event, image, layout, overlay, scrollable, Align, Clipboard, ContentFit,
+    Element, Event, Hasher, Layout, Length, Point, Rectangle, Scrollable, Size,
+    Spacing, Widget,
 };
 
 use std::u32;
@@ -13,14 +16,19 @@ use std::u32;
 /// It is normally used for alignment purposes.
 #[allow(missing_debug_implementations)]
 pub struct Container<'a, Message, Renderer: self::Renderer> {
-    padding: u16,
+    padding: Spacing,
     width: Length,
     height: Length,
+    min_width: u32,
+    min_height: u32,
     max_width: u32,
     max_height: u32,
+    aspect_ratio: Option<Ratio>,
     horizontal_alignment: Align,
     vertical_alignment: Align,
     style: Renderer::Style,
+    background_image: Option<(image::Handle, ContentFit)>,
+    clip: bool,
     content: Element<'a, Message, Renderer>,
 }
 
@@ -36,23 +44,31 @@ where
         T: Into<Element<'a, Message, Renderer>>,
     {
         Container {
-            padding: 0,
+            padding: Spacing::Units(0),
             width: Length::Shrink,
             height: Length::Shrink,
+            min_width: 0,
+            min_height: 0,
             max_width: u32::MAX,
             max_height: u32::MAX,
+            aspect_ratio: None,
             horizontal_alignment: Align::Start,
             vertical_alignment: Align::Start,
             style: Renderer::Style::default(),
+            background_image: None,
+            clip: false,
             content: content.into(),
         }
     }
 
     /// Sets the padding of the [`Container`].
     ///
+    /// A [`Spacing::Relative`] amount is relative to the available width.
+    ///
     /// [`Container`]: struct.Column.html
-    pub fn padding(mut self, units: u16) -> Self {
-        self.padding = units;
+    /// [`Spacing::Relative`]: ../../enum.Spacing.html#variant.Relative
+    pub fn padding(mut self, padding: impl Into<Spacing>) -> Self {
+        self.padding = padding.into();
         self
     }
 
@@ -72,9 +88,37 @@ where
         self
     }
 
+    /// Sets the minimum width of the [`Container`].
+    ///
+    /// A flexible [`Container`] in a [`Row`] is never given less space, and
+    /// the space it takes is removed from the other flexible elements.
+    ///
+    /// [`Container`]: struct.Container.html
+    /// [`Row`]: ../row/struct.Row.html
+    pub fn min_width(mut self, min_width: u32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Sets the minimum height of the [`Container`].
+    ///
+    /// A flexible [`Container`] in a [`Column`] is never given less space,
+    /// and the space it takes is removed from the other flexible elements.
+    ///
+    /// [`Container`]: struct.Container.html
+    /// [`Column`]: ../column/struct.Column.html
+    pub fn min_height(mut self, min_height: u32) -> Self {
+        self.min_height = min_height;
+        self
+    }
+
     /// Sets the maximum width of the [`Container`].
     ///
+    /// A flexible [`Container`] in a [`Row`] gives the space it does not
+    /// take to the other flexible elements.
+    ///
     /// [`Container`]: struct.Container.html
+    /// [`Row`]: ../row/struct.Row.html
     pub fn max_width(mut self, max_width: u32) -> Self {
         self.max_width = max_width;
         self
@@ -82,12 +126,36 @@ where
 
     /// Sets the maximum height of the [`Container`] in pixels.
     ///
+    /// A flexible [`Container`] in a [`Column`] gives the space it does not
+    /// take to the other flexible elements.
+    ///
     /// [`Container`]: struct.Container.html
+    /// [`Column`]: ../column/struct.Column.html
     pub fn max_height(mut self, max_height: u32) -> Self {
         self.max_height = max_height;
         self
     }
 
+    /// Sets the aspect ratio of the [`Container`], as its width divided by
+    /// its height (e.g. `16.0 / 9.0`).
+    ///
+    /// The ratio is only kept when one dimension of the [`Container`] is
+    /// [`Shrink`] and the other is not: the [`Shrink`] dimension is then
+    /// computed from the other one, including the padding, and clamped to
+    /// the available space. When both dimensions are [`Shrink`], or both are
+    /// set explicitly, the ratio is ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ratio is not positive and finite.
+    ///
+    /// [`Container`]: struct.Container.html
+    /// [`Shrink`]: ../../enum.Length.html#variant.Shrink
+    pub fn aspect_ratio(mut self, ratio: f32) -> Self {
+        self.aspect_ratio = Some(Ratio::new(ratio));
+        self
+    }
+
     /// Sets the content alignment for the horizontal axis of the [`Container`].
     ///
     /// [`Container`]: struct.Container.html
@@ -127,6 +195,78 @@ where
         self.style = style.into();
         self
     }
+
+    /// Sets an image drawn as the background of the [`Container`], behind
+    /// its content and on top of the background of its style.
+    ///
+    /// The image is fitted into the bounds of the [`Container`] using the
+    /// given [`ContentFit`] and clipped to them.
+    ///
+    /// [`Container`]: struct.Container.html
+    /// [`ContentFit`]: ../../enum.ContentFit.html
+    pub fn background_image(
+        mut self,
+        handle: impl Into<image::Handle>,
+        fit: ContentFit,
+    ) -> Self {
+        self.background_image = Some((handle.into(), fit));
+        self
+    }
+
+    /// Sets whether the content of the [`Container`] should be clipped to
+    /// its bounds.
+    ///
+    /// When enabled, the parts of the content overflowing the [`Container`]
+    /// are neither drawn nor interactive. This is disabled by default.
+    ///
+    /// [`Container`]: struct.Container.html
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    /// Makes the content of the [`Container`] scrollable whenever it does not
+    /// fit in the [`Container`], using the given [`scrollable::State`].
+    ///
+    /// This works like `overflow: auto` in CSS: while the content fits, the
+    /// [`Container`] behaves like a normal one, without any scrollbar. Once
+    /// the content overflows, it is clipped and a scrollbar appears.
+    ///
+    /// The content is laid out without a height limit, so it should not
+    /// [`Fill`] the [`Container`] vertically.
+    ///
+    /// [`Container`]: struct.Container.html
+    /// [`scrollable::State`]: ../scrollable/struct.State.html
+    /// [`Fill`]: ../../enum.Length.html#variant.Fill
+    pub fn auto_scroll(self, state: &'a mut scrollable::State) -> Self
+    where
+        Renderer: 'a + scrollable::Renderer,
+        Message: 'a,
+    {
+        let content = self.content;
+        let width = content.width();
+
+        Container {
+            content: Scrollable::new(state).width(width).push(content).into(),
+            ..self
+        }
+    }
+
+    /// Returns the cursor position seen by the content of the [`Container`].
+    ///
+    /// [`Container`]: struct.Container.html
+    fn content_cursor_position(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Point {
+        if self.clip && !layout.bounds().contains(cursor_position) {
+            // TODO: Encode cursor availability in the type system
+            Point::new(cursor_position.x, -1.0)
+        } else {
+            cursor_position
+        }
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -142,28 +282,85 @@ where
         self.height
     }
 
+    fn min_size(&self, renderer: &Renderer) -> Size {
+        let size = self
+            .content
+            .min_size(renderer)
+            .pad(self.padding.resolve(f32::INFINITY));
+
+        Size::new(
+            size.width.max(self.min_width as f32),
+            size.height.max(self.min_height as f32),
+        )
+    }
+
+    fn max_size(&self) -> Size {
+        let max = |max: u32| {
+            if max == u32::MAX {
+                f32::INFINITY
+            } else {
+                max as f32
+            }
+        };
+
+        Size::new(max(self.max_width), max(self.max_height))
+    }
+
     fn layout(
         &self,
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let padding = f32::from(self.padding);
-
         let limits = limits
             .loose()
             .max_width(self.max_width)
             .max_height(self.max_height)
+            .min_width(self.min_width)
+            .min_height(self.min_height)
             .width(self.width)
-            .height(self.height)
-            .pad(padding);
+            .height(self.height);
+
+        let padding = self.padding.resolve(limits.max().width);
+        let inner = limits.pad(padding);
 
-        let mut content = self.content.layout(renderer, &limits.loose());
-        let size = limits.resolve(content.size());
+        let ratio_size = self.aspect_ratio.and_then(|ratio| {
+            keep_ratio(ratio, self.width, self.height, &limits).map(|outer| {
+                Size::new(
+                    (outer.width - padding * 2.0).max(0.0),
+                    (outer.height - padding * 2.0).max(0.0),
+                )
+            })
+        });
+
+        let (mut content, size) = match ratio_size {
+            Some(size) => (
+                self.content
+                    .layout(renderer, &layout::Limits::new(Size::ZERO, size)),
+                size,
+            ),
+            None => {
+                let content = self.content.layout(renderer, &inner.loose());
+                let size = inner.resolve(content.size());
+
+                (content, size)
+            }
+        };
 
         content.move_to(Point::new(padding, padding));
         content.align(self.horizontal_alignment, self.vertical_alignment, size);
 
-        layout::Node::with_children(size.pad(padding), vec![content])
+        let baseline = content
+            .baseline()
+            .map(|baseline| content.bounds().y + baseline);
+
+        let mut node =
+            layout::Node::with_children(size.pad(padding), vec![content]);
+
+        if let Some(baseline) = baseline {
+            node.set_baseline(baseline);
+        }
+
+        node
     }
 
     fn on_event(
@@ -174,17 +371,26 @@ where
         messages: &mut Vec<Message>,
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>,
-    ) {
+    ) -> event::Status {
         self.content.widget.on_event(
             event,
             layout.children().next().unwrap(),
-            cursor_position,
+            self.content_cursor_position(layout, cursor_position),
             messages,
             renderer,
             clipboard,
         )
     }
 
+    fn layout_changed(
+        &mut self,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+    ) {
+        self.content
+            .layout_changed(layout.children().next().unwrap(), messages)
+    }
+
     fn draw(
         &self,
         renderer: &mut Renderer,
@@ -195,8 +401,12 @@ where
         renderer.draw(
             defaults,
             layout.bounds(),
-            cursor_position,
+            self.content_cursor_position(layout, cursor_position),
             &self.style,
+            self.background_image
+                .as_ref()
+                .map(|(handle, fit)| (handle, *fit)),
+            self.clip,
             &self.content,
             layout.children().next().unwrap(),
         )
@@ -209,8 +419,11 @@ where
         self.padding.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.min_width.hash(state);
+        self.min_height.hash(state);
         self.max_width.hash(state);
         self.max_height.hash(state);
+        self.aspect_ratio.hash(state);
 
         self.content.hash_layout(state);
     }
@@ -221,6 +434,19 @@ where
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         self.content.overlay(layout.children().next().unwrap())
     }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.content
+            .focused_bounds(layout.children().next().unwrap())
+    }
+
+    fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        messages: &mut Vec<Message>,
+    ) {
+        self.content.focusables(visit, messages)
+    }
 }
 
 /// The renderer of a [`Container`].
@@ -236,13 +462,20 @@ pub trait Renderer: crate::Renderer {
 
     /// Draws a [`Container`].
     ///
+    /// It receives the background image of the [`Container`], if any, along
+    /// with the [`ContentFit`] used to fit it into its bounds, and whether
+    /// the content should be clipped to the bounds.
+    ///
     /// [`Container`]: struct.Container.html
+    /// [`ContentFit`]: ../../enum.ContentFit.html
     fn draw<Message>(
         &mut self,
         defaults: &Self::Defaults,
         bounds: Rectangle,
         cursor_position: Point,
         style: &Self::Style,
+        background_image: Option<(&image::Handle, ContentFit)>,
+        clip: bool,
         content: &Element<'_, Message, Self>,
         content_layout: Layout<'_>,
     ) -> Self::Output;
@@ -260,3 +493,168 @@ where
         Element::new(column)
     }
 }
+
+/// Returns the size of a [`Container`] keeping the given aspect ratio, if
+/// exactly one of its dimensions is [`Shrink`] and the other one is bounded.
+///
+/// [`Container`]: struct.Container.html
+/// [`Shrink`]: ../../enum.Length.html#variant.Shrink
+fn keep_ratio(
+    ratio: Ratio,
+    width: Length,
+    height: Length,
+    limits: &layout::Limits,
+) -> Option<Size> {
+    let (min, max) = (limits.min(), limits.max());
+
+    match (width, height) {
+        (Length::Shrink, Length::Shrink) => None,
+        (_, Length::Shrink) if max.width.is_finite() => Some(Size::new(
+            max.width,
+            ratio.height(max.width).min(max.height).max(min.height),
+        )),
+        (Length::Shrink, _) if max.height.is_finite() => Some(Size::new(
+            ratio.width(max.height).min(max.width).max(min.width),
+            max.height,
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::testing::Harness;
+    use crate::{button, mouse, Button, Column, Size, Text};
+
+    fn clicks(clip: bool) -> Vec<()> {
+        let mut state = button::State::new();
+
+        let container = Container::new(
+            Button::<_, Null>::new(&mut state, Text::new(""))
+                .padding(40)
+                .on_press(()),
+        )
+        .width(Length::Units(50))
+        .clip(clip);
+
+        // The padding of the button makes it overflow the container
+        let mut harness = Harness::new(container, Size::new(200.0, 200.0));
+        harness.click(Point::new(65.0, 5.0));
+
+        harness.into_messages()
+    }
+
+    #[test]
+    fn clipped_content_is_not_interactive_outside_bounds() {
+        assert_eq!(clicks(false), vec![()]);
+        assert_eq!(clicks(true), vec![]);
+    }
+
+    fn scrolled_clicks(auto_scroll: bool) -> Vec<u8> {
+        let mut first = button::State::new();
+        let mut second = button::State::new();
+        let mut scrollable = scrollable::State::new();
+
+        let content = Column::<_, Null>::new()
+            .push(
+                Button::new(&mut first, Text::new(""))
+                    .width(Length::Units(100))
+                    .height(Length::Units(40))
+                    .on_press(1),
+            )
+            .push(
+                Button::new(&mut second, Text::new(""))
+                    .width(Length::Units(100))
+                    .height(Length::Units(40))
+                    .on_press(2),
+            );
+
+        let container = Container::new(content).height(Length::Units(50));
+
+        let container = if auto_scroll {
+            container.auto_scroll(&mut scrollable)
+        } else {
+            container
+        };
+
+        let mut harness = Harness::new(container, Size::new(100.0, 100.0));
+        harness.move_cursor_to(Point::new(10.0, 20.0));
+        harness.event(Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Pixels { x: 0.0, y: -30.0 },
+        }));
+        harness.click(Point::new(10.0, 20.0));
+
+        harness.into_messages()
+    }
+
+    #[test]
+    fn overflowing_content_is_scrolled_automatically() {
+        assert_eq!(scrolled_clicks(false), vec![1]);
+        assert_eq!(scrolled_clicks(true), vec![2]);
+    }
+
+    #[test]
+    fn fitting_content_is_not_scrolled() {
+        let mut scrollable = scrollable::State::new();
+
+        let container: Container<'_, (), Null> =
+            Container::new(Text::new("").height(Length::Units(30)))
+                .height(Length::Units(50))
+                .auto_scroll(&mut scrollable);
+
+        let mut harness = Harness::new(container, Size::new(100.0, 100.0));
+        harness.move_cursor_to(Point::new(10.0, 20.0));
+        harness.event(Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Pixels { x: 0.0, y: -30.0 },
+        }));
+        drop(harness);
+
+        assert_eq!(
+            scrollable.offset(
+                Rectangle::new(Point::ORIGIN, Size::new(100.0, 50.0)),
+                Rectangle::new(Point::ORIGIN, Size::new(100.0, 30.0)),
+            ),
+            0
+        );
+    }
+
+    #[test]
+    fn shrink_dimension_follows_the_aspect_ratio() {
+        let container: Container<'_, (), Null> =
+            Container::new(Text::new("").height(Length::Fill))
+                .width(Length::Fill)
+                .padding(10)
+                .aspect_ratio(2.0);
+
+        let harness = Harness::new(container, Size::new(200.0, 300.0));
+
+        assert_eq!(
+            harness.snapshot().lines().collect::<Vec<_>>(),
+            vec!["Container (0, 0) 200x100", "  Text (10, 10) 0x80"]
+        );
+    }
+
+    #[test]
+    fn shrink_width_follows_the_aspect_ratio_of_the_height() {
+        let container: Container<'_, (), Null> =
+            Container::new(Text::new("").width(Length::Fill))
+                .height(Length::Units(50))
+                .aspect_ratio(2.0);
+
+        let harness = Harness::new(container, Size::new(300.0, 300.0));
+
+        assert_eq!(
+            harness.snapshot().lines().collect::<Vec<_>>(),
+            vec!["Container (0, 0) 100x50", "  Text (0, 0) 100x20"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "an aspect ratio must be positive and finite")]
+    fn aspect_ratio_must_be_positive() {
+        let _: Container<'_, (), Null> =
+            Container::new(Text::new("")).aspect_ratio(-1.0);
+    }
+}
@@ -0,0 +1,449 @@
+//! Show a menu of actions when some content is right-clicked.
+use std::borrow::Cow;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use crate::event;
+use crate::focus::Focusable;
+use crate::keyboard;
+use crate::mouse;
+use crate::overlay::{self, menu, Menu};
+use crate::{
+    layout, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+
+/// An element that opens a menu of actions at the cursor when its content
+/// is right-clicked.
+///
+/// The menu is closed when an action is selected, when clicking outside of
+/// it, or when pressing `Escape`.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{context_menu, renderer::Null, Text};
+/// #
+/// # type ContextMenu<'a, T, Message> =
+/// #     iced_native::ContextMenu<'a, T, Message, Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     ActionSelected(&'static str),
+/// }
+///
+/// let mut state = context_menu::State::new();
+///
+/// let context_menu = ContextMenu::new(
+///     &mut state,
+///     Text::new("Right-click me!"),
+///     &["Copy", "Paste"][..],
+///     Message::ActionSelected,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ContextMenu<'a, T, Message, Renderer: menu::Renderer>
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    menu: &'a mut menu::State,
+    position: &'a mut Option<Point>,
+    hovered_option: &'a mut Option<usize>,
+    last_selection: &'a mut Option<usize>,
+    content: Element<'a, Message, Renderer>,
+    options: Cow<'a, [T]>,
+    on_selected: Box<dyn Fn(T) -> Message + 'a>,
+    menu_width: Option<u16>,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer as menu::Renderer>::Style,
+}
+
+/// The local state of a [`ContextMenu`].
+///
+/// [`ContextMenu`]: struct.ContextMenu.html
+#[derive(Debug, Clone)]
+pub struct State<T> {
+    menu: menu::State,
+    position: Option<Point>,
+    hovered_option: Option<usize>,
+    last_selection: Option<usize>,
+    options: PhantomData<T>,
+}
+
+impl<T> State<T> {
+    /// Creates a new [`State`] with the menu of the [`ContextMenu`] closed.
+    ///
+    /// [`State`]: struct.State.html
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Closes the menu of the [`ContextMenu`] with this [`State`].
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    /// [`State`]: struct.State.html
+    pub fn close(&mut self) {
+        self.position = None;
+        self.hovered_option = None;
+    }
+
+    /// Returns whether the menu of the [`ContextMenu`] with this [`State`] is
+    /// open.
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    /// [`State`]: struct.State.html
+    pub fn is_open(&self) -> bool {
+        self.position.is_some()
+    }
+}
+
+impl<T> Default for State<T> {
+    fn default() -> Self {
+        Self {
+            menu: menu::State::default(),
+            position: None,
+            hovered_option: None,
+            last_selection: None,
+            options: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: 'a, Message, Renderer: menu::Renderer>
+    ContextMenu<'a, T, Message, Renderer>
+where
+    T: ToString + Clone,
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    /// Creates a new [`ContextMenu`] with the given [`State`], content, list
+    /// of actions, and the message to produce when an action is selected.
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    /// [`State`]: struct.State.html
+    pub fn new(
+        state: &'a mut State<T>,
+        content: impl Into<Element<'a, Message, Renderer>>,
+        options: impl Into<Cow<'a, [T]>>,
+        on_selected: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        let State {
+            menu,
+            position,
+            hovered_option,
+            last_selection,
+            ..
+        } = state;
+
+        ContextMenu {
+            menu,
+            position,
+            hovered_option,
+            last_selection,
+            content: content.into(),
+            options: options.into(),
+            on_selected: Box::new(on_selected),
+            menu_width: None,
+            padding: 5,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the width of the menu of the [`ContextMenu`].
+    ///
+    /// By default, the menu is as wide as its widest action.
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    pub fn menu_width(mut self, width: u16) -> Self {
+        self.menu_width = Some(width);
+        self
+    }
+
+    /// Sets the padding of the actions of the [`ContextMenu`].
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the actions of the [`ContextMenu`].
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the actions of the [`ContextMenu`].
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the menu of the [`ContextMenu`].
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as menu::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn close(&mut self) {
+        *self.position = None;
+        *self.hovered_option = None;
+    }
+}
+
+impl<'a, T: 'a, Message, Renderer> Widget<Message, Renderer>
+    for ContextMenu<'a, T, Message, Renderer>
+where
+    T: ToString + Clone,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: 'a,
+    Renderer: menu::Renderer + 'a,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn min_size(&self, renderer: &Renderer) -> Size {
+        self.content.min_size(renderer)
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        let status = self.content.widget.on_event(
+            event.clone(),
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+
+        // TODO: Encode cursor availability in the type system
+        let is_cursor_available =
+            cursor_position.x >= 0.0 && cursor_position.y >= 0.0;
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+                if layout.bounds().contains(cursor_position) {
+                    *self.position = Some(cursor_position);
+                    *self.hovered_option = None;
+
+                    return event::Status::Captured;
+                } else if is_cursor_available {
+                    self.close();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_)) => {
+                if self.position.is_some() && is_cursor_available {
+                    self.close();
+                }
+
+                if let Some(index) = self.last_selection.take() {
+                    if let Some(option) = self.options.get(index) {
+                        messages.push((self.on_selected)(option.clone()));
+                    }
+
+                    self.close();
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => {
+                self.close();
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn layout_changed(
+        &mut self,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+    ) {
+        self.content.layout_changed(layout, messages)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.content
+            .draw(renderer, defaults, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if let Some(position) = *self.position {
+            let mut menu = Menu::new(
+                self.menu,
+                &self.options,
+                self.hovered_option,
+                self.last_selection,
+            )
+            .padding(self.padding)
+            .font(self.font)
+            .style(self.style.clone())
+            .avoid_overflow();
+
+            menu = match self.menu_width {
+                Some(width) => menu.width(width),
+                None => menu.fit_content(),
+            };
+
+            if let Some(text_size) = self.text_size {
+                menu = menu.text_size(text_size);
+            }
+
+            Some(menu.overlay(position, 0.0))
+        } else {
+            self.content.overlay(layout)
+        }
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.content.focused_bounds(layout)
+    }
+
+    fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        messages: &mut Vec<Message>,
+    ) {
+        self.content.focusables(visit, messages)
+    }
+}
+
+impl<'a, T: 'a, Message, Renderer> From<ContextMenu<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: ToString + Clone,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: 'a,
+    Renderer: menu::Renderer + 'a,
+{
+    fn from(
+        context_menu: ContextMenu<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(context_menu)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::testing::Harness;
+    use crate::{Size, Text};
+
+    const ACTIONS: &[&str] = &["Copy", "Paste"];
+
+    fn content() -> Text<Null> {
+        Text::new("Right-click me!")
+            .width(Length::Units(100))
+            .height(Length::Units(50))
+    }
+
+    #[test]
+    fn right_click_opens_menu_and_selection_closes_it() {
+        let mut state = State::new();
+        let context_menu =
+            ContextMenu::new(&mut state, content(), ACTIONS, |action| action);
+
+        let mut harness = Harness::new(context_menu, Size::new(200.0, 100.0));
+        harness.move_cursor_to(Point::new(10.0, 10.0));
+        harness.event(Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Right,
+        )));
+
+        // Each action is 30 units tall and the menu starts at the cursor
+        harness.click(Point::new(15.0, 50.0));
+
+        assert_eq!(harness.into_messages(), vec!["Paste"]);
+        assert!(!state.is_open());
+    }
+
+    #[test]
+    fn outside_click_closes_menu() {
+        let mut state = State::new();
+        let context_menu =
+            ContextMenu::new(&mut state, content(), ACTIONS, |action| action);
+
+        let mut harness = Harness::new(context_menu, Size::new(200.0, 100.0));
+        harness.move_cursor_to(Point::new(10.0, 10.0));
+        harness.event(Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Right,
+        )));
+        harness.click(Point::new(150.0, 90.0));
+
+        assert!(harness.into_messages().is_empty());
+        assert!(!state.is_open());
+    }
+
+    #[test]
+    fn menu_is_moved_inside_the_viewport() {
+        let mut state = State::new();
+        let context_menu =
+            ContextMenu::new(&mut state, content(), ACTIONS, |action| action)
+                .menu_width(60);
+
+        let mut harness = Harness::new(context_menu, Size::new(100.0, 100.0));
+        harness.move_cursor_to(Point::new(90.0, 10.0));
+        harness.event(Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Right,
+        )));
+
+        // The menu ends at the right edge instead of starting at the cursor
+        harness.click(Point::new(50.0, 50.0));
+
+        assert_eq!(harness.into_messages(), vec!["Paste"]);
+    }
+}
@@ -0,0 +1,474 @@
+//! Distribute content in rows and aligned columns.
+use std::hash::Hash;
+
+use crate::focus::Focusable;
+use crate::{
+    event, layout, overlay, Align, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Spacing, Widget,
+};
+
+/// A container that distributes its contents in rows with a fixed amount of
+/// columns.
+///
+/// Children are placed from left to right, starting a new row every
+/// [`columns`] children. Every column is as wide as its widest child and
+/// every row as tall as its tallest child, which keeps the children of a
+/// column aligned across rows.
+///
+/// The remaining width is shared equally between the columns containing
+/// children that fill horizontally, and the remaining height between the
+/// rows containing children that fill vertically.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{renderer::Null, Text};
+/// #
+/// # type Grid<'a, Message> = iced_native::Grid<'a, Message, Null>;
+/// #
+/// let form: Grid<'_, ()> = Grid::new(2)
+///     .spacing(10)
+///     .push(Text::new("Name"))
+///     .push(Text::new("Ferris"))
+///     .push(Text::new("Language"))
+///     .push(Text::new("Rust"));
+/// ```
+///
+/// [`columns`]: #method.new
+#[allow(missing_debug_implementations)]
+pub struct Grid<'a, Message, Renderer> {
+    columns: usize,
+    spacing: Spacing,
+    padding: Spacing,
+    width: Length,
+    height: Length,
+    align_items: Align,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Grid<'a, Message, Renderer> {
+    /// Creates an empty [`Grid`] with the given amount of columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the amount of columns is zero.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn new(columns: usize) -> Self {
+        Self::with_children(columns, Vec::new())
+    }
+
+    /// Creates a [`Grid`] with the given amount of columns and elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the amount of columns is zero.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn with_children(
+        columns: usize,
+        children: Vec<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        assert!(columns > 0, "a grid needs at least one column");
+
+        Grid {
+            columns,
+            spacing: Spacing::Units(0),
+            padding: Spacing::Units(0),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            align_items: Align::Start,
+            children,
+        }
+    }
+
+    /// Sets the spacing _between_ the rows and the columns of the [`Grid`].
+    ///
+    /// A [`Spacing::Relative`] amount is relative to the available width
+    /// between columns and to the available height between rows.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    /// [`Spacing::Relative`]: ../../enum.Spacing.html#variant.Relative
+    pub fn spacing(mut self, spacing: impl Into<Spacing>) -> Self {
+        self.spacing = spacing.into();
+        self
+    }
+
+    /// Sets the padding of the [`Grid`].
+    ///
+    /// A [`Spacing::Relative`] amount is relative to the available width.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    /// [`Spacing::Relative`]: ../../enum.Spacing.html#variant.Relative
+    pub fn padding(mut self, padding: impl Into<Spacing>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the horizontal alignment of the contents of the [`Grid`] inside
+    /// their column.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn align_items(mut self, align: Align) -> Self {
+        self.align_items = align;
+        self
+    }
+
+    /// Adds an element to the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn push<E>(mut self, child: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.children.push(child.into());
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Grid<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let padding = self.padding.resolve(limits.max().width);
+        let column_spacing = self.spacing.resolve(limits.max().width);
+        let row_spacing = self.spacing.resolve(limits.max().height);
+
+        let limits = limits.pad(padding);
+        let available = limits.max();
+        let rows = (self.children.len() + self.columns - 1) / self.columns;
+
+        let mut column_widths = vec![0.0f32; self.columns];
+        let mut row_heights = vec![0.0f32; rows];
+        let mut fill_columns = vec![false; self.columns];
+        let mut fill_rows = vec![false; rows];
+
+        for (i, child) in self.children.iter().enumerate() {
+            let (row, column) = (i / self.columns, i % self.columns);
+
+            let node = child
+                .layout(renderer, &layout::Limits::new(Size::ZERO, available));
+            let size = node.size();
+
+            // Filling children take the size of their cell instead
+            if child.width() == Length::Fill {
+                fill_columns[column] = true;
+            } else {
+                column_widths[column] = column_widths[column].max(size.width);
+            }
+
+            if child.height() == Length::Fill {
+                fill_rows[row] = true;
+            } else {
+                row_heights[row] = row_heights[row].max(size.height);
+            }
+        }
+
+        let total_column_spacing = column_spacing * (self.columns - 1) as f32;
+        let total_row_spacing = row_spacing * rows.saturating_sub(1) as f32;
+
+        share_remaining(
+            &mut column_widths,
+            &fill_columns,
+            available.width - total_column_spacing,
+        );
+        share_remaining(
+            &mut row_heights,
+            &fill_rows,
+            available.height - total_row_spacing,
+        );
+
+        let mut nodes = Vec::with_capacity(self.children.len());
+        let mut y = padding;
+
+        for (row, children) in self.children.chunks(self.columns).enumerate() {
+            let mut x = padding;
+
+            for (column, child) in children.iter().enumerate() {
+                let cell = Size::new(column_widths[column], row_heights[row]);
+
+                let mut node = child
+                    .layout(renderer, &layout::Limits::new(Size::ZERO, cell));
+
+                node.move_to(Point::new(x, y));
+                node.align(self.align_items, Align::Start, cell);
+                nodes.push(node);
+
+                x += cell.width + column_spacing;
+            }
+
+            y += row_heights[row] + row_spacing;
+        }
+
+        let intrinsic = Size::new(
+            column_widths.iter().sum::<f32>() + total_column_spacing,
+            row_heights.iter().sum::<f32>() + total_row_spacing,
+        );
+
+        let size = limits.resolve(intrinsic).pad(padding);
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .map(|(child, layout)| {
+                child.widget.on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    messages,
+                    renderer,
+                    clipboard,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn layout_changed(
+        &mut self,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+    ) {
+        for (child, layout) in self.children.iter_mut().zip(layout.children()) {
+            child.layout_changed(layout, messages);
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(defaults, &self.children, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.columns.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.align_items.hash(state);
+        self.spacing.hash(state);
+        self.padding.hash(state);
+
+        for child in &self.children {
+            child.widget.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .filter_map(|(child, layout)| child.widget.overlay(layout))
+            .next()
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.children
+            .iter()
+            .zip(layout.children())
+            .find_map(|(child, layout)| child.focused_bounds(layout))
+    }
+
+    fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        messages: &mut Vec<Message>,
+    ) {
+        for child in &mut self.children {
+            child.focusables(visit, messages);
+        }
+    }
+}
+
+/// Shares the space that the given tracks leave out of the available space
+/// equally between the tracks that fill it, if the available space is finite.
+fn share_remaining(tracks: &mut [f32], fill: &[bool], available: f32) {
+    let fill_count = fill.iter().filter(|&&fill| fill).count();
+
+    if fill_count == 0 || !available.is_finite() {
+        return;
+    }
+
+    let remaining = (available - tracks.iter().sum::<f32>()).max(0.0);
+
+    for (track, _) in tracks.iter_mut().zip(fill).filter(|(_, &fill)| fill) {
+        *track += remaining / fill_count as f32;
+    }
+}
+
+/// The renderer of a [`Grid`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Grid`] in your user interface.
+///
+/// [`Grid`]: struct.Grid.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws a [`Grid`].
+    ///
+    /// It receives:
+    /// - the children of the [`Grid`]
+    /// - the [`Layout`] of the [`Grid`] and its children
+    /// - the cursor position
+    ///
+    /// [`Grid`]: struct.Grid.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        content: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Grid<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        grid: Grid<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::testing::Harness;
+    use crate::Text;
+
+    fn cell(width: u16, height: u16) -> Text<Null> {
+        Text::new("")
+            .width(Length::Units(width))
+            .height(Length::Units(height))
+    }
+
+    #[test]
+    fn columns_are_aligned_across_rows() {
+        let grid: Grid<'_, (), Null> = Grid::new(2)
+            .spacing(10)
+            .push(cell(50, 20))
+            .push(cell(30, 40))
+            .push(cell(80, 20))
+            .push(cell(20, 20))
+            .push(cell(10, 10));
+
+        let harness = Harness::new(grid, Size::new(400.0, 400.0));
+
+        assert_eq!(
+            harness.snapshot().lines().collect::<Vec<_>>(),
+            vec![
+                "Grid (0, 0) 120x90",
+                "  Text (0, 0) 50x20",
+                "  Text (90, 0) 30x40",
+                "  Text (0, 50) 80x20",
+                "  Text (90, 50) 20x20",
+                "  Text (0, 80) 10x10",
+            ]
+        );
+    }
+
+    #[test]
+    fn filling_columns_share_remaining_width() {
+        let grid: Grid<'_, (), Null> = Grid::new(2)
+            .width(Length::Fill)
+            .padding(10)
+            .align_items(Align::End)
+            .push(cell(50, 20))
+            .push(Text::new("").width(Length::Fill))
+            .push(cell(30, 20));
+
+        let harness = Harness::new(grid, Size::new(200.0, 100.0));
+
+        assert_eq!(
+            harness.snapshot().lines().collect::<Vec<_>>(),
+            vec![
+                "Grid (0, 0) 200x60",
+                "  Text (10, 10) 50x20",
+                "  Text (60, 10) 130x20",
+                "  Text (30, 30) 30x20",
+            ]
+        );
+    }
+
+    #[test]
+    fn filling_rows_share_remaining_height() {
+        let grid: Grid<'_, (), Null> = Grid::new(2)
+            .height(Length::Fill)
+            .spacing(10)
+            .push(cell(50, 20))
+            .push(cell(30, 20))
+            .push(cell(50, 20))
+            .push(Text::new("").width(Length::Units(30)).height(Length::Fill));
+
+        let harness = Harness::new(grid, Size::new(200.0, 100.0));
+
+        assert_eq!(
+            harness.snapshot().lines().collect::<Vec<_>>(),
+            vec![
+                "Grid (0, 0) 90x100",
+                "  Text (0, 0) 50x20",
+                "  Text (60, 0) 30x20",
+                "  Text (0, 30) 50x20",
+                "  Text (60, 30) 30x70",
+            ]
+        );
+    }
+}
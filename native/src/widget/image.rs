@@ -23,6 +23,7 @@ pub struct Image {
     handle: Handle,
     width: Length,
     height: Length,
+    border_radius: u16,
 }
 
 impl Image {
@@ -34,6 +35,22 @@ impl Image {
             handle: handle.into(),
             width: Length::Shrink,
             height: Length::Shrink,
+            border_radius: 0,
+        }
+    }
+
+    /// Creates a new [`Image`] showing the image of the given [`Status`] once
+    /// it is ready, and the given placeholder until then.
+    ///
+    /// The placeholder is also shown if the image failed to load. A small,
+    /// already decoded image, like a blurred thumbnail, works best.
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`Status`]: enum.Status.html
+    pub fn loading<T: Into<Handle>>(status: &Status, placeholder: T) -> Self {
+        match status {
+            Status::Ready(handle) => Self::new(handle.clone()),
+            Status::Pending | Status::Failed => Self::new(placeholder),
         }
     }
 
@@ -52,6 +69,18 @@ impl Image {
         self.height = height;
         self
     }
+
+    /// Sets the border radius of the [`Image`].
+    ///
+    /// The [`Image`] will be clipped to a rectangle with rounded corners of the
+    /// given radius. A radius of half the size of a square [`Image`] will
+    /// produce a circle.
+    ///
+    /// [`Image`]: struct.Image.html
+    pub fn border_radius(mut self, border_radius: u16) -> Self {
+        self.border_radius = border_radius;
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Image
@@ -98,7 +127,7 @@ where
         layout: Layout<'_>,
         _cursor_position: Point,
     ) -> Renderer::Output {
-        renderer.draw(self.handle.clone(), layout)
+        renderer.draw(self.handle.clone(), self.border_radius, layout)
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -180,6 +209,14 @@ impl Handle {
     pub fn data(&self) -> &Data {
         &self.data
     }
+
+    /// Returns whether the [`Handle`] contains decoded pixels, which can be
+    /// displayed without any further processing.
+    ///
+    /// [`Handle`]: struct.Handle.html
+    pub fn is_decoded(&self) -> bool {
+        matches!(self.data.as_ref(), Data::Pixels { .. })
+    }
 }
 
 impl<T> From<T> for Handle
@@ -231,6 +268,48 @@ impl std::fmt::Debug for Data {
     }
 }
 
+/// The status of an image that is loaded in the background.
+///
+/// Decoding big images while drawing can stall the user interface. Instead,
+/// an application can keep a [`Status`] in its state, start decoding the image
+/// with a `Command` when it needs it and store the result once the `Command`
+/// produces its message. Since every message triggers a redraw, the image
+/// is displayed as soon as it is ready.
+///
+/// Use [`Image::loading`] to display a placeholder in the meantime.
+///
+/// [`Status`]: enum.Status.html
+/// [`Image::loading`]: struct.Image.html#method.loading
+#[derive(Debug, Clone)]
+pub enum Status {
+    /// The image is still being loaded.
+    Pending,
+
+    /// The image is ready to be displayed.
+    Ready(Handle),
+
+    /// The image could not be loaded.
+    Failed,
+}
+
+impl Default for Status {
+    fn default() -> Self {
+        Status::Pending
+    }
+}
+
+impl Status {
+    /// Returns the [`Handle`] of the image, if it is ready.
+    ///
+    /// [`Handle`]: struct.Handle.html
+    pub fn handle(&self) -> Option<&Handle> {
+        match self {
+            Status::Ready(handle) => Some(handle),
+            Status::Pending | Status::Failed => None,
+        }
+    }
+}
+
 /// The renderer of an [`Image`].
 ///
 /// Your [renderer] will need to implement this trait before being able to use
@@ -244,10 +323,15 @@ pub trait Renderer: crate::Renderer {
     /// [`Image`]: struct.Image.html
     fn dimensions(&self, handle: &Handle) -> (u32, u32);
 
-    /// Draws an [`Image`].
+    /// Draws an [`Image`] clipped to the given border radius.
     ///
     /// [`Image`]: struct.Image.html
-    fn draw(&mut self, handle: Handle, layout: Layout<'_>) -> Self::Output;
+    fn draw(
+        &mut self,
+        handle: Handle,
+        border_radius: u16,
+        layout: Layout<'_>,
+    ) -> Self::Output;
 }
 
 impl<'a, Message, Renderer> From<Image> for Element<'a, Message, Renderer>
@@ -258,3 +342,28 @@ where
         Element::new(image)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn placeholder_is_shown_until_the_image_is_ready() {
+        let placeholder = Handle::from_pixels(1, 1, vec![0; 4]);
+        let decoded = Handle::from_pixels(2, 2, vec![255; 16]);
+
+        assert!(placeholder.is_decoded());
+        assert!(!Handle::from_path("ferris.png").is_decoded());
+
+        for status in &[Status::Pending, Status::Failed] {
+            let image = Image::loading(status, placeholder.clone());
+
+            assert_eq!(image.handle.id(), placeholder.id());
+        }
+
+        let image =
+            Image::loading(&Status::Ready(decoded.clone()), placeholder);
+
+        assert_eq!(image.handle.id(), decoded.id());
+    }
+}
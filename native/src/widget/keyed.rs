@@ -0,0 +1,138 @@
+//! Keep the state of widgets attached to the items of dynamic lists.
+//!
+//! A [`State`] stores the local state of the widgets displaying the items of
+//! a list by a stable key of each item, so the state follows its item when
+//! the list is reordered, and is dropped when the item goes away.
+//!
+//! [`State`]: struct.State.html
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// The local states of the widgets displaying the items of a list, by key.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{renderer::Null, keyed, text_input, Column, TextInput};
+/// #
+/// struct Task {
+///     id: u64,
+///     description: String,
+/// }
+///
+/// fn view<'a>(
+///     tasks: &'a [Task],
+///     inputs: &'a mut keyed::State<u64, text_input::State>,
+/// ) -> Column<'a, String, Null> {
+///     let states = inputs.reconcile(tasks.iter().map(|task| task.id));
+///
+///     tasks.iter().zip(states).fold(Column::new(), |column, (task, state)| {
+///         column.push(TextInput::new(
+///             state,
+///             "Description",
+///             &task.description,
+///             |description| description,
+///         ))
+///     })
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct State<K, S> {
+    states: HashMap<K, S>,
+}
+
+impl<K, S> State<K, S>
+where
+    K: Eq + Hash + Clone,
+    S: Default,
+{
+    /// Creates an empty [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State {
+            states: HashMap::new(),
+        }
+    }
+
+    /// Reconciles the [`State`] with the keys of the current items, in
+    /// order, and returns the state of each of them.
+    ///
+    /// Items with new keys get a default state, and the states of the keys
+    /// that are gone are dropped. Keys are expected to be unique: the state
+    /// of a repeated key is only returned for its first occurrence.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn reconcile(
+        &mut self,
+        keys: impl IntoIterator<Item = K>,
+    ) -> Vec<&mut S> {
+        let keys: Vec<K> = keys.into_iter().collect();
+        let present: HashSet<&K> = keys.iter().collect();
+
+        self.states.retain(|key, _| present.contains(key));
+
+        for key in &keys {
+            let _ = self.states.entry(key.clone()).or_default();
+        }
+
+        let mut states: HashMap<&K, &mut S> = self.states.iter_mut().collect();
+
+        keys.iter().filter_map(|key| states.remove(key)).collect()
+    }
+
+    /// Returns the state of the item with the given key, if it has one.
+    pub fn get(&self, key: &K) -> Option<&S> {
+        self.states.get(key)
+    }
+
+    /// Returns the amount of states in the [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Returns whether the [`State`] has no states.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+}
+
+impl<K, S> Default for State<K, S>
+where
+    K: Eq + Hash + Clone,
+    S: Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn states_follow_their_keys() {
+        let mut state = State::<&str, u32>::new();
+
+        for (value, count) in
+            state.reconcile(vec!["a", "b", "c"]).into_iter().zip(1..)
+        {
+            *value = count;
+        }
+
+        let values: Vec<u32> = state
+            .reconcile(vec!["c", "d", "a"])
+            .into_iter()
+            .map(|value| *value)
+            .collect();
+
+        assert_eq!(values, vec![3, 0, 1]);
+        assert_eq!(state.get(&"b"), None);
+        assert_eq!(state.len(), 3);
+    }
+}
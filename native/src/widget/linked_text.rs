@@ -0,0 +1,557 @@
+//! Write paragraphs of text with clickable links.
+use crate::rich_text::{self, Span};
+use crate::{
+    event, layout, mouse, Clipboard, Color, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
+};
+
+use std::cell::{Ref, RefCell};
+use std::hash::Hash;
+use std::ops::Range;
+
+/// A paragraph of text where web addresses are detected and can be clicked.
+///
+/// Every URL starting with `http://` or `https://` is displayed with a
+/// distinct color. Clicking it produces the message built by
+/// [`on_link`] with the URL.
+///
+/// The paragraph is wrapped by the [`LinkedText`] itself, so the bounds of
+/// every link are known when handling clicks. A word wider than the
+/// [`LinkedText`] is never broken, it overflows its line instead.
+///
+/// # Example
+///
+/// ```
+/// # type LinkedText<Message> =
+/// #     iced_native::LinkedText<Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     LinkClicked(String),
+/// }
+///
+/// let text = LinkedText::new("Learn more at https://github.com/hecrj/iced!")
+///     .on_link(Message::LinkClicked);
+/// ```
+///
+/// [`LinkedText`]: struct.LinkedText.html
+/// [`on_link`]: #method.on_link
+#[allow(missing_debug_implementations)]
+pub struct LinkedText<Message, Renderer: self::Renderer> {
+    content: String,
+    links: Vec<Range<usize>>,
+    size: Option<u16>,
+    color: Option<Color>,
+    link_color: Color,
+    font: Renderer::Font,
+    width: Length,
+    height: Length,
+    on_link: Option<Box<dyn Fn(String) -> Message>>,
+    cache: RefCell<Option<(f32, Paragraph<Renderer::Font>)>>,
+}
+
+impl<Message, Renderer: self::Renderer> LinkedText<Message, Renderer> {
+    /// Creates a new [`LinkedText`] with the given contents, detecting the
+    /// links in them.
+    ///
+    /// [`LinkedText`]: struct.LinkedText.html
+    pub fn new<T: Into<String>>(content: T) -> Self {
+        let content = content.into();
+        let links = find_links(&content);
+
+        LinkedText {
+            content,
+            links,
+            size: None,
+            color: None,
+            link_color: Color::from_rgb(0.2, 0.4, 0.8),
+            font: Default::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            on_link: None,
+            cache: RefCell::new(None),
+        }
+    }
+
+    /// Sets the size of the [`LinkedText`].
+    ///
+    /// [`LinkedText`]: struct.LinkedText.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Color`] of the [`LinkedText`], except for its links.
+    ///
+    /// [`LinkedText`]: struct.LinkedText.html
+    /// [`Color`]: ../../struct.Color.html
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the [`Color`] of the links of the [`LinkedText`].
+    ///
+    /// [`LinkedText`]: struct.LinkedText.html
+    /// [`Color`]: ../../struct.Color.html
+    pub fn link_color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.link_color = color.into();
+        self
+    }
+
+    /// Sets the font of the [`LinkedText`].
+    ///
+    /// [`LinkedText`]: struct.LinkedText.html
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the width of the [`LinkedText`] boundaries.
+    ///
+    /// [`LinkedText`]: struct.LinkedText.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`LinkedText`] boundaries.
+    ///
+    /// [`LinkedText`]: struct.LinkedText.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the message that should be produced when a link of the
+    /// [`LinkedText`] is clicked, given its URL.
+    ///
+    /// [`LinkedText`]: struct.LinkedText.html
+    pub fn on_link<F>(mut self, on_link: F) -> Self
+    where
+        F: 'static + Fn(String) -> Message,
+    {
+        self.on_link = Some(Box::new(on_link));
+        self
+    }
+
+    /// Returns the index of the link under the given cursor position, if
+    /// any.
+    fn hovered_link(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) -> Option<usize> {
+        if !bounds.contains(cursor_position) {
+            return None;
+        }
+
+        let position = Point::new(
+            cursor_position.x - bounds.x,
+            cursor_position.y - bounds.y,
+        );
+
+        self.wrapped(renderer, bounds.width)
+            .links
+            .iter()
+            .find(|(bounds, _)| bounds.contains(position))
+            .map(|(_, link)| *link)
+    }
+
+    /// Returns the contents of the [`LinkedText`] wrapped into lines that
+    /// fit the given width.
+    ///
+    /// The wrapped lines are kept until the width changes, so they are only
+    /// computed once for layout, events and drawing.
+    ///
+    /// [`LinkedText`]: struct.LinkedText.html
+    fn wrapped(
+        &self,
+        renderer: &Renderer,
+        width: f32,
+    ) -> Ref<'_, Paragraph<Renderer::Font>> {
+        let is_cached = match &*self.cache.borrow() {
+            Some((cached_width, _)) => *cached_width == width,
+            None => false,
+        };
+
+        if !is_cached {
+            *self.cache.borrow_mut() =
+                Some((width, self.paragraph(renderer, width)));
+        }
+
+        Ref::map(self.cache.borrow(), |cache| {
+            &cache.as_ref().expect("Wrapped paragraph").1
+        })
+    }
+
+    /// Wraps the contents of the [`LinkedText`] into lines that fit the
+    /// given width.
+    ///
+    /// [`LinkedText`]: struct.LinkedText.html
+    fn paragraph(
+        &self,
+        renderer: &Renderer,
+        max_width: f32,
+    ) -> Paragraph<Renderer::Font> {
+        let size = self.size.unwrap_or(renderer.default_size());
+        let measure = |range: &Range<usize>| {
+            renderer
+                .measure_text(&self.content[range.clone()], size, self.font)
+                .0
+        };
+        let line_height = renderer.measure_text(" ", size, self.font).1;
+
+        let mut paragraph = Paragraph {
+            spans: Vec::new(),
+            links: Vec::new(),
+            size: Size::new(0.0, line_height),
+        };
+        let mut plain = String::new();
+        let mut position = Point::ORIGIN;
+        let mut line_start = 0;
+
+        for line in self.content.split('\n') {
+            if line_start > 0 {
+                plain.push('\n');
+                position = Point::new(0.0, position.y + line_height);
+            }
+
+            let mut space: Option<Range<usize>> = None;
+            let mut start = 0;
+
+            while start < line.len() {
+                let is_space = line[start..].starts_with(char::is_whitespace);
+                let end = line[start..]
+                    .find(|c: char| c.is_whitespace() != is_space)
+                    .map_or(line.len(), |i| start + i);
+                let token = line_start + start..line_start + end;
+
+                start = end;
+
+                if is_space {
+                    space = Some(token);
+                    continue;
+                }
+
+                let pieces = self.pieces(token);
+                let width: f32 = pieces.iter().map(|(r, _)| measure(r)).sum();
+                let space_width = space.as_ref().map_or(0.0, measure);
+
+                if position.x > 0.0
+                    && position.x + space_width + width > max_width
+                {
+                    plain.push('\n');
+                    position = Point::new(0.0, position.y + line_height);
+                } else if let Some(space) = space {
+                    plain.push_str(&self.content[space]);
+                    position.x += space_width;
+                }
+
+                space = None;
+
+                for (range, link) in pieces {
+                    let width = measure(&range);
+
+                    match link {
+                        Some(link) => {
+                            self.flush(&mut plain, &mut paragraph.spans);
+
+                            paragraph.spans.push(
+                                Span::new(&self.content[range])
+                                    .color(self.link_color)
+                                    .font(self.font),
+                            );
+                            paragraph.links.push((
+                                Rectangle {
+                                    x: position.x,
+                                    y: position.y,
+                                    width,
+                                    height: line_height,
+                                },
+                                link,
+                            ));
+                        }
+                        None => plain.push_str(&self.content[range]),
+                    }
+
+                    position.x += width;
+                }
+
+                paragraph.size.width = paragraph.size.width.max(position.x);
+            }
+
+            line_start += line.len() + 1;
+        }
+
+        self.flush(&mut plain, &mut paragraph.spans);
+        paragraph.size.height = position.y + line_height;
+
+        paragraph
+    }
+
+    /// Splits the given word at the boundaries of the links it contains.
+    fn pieces(&self, word: Range<usize>) -> Vec<(Range<usize>, Option<usize>)> {
+        let mut pieces = Vec::new();
+        let mut start = word.start;
+
+        for (i, link) in self.links.iter().enumerate() {
+            if link.end <= word.start || link.start >= word.end {
+                continue;
+            }
+
+            if link.start > start {
+                pieces.push((start..link.start, None));
+            }
+
+            let end = link.end.min(word.end);
+            pieces.push((link.start.max(start)..end, Some(i)));
+            start = end;
+        }
+
+        if start < word.end {
+            pieces.push((start..word.end, None));
+        }
+
+        pieces
+    }
+
+    fn flush(&self, plain: &mut String, spans: &mut Vec<Span<Renderer::Font>>) {
+        if plain.is_empty() {
+            return;
+        }
+
+        let mut span = Span::new(std::mem::take(plain)).font(self.font);
+        span.color = self.color;
+
+        spans.push(span);
+    }
+}
+
+/// The contents of a [`LinkedText`] wrapped into lines.
+///
+/// [`LinkedText`]: struct.LinkedText.html
+struct Paragraph<Font> {
+    spans: Vec<Span<Font>>,
+    links: Vec<(Rectangle, usize)>,
+    size: Size,
+}
+
+/// Finds the byte ranges of the URLs in the given text.
+///
+/// A URL starts with `http://` or `https://` and runs until the next
+/// whitespace. Punctuation at its end is not included, so a URL can end a
+/// sentence or be wrapped in parentheses.
+pub fn find_links(text: &str) -> Vec<Range<usize>> {
+    const SCHEMES: [&str; 2] = ["https://", "http://"];
+    const TRAILING: &[char] =
+        &['.', ',', ':', ';', '!', '?', '\'', '"', ')', ']', '}', '>'];
+
+    let mut links = Vec::new();
+    let mut offset = 0;
+
+    for word in text.split_whitespace() {
+        let word_start = offset + text[offset..].find(word).unwrap_or(0);
+        offset = word_start + word.len();
+
+        let start = SCHEMES
+            .iter()
+            .filter_map(|scheme| {
+                word.find(scheme).map(|start| (start, scheme.len()))
+            })
+            .min();
+
+        if let Some((start, scheme_len)) = start {
+            let url = word[start..].trim_end_matches(TRAILING);
+
+            if url.len() > scheme_len {
+                let start = word_start + start;
+
+                links.push(start..start + url.len());
+            }
+        }
+    }
+
+    links
+}
+
+/// The renderer of a [`LinkedText`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`LinkedText`] in your user interface.
+///
+/// [`LinkedText`]: struct.LinkedText.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: rich_text::Renderer {
+    /// Draws a [`LinkedText`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`LinkedText`]
+    ///   * the [`Span`]s of its wrapped lines, separated by newlines
+    ///   * the default size of the [`Span`]s
+    ///   * whether a link is hovered by the mouse
+    ///
+    /// The lines are already wrapped, so they must not be wrapped again.
+    ///
+    /// [`LinkedText`]: struct.LinkedText.html
+    /// [`Span`]: ../rich_text/struct.Span.html
+    fn draw(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        spans: &[Span<Self::Font>],
+        size: u16,
+        is_link_hovered: bool,
+    ) -> Self::Output;
+}
+
+impl<Message, Renderer> Widget<Message, Renderer>
+    for LinkedText<Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let paragraph = self.paragraph(renderer, limits.max().width);
+        let size = limits.resolve(paragraph.size);
+
+        // The lines wrapped in the available width fit the resolved width
+        // too, so they are reused for events and drawing
+        *self.cache.borrow_mut() = Some((size.width, paragraph));
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        let on_link = match &self.on_link {
+            Some(on_link) => on_link,
+            None => return event::Status::Ignored,
+        };
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            let link =
+                self.hovered_link(renderer, layout.bounds(), cursor_position);
+
+            if let Some(link) = link {
+                let url = &self.content[self.links[link].clone()];
+
+                messages.push(on_link(url.to_string()));
+
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let size = self.size.unwrap_or(renderer.default_size());
+
+        let is_link_hovered = self.on_link.is_some()
+            && self
+                .hovered_link(renderer, bounds, cursor_position)
+                .is_some();
+
+        let paragraph = self.wrapped(renderer, bounds.width);
+
+        self::Renderer::draw(
+            renderer,
+            defaults,
+            bounds,
+            &paragraph.spans,
+            size,
+            is_link_hovered,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash(state);
+        self.size.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+impl<'a, Message, Renderer> From<LinkedText<Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        linked_text: LinkedText<Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(linked_text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::testing::Harness;
+
+    #[test]
+    fn links_do_not_include_trailing_punctuation() {
+        let text = "See https://iced.rs, (or http://example.com/a?b=c).";
+
+        let links: Vec<_> = find_links(text)
+            .into_iter()
+            .map(|link| &text[link])
+            .collect();
+
+        assert_eq!(links, vec!["https://iced.rs", "http://example.com/a?b=c"]);
+        assert!(find_links("https:// alone").is_empty());
+    }
+
+    #[test]
+    fn clicking_a_link_produces_its_url() {
+        let text: LinkedText<String, Null> =
+            LinkedText::new("Documentation:\nhttps://docs.rs/iced")
+                .on_link(|url| url);
+
+        let mut harness = Harness::new(text, Size::new(100.0, 100.0));
+
+        harness.click(Point::new(0.0, 10.0));
+        harness.click(Point::new(0.0, 30.0));
+
+        assert_eq!(
+            harness.into_messages(),
+            vec![String::from("https://docs.rs/iced")]
+        );
+    }
+}
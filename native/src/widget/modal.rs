@@ -0,0 +1,624 @@
+//! Display content on top of a dimmed backdrop, capturing all input.
+use std::hash::Hash;
+
+use crate::event;
+use crate::focus::{self, Focusable};
+use crate::keyboard;
+use crate::mouse;
+use crate::overlay;
+use crate::{
+    layout, Align, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+
+/// An element that can show some content centered in the viewport, on top of
+/// a backdrop covering everything else.
+///
+/// While shown, the content of the [`Modal`] captures all the input: nothing
+/// behind the backdrop receives any events. This traps the keyboard focus
+/// inside the content, where `Tab` and `Shift+Tab` cycle through its
+/// focusable widgets. Any widget focused behind the backdrop, like the one
+/// that opened the [`Modal`], keeps its focus and gets the input back once
+/// the [`Modal`] is dismissed, since dismissing it unfocuses the content.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{renderer::Null, Text};
+/// #
+/// # type Modal<'a, Message> = iced_native::Modal<'a, Message, Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     DialogDismissed,
+/// }
+///
+/// let modal = Modal::new(
+///     true,
+///     Text::new("The application"),
+///     Text::new("Are you sure?"),
+/// )
+/// .on_dismiss(Message::DialogDismissed);
+/// ```
+///
+/// [`Modal`]: struct.Modal.html
+#[allow(missing_debug_implementations)]
+pub struct Modal<'a, Message, Renderer: self::Renderer> {
+    is_shown: bool,
+    base: Element<'a, Message, Renderer>,
+    content: Element<'a, Message, Renderer>,
+    on_dismiss: Option<Message>,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer> Modal<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Modal`] that displays the given base element and, if
+    /// shown, the given content on top of it.
+    ///
+    /// [`Modal`]: struct.Modal.html
+    pub fn new<B, C>(is_shown: bool, base: B, content: C) -> Self
+    where
+        B: Into<Element<'a, Message, Renderer>>,
+        C: Into<Element<'a, Message, Renderer>>,
+    {
+        Modal {
+            is_shown,
+            base: base.into(),
+            content: content.into(),
+            on_dismiss: None,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the message that will be produced when the backdrop of the
+    /// [`Modal`] is clicked or `Escape` is pressed.
+    ///
+    /// Unless set, the [`Modal`] can only be closed by its content.
+    ///
+    /// [`Modal`]: struct.Modal.html
+    pub fn on_dismiss(mut self, message: Message) -> Self {
+        self.on_dismiss = Some(message);
+        self
+    }
+
+    /// Sets the style of the [`Modal`].
+    ///
+    /// [`Modal`]: struct.Modal.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Modal<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.base.width()
+    }
+
+    fn height(&self) -> Length {
+        self.base.height()
+    }
+
+    fn min_size(&self, renderer: &Renderer) -> Size {
+        self.base.min_size(renderer)
+    }
+
+    fn max_size(&self) -> Size {
+        self.base.max_size()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.base.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        // The content of the modal captures all the input while shown
+        if self.is_shown {
+            event::Status::Captured
+        } else {
+            self.base.widget.on_event(
+                event,
+                layout,
+                cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            )
+        }
+    }
+
+    fn layout_changed(
+        &mut self,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+    ) {
+        self.base.layout_changed(layout, messages)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let cursor_position = if self.is_shown {
+            // TODO: Encode cursor availability in the type system
+            Point::new(-1.0, -1.0)
+        } else {
+            cursor_position
+        };
+
+        self.base.draw(renderer, defaults, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.base.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if self.is_shown {
+            Some(overlay::Element::new(
+                Point::ORIGIN,
+                Box::new(Overlay {
+                    content: &mut self.content,
+                    on_dismiss: self.on_dismiss.clone(),
+                    style: &self.style,
+                }),
+            ))
+        } else {
+            self.base.overlay(layout)
+        }
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        if self.is_shown {
+            None
+        } else {
+            self.base.focused_bounds(layout)
+        }
+    }
+
+    fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        messages: &mut Vec<Message>,
+    ) {
+        if !self.is_shown {
+            self.base.focusables(visit, messages);
+        }
+    }
+}
+
+struct Overlay<'a, 'b, Message, Renderer: self::Renderer> {
+    content: &'b mut Element<'a, Message, Renderer>,
+    on_dismiss: Option<Message>,
+    style: &'b Renderer::Style,
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Overlay<'a, 'b, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        _position: Point,
+    ) -> layout::Node {
+        let mut content = self
+            .content
+            .layout(renderer, &layout::Limits::new(Size::ZERO, bounds));
+
+        content.align(Align::Center, Align::Center, bounds);
+
+        layout::Node::with_children(bounds, vec![content])
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            defaults,
+            layout.bounds(),
+            cursor_position,
+            self.style,
+            self.content,
+            layout.children().next().unwrap(),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher, _position: Point) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let content_layout = layout.children().next().unwrap();
+
+        // Tab cycles the focus inside the content, and never leaves it
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::Tab,
+            modifiers,
+            ..
+        }) = event
+        {
+            let content = &mut self.content;
+
+            focus::cycle(
+                |visit| content.focusables(visit, messages),
+                modifiers.shift,
+            );
+
+            return;
+        }
+
+        let is_dismissed = self.on_dismiss.is_some()
+            && match event {
+                Event::Mouse(mouse::Event::ButtonPressed(
+                    mouse::Button::Left,
+                )) => !content_layout.bounds().contains(cursor_position),
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code: keyboard::KeyCode::Escape,
+                    ..
+                }) => true,
+                _ => false,
+            };
+
+        if is_dismissed {
+            // The focus goes back to the widgets behind the backdrop
+            let content = &mut self.content;
+
+            focus::clear(|visit| content.focusables(visit, messages));
+
+            messages.extend(self.on_dismiss.clone());
+        } else {
+            let _ = self.content.widget.on_event(
+                event,
+                content_layout,
+                cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            );
+        }
+    }
+}
+
+/// The renderer of a [`Modal`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Modal`] in your user interface.
+///
+/// [`Modal`]: struct.Modal.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws the backdrop and the content of a [`Modal`].
+    ///
+    /// It receives:
+    /// - the bounds of the backdrop, covering the whole viewport
+    /// - the cursor position
+    /// - the style of the [`Modal`]
+    /// - the content of the [`Modal`] and its [`Layout`]
+    ///
+    /// [`Modal`]: struct.Modal.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        style: &Self::Style,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Modal<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a + Clone,
+{
+    fn from(
+        modal: Modal<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(modal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keyboard::KeyCode;
+    use crate::renderer::Null;
+    use crate::testing::Harness;
+    use crate::{button, text_input, Button, Column, Text, TextInput};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        BasePressed,
+        ContentPressed,
+        BaseTyped(String),
+        ContentTyped(usize, String),
+        Focused(usize),
+        Blurred(usize),
+        Dismissed,
+    }
+
+    #[test]
+    fn content_captures_input_and_backdrop_dismisses() {
+        let mut base = button::State::new();
+        let mut content = button::State::new();
+
+        let modal = Modal::new(
+            true,
+            Button::<_, Null>::new(&mut base, Text::new(""))
+                .width(Length::Units(200))
+                .height(Length::Units(200))
+                .on_press(Message::BasePressed),
+            Button::new(&mut content, Text::new(""))
+                .width(Length::Units(50))
+                .height(Length::Units(50))
+                .on_press(Message::ContentPressed),
+        )
+        .on_dismiss(Message::Dismissed);
+
+        let mut harness = Harness::new(modal, Size::new(200.0, 200.0));
+
+        // The content is centered in the viewport
+        harness.click(Point::new(100.0, 100.0));
+        harness.click(Point::new(10.0, 10.0));
+
+        assert_eq!(
+            harness.into_messages(),
+            vec![Message::ContentPressed, Message::Dismissed]
+        );
+    }
+
+    #[test]
+    fn escape_dismisses_and_keys_do_not_reach_base() {
+        let mut base = text_input::State::focused();
+        let mut content = button::State::new();
+
+        let modal = Modal::new(
+            true,
+            TextInput::<_, Null>::new(&mut base, "", "", Message::BaseTyped),
+            Button::new(&mut content, Text::new(""))
+                .on_press(Message::ContentPressed),
+        )
+        .on_dismiss(Message::Dismissed);
+
+        let mut harness = Harness::new(modal, Size::new(200.0, 200.0));
+        harness.type_text("a");
+        harness.press_key(KeyCode::Tab);
+        harness.press_key(KeyCode::Escape);
+
+        assert_eq!(harness.into_messages(), vec![Message::Dismissed]);
+        assert!(base.is_focused());
+    }
+
+    #[test]
+    fn tab_cycles_focus_inside_and_escape_restores_it() {
+        let mut base = text_input::State::focused();
+        let mut first = text_input::State::new();
+        let mut second = text_input::State::new();
+
+        {
+            let modal = Modal::new(
+                true,
+                TextInput::<_, Null>::new(
+                    &mut base,
+                    "",
+                    "",
+                    Message::BaseTyped,
+                ),
+                Column::new()
+                    .push(TextInput::new(&mut first, "", "", |value| {
+                        Message::ContentTyped(0, value)
+                    }))
+                    .push(TextInput::new(&mut second, "", "", |value| {
+                        Message::ContentTyped(1, value)
+                    })),
+            )
+            .on_dismiss(Message::Dismissed);
+
+            let mut harness = Harness::new(modal, Size::new(200.0, 200.0));
+
+            harness.press_key(KeyCode::Tab);
+            harness.type_text("a");
+            harness.press_key(KeyCode::Tab);
+            harness.press_key(KeyCode::Tab);
+            harness.type_text("b");
+
+            harness.event(Event::Keyboard(keyboard::Event::ModifiersChanged(
+                keyboard::ModifiersState {
+                    shift: true,
+                    ..keyboard::ModifiersState::default()
+                },
+            )));
+            harness.press_key(KeyCode::Tab);
+            harness.type_text("c");
+
+            harness.press_key(KeyCode::Escape);
+
+            assert_eq!(
+                harness.into_messages(),
+                vec![
+                    Message::ContentTyped(0, String::from("a")),
+                    Message::ContentTyped(0, String::from("ab")),
+                    Message::ContentTyped(1, String::from("c")),
+                    Message::Dismissed,
+                ]
+            );
+        }
+
+        assert!(base.is_focused());
+        assert!(!first.is_focused());
+        assert!(!second.is_focused());
+    }
+
+    #[test]
+    fn tab_cycles_focus_inside_mapped_content() {
+        let mut base = text_input::State::new();
+        let mut first = text_input::State::new();
+        let mut second = text_input::State::new();
+
+        let messages = {
+            let modal = Modal::new(
+                true,
+                TextInput::<_, Null>::new(
+                    &mut base,
+                    "",
+                    "",
+                    Message::BaseTyped,
+                ),
+                Column::new()
+                    .push(
+                        Element::from(TextInput::new(
+                            &mut first,
+                            "",
+                            "",
+                            |value| value,
+                        ))
+                        .map(|value| Message::ContentTyped(0, value)),
+                    )
+                    .push(
+                        Element::from(TextInput::new(
+                            &mut second,
+                            "",
+                            "",
+                            |value| value,
+                        ))
+                        .map(|value| Message::ContentTyped(1, value)),
+                    ),
+            );
+
+            let mut harness = Harness::new(modal, Size::new(200.0, 200.0));
+
+            harness.press_key(KeyCode::Tab);
+            harness.press_key(KeyCode::Tab);
+            harness.type_text("a");
+
+            harness.into_messages()
+        };
+
+        assert_eq!(messages, vec![Message::ContentTyped(1, String::from("a"))]);
+        assert!(!base.is_focused());
+        assert!(!first.is_focused());
+        assert!(second.is_focused());
+    }
+
+    #[test]
+    fn focus_changes_go_through_the_text_inputs() {
+        let mut base = text_input::State::new();
+        let mut first = text_input::State::new();
+        let mut second = text_input::State::new();
+
+        let modal = Modal::new(
+            true,
+            TextInput::<_, Null>::new(&mut base, "", "", Message::BaseTyped),
+            Column::new()
+                .push(
+                    TextInput::new(&mut first, "", "", |value| {
+                        Message::ContentTyped(0, value)
+                    })
+                    .on_focus(Message::Focused(0))
+                    .on_blur(Message::Blurred(0)),
+                )
+                .push(
+                    TextInput::new(&mut second, "", " b ", |value| {
+                        Message::ContentTyped(1, value)
+                    })
+                    .on_focus(Message::Focused(1))
+                    .on_blur(Message::Blurred(1))
+                    .normalize_on_blur(|value| value.trim().to_string()),
+                ),
+        )
+        .on_dismiss(Message::Dismissed);
+
+        let mut harness = Harness::new(modal, Size::new(200.0, 200.0));
+
+        harness.press_key(KeyCode::Tab);
+        harness.press_key(KeyCode::Tab);
+        harness.press_key(KeyCode::Escape);
+
+        assert_eq!(
+            harness.into_messages(),
+            vec![
+                Message::Focused(0),
+                Message::Blurred(0),
+                Message::Focused(1),
+                Message::ContentTyped(1, String::from("b")),
+                Message::Blurred(1),
+                Message::Dismissed,
+            ]
+        );
+    }
+
+    #[test]
+    fn hidden_modal_forwards_input_to_base() {
+        let mut base = button::State::new();
+        let mut content = button::State::new();
+
+        let modal = Modal::new(
+            false,
+            Button::<_, Null>::new(&mut base, Text::new(""))
+                .width(Length::Units(200))
+                .on_press(Message::BasePressed),
+            Button::new(&mut content, Text::new(""))
+                .on_press(Message::ContentPressed),
+        );
+
+        let mut harness = Harness::new(modal, Size::new(200.0, 200.0));
+        harness.click(Point::new(10.0, 10.0));
+
+        assert_eq!(harness.into_messages(), vec![Message::BasePressed]);
+    }
+}
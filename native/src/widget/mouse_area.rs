@@ -0,0 +1,361 @@
+//! Track the mouse over any element.
+//!
+//! A [`MouseArea`] has some local [`State`].
+//!
+//! [`MouseArea`]: struct.MouseArea.html
+//! [`State`]: struct.State.html
+use std::hash::Hash;
+
+use crate::focus::Focusable;
+use crate::{
+    event, layout, mouse, overlay, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
+};
+
+/// An element that tracks whether the mouse is hovering or pressing its
+/// content.
+///
+/// This gives hover and press effects to any element, even those that do
+/// not support them, like a [`Text`] or an [`Image`]. The [`State`] of a
+/// [`MouseArea`] can be queried while building the content, in order to style
+/// it:
+///
+/// ```
+/// # use iced_native::{mouse_area, renderer::Null, Text};
+/// #
+/// # type MouseArea<'a, Message> = iced_native::MouseArea<'a, Message, Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Entered,
+///     Exited,
+/// }
+///
+/// let mut state = mouse_area::State::new();
+///
+/// let size = if state.is_hovered() { 24 } else { 20 };
+///
+/// let label = MouseArea::new(&mut state, Text::new("Hover me").size(size))
+///     .on_enter(Message::Entered)
+///     .on_exit(Message::Exited);
+/// ```
+///
+/// [`Text`]: ../text/struct.Text.html
+/// [`Image`]: ../image/struct.Image.html
+/// [`MouseArea`]: struct.MouseArea.html
+/// [`State`]: struct.State.html
+#[allow(missing_debug_implementations)]
+pub struct MouseArea<'a, Message, Renderer> {
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+    on_enter: Option<Message>,
+    on_exit: Option<Message>,
+    on_press: Option<Message>,
+    on_release: Option<Message>,
+}
+
+impl<'a, Message, Renderer> MouseArea<'a, Message, Renderer> {
+    /// Creates a new [`MouseArea`] wrapping the given content.
+    ///
+    /// [`MouseArea`]: struct.MouseArea.html
+    pub fn new<E>(state: &'a mut State, content: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        MouseArea {
+            state,
+            content: content.into(),
+            on_enter: None,
+            on_exit: None,
+            on_press: None,
+            on_release: None,
+        }
+    }
+
+    /// Sets the message produced when the mouse enters the [`MouseArea`].
+    ///
+    /// [`MouseArea`]: struct.MouseArea.html
+    pub fn on_enter(mut self, message: Message) -> Self {
+        self.on_enter = Some(message);
+        self
+    }
+
+    /// Sets the message produced when the mouse leaves the [`MouseArea`].
+    ///
+    /// [`MouseArea`]: struct.MouseArea.html
+    pub fn on_exit(mut self, message: Message) -> Self {
+        self.on_exit = Some(message);
+        self
+    }
+
+    /// Sets the message produced when the left mouse button is pressed over
+    /// the [`MouseArea`].
+    ///
+    /// [`MouseArea`]: struct.MouseArea.html
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+
+    /// Sets the message produced when the left mouse button is released
+    /// after being pressed over the [`MouseArea`].
+    ///
+    /// The message is produced even if the mouse left the [`MouseArea`]
+    /// in the meantime.
+    ///
+    /// [`MouseArea`]: struct.MouseArea.html
+    pub fn on_release(mut self, message: Message) -> Self {
+        self.on_release = Some(message);
+        self
+    }
+}
+
+/// The local state of a [`MouseArea`].
+///
+/// [`MouseArea`]: struct.MouseArea.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    is_hovered: bool,
+    is_pressed: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the mouse is over the [`MouseArea`].
+    ///
+    /// [`MouseArea`]: struct.MouseArea.html
+    pub fn is_hovered(&self) -> bool {
+        self.is_hovered
+    }
+
+    /// Returns whether the left mouse button was pressed over the
+    /// [`MouseArea`] and is still held.
+    ///
+    /// [`MouseArea`]: struct.MouseArea.html
+    pub fn is_pressed(&self) -> bool {
+        self.is_pressed
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for MouseArea<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn min_size(&self, renderer: &Renderer) -> Size {
+        self.content.min_size(renderer)
+    }
+
+    fn max_size(&self) -> Size {
+        self.content.max_size()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self.content.layout(renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        let status = self.content.widget.on_event(
+            event.clone(),
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+
+        let is_over = layout.bounds().contains(cursor_position);
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+                if is_over != self.state.is_hovered =>
+            {
+                self.state.is_hovered = is_over;
+
+                let message = if is_over {
+                    self.on_enter.clone()
+                } else {
+                    self.on_exit.clone()
+                };
+
+                messages.extend(message);
+            }
+            Event::Mouse(mouse::Event::CursorLeft) if self.state.is_hovered => {
+                self.state.is_hovered = false;
+
+                messages.extend(self.on_exit.clone());
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if is_over =>
+            {
+                self.state.is_pressed = true;
+
+                if let Some(on_press) = self.on_press.clone() {
+                    messages.push(on_press);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if self.state.is_pressed =>
+            {
+                self.state.is_pressed = false;
+
+                messages.extend(self.on_release.clone());
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn layout_changed(
+        &mut self,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+    ) {
+        self.content
+            .layout_changed(layout.children().next().unwrap(), messages)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.content.draw(
+            renderer,
+            defaults,
+            layout.children().next().unwrap(),
+            cursor_position,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout.children().next().unwrap())
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.content
+            .focused_bounds(layout.children().next().unwrap())
+    }
+
+    fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        messages: &mut Vec<Message>,
+    ) {
+        self.content.focusables(visit, messages)
+    }
+}
+
+impl<'a, Message, Renderer> From<MouseArea<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a + Clone,
+{
+    fn from(
+        mouse_area: MouseArea<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(mouse_area)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::testing::Harness;
+    use crate::{Size, Text};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Entered,
+        Exited,
+        Pressed,
+        Released,
+    }
+
+    #[test]
+    fn hover_and_press_are_tracked_around_the_content() {
+        let mut state = State::new();
+
+        {
+            let mouse_area = MouseArea::<_, Null>::new(
+                &mut state,
+                Text::new("").width(Length::Units(50)),
+            )
+            .on_enter(Message::Entered)
+            .on_exit(Message::Exited)
+            .on_press(Message::Pressed)
+            .on_release(Message::Released);
+
+            let mut harness = Harness::new(mouse_area, Size::new(100.0, 100.0));
+
+            harness.move_cursor_to(Point::new(10.0, 10.0));
+            harness.move_cursor_to(Point::new(20.0, 10.0));
+            harness.event(Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Left,
+            )));
+            harness.move_cursor_to(Point::new(80.0, 10.0));
+            harness.event(Event::Mouse(mouse::Event::ButtonReleased(
+                mouse::Button::Left,
+            )));
+
+            assert_eq!(
+                harness.into_messages(),
+                vec![
+                    Message::Entered,
+                    Message::Pressed,
+                    Message::Exited,
+                    Message::Released,
+                ]
+            );
+        }
+
+        assert!(!state.is_hovered());
+        assert!(!state.is_pressed());
+    }
+}
@@ -0,0 +1,518 @@
+//! Display a dropdown list of values to select any number of.
+use crate::{
+    event, layout, mouse, overlay,
+    overlay::menu::{self, Menu},
+    pick_list::{self, MenuWidth},
+    scrollable, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Widget,
+};
+use std::borrow::Cow;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// A widget for selecting any number of values from a list of options.
+///
+/// Every option of the menu displays a checkbox. Clicking an option toggles
+/// whether it is selected and, by default, keeps the menu open.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{multi_pick_list, renderer::Null};
+/// #
+/// # type MultiPickList<'a, T, Message> =
+/// #     iced_native::MultiPickList<'a, T, Message, Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     ToppingsChanged(Vec<&'static str>),
+/// }
+///
+/// let mut state = multi_pick_list::State::new();
+///
+/// let multi_pick_list = MultiPickList::new(
+///     &mut state,
+///     &["Cheese", "Olives", "Mushrooms"][..],
+///     vec!["Cheese"],
+///     Message::ToppingsChanged,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MultiPickList<'a, T, Message, Renderer: pick_list::Renderer>
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    menu: &'a mut menu::State,
+    is_open: &'a mut bool,
+    hovered_option: &'a mut Option<usize>,
+    last_selection: &'a mut Option<usize>,
+    on_changed: Box<dyn Fn(Vec<T>) -> Message>,
+    format: Box<dyn Fn(&T) -> String + 'a>,
+    summary: Option<Box<Summary<'a, T>>>,
+    options: Cow<'a, [T]>,
+    selected: Vec<T>,
+    checked: Vec<bool>,
+    close_on_select: bool,
+    width: Length,
+    menu_width: MenuWidth,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer as pick_list::Renderer>::Style,
+}
+
+type Summary<'a, T> = dyn Fn(&[T]) -> String + 'a;
+
+/// The local state of a [`MultiPickList`].
+///
+/// [`MultiPickList`]: struct.MultiPickList.html
+#[derive(Debug, Clone)]
+pub struct State<T> {
+    menu: menu::State,
+    is_open: bool,
+    hovered_option: Option<usize>,
+    last_selection: Option<usize>,
+    options: PhantomData<T>,
+}
+
+impl<T> State<T> {
+    /// Creates a new [`State`] with the menu of the [`MultiPickList`] closed.
+    ///
+    /// [`State`]: struct.State.html
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Opens the menu of the [`MultiPickList`] with this [`State`].
+    ///
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    /// [`State`]: struct.State.html
+    pub fn open(&mut self) {
+        self.is_open = true;
+    }
+
+    /// Closes the menu of the [`MultiPickList`] with this [`State`].
+    ///
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    /// [`State`]: struct.State.html
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.hovered_option = None;
+    }
+
+    /// Returns whether the menu of the [`MultiPickList`] with this [`State`]
+    /// is open.
+    ///
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    /// [`State`]: struct.State.html
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+}
+
+impl<T> Default for State<T> {
+    fn default() -> Self {
+        Self {
+            menu: menu::State::default(),
+            is_open: false,
+            hovered_option: None,
+            last_selection: None,
+            options: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: 'a, Message, Renderer: pick_list::Renderer>
+    MultiPickList<'a, T, Message, Renderer>
+where
+    T: ToString + Eq,
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    /// Creates a new [`MultiPickList`] with the given [`State`], a list of
+    /// options, the currently selected values, and the message to produce
+    /// when the selection changes.
+    ///
+    /// The message receives the whole new selection: the current one with the
+    /// clicked option either added at the end or removed.
+    ///
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    /// [`State`]: struct.State.html
+    pub fn new(
+        state: &'a mut State<T>,
+        options: impl Into<Cow<'a, [T]>>,
+        selected: Vec<T>,
+        on_changed: impl Fn(Vec<T>) -> Message + 'static,
+    ) -> Self {
+        let State {
+            menu,
+            is_open,
+            hovered_option,
+            last_selection,
+            ..
+        } = state;
+
+        let options = options.into();
+        let checked = options
+            .iter()
+            .map(|option| selected.contains(option))
+            .collect();
+
+        Self {
+            menu,
+            is_open,
+            hovered_option,
+            last_selection,
+            on_changed: Box::new(on_changed),
+            format: Box::new(ToString::to_string),
+            summary: None,
+            options,
+            selected,
+            checked,
+            close_on_select: false,
+            width: Length::Shrink,
+            menu_width: MenuWidth::default(),
+            padding: Renderer::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the function used to format the options of the [`MultiPickList`]
+    /// in the menu.
+    ///
+    /// By default, options are displayed using their `ToString`
+    /// implementation.
+    ///
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    pub fn format(mut self, format: impl Fn(&T) -> String + 'a) -> Self {
+        self.format = Box::new(format);
+        self
+    }
+
+    /// Sets the function used to summarize the selected values of the
+    /// [`MultiPickList`].
+    ///
+    /// The summary is only displayed when at least one value is selected. By
+    /// default, a single value is displayed using the [`format`] function and
+    /// more values as a count (e.g. `3 selected`).
+    ///
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    /// [`format`]: #method.format
+    pub fn summary(mut self, summary: impl Fn(&[T]) -> String + 'a) -> Self {
+        self.summary = Some(Box::new(summary));
+        self
+    }
+
+    /// Sets whether the menu of the [`MultiPickList`] closes after an option
+    /// is toggled.
+    ///
+    /// By default, the menu stays open so multiple options can be toggled in
+    /// a row.
+    ///
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    pub fn close_on_select(mut self, close_on_select: bool) -> Self {
+        self.close_on_select = close_on_select;
+        self
+    }
+
+    /// Sets the width of the [`MultiPickList`].
+    ///
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the [`MenuWidth`] of the [`MultiPickList`].
+    ///
+    /// By default, the menu is as wide as the [`MultiPickList`].
+    ///
+    /// [`MenuWidth`]: ../pick_list/enum.MenuWidth.html
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    pub fn menu_width(mut self, menu_width: MenuWidth) -> Self {
+        self.menu_width = menu_width;
+        self
+    }
+
+    /// Sets the padding of the [`MultiPickList`].
+    ///
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`MultiPickList`].
+    ///
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the [`MultiPickList`].
+    ///
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`MultiPickList`].
+    ///
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as pick_list::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn open(&mut self) {
+        *self.is_open = true;
+        *self.hovered_option = self.checked.iter().position(|&c| c);
+    }
+
+    fn close(&mut self) {
+        *self.is_open = false;
+        *self.hovered_option = None;
+    }
+
+    fn summarize(&self) -> Option<String> {
+        match (&self.summary, self.selected.as_slice()) {
+            (_, []) => None,
+            (Some(summary), selected) => Some(summary(selected)),
+            (None, [value]) => Some((self.format)(value)),
+            (None, selected) => Some(format!("{} selected", selected.len())),
+        }
+    }
+}
+
+impl<'a, T: 'a, Message, Renderer> Widget<Message, Renderer>
+    for MultiPickList<'a, T, Message, Renderer>
+where
+    T: Clone + ToString + Eq,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: 'static,
+    Renderer: pick_list::Renderer + scrollable::Renderer + 'a,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        pick_list::layout_control(
+            renderer,
+            limits,
+            &self.options,
+            &self.format,
+            self.width,
+            self.padding,
+            self.text_size,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        pick_list::hash_control(state, &self.options, &self.format, self.width)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            if let Some(option) = self
+                .last_selection
+                .take()
+                .and_then(|index| self.options.get(index))
+            {
+                let mut selected = self.selected.clone();
+
+                match selected.iter().position(|value| value == option) {
+                    Some(index) => {
+                        let _ = selected.remove(index);
+                    }
+                    None => {
+                        selected.push(option.clone());
+                    }
+                }
+
+                messages.push((self.on_changed)(selected));
+
+                if self.close_on_select {
+                    self.close();
+                }
+
+                return event::Status::Captured;
+            } else if *self.is_open {
+                // TODO: Encode cursor availability in the type system
+                if cursor_position.x >= 0.0 && cursor_position.y >= 0.0 {
+                    self.close();
+                }
+            } else if layout.bounds().contains(cursor_position) {
+                self.open();
+
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        pick_list::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            cursor_position,
+            self.summarize(),
+            self.padding,
+            self.text_size.unwrap_or(renderer.default_size()),
+            self.font,
+            None,
+            &self.style,
+        )
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if *self.is_open {
+            let bounds = layout.bounds();
+
+            let menu = Menu::new(
+                self.menu,
+                &self.options,
+                self.hovered_option,
+                self.last_selection,
+            )
+            .format(&self.format)
+            .checked(&self.checked);
+
+            let menu = pick_list::configure_menu(
+                menu,
+                None,
+                bounds,
+                self.menu_width,
+                self.padding,
+                self.text_size,
+                self.font,
+                Renderer::menu_style(&self.style),
+            );
+
+            Some(menu.overlay(layout.position(), bounds.height))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T: 'a, Message, Renderer> From<MultiPickList<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: Clone + ToString + Eq,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: 'static,
+    Renderer: pick_list::Renderer + 'a,
+{
+    fn from(
+        multi_pick_list: MultiPickList<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(multi_pick_list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::testing::Harness;
+    use crate::Size;
+
+    const TOPPINGS: &[&str] = &["Cheese", "Olives", "Mushrooms"];
+
+    fn multi_pick_list<'a>(
+        state: &'a mut State<&'static str>,
+        selected: Vec<&'static str>,
+    ) -> MultiPickList<'a, &'static str, Vec<&'static str>, Null> {
+        MultiPickList::new(state, TOPPINGS, selected, |selected| selected)
+            .width(Length::Units(100))
+    }
+
+    #[test]
+    fn selecting_toggles_membership_and_keeps_menu_open() {
+        let mut state = State::new();
+        let mut harness = Harness::new(
+            multi_pick_list(&mut state, vec!["Cheese"]),
+            Size::new(200.0, 200.0),
+        );
+
+        // The control is 20 units tall and each option as well
+        harness.click(Point::new(5.0, 5.0));
+        harness.click(Point::new(5.0, 25.0));
+        harness.click(Point::new(5.0, 65.0));
+
+        assert_eq!(
+            harness.into_messages(),
+            vec![vec![], vec!["Cheese", "Mushrooms"]]
+        );
+        assert!(state.is_open());
+    }
+
+    #[test]
+    fn close_on_select_closes_menu() {
+        let mut state = State::new();
+        let mut harness = Harness::new(
+            multi_pick_list(&mut state, vec![]).close_on_select(true),
+            Size::new(200.0, 200.0),
+        );
+
+        harness.click(Point::new(5.0, 5.0));
+        harness.click(Point::new(5.0, 45.0));
+
+        assert_eq!(harness.into_messages(), vec![vec!["Olives"]]);
+        assert!(!state.is_open());
+    }
+
+    #[test]
+    fn summary_counts_selected_values() {
+        let mut state = State::new();
+
+        assert_eq!(multi_pick_list(&mut state, vec![]).summarize(), None);
+        assert_eq!(
+            multi_pick_list(&mut state, vec!["Olives"]).summarize(),
+            Some(String::from("Olives"))
+        );
+        assert_eq!(
+            multi_pick_list(&mut state, vec!["Cheese", "Olives"]).summarize(),
+            Some(String::from("2 selected"))
+        );
+    }
+}
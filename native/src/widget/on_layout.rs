@@ -0,0 +1,263 @@
+//! Observe the bounds of any element.
+//!
+//! An [`OnLayout`] has some local [`State`].
+//!
+//! [`OnLayout`]: struct.OnLayout.html
+//! [`State`]: struct.State.html
+use std::hash::Hash;
+
+use crate::focus::Focusable;
+use crate::{
+    event, layout, overlay, Clipboard, Element, Event, Hasher, Layout, Length,
+    Point, Rectangle, Size, Widget,
+};
+
+/// An element that produces a message with the bounds of its content
+/// whenever they change.
+///
+/// This lets an application observe where its content ends up on the screen,
+/// like to place a custom popup next to it. A message is only produced when
+/// the bounds differ from the ones reported last time.
+///
+/// The bounds are checked whenever the layout of the user interface changes,
+/// so an [`OnLayout`] must be placed in widgets that forward
+/// [`Widget::layout_changed`] to their children, like a [`Column`] or a
+/// [`Container`]. The bounds are in layout coordinates: inside a
+/// [`Scrollable`], they do not account for its scroll offset.
+///
+/// ```
+/// # use iced_native::{on_layout, renderer::Null, Rectangle, Text};
+/// #
+/// # type OnLayout<'a, Message> = iced_native::OnLayout<'a, Message, Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     TitleLaidOut(Rectangle),
+/// }
+///
+/// let mut state = on_layout::State::new();
+///
+/// let title =
+///     OnLayout::new(&mut state, Text::new("Title"), Message::TitleLaidOut);
+/// ```
+///
+/// [`OnLayout`]: struct.OnLayout.html
+/// [`Widget::layout_changed`]: ../trait.Widget.html#method.layout_changed
+/// [`Column`]: ../struct.Column.html
+/// [`Container`]: ../struct.Container.html
+/// [`Scrollable`]: ../struct.Scrollable.html
+#[allow(missing_debug_implementations)]
+pub struct OnLayout<'a, Message, Renderer> {
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+    on_layout: Box<dyn Fn(Rectangle) -> Message + 'a>,
+}
+
+impl<'a, Message, Renderer> OnLayout<'a, Message, Renderer> {
+    /// Creates a new [`OnLayout`] wrapping the given content, with a function
+    /// that produces a message from its new bounds.
+    ///
+    /// [`OnLayout`]: struct.OnLayout.html
+    pub fn new<E, F>(state: &'a mut State, content: E, on_layout: F) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+        F: 'a + Fn(Rectangle) -> Message,
+    {
+        OnLayout {
+            state,
+            content: content.into(),
+            on_layout: Box::new(on_layout),
+        }
+    }
+}
+
+/// The local state of an [`OnLayout`].
+///
+/// [`OnLayout`]: struct.OnLayout.html
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct State {
+    bounds: Option<Rectangle>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns the bounds of the [`OnLayout`] reported last time, if any.
+    ///
+    /// [`OnLayout`]: struct.OnLayout.html
+    pub fn bounds(&self) -> Option<Rectangle> {
+        self.bounds
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for OnLayout<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn min_size(&self, renderer: &Renderer) -> Size {
+        self.content.min_size(renderer)
+    }
+
+    fn max_size(&self) -> Size {
+        self.content.max_size()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self.content.layout(renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        self.content.widget.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn layout_changed(
+        &mut self,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+    ) {
+        let bounds = layout.bounds();
+
+        if self.state.bounds != Some(bounds) {
+            self.state.bounds = Some(bounds);
+
+            messages.push((self.on_layout)(bounds));
+        }
+
+        self.content
+            .layout_changed(layout.children().next().unwrap(), messages)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        // The new bounds are reported on the next update, which the shell
+        // only runs right away while a redraw is requested
+        if self.state.bounds != Some(layout.bounds()) {
+            renderer.request_redraw();
+        }
+
+        self.content.draw(
+            renderer,
+            defaults,
+            layout.children().next().unwrap(),
+            cursor_position,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout.children().next().unwrap())
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.content
+            .focused_bounds(layout.children().next().unwrap())
+    }
+
+    fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        messages: &mut Vec<Message>,
+    ) {
+        self.content.focusables(visit, messages)
+    }
+}
+
+impl<'a, Message, Renderer> From<OnLayout<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        on_layout: OnLayout<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(on_layout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::testing::Harness;
+    use crate::{Column, Text};
+
+    #[test]
+    fn bounds_are_reported_only_when_they_change() {
+        let mut state = State::new();
+
+        let messages = {
+            let content = Column::<_, Null>::new()
+                .push(Text::new("").height(Length::Units(20)))
+                .push(OnLayout::new(
+                    &mut state,
+                    Text::new("")
+                        .width(Length::Units(30))
+                        .height(Length::Units(10)),
+                    |bounds| bounds,
+                ));
+
+            let mut harness = Harness::new(content, Size::new(100.0, 100.0));
+            harness.layout_changed();
+            harness.move_cursor_to(Point::new(10.0, 10.0));
+            harness.layout_changed();
+
+            harness.into_messages()
+        };
+
+        let bounds =
+            Rectangle::new(Point::new(0.0, 20.0), Size::new(30.0, 10.0));
+
+        assert_eq!(messages, vec![bounds]);
+        assert_eq!(state.bounds(), Some(bounds));
+    }
+}
@@ -29,8 +29,9 @@ pub use state::{Focus, State};
 pub use title_bar::TitleBar;
 
 use crate::{
-    container, keyboard, layout, mouse, overlay, row, text, Clipboard, Element,
-    Event, Hasher, Layout, Length, Point, Rectangle, Size, Vector, Widget,
+    container, event, keyboard, layout, mouse, overlay, row, text, Clipboard,
+    Element, Event, Hasher, Layout, Length, Point, Rectangle, Size, Vector,
+    Widget,
 };
 
 /// A collection of panes distributed using either vertical or horizontal splits
@@ -452,7 +453,7 @@ where
         messages: &mut Vec<Message>,
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>,
-    ) {
+    ) -> event::Status {
         match event {
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::ButtonPressed(mouse::Button::Left) => {
@@ -541,6 +542,7 @@ where
                     keyboard::Event::KeyPressed {
                         modifiers,
                         key_code,
+                        ..
                     } => {
                         if let Some(on_key_press) = &self.on_key_press {
                             // TODO: Discard when event is captured
@@ -565,20 +567,22 @@ where
         }
 
         if self.state.picked_pane().is_none() {
-            {
-                self.elements.iter_mut().zip(layout.children()).for_each(
-                    |((_, pane), layout)| {
-                        pane.on_event(
-                            event.clone(),
-                            layout,
-                            cursor_position,
-                            messages,
-                            renderer,
-                            clipboard,
-                        )
-                    },
-                );
-            }
+            self.elements
+                .iter_mut()
+                .zip(layout.children())
+                .map(|((_, pane), layout)| {
+                    pane.on_event(
+                        event.clone(),
+                        layout,
+                        cursor_position,
+                        messages,
+                        renderer,
+                        clipboard,
+                    )
+                })
+                .fold(event::Status::Ignored, event::Status::merge)
+        } else {
+            event::Status::Ignored
         }
     }
 
@@ -1,4 +1,5 @@
 use crate::container;
+use crate::event;
 use crate::layout;
 use crate::overlay;
 use crate::pane_grid::{self, TitleBar};
@@ -154,11 +155,13 @@ where
         messages: &mut Vec<Message>,
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>,
-    ) {
+    ) -> event::Status {
+        let mut status = event::Status::Ignored;
+
         let body_layout = if let Some(title_bar) = &mut self.title_bar {
             let mut children = layout.children();
 
-            title_bar.on_event(
+            status = title_bar.on_event(
                 event.clone(),
                 children.next().unwrap(),
                 cursor_position,
@@ -172,7 +175,7 @@ where
             layout
         };
 
-        self.body.on_event(
+        let body_status = self.body.on_event(
             event,
             body_layout,
             cursor_position,
@@ -180,6 +183,8 @@ where
             renderer,
             clipboard,
         );
+
+        status.merge(body_status)
     }
 
     pub(crate) fn hash_layout(&self, state: &mut Hasher) {
@@ -1,3 +1,4 @@
+use crate::event;
 use crate::layout;
 use crate::pane_grid;
 use crate::{
@@ -245,7 +246,7 @@ where
         messages: &mut Vec<Message>,
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>,
-    ) {
+    ) -> event::Status {
         if let Some(controls) = &mut self.controls {
             let mut children = layout.children();
             let padded = children.next().unwrap();
@@ -261,7 +262,9 @@ where
                 messages,
                 renderer,
                 clipboard,
-            );
+            )
+        } else {
+            event::Status::Ignored
         }
     }
 }
@@ -1,41 +1,163 @@
 //! Display a dropdown list of selectable values.
 use crate::{
-    layout, mouse, overlay,
+    event, layout, mouse, overlay,
     overlay::menu::{self, Menu},
     scrollable, text, Clipboard, Element, Event, Hasher, Layout, Length, Point,
     Rectangle, Size, Widget,
 };
 use std::borrow::Cow;
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::Hash;
+use std::marker::PhantomData;
 
 /// A widget for selecting a single value from a list of options.
 #[allow(missing_debug_implementations)]
-pub struct PickList<'a, T, Message, Renderer: self::Renderer>
-where
-    [T]: ToOwned<Owned = Vec<T>>,
-{
+pub struct PickList<'a, T, Message, Renderer: self::Renderer> {
     menu: &'a mut menu::State,
     is_open: &'a mut bool,
     hovered_option: &'a mut Option<usize>,
-    last_selection: &'a mut Option<T>,
-    on_selected: Box<dyn Fn(T) -> Message>,
-    options: Cow<'a, [T]>,
+    last_selection: &'a mut Option<usize>,
+    group_token: &'a mut Option<usize>,
+    group: Option<&'a Group>,
+    on_selected: OnSelected<'a, T, Message>,
+    format: Box<dyn Fn(&T) -> String + 'a>,
+    options: Options<'a, T>,
+    order: Option<Vec<usize>>,
+    labels: Vec<Label>,
+    leading: Option<Box<dyn Fn(&T) -> String + 'a>>,
+    marks_selected: bool,
     selected: Option<T>,
+    selected_index: Option<usize>,
+    close_on_select: bool,
     width: Length,
+    menu_width: MenuWidth,
     padding: u16,
     text_size: Option<u16>,
     font: Renderer::Font,
+    icon: Option<Icon<Renderer::Font>>,
     style: <Renderer as self::Renderer>::Style,
 }
 
+/// The glyph of an icon font displayed as the dropdown arrow of a
+/// [`PickList`].
+///
+/// [`PickList`]: struct.PickList.html
+#[derive(Debug, Clone, Copy)]
+pub struct Icon<Font> {
+    /// The font containing the glyph.
+    pub font: Font,
+
+    /// The glyph displayed at the right of the [`PickList`].
+    ///
+    /// [`PickList`]: struct.PickList.html
+    pub code_point: char,
+}
+
+/// The options of a [`PickList`], either borrowed or owned.
+///
+/// Unlike a `Cow`, owning the options does not need them to be cloneable.
+///
+/// [`PickList`]: struct.PickList.html
+enum Options<'a, T> {
+    Borrowed(&'a [T]),
+    Owned(Vec<T>),
+}
+
+impl<'a, T> From<Cow<'a, [T]>> for Options<'a, T>
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    fn from(options: Cow<'a, [T]>) -> Self {
+        match options {
+            Cow::Borrowed(options) => Options::Borrowed(options),
+            Cow::Owned(options) => Options::Owned(options),
+        }
+    }
+}
+
+impl<'a, T> std::ops::Deref for Options<'a, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        match self {
+            Options::Borrowed(options) => options,
+            Options::Owned(options) => options,
+        }
+    }
+}
+
+/// Produces a message from an option of a [`PickList`] and its index.
+///
+/// [`PickList`]: struct.PickList.html
+type OnSelected<'a, T, Message> = Box<dyn Fn(usize, &T) -> Message + 'a>;
+
+/// The width of the menu of a [`PickList`].
+///
+/// [`PickList`]: struct.PickList.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MenuWidth {
+    /// The menu is as wide as the [`PickList`].
+    ///
+    /// [`PickList`]: struct.PickList.html
+    MatchControl,
+
+    /// The menu has a fixed width in logical pixels.
+    Fixed(u16),
+
+    /// The menu is as wide as its widest option.
+    FitContent,
+}
+
+impl Default for MenuWidth {
+    fn default() -> Self {
+        MenuWidth::MatchControl
+    }
+}
+
 /// The local state of a [`PickList`].
 ///
+/// The state only keeps track of options by their index, so it never holds
+/// a copy of any of them.
+///
 /// [`PickList`]: struct.PickList.html
 #[derive(Debug, Clone)]
 pub struct State<T> {
     menu: menu::State,
     is_open: bool,
     hovered_option: Option<usize>,
-    last_selection: Option<T>,
+    last_selection: Option<usize>,
+    group_token: Option<usize>,
+    options: PhantomData<T>,
+}
+
+impl<T> State<T> {
+    /// Opens the menu of the [`PickList`] with this [`State`].
+    ///
+    /// [`PickList`]: struct.PickList.html
+    /// [`State`]: struct.State.html
+    pub fn open(&mut self) {
+        self.is_open = true;
+    }
+
+    /// Closes the menu of the [`PickList`] with this [`State`].
+    ///
+    /// [`PickList`]: struct.PickList.html
+    /// [`State`]: struct.State.html
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.hovered_option = None;
+    }
+
+    /// Returns whether the menu of the [`PickList`] with this [`State`] is
+    /// open.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    /// [`State`]: struct.State.html
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
 }
 
 impl<T> Default for State<T> {
@@ -45,14 +167,63 @@ impl<T> Default for State<T> {
             is_open: bool::default(),
             hovered_option: Option::default(),
             last_selection: Option::default(),
+            group_token: Option::default(),
+            options: PhantomData,
         }
     }
 }
 
-impl<'a, T: 'a, Message, Renderer: self::Renderer>
+/// The shared state of a group of [`PickList`] widgets that behave like a
+/// menu bar.
+///
+/// At most one [`PickList`] of a [`Group`] is open at a time. While any of
+/// them is open, hovering another one opens it and closes the rest.
+///
+/// [`PickList`]: struct.PickList.html
+/// [`Group`]: struct.Group.html
+#[derive(Debug, Default)]
+pub struct Group {
+    open: Cell<Option<usize>>,
+    next_token: Cell<usize>,
+}
+
+impl Group {
+    /// Creates a new [`Group`] with all of its [`PickList`] widgets closed.
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`PickList`]: struct.PickList.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether any [`PickList`] of the [`Group`] is open.
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`PickList`]: struct.PickList.html
+    pub fn is_open(&self) -> bool {
+        self.open.get().is_some()
+    }
+
+    fn open(&self) -> usize {
+        let token = self.next_token.get();
+
+        self.next_token.set(token.wrapping_add(1));
+        self.open.set(Some(token));
+
+        token
+    }
+
+    fn close(&self, token: Option<usize>) {
+        if token.is_some() && self.open.get() == token {
+            self.open.set(None);
+        }
+    }
+}
+
+impl<'a, T: 'a, Message: 'a, Renderer: self::Renderer>
     PickList<'a, T, Message, Renderer>
 where
-    T: ToString + Eq,
+    T: Clone + ToString + Eq,
     [T]: ToOwned<Owned = Vec<T>>,
 {
     /// Creates a new [`PickList`] with the given [`State`], a list of options,
@@ -66,12 +237,98 @@ where
         options: impl Into<Cow<'a, [T]>>,
         selected: Option<T>,
         on_selected: impl Fn(T) -> Message + 'static,
+    ) -> Self {
+        let options = options.into();
+        let selected_index = selected.as_ref().and_then(|selected| {
+            options.iter().position(|option| option == selected)
+        });
+
+        let mut pick_list = Self::with_selection(
+            state,
+            options.into(),
+            selected_index,
+            move |_, option: &T| on_selected(option.clone()),
+        );
+
+        pick_list.selected = selected;
+        pick_list
+    }
+
+    /// Creates a new [`PickList`] like [`new`], but producing the message
+    /// with both the previously selected value and the newly selected one.
+    ///
+    /// This is useful to implement undo or to diff selections without
+    /// keeping track of the previous value separately.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    /// [`new`]: #method.new
+    pub fn new_with_previous(
+        state: &'a mut State<T>,
+        options: impl Into<Cow<'a, [T]>>,
+        selected: Option<T>,
+        on_selected: impl Fn(Option<T>, T) -> Message + 'static,
+    ) -> Self {
+        let options = options.into();
+        let selected_index = selected.as_ref().and_then(|selected| {
+            options.iter().position(|option| option == selected)
+        });
+        let previous = selected.clone();
+
+        let mut pick_list = Self::with_selection(
+            state,
+            options.into(),
+            selected_index,
+            move |_, option: &T| on_selected(previous.clone(), option.clone()),
+        );
+
+        pick_list.selected = selected;
+        pick_list
+    }
+}
+
+impl<'a, T: 'a, Message, Renderer: self::Renderer>
+    PickList<'a, T, Message, Renderer>
+where
+    T: ToString,
+{
+    /// Creates a new [`PickList`] with the given [`State`], a list of options,
+    /// the index of the current selected option, and the message to produce
+    /// with the index of an option when it is selected.
+    ///
+    /// Unlike [`new`], options are only referred to by their index, so they
+    /// never need to be compared nor cloned. This is useful when your data
+    /// model refers to options by their index.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    /// [`State`]: struct.State.html
+    /// [`new`]: #method.new
+    pub fn new_indexed(
+        state: &'a mut State<T>,
+        options: &'a [T],
+        selected: Option<usize>,
+        on_selected: impl Fn(usize) -> Message + 'static,
+    ) -> Self {
+        Self::with_selection(
+            state,
+            Options::Borrowed(options),
+            selected,
+            move |index, _| on_selected(index),
+        )
+    }
+
+    fn with_selection(
+        state: &'a mut State<T>,
+        options: Options<'a, T>,
+        selected_index: Option<usize>,
+        on_selected: impl Fn(usize, &T) -> Message + 'a,
     ) -> Self {
         let State {
             menu,
             is_open,
             hovered_option,
             last_selection,
+            group_token,
+            ..
         } = state;
 
         Self {
@@ -79,17 +336,104 @@ where
             is_open,
             hovered_option,
             last_selection,
+            group_token,
+            group: None,
             on_selected: Box::new(on_selected),
-            options: options.into(),
-            selected,
+            format: Box::new(ToString::to_string),
+            options,
+            order: None,
+            labels: Vec::new(),
+            leading: None,
+            marks_selected: false,
+            selected: None,
+            selected_index,
+            close_on_select: true,
             width: Length::Shrink,
+            menu_width: MenuWidth::default(),
             text_size: None,
             padding: Renderer::DEFAULT_PADDING,
             font: Default::default(),
+            icon: None,
             style: Default::default(),
         }
     }
 
+    /// Sets the function used to format the options of the [`PickList`].
+    ///
+    /// The resulting labels are displayed for the selected value and in the
+    /// menu. By default, options are displayed using their `ToString`
+    /// implementation.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    pub fn format(mut self, format: impl Fn(&T) -> String + 'a) -> Self {
+        self.format = Box::new(format);
+        self
+    }
+
+    /// Makes the [`PickList`] part of the given [`Group`], enabling
+    /// menu bar behavior.
+    ///
+    /// While any [`PickList`] of the [`Group`] is open, hovering this one
+    /// opens it and closes the others. Ordinary dropdowns should not use a
+    /// [`Group`].
+    ///
+    /// [`PickList`]: struct.PickList.html
+    /// [`Group`]: struct.Group.html
+    pub fn group(mut self, group: &'a Group) -> Self {
+        self.group = Some(group);
+        self
+    }
+
+    /// Sorts the options displayed in the menu of the [`PickList`] with the
+    /// given comparison function.
+    ///
+    /// The list of options itself is not reordered, so selecting an option
+    /// still produces the message of the right option. The sort is stable,
+    /// keeping the original order of equal options.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    pub fn sort_by(mut self, compare: impl Fn(&T, &T) -> Ordering) -> Self {
+        let options = &self.options;
+        let mut order: Vec<usize> = (0..options.len()).collect();
+
+        order.sort_by(|a, b| compare(&options[*a], &options[*b]));
+
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the function producing the text displayed before the label of
+    /// each option in the menu of the [`PickList`], like an icon.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    pub fn leading(mut self, leading: impl Fn(&T) -> String + 'a) -> Self {
+        self.leading = Some(Box::new(leading));
+        self
+    }
+
+    /// Sets whether the menu of the [`PickList`] displays a checkmark after
+    /// the label of the selected option.
+    ///
+    /// This is disabled by default.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    pub fn mark_selected(mut self, mark_selected: bool) -> Self {
+        self.marks_selected = mark_selected;
+        self
+    }
+
+    /// Sets whether the menu of the [`PickList`] closes after an option is
+    /// selected.
+    ///
+    /// By default, the menu closes. Keeping it open is useful for pinned
+    /// menus or pickers where the selection is refined in multiple steps.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    pub fn close_on_select(mut self, close_on_select: bool) -> Self {
+        self.close_on_select = close_on_select;
+        self
+    }
+
     /// Sets the width of the [`PickList`].
     ///
     /// [`PickList`]: struct.PickList.html
@@ -98,6 +442,17 @@ where
         self
     }
 
+    /// Sets the [`MenuWidth`] of the [`PickList`].
+    ///
+    /// By default, the menu is as wide as the [`PickList`].
+    ///
+    /// [`MenuWidth`]: enum.MenuWidth.html
+    /// [`PickList`]: struct.PickList.html
+    pub fn menu_width(mut self, menu_width: MenuWidth) -> Self {
+        self.menu_width = menu_width;
+        self
+    }
+
     /// Sets the padding of the [`PickList`].
     ///
     /// [`PickList`]: struct.PickList.html
@@ -122,6 +477,18 @@ where
         self
     }
 
+    /// Sets the [`Icon`] displayed as the dropdown arrow of the
+    /// [`PickList`].
+    ///
+    /// By default, an arrow pointing down is displayed.
+    ///
+    /// [`Icon`]: struct.Icon.html
+    /// [`PickList`]: struct.PickList.html
+    pub fn icon(mut self, icon: Icon<Renderer::Font>) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
     /// Sets the style of the [`PickList`].
     ///
     /// [`PickList`]: struct.PickList.html
@@ -134,11 +501,63 @@ where
     }
 }
 
+impl<'a, T: 'a, Message, Renderer: self::Renderer>
+    PickList<'a, T, Message, Renderer>
+{
+    /// Returns the position of the selected option in the menu, if any.
+    fn selected_position(&self) -> Option<usize> {
+        match &self.order {
+            Some(order) => self.selected_index.and_then(|selected| {
+                order.iter().position(|&index| index == selected)
+            }),
+            None => self.selected_index,
+        }
+    }
+
+    fn open(&mut self) {
+        *self.is_open = true;
+        *self.hovered_option = self.selected_position();
+        self.menu.reveal_hovered();
+
+        if let Some(group) = self.group {
+            *self.group_token = Some(group.open());
+        }
+    }
+
+    fn close(&mut self) {
+        *self.is_open = false;
+
+        if let Some(group) = self.group {
+            group.close(self.group_token.take());
+        }
+    }
+
+    fn sync_group(&mut self) {
+        if let Some(group) = self.group {
+            match (*self.is_open, *self.group_token) {
+                // Opened through `State::open`
+                (true, None) => {
+                    *self.group_token = Some(group.open());
+                }
+                // Superseded by another pick list of the group
+                (true, token) if group.open.get() != token => {
+                    *self.is_open = false;
+                    *self.group_token = None;
+                }
+                // Closed through `State::close`
+                (false, Some(_)) => {
+                    group.close(self.group_token.take());
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
 impl<'a, T: 'a, Message, Renderer> Widget<Message, Renderer>
     for PickList<'a, T, Message, Renderer>
 where
-    T: Clone + ToString + Eq,
-    [T]: ToOwned<Owned = Vec<T>>,
+    T: ToString,
     Message: 'static,
     Renderer: self::Renderer + scrollable::Renderer + 'a,
 {
@@ -155,64 +574,19 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        use std::f32;
-
-        let limits = limits
-            .width(self.width)
-            .height(Length::Shrink)
-            .pad(f32::from(self.padding));
-
-        let text_size = self.text_size.unwrap_or(renderer.default_size());
-
-        let max_width = match self.width {
-            Length::Shrink => {
-                let labels = self.options.iter().map(ToString::to_string);
-
-                labels
-                    .map(|label| {
-                        let (width, _) = renderer.measure(
-                            &label,
-                            text_size,
-                            Renderer::Font::default(),
-                            Size::new(f32::INFINITY, f32::INFINITY),
-                        );
-
-                        width.round() as u32
-                    })
-                    .max()
-                    .unwrap_or(100)
-            }
-            _ => 0,
-        };
-
-        let size = {
-            let intrinsic = Size::new(
-                max_width as f32
-                    + f32::from(text_size)
-                    + f32::from(self.padding),
-                f32::from(text_size),
-            );
-
-            limits.resolve(intrinsic).pad(f32::from(self.padding))
-        };
-
-        layout::Node::new(size)
+        layout_control(
+            renderer,
+            limits,
+            &self.options,
+            &self.format,
+            self.width,
+            self.padding,
+            self.text_size,
+        )
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
-        use std::hash::Hash as _;
-
-        match self.width {
-            Length::Shrink => {
-                self.options
-                    .iter()
-                    .map(ToString::to_string)
-                    .for_each(|label| label.hash(state));
-            }
-            _ => {
-                self.width.hash(state);
-            }
-        }
+        hash_control(state, &self.options, &self.format, self.width)
     }
 
     fn on_event(
@@ -223,31 +597,55 @@ where
         messages: &mut Vec<Message>,
         _renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
-    ) {
+    ) -> event::Status {
+        self.sync_group();
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
-                if *self.is_open {
+                if let Some(position) = self.last_selection.take() {
+                    // The menu refers to options by their displayed position
+                    let index = match &self.order {
+                        Some(order) => order.get(position).copied(),
+                        None => Some(position),
+                    };
+
+                    if let Some((index, option)) = index.and_then(|index| {
+                        self.options.get(index).map(|option| (index, option))
+                    }) {
+                        messages.push((self.on_selected)(index, option));
+                    }
+
+                    if self.close_on_select {
+                        self.close();
+                    }
+
+                    return event::Status::Captured;
+                } else if *self.is_open {
                     // TODO: Encode cursor availability in the type system
-                    *self.is_open =
-                        cursor_position.x < 0.0 || cursor_position.y < 0.0;
+                    if cursor_position.x >= 0.0 && cursor_position.y >= 0.0 {
+                        self.close();
+                    }
                 } else if layout.bounds().contains(cursor_position) {
-                    let selected = self.selected.as_ref();
+                    self.open();
 
-                    *self.is_open = true;
-                    *self.hovered_option = self
-                        .options
-                        .iter()
-                        .position(|option| Some(option) == selected);
+                    return event::Status::Captured;
                 }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let group_is_open =
+                    matches!(self.group, Some(group) if group.is_open());
 
-                if let Some(last_selection) = self.last_selection.take() {
-                    messages.push((self.on_selected)(last_selection));
-
-                    *self.is_open = false;
+                if group_is_open
+                    && !*self.is_open
+                    && layout.bounds().contains(cursor_position)
+                {
+                    self.open();
                 }
             }
             _ => {}
         }
+
+        event::Status::Ignored
     }
 
     fn draw(
@@ -261,10 +659,16 @@ where
             renderer,
             layout.bounds(),
             cursor_position,
-            self.selected.as_ref().map(ToString::to_string),
+            self.selected
+                .as_ref()
+                .or_else(|| {
+                    self.selected_index.and_then(|i| self.options.get(i))
+                })
+                .map(&self.format),
             self.padding,
             self.text_size.unwrap_or(renderer.default_size()),
             self.font,
+            self.icon.as_ref(),
             &self.style,
         )
     }
@@ -273,31 +677,228 @@ where
         &mut self,
         layout: Layout<'_>,
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.sync_group();
+
         if *self.is_open {
             let bounds = layout.bounds();
 
-            let mut menu = Menu::new(
-                &mut self.menu,
-                &self.options,
-                &mut self.hovered_option,
-                &mut self.last_selection,
-            )
-            .width(bounds.width.round() as u16)
-            .padding(self.padding)
-            .font(self.font)
-            .style(Renderer::menu_style(&self.style));
+            let style = Renderer::menu_style(&self.style);
+            let selected = if self.marks_selected {
+                Some(self.selected_position())
+            } else {
+                None
+            };
 
-            if let Some(text_size) = self.text_size {
-                menu = menu.text_size(text_size);
-            }
+            let overlay = match &self.order {
+                Some(order) => {
+                    // Sorted options are displayed through their labels
+                    let options = &self.options;
+                    let format = &self.format;
+                    let leading = &self.leading;
 
-            Some(menu.overlay(layout.position(), bounds.height))
+                    self.labels = order
+                        .iter()
+                        .map(|&index| Label {
+                            text: format(&options[index]),
+                            leading: leading
+                                .as_ref()
+                                .map(|leading| leading(&options[index])),
+                        })
+                        .collect();
+
+                    let mut menu = Menu::new(
+                        self.menu,
+                        &self.labels,
+                        self.hovered_option,
+                        self.last_selection,
+                    );
+
+                    if self.leading.is_some() {
+                        menu = menu.leading(&Label::leading);
+                    }
+
+                    configure_menu(
+                        menu,
+                        selected,
+                        bounds,
+                        self.menu_width,
+                        self.padding,
+                        self.text_size,
+                        self.font,
+                        style,
+                    )
+                    .overlay(layout.position(), bounds.height)
+                }
+                None => {
+                    let mut menu = Menu::new(
+                        self.menu,
+                        &self.options,
+                        self.hovered_option,
+                        self.last_selection,
+                    )
+                    .format(&self.format);
+
+                    if let Some(leading) = &self.leading {
+                        menu = menu.leading(leading);
+                    }
+
+                    configure_menu(
+                        menu,
+                        selected,
+                        bounds,
+                        self.menu_width,
+                        self.padding,
+                        self.text_size,
+                        self.font,
+                        style,
+                    )
+                    .overlay(layout.position(), bounds.height)
+                }
+            };
+
+            Some(overlay)
         } else {
             None
         }
     }
 }
 
+/// Lays out the control of a [`PickList`] displaying the given options.
+///
+/// [`PickList`]: struct.PickList.html
+pub(crate) fn layout_control<T, Renderer>(
+    renderer: &Renderer,
+    limits: &layout::Limits,
+    options: &[T],
+    format: impl Fn(&T) -> String,
+    width: Length,
+    padding: u16,
+    text_size: Option<u16>,
+) -> layout::Node
+where
+    Renderer: text::Renderer,
+{
+    use std::f32;
+
+    let limits = limits
+        .width(width)
+        .height(Length::Shrink)
+        .pad(f32::from(padding));
+
+    let text_size = text_size.unwrap_or(renderer.default_size());
+
+    let max_width = match width {
+        Length::Shrink => {
+            let labels = options.iter().map(format);
+
+            labels
+                .map(|label| {
+                    let (width, _) = renderer.measure_text(
+                        &label,
+                        text_size,
+                        Renderer::Font::default(),
+                    );
+
+                    width.round() as u32
+                })
+                .max()
+                .unwrap_or(100)
+        }
+        _ => 0,
+    };
+
+    let size = {
+        let intrinsic = Size::new(
+            max_width as f32 + f32::from(text_size) + f32::from(padding),
+            f32::from(text_size),
+        );
+
+        limits.resolve(intrinsic).pad(f32::from(padding))
+    };
+
+    layout::Node::new(size)
+}
+
+/// Hashes the layout of the control of a [`PickList`] displaying the given
+/// options.
+///
+/// [`PickList`]: struct.PickList.html
+pub(crate) fn hash_control<T>(
+    state: &mut Hasher,
+    options: &[T],
+    format: impl Fn(&T) -> String,
+    width: Length,
+) {
+    match width {
+        Length::Shrink => {
+            options
+                .iter()
+                .map(format)
+                .for_each(|label| label.hash(state));
+        }
+        _ => {
+            width.hash(state);
+        }
+    }
+}
+
+/// Applies the settings of a [`PickList`] to its [`Menu`].
+///
+/// [`PickList`]: struct.PickList.html
+/// [`Menu`]: ../../overlay/menu/struct.Menu.html
+pub(crate) fn configure_menu<'a, T, Renderer>(
+    menu: Menu<'a, T, Renderer>,
+    selected: Option<Option<usize>>,
+    bounds: Rectangle,
+    menu_width: MenuWidth,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer as menu::Renderer>::Style,
+) -> Menu<'a, T, Renderer>
+where
+    T: ToString,
+    Renderer: menu::Renderer + 'a,
+{
+    let menu = menu.padding(padding).font(font).style(style);
+
+    let menu = match selected {
+        Some(selected) => menu.mark_selected(selected),
+        None => menu,
+    };
+
+    let menu = match menu_width {
+        MenuWidth::MatchControl => menu.width(bounds.width.round() as u16),
+        MenuWidth::Fixed(width) => menu.width(width),
+        MenuWidth::FitContent => menu.fit_content(),
+    };
+
+    match text_size {
+        Some(text_size) => menu.text_size(text_size),
+        None => menu,
+    }
+}
+
+/// The label of an option in the sorted menu of a [`PickList`].
+///
+/// [`PickList`]: struct.PickList.html
+struct Label {
+    text: String,
+    leading: Option<String>,
+}
+
+impl Label {
+    fn leading(&self) -> String {
+        self.leading.clone().unwrap_or_default()
+    }
+}
+
+impl fmt::Display for Label {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
 /// The renderer of a [`PickList`].
 ///
 /// Your [renderer] will need to implement this trait before being
@@ -326,7 +927,10 @@ pub trait Renderer: text::Renderer + menu::Renderer {
 
     /// Draws a [`PickList`].
     ///
+    /// It receives the custom [`Icon`] of the dropdown arrow, if any.
+    ///
     /// [`PickList`]: struct.PickList.html
+    /// [`Icon`]: struct.Icon.html
     fn draw(
         &mut self,
         bounds: Rectangle,
@@ -335,6 +939,7 @@ pub trait Renderer: text::Renderer + menu::Renderer {
         padding: u16,
         text_size: u16,
         font: Self::Font,
+        icon: Option<&Icon<Self::Font>>,
         style: &<Self as Renderer>::Style,
     ) -> Self::Output;
 }
@@ -342,8 +947,7 @@ pub trait Renderer: text::Renderer + menu::Renderer {
 impl<'a, T: 'a, Message, Renderer> Into<Element<'a, Message, Renderer>>
     for PickList<'a, T, Message, Renderer>
 where
-    T: Clone + ToString + Eq,
-    [T]: ToOwned<Owned = Vec<T>>,
+    T: ToString,
     Renderer: self::Renderer + 'a,
     Message: 'static,
 {
@@ -351,3 +955,287 @@ where
         Element::new(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::testing::Harness;
+    use crate::Row;
+
+    use std::rc::Rc;
+
+    /// An option that counts how many times it has been cloned.
+    #[derive(Debug)]
+    struct Tracked {
+        name: &'static str,
+        clones: Rc<Cell<usize>>,
+    }
+
+    impl Clone for Tracked {
+        fn clone(&self) -> Self {
+            self.clones.set(self.clones.get() + 1);
+
+            Tracked {
+                name: self.name,
+                clones: self.clones.clone(),
+            }
+        }
+    }
+
+    impl PartialEq for Tracked {
+        fn eq(&self, other: &Self) -> bool {
+            self.name == other.name
+        }
+    }
+
+    impl Eq for Tracked {}
+
+    impl fmt::Display for Tracked {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.name)
+        }
+    }
+
+    #[test]
+    fn selection_only_clones_the_selected_option() {
+        let clones = Rc::new(Cell::new(0));
+        let options: Vec<_> = ["Rust", "Elm", "Haskell"]
+            .iter()
+            .map(|name| Tracked {
+                name,
+                clones: clones.clone(),
+            })
+            .collect();
+
+        let mut state = State::default();
+        let pick_list: PickList<'_, _, _, Null> =
+            PickList::new(&mut state, &options[..], None, |option| option.name)
+                .width(Length::Units(100));
+
+        let mut harness = Harness::new(pick_list, Size::new(200.0, 200.0));
+        harness.click(Point::new(10.0, 10.0));
+
+        // Each option is 20 units tall and the menu starts below the control
+        harness.move_cursor_to(Point::new(10.0, 50.0));
+        harness.click(Point::new(10.0, 50.0));
+
+        assert_eq!(harness.into_messages(), vec!["Elm"]);
+        assert_eq!(clones.get(), 1);
+        assert!(!state.is_open());
+    }
+
+    #[test]
+    fn menu_can_stay_open_after_a_selection() {
+        let options = ["Rust", "Elm", "Haskell"];
+
+        let mut state = State::default();
+        let pick_list: PickList<'_, _, _, Null> =
+            PickList::new(&mut state, &options[..], None, |option| option)
+                .width(Length::Units(100))
+                .close_on_select(false);
+
+        let mut harness = Harness::new(pick_list, Size::new(200.0, 200.0));
+        harness.click(Point::new(10.0, 10.0));
+
+        harness.move_cursor_to(Point::new(10.0, 50.0));
+        harness.click(Point::new(10.0, 50.0));
+        harness.move_cursor_to(Point::new(10.0, 70.0));
+        harness.click(Point::new(10.0, 70.0));
+
+        assert_eq!(harness.into_messages(), vec!["Elm", "Haskell"]);
+        assert!(state.is_open());
+    }
+
+    #[test]
+    fn selection_can_produce_the_previous_value() {
+        let options = ["Rust", "Elm", "Haskell"];
+
+        let mut state = State::default();
+        let pick_list: PickList<'_, _, _, Null> = PickList::new_with_previous(
+            &mut state,
+            &options[..],
+            Some("Rust"),
+            |previous, option| (previous, option),
+        )
+        .width(Length::Units(100));
+
+        let mut harness = Harness::new(pick_list, Size::new(200.0, 200.0));
+        harness.click(Point::new(10.0, 10.0));
+
+        harness.move_cursor_to(Point::new(10.0, 50.0));
+        harness.click(Point::new(10.0, 50.0));
+
+        assert_eq!(harness.into_messages(), vec![(Some("Rust"), "Elm")]);
+    }
+
+    #[test]
+    fn sorted_options_produce_their_own_message() {
+        let options = ["Rust", "Elm", "Haskell"];
+
+        let mut state = State::default();
+        let pick_list: PickList<'_, _, _, Null> =
+            PickList::new_indexed(&mut state, &options[..], None, |index| {
+                index
+            })
+            .sort_by(|a, b| a.cmp(b))
+            .width(Length::Units(100));
+
+        let mut harness = Harness::new(pick_list, Size::new(200.0, 200.0));
+        harness.click(Point::new(10.0, 10.0));
+
+        // The menu displays "Elm", "Haskell" and "Rust"
+        harness.move_cursor_to(Point::new(10.0, 50.0));
+        harness.click(Point::new(10.0, 50.0));
+
+        assert_eq!(harness.into_messages(), vec![2]);
+    }
+
+    #[test]
+    fn indexed_options_do_not_need_to_be_comparable_nor_cloneable() {
+        struct Language(&'static str);
+
+        impl fmt::Display for Language {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.0)
+            }
+        }
+
+        let options = [Language("Rust"), Language("Elm"), Language("Haskell")];
+
+        let mut state = State::default();
+        let pick_list: PickList<'_, _, _, Null> =
+            PickList::new_indexed(&mut state, &options[..], Some(2), |index| {
+                index
+            })
+            .width(Length::Units(100));
+
+        let mut harness = Harness::new(pick_list, Size::new(200.0, 200.0));
+        harness.click(Point::new(10.0, 10.0));
+
+        harness.move_cursor_to(Point::new(10.0, 50.0));
+        harness.click(Point::new(10.0, 50.0));
+
+        assert_eq!(harness.into_messages(), vec![1]);
+        assert!(!state.is_open());
+    }
+
+    #[test]
+    fn opening_the_menu_reveals_the_selected_option() {
+        let options: Vec<_> =
+            (0..10).map(|i| format!("Option {}", i)).collect();
+
+        let mut state = State::default();
+        let pick_list: PickList<'_, _, _, Null> =
+            PickList::new_indexed(&mut state, &options[..], Some(8), |index| {
+                index
+            })
+            .width(Length::Units(100));
+
+        // The menu only has room for about 4 options below the control
+        let mut harness = Harness::new(pick_list, Size::new(200.0, 100.0));
+        harness.click(Point::new(10.0, 10.0));
+
+        // The selected option is at the bottom of the menu
+        harness.move_cursor_to(Point::new(10.0, 90.0));
+        harness.click(Point::new(10.0, 90.0));
+
+        assert_eq!(harness.into_messages(), vec![8]);
+    }
+
+    fn menu_width(leading: bool, mark_selected: bool) -> f32 {
+        let mut state = State::default();
+        state.open();
+
+        let mut pick_list = PickList::<_, (), Null>::new(
+            &mut state,
+            &["Rust", "Elm"][..],
+            Some("Elm"),
+            |_| (),
+        )
+        .sort_by(|a, b| a.cmp(b))
+        .menu_width(MenuWidth::FitContent)
+        .padding(5)
+        .mark_selected(mark_selected);
+
+        if leading {
+            pick_list = pick_list.leading(|_| String::from("*"));
+        }
+
+        let node = Widget::<(), Null>::layout(
+            &pick_list,
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(400.0, 400.0)),
+        );
+
+        let overlay =
+            Widget::<(), Null>::overlay(&mut pick_list, Layout::new(&node))
+                .expect("open menu");
+
+        overlay.layout(&Null, Size::new(400.0, 400.0)).size().width
+    }
+
+    #[test]
+    fn menu_displays_leading_texts_and_marks_the_selected_option() {
+        // The null renderer measures every label as empty, and the default
+        // text size is 20
+        assert_eq!(menu_width(false, false), 12.0);
+        assert_eq!(menu_width(true, false), 37.0);
+        assert_eq!(menu_width(true, true), 62.0);
+    }
+
+    #[test]
+    fn hovering_a_pick_list_of_an_open_group_opens_it() {
+        let options = ["Rust", "Elm", "Haskell"];
+        let group = Group::new();
+
+        let mut file = State::default();
+        let mut edit = State::default();
+
+        let row: Row<'_, _, Null> = Row::new()
+            .push(
+                PickList::new(&mut file, &options[..], None, |option| option)
+                    .width(Length::Units(100))
+                    .group(&group),
+            )
+            .push(
+                PickList::new(&mut edit, &options[..], None, |option| option)
+                    .width(Length::Units(100))
+                    .group(&group),
+            );
+
+        let mut harness = Harness::new(row, Size::new(200.0, 200.0));
+        harness.click(Point::new(10.0, 10.0));
+        harness.move_cursor_to(Point::new(150.0, 10.0));
+
+        // The superseded pick list closes on the next event
+        harness.move_cursor_to(Point::new(160.0, 10.0));
+        drop(harness);
+
+        assert!(!file.is_open());
+        assert!(edit.is_open());
+        assert!(group.is_open());
+    }
+
+    #[test]
+    fn closing_the_open_pick_list_closes_its_group() {
+        let options = ["Rust", "Elm", "Haskell"];
+        let group = Group::new();
+
+        let mut state = State::default();
+        let pick_list: PickList<'_, _, _, Null> =
+            PickList::new(&mut state, &options[..], None, |option| option)
+                .width(Length::Units(100))
+                .group(&group);
+
+        let mut harness = Harness::new(pick_list, Size::new(200.0, 200.0));
+        harness.click(Point::new(10.0, 10.0));
+        assert!(group.is_open());
+
+        harness.click(Point::new(150.0, 150.0));
+        drop(harness);
+
+        assert!(!state.is_open());
+        assert!(!group.is_open());
+    }
+}
@@ -0,0 +1,494 @@
+//! Display a popup anchored to some content.
+use std::hash::Hash;
+
+use crate::event;
+use crate::focus::Focusable;
+use crate::keyboard;
+use crate::mouse;
+use crate::overlay;
+use crate::{
+    layout, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+
+/// An element that displays a popup next to its content, the anchor, while
+/// it is open.
+///
+/// The popup can be any element, like a date picker under a text field. It
+/// is displayed in an overlay and receives events like any other widget. If
+/// it does not fit on the requested side of the anchor, it is flipped to the
+/// opposite side, and it is always kept inside the viewport.
+///
+/// Whether the popup is open is decided by the application, which can be
+/// notified when the popup should be dismissed.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{popover, renderer::Null, Text};
+/// #
+/// # type Popover<'a, Message> = iced_native::Popover<'a, Message, Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     CalendarDismissed,
+/// }
+///
+/// let is_calendar_open = true;
+///
+/// let popover = Popover::new(
+///     Text::new("2020-10-14"),
+///     Text::new("A calendar"),
+///     is_calendar_open,
+/// )
+/// .position(popover::Position::Below)
+/// .gap(5)
+/// .on_dismiss(Message::CalendarDismissed);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Popover<'a, Message, Renderer> {
+    anchor: Element<'a, Message, Renderer>,
+    popup: Element<'a, Message, Renderer>,
+    is_open: bool,
+    position: Position,
+    gap: u16,
+    on_dismiss: Option<Message>,
+}
+
+/// The position of the popup of a [`Popover`], relative to its anchor.
+///
+/// [`Popover`]: struct.Popover.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Position {
+    /// The popup is below the anchor, aligned to its left edge.
+    Below,
+
+    /// The popup is above the anchor, aligned to its left edge.
+    Above,
+
+    /// The popup is on the left of the anchor, aligned to its top edge.
+    Start,
+
+    /// The popup is on the right of the anchor, aligned to its top edge.
+    End,
+}
+
+impl<'a, Message, Renderer> Popover<'a, Message, Renderer> {
+    /// Creates a new [`Popover`] with the given anchor, popup, and whether
+    /// the popup is open.
+    ///
+    /// By default, the popup is displayed [`Below`] the anchor.
+    ///
+    /// [`Popover`]: struct.Popover.html
+    /// [`Below`]: enum.Position.html#variant.Below
+    pub fn new(
+        anchor: impl Into<Element<'a, Message, Renderer>>,
+        popup: impl Into<Element<'a, Message, Renderer>>,
+        is_open: bool,
+    ) -> Self {
+        Popover {
+            anchor: anchor.into(),
+            popup: popup.into(),
+            is_open,
+            position: Position::Below,
+            gap: 0,
+            on_dismiss: None,
+        }
+    }
+
+    /// Sets the [`Position`] of the popup of the [`Popover`].
+    ///
+    /// [`Position`]: enum.Position.html
+    /// [`Popover`]: struct.Popover.html
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the distance between the popup and the anchor of the
+    /// [`Popover`].
+    ///
+    /// [`Popover`]: struct.Popover.html
+    pub fn gap(mut self, gap: u16) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets the message produced when the popup of the [`Popover`] should
+    /// be dismissed.
+    ///
+    /// It is produced when clicking outside of both the popup and the
+    /// anchor, or when pressing `Escape`, while the popup is open.
+    ///
+    /// [`Popover`]: struct.Popover.html
+    pub fn on_dismiss(mut self, message: Message) -> Self {
+        self.on_dismiss = Some(message);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Popover<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.anchor.width()
+    }
+
+    fn height(&self) -> Length {
+        self.anchor.height()
+    }
+
+    fn min_size(&self, renderer: &Renderer) -> Size {
+        self.anchor.min_size(renderer)
+    }
+
+    fn max_size(&self) -> Size {
+        self.anchor.max_size()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.anchor.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        self.anchor.widget.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn layout_changed(
+        &mut self,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+    ) {
+        self.anchor.layout_changed(layout, messages)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.anchor
+            .draw(renderer, defaults, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.anchor.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let Popover {
+            anchor,
+            popup,
+            is_open,
+            position,
+            gap,
+            on_dismiss,
+        } = self;
+
+        anchor.overlay(layout).or_else(move || {
+            if !*is_open {
+                return None;
+            }
+
+            Some(overlay::Element::new(
+                layout.position(),
+                Box::new(Overlay {
+                    popup,
+                    anchor: layout.bounds().size(),
+                    position: *position,
+                    gap: *gap,
+                    on_dismiss,
+                }),
+            ))
+        })
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.anchor.focused_bounds(layout)
+    }
+
+    fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        messages: &mut Vec<Message>,
+    ) {
+        self.anchor.focusables(visit, messages)
+    }
+}
+
+struct Overlay<'a, 'b, Message, Renderer> {
+    popup: &'b mut Element<'a, Message, Renderer>,
+    anchor: Size,
+    position: Position,
+    gap: u16,
+    on_dismiss: &'b Option<Message>,
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Overlay<'a, 'b, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: crate::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let popup = self
+            .popup
+            .layout(renderer, &layout::Limits::new(Size::ZERO, bounds));
+
+        let anchor = Rectangle {
+            x: position.x,
+            y: position.y,
+            width: self.anchor.width,
+            height: self.anchor.height,
+        };
+
+        let size = popup.size();
+        let origin =
+            place(self.position, anchor, size, f32::from(self.gap), bounds);
+
+        // The bounds of the anchor are kept in the layout, relative to the
+        // popup, in order to tell clicks on the anchor apart when dismissing
+        let mut anchor_node = layout::Node::new(self.anchor);
+        anchor_node
+            .move_to(Point::new(position.x - origin.x, position.y - origin.y));
+
+        let mut node =
+            layout::Node::with_children(size, vec![popup, anchor_node]);
+        node.move_to(origin);
+
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.popup.draw(
+            renderer,
+            defaults,
+            layout.children().next().unwrap(),
+            cursor_position,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher, position: Point) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+        (self.anchor.width as u32).hash(state);
+        (self.anchor.height as u32).hash(state);
+        self.position.hash(state);
+        self.gap.hash(state);
+        self.popup.hash_layout(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let mut children = layout.children();
+        let popup_layout = children.next().unwrap();
+        let anchor_layout = children.next().unwrap();
+
+        let is_dismissed = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_)) => {
+                !popup_layout.bounds().contains(cursor_position)
+                    && !anchor_layout.bounds().contains(cursor_position)
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => true,
+            _ => false,
+        };
+
+        if is_dismissed {
+            messages.extend(self.on_dismiss.clone());
+        }
+
+        let _ = self.popup.widget.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+    }
+}
+
+/// Returns the position of a popup of the given size next to the anchor
+/// bounds.
+///
+/// The popup is flipped to the opposite side of the anchor if it only fits
+/// there, and it is kept inside the viewport, favoring its top-left corner
+/// when it does not fit.
+fn place(
+    position: Position,
+    anchor: Rectangle,
+    size: Size,
+    gap: f32,
+    viewport: Size,
+) -> Point {
+    let below = anchor.y + anchor.height + gap;
+    let above = anchor.y - gap - size.height;
+    let end = anchor.x + anchor.width + gap;
+    let start = anchor.x - gap - size.width;
+
+    let fits_below = below + size.height <= viewport.height;
+    let fits_above = above >= 0.0;
+    let fits_end = end + size.width <= viewport.width;
+    let fits_start = start >= 0.0;
+
+    let (x, y) = match position {
+        Position::Below if fits_below || !fits_above => (anchor.x, below),
+        Position::Below => (anchor.x, above),
+        Position::Above if fits_above || !fits_below => (anchor.x, above),
+        Position::Above => (anchor.x, below),
+        Position::End if fits_end || !fits_start => (end, anchor.y),
+        Position::End => (start, anchor.y),
+        Position::Start if fits_start || !fits_end => (start, anchor.y),
+        Position::Start => (end, anchor.y),
+    };
+
+    Point::new(
+        x.min(viewport.width - size.width).max(0.0),
+        y.min(viewport.height - size.height).max(0.0),
+    )
+}
+
+impl<'a, Message, Renderer> From<Popover<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(
+        popover: Popover<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(popover)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::testing::Harness;
+    use crate::{button, Button, Text};
+
+    const ANCHOR: Rectangle = Rectangle {
+        x: 100.0,
+        y: 100.0,
+        width: 50.0,
+        height: 20.0,
+    };
+
+    const VIEWPORT: Size = Size {
+        width: 300.0,
+        height: 200.0,
+    };
+
+    #[test]
+    fn popup_is_flipped_when_it_only_fits_on_the_other_side() {
+        let size = Size::new(60.0, 50.0);
+
+        assert_eq!(
+            place(Position::Below, ANCHOR, size, 5.0, VIEWPORT),
+            Point::new(100.0, 125.0)
+        );
+        assert_eq!(
+            place(Position::Start, ANCHOR, size, 5.0, VIEWPORT),
+            Point::new(35.0, 100.0)
+        );
+
+        let tall = Size::new(60.0, 90.0);
+
+        assert_eq!(
+            place(Position::Below, ANCHOR, tall, 5.0, VIEWPORT),
+            Point::new(100.0, 5.0)
+        );
+        assert_eq!(
+            place(Position::End, ANCHOR, Size::new(160.0, 50.0), 5.0, VIEWPORT),
+            Point::new(140.0, 100.0)
+        );
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Picked,
+        Dismissed,
+    }
+
+    #[test]
+    fn popup_receives_events_and_can_be_dismissed() {
+        let mut pick = button::State::new();
+
+        let popover = Popover::<_, Null>::new(
+            Text::new("").width(Length::Units(50)),
+            Button::new(&mut pick, Text::new(""))
+                .width(Length::Units(40))
+                .on_press(Message::Picked),
+            true,
+        )
+        .on_dismiss(Message::Dismissed);
+
+        let mut harness = Harness::new(popover, Size::new(100.0, 100.0));
+
+        // The popup is right below the anchor
+        harness.click(Point::new(10.0, 30.0));
+        // Clicking the anchor keeps the popup open
+        harness.click(Point::new(45.0, 10.0));
+        harness.click(Point::new(80.0, 80.0));
+
+        assert_eq!(
+            harness.into_messages(),
+            vec![Message::Picked, Message::Dismissed]
+        );
+    }
+}
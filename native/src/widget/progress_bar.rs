@@ -24,6 +24,7 @@ pub struct ProgressBar<Renderer: self::Renderer> {
     value: f32,
     width: Length,
     height: Option<Length>,
+    segments: Option<u16>,
     style: Renderer::Style,
 }
 
@@ -41,6 +42,7 @@ impl<Renderer: self::Renderer> ProgressBar<Renderer> {
             range,
             width: Length::Fill,
             height: None,
+            segments: None,
             style: Renderer::Style::default(),
         }
     }
@@ -61,6 +63,17 @@ impl<Renderer: self::Renderer> ProgressBar<Renderer> {
         self
     }
 
+    /// Divides the [`ProgressBar`] in the given amount of segments.
+    ///
+    /// A segmented [`ProgressBar`] only fills whole segments, based on its
+    /// current value. By default, a [`ProgressBar`] fills continuously.
+    ///
+    /// [`ProgressBar`]: struct.ProgressBar.html
+    pub fn segments(mut self, segments: u16) -> Self {
+        self.segments = Some(segments).filter(|segments| *segments > 0);
+        self
+    }
+
     /// Sets the style of the [`ProgressBar`].
     ///
     /// [`ProgressBar`]: struct.ProgressBar.html
@@ -109,6 +122,7 @@ where
             layout.bounds(),
             self.range.clone(),
             self.value,
+            self.segments,
             &self.style,
         )
     }
@@ -144,6 +158,7 @@ pub trait Renderer: crate::Renderer {
     ///   * the bounds of the [`ProgressBar`]
     ///   * the range of values of the [`ProgressBar`]
     ///   * the current value of the [`ProgressBar`]
+    ///   * the amount of segments of the [`ProgressBar`], if segmented
     ///   * maybe a specific background of the [`ProgressBar`]
     ///   * maybe a specific active color of the [`ProgressBar`]
     ///
@@ -153,6 +168,7 @@ pub trait Renderer: crate::Renderer {
         bounds: Rectangle,
         range: RangeInclusive<f32>,
         value: f32,
+        segments: Option<u16>,
         style: &Self::Style,
     ) -> Self::Output;
 }
@@ -1,11 +1,21 @@
 //! Create choices using radio buttons.
+use crate::animation::Animation;
+use crate::focus::Focusable;
 use crate::{
-    layout, mouse, row, text, Align, Clipboard, Element, Event, Hasher,
-    HorizontalAlignment, Layout, Length, Point, Rectangle, Row, Text,
-    VerticalAlignment, Widget,
+    event, keyboard, layout, mouse, row, text, Align, Clipboard, Element,
+    Event, Hasher, HorizontalAlignment, Layout, Length, Point, Rectangle, Row,
+    Size, Text, VerticalAlignment, Widget,
 };
 
 use std::hash::Hash;
+use std::time::Duration;
+
+/// The time it takes the [`Radio`] buttons of a [`RadioGroup`] to transition
+/// to a new selection.
+///
+/// [`Radio`]: struct.Radio.html
+/// [`RadioGroup`]: struct.RadioGroup.html
+pub const TRANSITION_DURATION: Duration = Duration::from_millis(150);
 
 /// A circular button representing a choice.
 ///
@@ -42,6 +52,7 @@ pub struct Radio<Message, Renderer: self::Renderer + text::Renderer> {
     size: u16,
     spacing: u16,
     text_size: Option<u16>,
+    is_label_clickable: bool,
     style: Renderer::Style,
 }
 
@@ -78,6 +89,7 @@ where
             size: <Renderer as self::Renderer>::DEFAULT_SIZE,
             spacing: Renderer::DEFAULT_SPACING, //15
             text_size: None,
+            is_label_clickable: true,
             style: Renderer::Style::default(),
         }
     }
@@ -121,6 +133,18 @@ where
         self.style = style.into();
         self
     }
+
+    /// Sets whether clicking the label of the [`Radio`] button selects it.
+    ///
+    /// By default, the whole [`Radio`] button is clickable. Disable it to
+    /// only react to clicks on the button itself, like when the label has
+    /// its own interactive content.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    pub fn label_clickable(mut self, is_label_clickable: bool) -> Self {
+        self.is_label_clickable = is_label_clickable;
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Radio<Message, Renderer>
@@ -166,15 +190,19 @@ where
         messages: &mut Vec<Message>,
         _renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
-    ) {
+    ) -> event::Status {
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
-                if layout.bounds().contains(cursor_position) {
+                if self.clickable_bounds(layout).contains(cursor_position) {
                     messages.push(self.on_click.clone());
+
+                    return event::Status::Captured;
                 }
             }
             _ => {}
         }
+
+        event::Status::Ignored
     }
 
     fn draw(
@@ -184,7 +212,41 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> Renderer::Output {
-        let bounds = layout.bounds();
+        self.draw_radio(renderer, defaults, layout, cursor_position, false, 1.0)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.label.hash(state);
+    }
+}
+
+impl<Message, Renderer> Radio<Message, Renderer>
+where
+    Renderer: self::Renderer + text::Renderer,
+{
+    /// Returns the bounds that select the [`Radio`] button when clicked.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    fn clickable_bounds(&self, layout: Layout<'_>) -> Rectangle {
+        if self.is_label_clickable {
+            layout.bounds()
+        } else {
+            layout.children().next().unwrap().bounds()
+        }
+    }
+
+    fn draw_radio(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        is_focused: bool,
+        transition: f32,
+    ) -> Renderer::Output {
         let mut children = layout.children();
 
         let radio_layout = children.next().unwrap();
@@ -203,23 +265,444 @@ where
             VerticalAlignment::Center,
         );
 
-        let is_mouse_over = bounds.contains(cursor_position);
+        let is_mouse_over =
+            self.clickable_bounds(layout).contains(cursor_position);
 
         self::Renderer::draw(
             renderer,
             radio_bounds,
             self.is_selected,
+            transition,
             is_mouse_over,
+            is_focused,
             label,
             &self.style,
         )
     }
+}
+
+/// A group of [`Radio`] buttons that can be navigated with the arrow keys.
+///
+/// Clicking any [`Radio`] of the [`RadioGroup`] focuses it. While focused,
+/// the arrow keys of its [`Orientation`] select the next or previous
+/// [`Radio`] button, wrapping around at the ends.
+///
+/// A [`RadioGroup`] animates the transition to a new selection, which lasts
+/// [`TRANSITION_DURATION`].
+///
+/// # Example
+/// ```
+/// # use iced_native::radio;
+/// # type Radio<Message> =
+/// #     iced_native::Radio<Message, iced_native::renderer::Null>;
+/// # type RadioGroup<'a, Message> =
+/// #     iced_native::RadioGroup<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// pub enum Choice {
+///     A,
+///     B,
+/// }
+///
+/// #[derive(Debug, Clone, Copy)]
+/// pub enum Message {
+///     RadioSelected(Choice),
+/// }
+///
+/// let mut state = radio::State::new();
+/// let selected_choice = Some(Choice::A);
+///
+/// RadioGroup::new(&mut state)
+///     .orientation(radio::Orientation::Horizontal)
+///     .push(Radio::new(
+///         Choice::A,
+///         "This is A",
+///         selected_choice,
+///         Message::RadioSelected,
+///     ))
+///     .push(Radio::new(
+///         Choice::B,
+///         "This is B",
+///         selected_choice,
+///         Message::RadioSelected,
+///     ));
+/// ```
+///
+/// [`Radio`]: struct.Radio.html
+/// [`RadioGroup`]: struct.RadioGroup.html
+/// [`Orientation`]: enum.Orientation.html
+/// [`TRANSITION_DURATION`]: constant.TRANSITION_DURATION.html
+#[allow(missing_debug_implementations)]
+pub struct RadioGroup<'a, Message, Renderer: self::Renderer + text::Renderer> {
+    state: &'a mut State,
+    radios: Vec<Radio<Message, Renderer>>,
+    orientation: Orientation,
+    spacing: u16,
+    is_animated: bool,
+    is_first_build: bool,
+}
+
+/// The direction in which the [`Radio`] buttons of a [`RadioGroup`] are
+/// laid out.
+///
+/// It also decides the arrow keys that move the selection: Up and Down for
+/// [`Vertical`], Left and Right for [`Horizontal`].
+///
+/// [`Radio`]: struct.Radio.html
+/// [`RadioGroup`]: struct.RadioGroup.html
+/// [`Vertical`]: #variant.Vertical
+/// [`Horizontal`]: #variant.Horizontal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// The [`Radio`] buttons are laid out in a column.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    Vertical,
+
+    /// The [`Radio`] buttons are laid out in a row.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    Horizontal,
+}
+
+impl Default for Orientation {
+    fn default() -> Self {
+        Orientation::Vertical
+    }
+}
+
+/// The local state of a [`RadioGroup`].
+///
+/// [`RadioGroup`]: struct.RadioGroup.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    is_focused: bool,
+    is_built: bool,
+    selected: Option<usize>,
+    // The previous selection, and the transition from it
+    transition: Option<(Option<usize>, Animation)>,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`RadioGroup`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the [`RadioGroup`] is currently focused or not.
+    ///
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+}
+
+impl Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, Message, Renderer> RadioGroup<'a, Message, Renderer>
+where
+    Renderer: self::Renderer + text::Renderer,
+{
+    /// Creates an empty [`RadioGroup`] with the given [`State`].
+    ///
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    /// [`State`]: struct.State.html
+    pub fn new(state: &'a mut State) -> Self {
+        let is_first_build = !state.is_built;
+        state.is_built = true;
+
+        RadioGroup {
+            state,
+            radios: Vec::new(),
+            orientation: Orientation::default(),
+            spacing: 0,
+            is_animated: true,
+            is_first_build,
+        }
+    }
+
+    /// Sets the [`Orientation`] of the [`RadioGroup`].
+    ///
+    /// [`Orientation`]: enum.Orientation.html
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the spacing _between_ the [`Radio`] buttons of the
+    /// [`RadioGroup`].
+    ///
+    /// [`Radio`]: struct.Radio.html
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets whether the [`RadioGroup`] animates the transition to a new
+    /// selection.
+    ///
+    /// Disable it to change the selection instantly, like when the user
+    /// prefers reduced motion.
+    ///
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn animated(mut self, is_animated: bool) -> Self {
+        self.is_animated = is_animated;
+        self
+    }
+
+    /// Adds a [`Radio`] button to the [`RadioGroup`].
+    ///
+    /// [`Radio`]: struct.Radio.html
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn push(mut self, radio: Radio<Message, Renderer>) -> Self {
+        let index = self.radios.len();
+
+        if radio.is_selected && self.state.selected != Some(index) {
+            if !self.is_first_build {
+                self.state.transition = Some((
+                    self.state.selected,
+                    Animation::new(TRANSITION_DURATION),
+                ));
+            }
+
+            self.state.selected = Some(index);
+        }
+
+        self.radios.push(radio);
+        self
+    }
+
+    fn selected(&self) -> Option<usize> {
+        self.radios.iter().position(|radio| radio.is_selected)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for RadioGroup<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer + text::Renderer + row::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.loose();
+        let spacing = f32::from(self.spacing);
+
+        let mut children = Vec::with_capacity(self.radios.len());
+        let mut size = Size::ZERO;
+
+        for radio in &self.radios {
+            let mut node =
+                Widget::<Message, Renderer>::layout(radio, renderer, &limits);
+            let node_size = node.size();
+
+            match self.orientation {
+                Orientation::Vertical => {
+                    if !children.is_empty() {
+                        size.height += spacing;
+                    }
+
+                    node.move_to(Point::new(0.0, size.height));
+
+                    size.width = size.width.max(node_size.width);
+                    size.height += node_size.height;
+                }
+                Orientation::Horizontal => {
+                    if !children.is_empty() {
+                        size.width += spacing;
+                    }
+
+                    node.move_to(Point::new(size.width, 0.0));
+
+                    size.width += node_size.width;
+                    size.height = size.height.max(node_size.height);
+                }
+            }
+
+            children.push(node);
+        }
+
+        layout::Node::with_children(limits.resolve(size), children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                self.state.is_focused = layout
+                    .children()
+                    .any(|layout| layout.bounds().contains(cursor_position));
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if self.state.is_focused && !self.radios.is_empty() => {
+                let (previous, next) = match self.orientation {
+                    Orientation::Vertical => {
+                        (keyboard::KeyCode::Up, keyboard::KeyCode::Down)
+                    }
+                    Orientation::Horizontal => {
+                        (keyboard::KeyCode::Left, keyboard::KeyCode::Right)
+                    }
+                };
+
+                let total = self.radios.len();
+
+                let target = if key_code == next {
+                    Some(self.selected().map(|i| (i + 1) % total).unwrap_or(0))
+                } else if key_code == previous {
+                    Some(
+                        self.selected()
+                            .map(|i| (i + total - 1) % total)
+                            .unwrap_or(total - 1),
+                    )
+                } else {
+                    if key_code == keyboard::KeyCode::Escape {
+                        self.state.is_focused = false;
+                    }
+
+                    None
+                };
+
+                if let Some(target) = target {
+                    messages.push(self.radios[target].on_click.clone());
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        self.radios
+            .iter_mut()
+            .zip(layout.children())
+            .map(|(radio, layout)| {
+                radio.on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    messages,
+                    renderer,
+                    clipboard,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let focused = if self.state.is_focused {
+            Some(self.selected().unwrap_or(0))
+        } else {
+            None
+        };
+
+        let transition = match self.state.transition {
+            Some((previous, transition)) if self.is_animated => {
+                let progress = transition.progress();
+
+                if progress < 1.0 {
+                    renderer.request_redraw();
+                }
+
+                Some((previous, progress))
+            }
+            _ => None,
+        };
+
+        let radios = self
+            .radios
+            .iter()
+            .zip(layout.children())
+            .enumerate()
+            .map(|(i, (radio, layout))| {
+                radio.draw_radio(
+                    renderer,
+                    defaults,
+                    layout,
+                    cursor_position,
+                    focused == Some(i),
+                    match transition {
+                        Some((previous, progress))
+                            if self.state.selected == Some(i)
+                                || previous == Some(i) =>
+                        {
+                            progress
+                        }
+                        _ => 1.0,
+                    },
+                )
+            })
+            .collect();
+
+        self::Renderer::draw_group(renderer, radios)
+    }
 
     fn hash_layout(&self, state: &mut Hasher) {
         struct Marker;
         std::any::TypeId::of::<Marker>().hash(state);
 
-        self.label.hash(state);
+        self.orientation.hash(state);
+        self.spacing.hash(state);
+
+        for radio in &self.radios {
+            Widget::<Message, Renderer>::hash_layout(radio, state);
+        }
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        if self.state.is_focused {
+            Some(layout.bounds())
+        } else {
+            None
+        }
+    }
+
+    fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        _messages: &mut Vec<Message>,
+    ) {
+        visit(&mut *self.state);
     }
 }
 
@@ -249,7 +732,10 @@ pub trait Renderer: crate::Renderer {
     /// It receives:
     ///   * the bounds of the [`Radio`]
     ///   * whether the [`Radio`] is selected or not
+    ///   * the progress of the transition to the current state, from `0.0`
+    ///     to `1.0`
     ///   * whether the mouse is over the [`Radio`] or not
+    ///   * whether the [`Radio`] is focused or not
     ///   * the drawn label of the [`Radio`]
     ///
     /// [`Radio`]: struct.Radio.html
@@ -257,10 +743,18 @@ pub trait Renderer: crate::Renderer {
         &mut self,
         bounds: Rectangle,
         is_selected: bool,
+        transition: f32,
         is_mouse_over: bool,
+        is_focused: bool,
         label: Self::Output,
         style: &Self::Style,
     ) -> Self::Output;
+
+    /// Draws a [`RadioGroup`], given its drawn [`Radio`] buttons.
+    ///
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    /// [`Radio`]: struct.Radio.html
+    fn draw_group(&mut self, radios: Vec<Self::Output>) -> Self::Output;
 }
 
 impl<'a, Message, Renderer> From<Radio<Message, Renderer>>
@@ -273,3 +767,93 @@ where
         Element::new(radio)
     }
 }
+
+impl<'a, Message, Renderer> From<RadioGroup<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer + row::Renderer + text::Renderer,
+{
+    fn from(
+        radio_group: RadioGroup<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(radio_group)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keyboard::KeyCode;
+    use crate::renderer::Null;
+    use crate::testing::Harness;
+
+    fn radio(value: u8, selected: Option<u8>) -> Radio<u8, Null> {
+        Radio::new(value, value.to_string(), selected, |value| value)
+    }
+
+    #[test]
+    fn arrows_move_selection_once_focused() {
+        let mut state = State::new();
+        let group = RadioGroup::new(&mut state)
+            .orientation(Orientation::Horizontal)
+            .spacing(10)
+            .push(radio(0, Some(1)))
+            .push(radio(1, Some(1)))
+            .push(radio(2, Some(1)));
+
+        let mut harness = Harness::new(group, Size::new(500.0, 100.0));
+        harness.press_key(KeyCode::Right);
+        assert!(harness.messages().is_empty());
+
+        harness.click(Point::new(5.0, 5.0));
+        harness.press_key(KeyCode::Right);
+        harness.press_key(KeyCode::Left);
+        harness.press_key(KeyCode::Down);
+
+        assert_eq!(harness.into_messages(), vec![0, 2, 0]);
+        assert!(state.is_focused());
+    }
+
+    #[test]
+    fn label_can_be_excluded_from_clicks() {
+        let radio = |is_label_clickable| {
+            radio(0, None)
+                .width(Length::Units(200))
+                .label_clickable(is_label_clickable)
+        };
+
+        let mut harness = Harness::new(radio(true), Size::new(200.0, 100.0));
+        harness.click(Point::new(150.0, 5.0));
+        assert_eq!(harness.into_messages(), vec![0]);
+
+        let mut harness = Harness::new(radio(false), Size::new(200.0, 100.0));
+        harness.click(Point::new(150.0, 5.0));
+        harness.click(Point::new(1.0, 5.0));
+        assert_eq!(harness.into_messages(), vec![0]);
+    }
+
+    #[test]
+    fn new_selections_start_a_transition() {
+        fn group(state: &mut State, selected: Option<u8>) {
+            let _ = RadioGroup::<'_, u8, Null>::new(state)
+                .push(radio(0, selected))
+                .push(radio(1, selected));
+        }
+
+        let mut state = State::new();
+
+        group(&mut state, Some(0));
+        assert_eq!(state.transition, None);
+
+        group(&mut state, Some(0));
+        assert_eq!(state.transition, None);
+
+        group(&mut state, Some(1));
+        assert_eq!(state.selected, Some(1));
+        assert_eq!(
+            state.transition.map(|(previous, _)| previous),
+            Some(Some(0))
+        );
+    }
+}
@@ -0,0 +1,316 @@
+//! Build content that adapts to the space available.
+//!
+//! A [`Responsive`] has some local [`State`].
+//!
+//! [`Responsive`]: struct.Responsive.html
+//! [`State`]: struct.State.html
+use std::cell::{Cell, RefCell, RefMut};
+use std::hash::Hash;
+
+use crate::focus::Focusable;
+use crate::{
+    event, layout, overlay, Clipboard, Element, Event, Hasher, Layout, Length,
+    Point, Rectangle, Size, Widget,
+};
+
+/// An element that builds its content from the [`Size`] available to it.
+///
+/// This allows layouts to restructure themselves depending on the space
+/// they are given, like switching a row to a column on narrow windows.
+///
+/// By default, a [`Responsive`] fills all the space available.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{renderer::Null, responsive, Column, Row, Text};
+/// #
+/// # type Responsive<'a, Message> =
+/// #     iced_native::Responsive<'a, Message, Null>;
+/// #
+/// let mut state = responsive::State::new();
+///
+/// let responsive: Responsive<'_, ()> = Responsive::new(&mut state, |size| {
+///     let items = vec![Text::new("Left").into(), Text::new("Right").into()];
+///
+///     if size.width < 400.0 {
+///         Column::with_children(items).into()
+///     } else {
+///         Row::with_children(items).into()
+///     }
+/// });
+/// ```
+///
+/// [`Size`]: ../../struct.Size.html
+/// [`Responsive`]: struct.Responsive.html
+#[allow(missing_debug_implementations)]
+pub struct Responsive<'a, Message, Renderer> {
+    state: &'a mut State,
+    view: Box<dyn Fn(Size) -> Element<'a, Message, Renderer> + 'a>,
+    width: Length,
+    height: Length,
+    content: RefCell<Option<(Size, Element<'a, Message, Renderer>)>>,
+}
+
+/// The local state of a [`Responsive`].
+///
+/// It remembers the [`Size`] the content was last built for, so it can be
+/// built again before computing the layout.
+///
+/// [`Responsive`]: struct.Responsive.html
+/// [`Size`]: ../../struct.Size.html
+#[derive(Debug, Clone)]
+pub struct State {
+    size: Cell<Size>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State {
+            size: Cell::new(Size::ZERO),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Responsive<'a, Message, Renderer> {
+    /// Creates a new [`Responsive`] with the given [`State`] and a function
+    /// that builds its content from the [`Size`] available.
+    ///
+    /// The function may be called multiple times per frame, whenever the
+    /// available space changes.
+    ///
+    /// [`Responsive`]: struct.Responsive.html
+    /// [`State`]: struct.State.html
+    /// [`Size`]: ../../struct.Size.html
+    pub fn new(
+        state: &'a mut State,
+        view: impl Fn(Size) -> Element<'a, Message, Renderer> + 'a,
+    ) -> Self {
+        Responsive {
+            state,
+            view: Box::new(view),
+            width: Length::Fill,
+            height: Length::Fill,
+            content: RefCell::new(None),
+        }
+    }
+
+    /// Sets the width of the [`Responsive`].
+    ///
+    /// [`Responsive`]: struct.Responsive.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Responsive`].
+    ///
+    /// [`Responsive`]: struct.Responsive.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Returns the content of the [`Responsive`] for the given [`Size`],
+    /// building it again only if the size changed.
+    ///
+    /// [`Responsive`]: struct.Responsive.html
+    /// [`Size`]: ../../struct.Size.html
+    fn content(
+        &self,
+        size: Size,
+    ) -> RefMut<'_, Element<'a, Message, Renderer>> {
+        RefMut::map(self.content.borrow_mut(), |content| {
+            if matches!(content, Some((built, _)) if *built != size) {
+                *content = None;
+            }
+
+            let (_, element) =
+                content.get_or_insert_with(|| (size, (self.view)(size)));
+
+            element
+        })
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Responsive<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(Size::ZERO);
+
+        self.state.size.set(size);
+
+        let content = self
+            .content(size)
+            .layout(renderer, &layout::Limits::new(Size::ZERO, size));
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        self.content(layout.bounds().size()).widget.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.content(layout.bounds().size()).draw(
+            renderer,
+            defaults,
+            layout.children().next().unwrap(),
+            cursor_position,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+
+        // The layout is only computed when the hash changes, so the content
+        // needs to be built beforehand with the last known size
+        self.content(self.state.size.get()).hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let size = layout.bounds().size();
+        let _ = self.content(size);
+
+        let (_, content) = self.content.get_mut().as_mut()?;
+
+        content.overlay(layout.children().next().unwrap())
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.content(layout.bounds().size())
+            .focused_bounds(layout.children().next().unwrap())
+    }
+
+    fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        messages: &mut Vec<Message>,
+    ) {
+        if let Some((_, content)) = self.content.get_mut() {
+            content.focusables(visit, messages);
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Responsive<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        responsive: Responsive<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(responsive)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::testing::Harness;
+    use crate::{Column, Row, Text};
+
+    fn view<'a>(size: Size) -> Element<'a, (), Null> {
+        let items = vec![
+            Text::new("").width(Length::Units(100)).into(),
+            Text::new("").width(Length::Units(100)).into(),
+        ];
+
+        if size.width < 200.0 {
+            Column::with_children(items).into()
+        } else {
+            Row::with_children(items).into()
+        }
+    }
+
+    #[test]
+    fn content_is_built_for_the_available_size() {
+        let mut state = State::new();
+        let harness = Harness::new(
+            Responsive::new(&mut state, view),
+            Size::new(150.0, 100.0),
+        );
+
+        assert_eq!(
+            harness.snapshot().lines().collect::<Vec<_>>(),
+            vec![
+                "Responsive (0, 0) 150x100",
+                "  Column (0, 0) 100x40",
+                "    Text (0, 0) 100x20",
+                "    Text (0, 20) 100x20",
+            ]
+        );
+
+        let mut state = State::new();
+        let harness = Harness::new(
+            Responsive::new(&mut state, view),
+            Size::new(300.0, 100.0),
+        );
+
+        assert_eq!(
+            harness.snapshot().lines().collect::<Vec<_>>(),
+            vec![
+                "Responsive (0, 0) 300x100",
+                "  Row (0, 0) 200x20",
+                "    Text (0, 0) 100x20",
+                "    Text (100, 0) 100x20",
+            ]
+        );
+    }
+}
@@ -0,0 +1,363 @@
+//! Write paragraphs of text made of differently styled spans.
+use crate::{
+    layout, text, Color, Element, Hasher, HorizontalAlignment, Layout, Length,
+    Point, Rectangle, Size, VerticalAlignment, Widget,
+};
+
+use std::hash::Hash;
+
+/// A paragraph of text made of multiple [`Span`]s.
+///
+/// The spans are laid out together, wrapping as a single flowing paragraph.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{rich_text::Span, Color};
+/// # type RichText = iced_native::RichText<iced_native::renderer::Null>;
+/// #
+/// RichText::new()
+///     .push("I ")
+///     .push(Span::new("<3").color(Color::from_rgb(1.0, 0.0, 0.0)))
+///     .push(Span::new(" iced!").size(40))
+///     .size(20);
+/// ```
+///
+/// [`Span`]: struct.Span.html
+#[derive(Debug)]
+pub struct RichText<Renderer: self::Renderer> {
+    spans: Vec<Span<Renderer::Font>>,
+    size: Option<u16>,
+    width: Length,
+    height: Length,
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+}
+
+impl<Renderer: self::Renderer> RichText<Renderer> {
+    /// Creates an empty [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn new() -> Self {
+        Self::with_spans(Vec::new())
+    }
+
+    /// Creates a [`RichText`] with the given [`Span`]s.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`Span`]: struct.Span.html
+    pub fn with_spans(spans: Vec<Span<Renderer::Font>>) -> Self {
+        RichText {
+            spans,
+            size: None,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Top,
+        }
+    }
+
+    /// Adds a [`Span`] to the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`Span`]: struct.Span.html
+    pub fn push(mut self, span: impl Into<Span<Renderer::Font>>) -> Self {
+        self.spans.push(span.into());
+        self
+    }
+
+    /// Sets the default size of the [`RichText`].
+    ///
+    /// Spans without a specific size will use this size.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the width of the [`RichText`] boundaries.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`RichText`] boundaries.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`HorizontalAlignment`] of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`HorizontalAlignment`]: ../../enum.HorizontalAlignment.html
+    pub fn horizontal_alignment(
+        mut self,
+        alignment: HorizontalAlignment,
+    ) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the [`VerticalAlignment`] of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`VerticalAlignment`]: ../../enum.VerticalAlignment.html
+    pub fn vertical_alignment(mut self, alignment: VerticalAlignment) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+}
+
+impl<Renderer: self::Renderer> Default for RichText<Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A fragment of text with its own styling inside a [`RichText`].
+///
+/// Any property left unset is inherited from the [`RichText`].
+///
+/// [`RichText`]: struct.RichText.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span<Font = crate::Font> {
+    /// The contents of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    pub content: String,
+
+    /// The size of the [`Span`], if any.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub size: Option<u16>,
+
+    /// The [`Color`] of the [`Span`], if any.
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`Color`]: ../../struct.Color.html
+    pub color: Option<Color>,
+
+    /// The font of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    pub font: Font,
+}
+
+impl<Font: Default> Span<Font> {
+    /// Creates a new [`Span`] with the given contents.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn new(content: impl Into<String>) -> Self {
+        Span {
+            content: content.into(),
+            size: None,
+            color: None,
+            font: Font::default(),
+        }
+    }
+
+    /// Sets the size of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`Color`]: ../../struct.Color.html
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the font of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+}
+
+impl<Font: Default> From<&str> for Span<Font> {
+    fn from(content: &str) -> Self {
+        Span::new(content)
+    }
+}
+
+impl<Font: Default> From<String> for Span<Font> {
+    fn from(content: String) -> Self {
+        Span::new(content)
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for RichText<Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = self.size.unwrap_or(renderer.default_size());
+
+        let bounds = limits.max();
+
+        let (width, height) = renderer.measure_spans(&self.spans, size, bounds);
+
+        let size = limits.resolve(Size::new(width, height));
+
+        layout::Node::new(size)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            defaults,
+            layout.bounds(),
+            &self.spans,
+            self.size.unwrap_or(renderer.default_size()),
+            self.horizontal_alignment,
+            self.vertical_alignment,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        for span in &self.spans {
+            span.content.hash(state);
+            span.size.hash(state);
+        }
+
+        self.size.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`RichText`] paragraph.
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use [`RichText`] in your [`UserInterface`].
+///
+/// [`RichText`]: struct.RichText.html
+/// [renderer]: ../../renderer/index.html
+/// [`UserInterface`]: ../../struct.UserInterface.html
+pub trait Renderer: text::Renderer {
+    /// Measures the given [`Span`]s laid out together in the given bounds and
+    /// returns the minimum boundaries that can fit the contents.
+    ///
+    /// The `size` is used for any [`Span`] without a specific size.
+    ///
+    /// [`Span`]: struct.Span.html
+    fn measure_spans(
+        &self,
+        spans: &[Span<Self::Font>],
+        size: u16,
+        bounds: Size,
+    ) -> (f32, f32);
+
+    /// Draws a [`RichText`] paragraph.
+    ///
+    /// It receives:
+    ///   * the bounds of the [`RichText`]
+    ///   * the [`Span`]s of the [`RichText`]
+    ///   * the default size of the [`Span`]s
+    ///   * the [`HorizontalAlignment`] of the [`RichText`]
+    ///   * the [`VerticalAlignment`] of the [`RichText`]
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`Span`]: struct.Span.html
+    /// [`HorizontalAlignment`]: ../../enum.HorizontalAlignment.html
+    /// [`VerticalAlignment`]: ../../enum.VerticalAlignment.html
+    fn draw(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        spans: &[Span<Self::Font>],
+        size: u16,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<RichText<Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer + 'a,
+{
+    fn from(rich_text: RichText<Renderer>) -> Element<'a, Message, Renderer> {
+        Element::new(rich_text)
+    }
+}
+
+impl<Renderer: self::Renderer> Clone for RichText<Renderer> {
+    fn clone(&self) -> Self {
+        Self {
+            spans: self.spans.clone(),
+            size: self.size,
+            width: self.width,
+            height: self.height,
+            horizontal_alignment: self.horizontal_alignment,
+            vertical_alignment: self.vertical_alignment,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+
+    use std::hash::Hasher as _;
+
+    fn layout_hash(rich_text: &RichText<Null>) -> u64 {
+        let mut hasher = Hasher::default();
+        Widget::<(), Null>::hash_layout(rich_text, &mut hasher);
+
+        hasher.finish()
+    }
+
+    #[test]
+    fn only_the_layout_of_spans_changes_the_hash() {
+        let rich_text = || RichText::<Null>::new().push("I ").push("<3");
+
+        let recolored =
+            rich_text().push(Span::new(" iced!").color(Color::BLACK));
+        let resized = rich_text().push(Span::new(" iced!").size(40));
+
+        assert_eq!(
+            layout_hash(&rich_text().push(" iced!")),
+            layout_hash(&recolored)
+        );
+        assert_ne!(layout_hash(&recolored), layout_hash(&resized));
+    }
+}
@@ -1,18 +1,25 @@
 //! Distribute content horizontally.
 use std::hash::Hash;
 
+use crate::focus::Focusable;
 use crate::{
-    layout, overlay, Align, Clipboard, Element, Event, Hasher, Layout, Length,
-    Point, Widget,
+    event, layout, overlay, Align, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Spacing, Widget,
 };
 
 use std::u32;
 
 /// A container that distributes its contents horizontally.
+///
+/// A [`Row`] never grows past the limits of its parent, even if it shrinks
+/// to fit its contents. The children that do not fit overflow the [`Row`]
+/// without being clipped, which is logged when the `debug` feature is enabled.
+///
+/// [`Row`]: struct.Row.html
 #[allow(missing_debug_implementations)]
 pub struct Row<'a, Message, Renderer> {
-    spacing: u16,
-    padding: u16,
+    spacing: Spacing,
+    padding: Spacing,
     width: Length,
     height: Length,
     max_width: u32,
@@ -36,8 +43,8 @@ impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
         children: Vec<Element<'a, Message, Renderer>>,
     ) -> Self {
         Row {
-            spacing: 0,
-            padding: 0,
+            spacing: Spacing::Units(0),
+            padding: Spacing::Units(0),
             width: Length::Shrink,
             height: Length::Shrink,
             max_width: u32::MAX,
@@ -52,16 +59,23 @@ impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
     /// Custom margins per element do not exist in Iced. You should use this
     /// method instead! While less flexible, it helps you keep spacing between
     /// elements consistent.
-    pub fn spacing(mut self, units: u16) -> Self {
-        self.spacing = units;
+    ///
+    /// A [`Spacing::Relative`] amount is relative to the available width.
+    ///
+    /// [`Spacing::Relative`]: ../../enum.Spacing.html#variant.Relative
+    pub fn spacing(mut self, spacing: impl Into<Spacing>) -> Self {
+        self.spacing = spacing.into();
         self
     }
 
     /// Sets the padding of the [`Row`].
     ///
+    /// A [`Spacing::Relative`] amount is relative to the available width.
+    ///
     /// [`Row`]: struct.Row.html
-    pub fn padding(mut self, units: u16) -> Self {
-        self.padding = units;
+    /// [`Spacing::Relative`]: ../../enum.Spacing.html#variant.Relative
+    pub fn padding(mut self, padding: impl Into<Spacing>) -> Self {
+        self.padding = padding.into();
         self
     }
 
@@ -99,7 +113,11 @@ impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
 
     /// Sets the vertical alignment of the contents of the [`Row`] .
     ///
+    /// Use [`Align::Baseline`] to line up the text of contents with
+    /// different sizes.
+    ///
     /// [`Row`]: struct.Row.html
+    /// [`Align::Baseline`]: ../../enum.Align.html#variant.Baseline
     pub fn align_items(mut self, align: Align) -> Self {
         self.align_items = align;
         self
@@ -131,6 +149,16 @@ where
         self.height
     }
 
+    fn min_size(&self, renderer: &Renderer) -> Size {
+        layout::flex::min_size(
+            layout::flex::Axis::Horizontal,
+            renderer,
+            self.padding.resolve(f32::INFINITY),
+            self.spacing.resolve(f32::INFINITY),
+            &self.children,
+        )
+    }
+
     fn layout(
         &self,
         renderer: &Renderer,
@@ -146,8 +174,8 @@ where
             layout::flex::Axis::Horizontal,
             renderer,
             &limits,
-            self.padding as f32,
-            self.spacing as f32,
+            self.padding.resolve(limits.max().width),
+            self.spacing.resolve(limits.max().width),
             self.align_items,
             &self.children,
         )
@@ -161,9 +189,11 @@ where
         messages: &mut Vec<Message>,
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>,
-    ) {
-        self.children.iter_mut().zip(layout.children()).for_each(
-            |(child, layout)| {
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .map(|(child, layout)| {
                 child.widget.on_event(
                     event.clone(),
                     layout,
@@ -172,8 +202,18 @@ where
                     renderer,
                     clipboard,
                 )
-            },
-        );
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn layout_changed(
+        &mut self,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+    ) {
+        for (child, layout) in self.children.iter_mut().zip(layout.children()) {
+            child.layout_changed(layout, messages);
+        }
     }
 
     fn draw(
@@ -213,6 +253,23 @@ where
             .filter_map(|(child, layout)| child.widget.overlay(layout))
             .next()
     }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.children
+            .iter()
+            .zip(layout.children())
+            .find_map(|(child, layout)| child.focused_bounds(layout))
+    }
+
+    fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        messages: &mut Vec<Message>,
+    ) {
+        for child in &mut self.children {
+            child.focusables(visit, messages);
+        }
+    }
 }
 
 /// The renderer of a [`Row`].
@@ -251,3 +308,118 @@ where
         Element::new(row)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::testing::snapshot;
+    use crate::{Column, Container, Size, Text, VerticalAlignment};
+
+    #[test]
+    fn text_of_different_sizes_aligns_on_baseline() {
+        let row: Element<'_, (), Null> = Row::new()
+            .align_items(Align::Baseline)
+            .push(Text::new("Big").size(40).width(Length::Units(10)))
+            .push(Text::new("Small").size(20).width(Length::Units(10)))
+            .push(
+                Text::new("Centered")
+                    .size(20)
+                    .width(Length::Units(10))
+                    .height(Length::Units(60))
+                    .vertical_alignment(VerticalAlignment::Center),
+            )
+            .into();
+
+        let node = row.layout(
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+
+        assert_eq!(
+            snapshot(&node).lines().collect::<Vec<_>>(),
+            vec![
+                "Row (0, 0) 30x60",
+                "  Text (0, 4) 10x20",
+                "  Text (10, 20) 10x20",
+                "  Text (20, 0) 10x60",
+            ]
+        );
+        assert_eq!(node.baseline(), Some(36.0));
+    }
+
+    #[test]
+    fn columns_and_containers_align_on_their_first_baseline() {
+        let row: Element<'_, (), Null> = Row::new()
+            .align_items(Align::Baseline)
+            .push(Text::new("Big").size(40).width(Length::Units(10)))
+            .push(
+                Column::new()
+                    .push(Text::new("First").size(20).width(Length::Units(10)))
+                    .push(
+                        Text::new("Second").size(20).width(Length::Units(10)),
+                    ),
+            )
+            .push(
+                Container::new(
+                    Text::new("Padded").size(20).width(Length::Units(10)),
+                )
+                .padding(10),
+            )
+            .into();
+
+        let node = row.layout(
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+
+        assert_eq!(
+            snapshot(&node).lines().collect::<Vec<_>>(),
+            vec![
+                "Row (0, 0) 50x56",
+                "  Text (0, 0) 10x20",
+                "  Column (10, 16) 10x40",
+                "    Text (10, 16) 10x20",
+                "    Text (10, 36) 10x20",
+                "  Container (20, 6) 30x40",
+                "    Text (30, 16) 10x20",
+            ]
+        );
+        assert_eq!(node.baseline(), Some(32.0));
+    }
+
+    #[test]
+    fn bounded_children_give_space_to_the_others() {
+        let row: Element<'_, (), Null> = Row::new()
+            .width(Length::Fill)
+            .push(
+                Container::new(Text::new(""))
+                    .width(Length::Fill)
+                    .min_width(200),
+            )
+            .push(Text::new("").width(Length::Fill))
+            .push(
+                Container::new(Text::new(""))
+                    .width(Length::Fill)
+                    .max_width(50),
+            )
+            .into();
+
+        let node = row.layout(
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(500.0, 200.0)),
+        );
+
+        assert_eq!(
+            snapshot(&node).lines().collect::<Vec<_>>(),
+            vec![
+                "Row (0, 0) 500x20",
+                "  Container (0, 0) 200x20",
+                "    Text (0, 0) 0x20",
+                "  Text (200, 0) 250x20",
+                "  Container (450, 0) 50x20",
+                "    Text (450, 0) 0x20",
+            ]
+        );
+    }
+}
@@ -1,10 +1,42 @@
 //! Navigate an endless amount of content with a scrollbar.
+use crate::focus::Focusable;
 use crate::{
-    column, layout, mouse, overlay, Align, Clipboard, Column, Element, Event,
-    Hasher, Layout, Length, Point, Rectangle, Size, Vector, Widget,
+    column, event, layout, mouse, overlay, touch, Align, Clipboard, Column,
+    Element, Event, Hasher, Layout, Length, Point, Rectangle, Size, Vector,
+    Widget,
 };
 
-use std::{f32, hash::Hash, u32};
+use std::{
+    f32,
+    hash::{Hash, Hasher as _},
+    time::{Duration, Instant},
+    u32,
+};
+
+/// The amount of units a [`Scrollable`] scrolls per line of a
+/// [`ScrollDelta::Lines`].
+///
+/// [`Scrollable`]: struct.Scrollable.html
+/// [`ScrollDelta::Lines`]: ../../mouse/enum.ScrollDelta.html#variant.Lines
+pub const LINE_HEIGHT: f32 = 60.0;
+
+/// The time it takes the speed of a [`Scrollable`] released after being
+/// dragged to decay by about two thirds.
+///
+/// [`Scrollable`]: struct.Scrollable.html
+const FLING_TIME_CONSTANT: Duration = Duration::from_millis(325);
+
+/// The speed below which a [`Scrollable`] released after being dragged stops,
+/// in units per second.
+///
+/// [`Scrollable`]: struct.Scrollable.html
+const FLING_MIN_VELOCITY: f32 = 10.0;
+
+/// The maximum speed of a [`Scrollable`] released after being dragged, in
+/// units per second.
+///
+/// [`Scrollable`]: struct.Scrollable.html
+const FLING_MAX_VELOCITY: f32 = 5000.0;
 
 /// A widget that can vertically display an infinite amount of content with a
 /// scrollbar.
@@ -13,21 +45,104 @@ pub struct Scrollable<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     height: Length,
     max_height: u32,
+    auto_hide: Option<AutoHide>,
+    scroll_to_focus: bool,
+    anchor_to_visible: bool,
+    scrollbar_side: Side,
+    drag_to_scroll: bool,
     content: Column<'a, Message, Renderer>,
+    keys: Vec<Option<u64>>,
     style: Renderer::Style,
 }
 
+/// The timing of a [`Scrollable`] with a scrollbar that hides itself after
+/// some inactivity.
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AutoHide {
+    /// The time the scrollbar stays fully visible after the last
+    /// interaction.
+    pub delay: Duration,
+
+    /// The time the scrollbar takes to fade out once the delay has passed.
+    pub fade_out: Duration,
+}
+
+impl Default for AutoHide {
+    fn default() -> Self {
+        AutoHide {
+            delay: Duration::from_millis(1000),
+            fade_out: Duration::from_millis(300),
+        }
+    }
+}
+
+/// The side of a [`Scrollable`] where its scrollbar is placed.
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    /// The scrollbar is placed on the left, like in right-to-left layouts.
+    Left,
+
+    /// The scrollbar is placed on the right.
+    Right,
+}
+
+impl Default for Side {
+    fn default() -> Self {
+        Side::Right
+    }
+}
+
+impl AutoHide {
+    fn alpha(&self, elapsed: Duration) -> f32 {
+        if elapsed <= self.delay {
+            1.0
+        } else if elapsed >= self.delay + self.fade_out {
+            0.0
+        } else {
+            1.0 - (elapsed - self.delay).as_secs_f32()
+                / self.fade_out.as_secs_f32()
+        }
+    }
+}
+
 impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
     /// Creates a new [`Scrollable`] with the given [`State`].
     ///
     /// [`Scrollable`]: struct.Scrollable.html
     /// [`State`]: struct.State.html
     pub fn new(state: &'a mut State) -> Self {
+        Self::with_column(state, Column::new())
+    }
+
+    /// Creates a new [`Scrollable`] with the given [`State`], displaying the
+    /// given [`Column`].
+    ///
+    /// The [`Column`] is laid out without a height limit, so it always fits
+    /// its contents. The part that does not fit in the [`Scrollable`] is
+    /// clipped and can be scrolled into view.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`State`]: struct.State.html
+    /// [`Column`]: ../column/struct.Column.html
+    pub fn with_column(
+        state: &'a mut State,
+        column: Column<'a, Message, Renderer>,
+    ) -> Self {
         Scrollable {
             state,
             height: Length::Shrink,
             max_height: u32::MAX,
-            content: Column::new(),
+            auto_hide: None,
+            scroll_to_focus: false,
+            anchor_to_visible: false,
+            scrollbar_side: Side::default(),
+            drag_to_scroll: false,
+            content: column,
+            keys: Vec::new(),
             style: Renderer::Style::default(),
         }
     }
@@ -44,6 +159,12 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
 
     /// Sets the padding of the [`Scrollable`].
     ///
+    /// The padding insets the contents inside the scrolled area, so they keep
+    /// some room from the edges of the viewport when scrolled to the very
+    /// top or bottom. The padding is part of the scrolled contents: it counts
+    /// towards the maximum offset and the scrollbar is still drawn at the
+    /// edge of the viewport.
+    ///
     /// [`Scrollable`]: struct.Scrollable.html
     pub fn padding(mut self, units: u16) -> Self {
         self.content = self.content.padding(units);
@@ -82,6 +203,90 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
         self
     }
 
+    /// Makes the scrollbar of the [`Scrollable`] fade out after some
+    /// inactivity, using the given [`AutoHide`] timing.
+    ///
+    /// The scrollbar reappears when scrolling or hovering it. The scrollbar
+    /// never reserves any layout space, so the contents do not move when it
+    /// hides.
+    ///
+    /// The scrollbar is shown when the [`Scrollable`] first appears. It
+    /// requests redraws until the scrollbar is hidden, so the fade out
+    /// animates on its own.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`AutoHide`]: struct.AutoHide.html
+    pub fn auto_hide(mut self, auto_hide: AutoHide) -> Self {
+        let _ = self.state.last_activity.get_or_insert_with(Instant::now);
+
+        self.auto_hide = Some(auto_hide);
+        self
+    }
+
+    /// Sets whether the [`Scrollable`] should scroll to reveal a child when
+    /// it gains focus.
+    ///
+    /// This is disabled by default.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn scroll_to_focus(mut self, scroll_to_focus: bool) -> Self {
+        self.scroll_to_focus = scroll_to_focus;
+        self
+    }
+
+    /// Sets whether the [`Scrollable`] should keep the child at the top of
+    /// its view in place when the size of the contents changes.
+    ///
+    /// By default, the [`Scrollable`] keeps its scrolling offset, so the view
+    /// jumps when a child above it grows or shrinks. When anchored, the
+    /// offset follows the first visible child instead, which is what dynamic
+    /// lists like chats and logs usually need.
+    ///
+    /// Children added with [`push_keyed`] are found again by their key, so
+    /// the view stays in place even when children are inserted or removed
+    /// above it. Other children are found again by their position.
+    ///
+    /// This is disabled by default.
+    ///
+    /// [`push_keyed`]: #method.push_keyed
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn anchor_to_visible(mut self, anchor_to_visible: bool) -> Self {
+        self.anchor_to_visible = anchor_to_visible;
+        self
+    }
+
+    /// Sets the [`Side`] of the [`Scrollable`] where its scrollbar is placed.
+    ///
+    /// By default, the scrollbar is placed on the right. On either side, the
+    /// scrollbar is drawn on top of the contents without moving them.
+    ///
+    /// [`Side`]: enum.Side.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn scrollbar_side(mut self, side: Side) -> Self {
+        self.scrollbar_side = side;
+        self
+    }
+
+    /// Sets whether the contents of the [`Scrollable`] can be dragged with
+    /// the mouse to scroll them.
+    ///
+    /// The contents only start following the mouse once it moves past the
+    /// [`mouse::drag::THRESHOLD`], so the children can still be clicked.
+    /// Releasing the contents while dragging them keeps them scrolling for a
+    /// moment, slowing down until they stop.
+    ///
+    /// This is disabled by default, since it takes over the drags of any
+    /// child that handles them on its own, like a [`Slider`].
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`mouse::drag::THRESHOLD`]: ../../mouse/drag/constant.THRESHOLD.html
+    /// [`Slider`]: ../slider/struct.Slider.html
+    pub fn drag_to_scroll(mut self, drag_to_scroll: bool) -> Self {
+        self.drag_to_scroll = drag_to_scroll;
+        self
+    }
+
     /// Sets the horizontal alignment of the contents of the [`Scrollable`] .
     ///
     /// [`Scrollable`]: struct.Scrollable.html
@@ -106,8 +311,137 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
         E: Into<Element<'a, Message, Renderer>>,
     {
         self.content = self.content.push(child);
+        self.keys.push(None);
         self
     }
+
+    /// Adds an element to the [`Scrollable`] identified by the given key.
+    ///
+    /// The key is used to keep the view anchored to the element when
+    /// [`anchor_to_visible`] is set, and should be unique among the children
+    /// of the [`Scrollable`].
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`anchor_to_visible`]: #method.anchor_to_visible
+    pub fn push_keyed<K, E>(mut self, key: K, child: E) -> Self
+    where
+        K: Hash,
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        let mut hasher = Hasher::default();
+        key.hash(&mut hasher);
+
+        self.content = self.content.push(child);
+        self.keys.push(Some(hasher.finish()));
+        self
+    }
+
+    /// Returns the [`Scrollbar`] of the [`Scrollable`], placed on its
+    /// [`Side`].
+    ///
+    /// [`Scrollbar`]: struct.Scrollbar.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`Side`]: enum.Side.html
+    fn scrollbar(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        offset: u32,
+    ) -> Option<Scrollbar> {
+        let scrollbar = renderer.scrollbar(bounds, content_bounds, offset)?;
+
+        Some(match self.scrollbar_side {
+            Side::Right => scrollbar,
+            Side::Left => scrollbar.mirrored(bounds),
+        })
+    }
+
+    /// Returns the [`State`] of the [`Scrollable`] with its offset following
+    /// the anchored child in the given layout of the contents, if any.
+    ///
+    /// A child that gained focus since the last event is revealed, so it is
+    /// visible as soon as it is drawn. The contents keep scrolling while they
+    /// are flung.
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    fn anchored(&self, bounds: Rectangle, content: Layout<'_>) -> State {
+        let mut state = *self.state;
+
+        if let (true, Some(anchor)) = (self.anchor_to_visible, state.anchor) {
+            let index = match anchor.key {
+                Some(key) => {
+                    self.keys.iter().position(|other| *other == Some(key))
+                }
+                None => Some(anchor.index),
+            };
+
+            if let Some(child) =
+                index.and_then(|index| content.children().nth(index))
+            {
+                state.offset =
+                    (child.bounds().y - content.bounds().y - anchor.top)
+                        .max(0.0);
+            }
+        }
+
+        if self.scroll_to_focus {
+            let focused = self.content.focused_bounds(content);
+
+            if focused != state.last_focused {
+                if let Some(focused) = focused {
+                    state.scroll_into_view(focused, bounds, content.bounds());
+                }
+
+                state.last_focused = focused;
+            }
+        }
+
+        if let Some(fling) = state.fling {
+            let elapsed = fling.started_at.elapsed();
+            let hidden_content =
+                (content.bounds().height - bounds.height).max(0.0);
+            let offset = fling.offset_at(elapsed);
+
+            state.offset = offset.clamp(0.0, hidden_content);
+
+            // The fling stops at the edges of the contents
+            if fling.is_finished(elapsed) || offset != state.offset {
+                state.fling = None;
+            }
+        }
+
+        state
+    }
+
+    /// Anchors the [`Scrollable`] to the first child visible at the top of
+    /// its view.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    fn update_anchor(&mut self, bounds: Rectangle, content: Layout<'_>) {
+        if !self.anchor_to_visible {
+            return;
+        }
+
+        let content_bounds = content.bounds();
+        let hidden_content = (content_bounds.height - bounds.height).max(0.0);
+        let top = content_bounds.y + self.state.offset.min(hidden_content);
+
+        self.state.anchor = content
+            .children()
+            .enumerate()
+            .find(|(_, child)| {
+                let child = child.bounds();
+
+                child.y + child.height > top
+            })
+            .map(|(index, child)| Anchor {
+                key: self.keys.get(index).copied().flatten(),
+                index,
+                top: child.bounds().y - top,
+            });
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -123,6 +457,14 @@ where
         self.height
     }
 
+    fn min_size(&self, renderer: &Renderer) -> Size {
+        let content =
+            Widget::<Message, Renderer>::min_size(&self.content, renderer);
+
+        // The contents can always be scrolled vertically
+        Size::new(content.width, 0.0)
+    }
+
     fn layout(
         &self,
         renderer: &Renderer,
@@ -152,44 +494,37 @@ where
         messages: &mut Vec<Message>,
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>,
-    ) {
+    ) -> event::Status {
         let bounds = layout.bounds();
         let is_mouse_over = bounds.contains(cursor_position);
 
         let content = layout.children().next().unwrap();
         let content_bounds = content.bounds();
 
-        // TODO: Event capture. Nested scrollables should capture scroll events.
-        if is_mouse_over {
-            match event {
-                Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-                    match delta {
-                        mouse::ScrollDelta::Lines { y, .. } => {
-                            // TODO: Configurable speed (?)
-                            self.state.scroll(y * 60.0, bounds, content_bounds);
-                        }
-                        mouse::ScrollDelta::Pixels { y, .. } => {
-                            self.state.scroll(y, bounds, content_bounds);
-                        }
-                    }
-                }
-                _ => {}
-            }
-        }
+        // The contents may have changed since the last event
+        *self.state = self.anchored(bounds, content);
+
+        let mut status = event::Status::Ignored;
 
         let offset = self.state.offset(bounds, content_bounds);
-        let scrollbar = renderer.scrollbar(bounds, content_bounds, offset);
+        let scrollbar =
+            self.scrollbar(renderer, bounds, content_bounds, offset);
         let is_mouse_over_scrollbar = scrollbar
             .as_ref()
             .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
             .unwrap_or(false);
 
+        if is_mouse_over_scrollbar || self.state.is_scroller_grabbed() {
+            self.state.last_activity = Some(Instant::now());
+        }
+
         if self.state.is_scroller_grabbed() {
             match event {
                 Event::Mouse(mouse::Event::ButtonReleased(
                     mouse::Button::Left,
                 )) => {
                     self.state.scroller_grabbed_at = None;
+                    status = event::Status::Captured;
                 }
                 Event::Mouse(mouse::Event::CursorMoved { .. }) => {
                     if let (Some(scrollbar), Some(scroller_grabbed_at)) =
@@ -203,61 +538,261 @@ where
                             bounds,
                             content_bounds,
                         );
+
+                        status = event::Status::Captured;
                     }
                 }
                 _ => {}
             }
         } else if is_mouse_over_scrollbar {
-            match event {
-                Event::Mouse(mouse::Event::ButtonPressed(
-                    mouse::Button::Left,
-                )) => {
-                    if let Some(scrollbar) = scrollbar {
-                        if let Some(scroller_grabbed_at) =
-                            scrollbar.grab_scroller(cursor_position)
-                        {
-                            self.state.scroll_to(
-                                scrollbar.scroll_percentage(
-                                    scroller_grabbed_at,
-                                    cursor_position,
-                                ),
-                                bounds,
-                                content_bounds,
-                            );
+            if let Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Left,
+            )) = event
+            {
+                if let Some(scrollbar) = scrollbar {
+                    if let Some(scroller_grabbed_at) =
+                        scrollbar.grab_scroller(cursor_position)
+                    {
+                        self.state.scroll_to(
+                            scrollbar.scroll_percentage(
+                                scroller_grabbed_at,
+                                cursor_position,
+                            ),
+                            bounds,
+                            content_bounds,
+                        );
+
+                        self.state.scroller_grabbed_at =
+                            Some(scroller_grabbed_at);
 
-                            self.state.scroller_grabbed_at =
-                                Some(scroller_grabbed_at);
+                        status = event::Status::Captured;
+                    }
+                }
+            }
+        }
+
+        let mut is_dragging = false;
+
+        if let (true, false, Event::Mouse(mouse_event)) = (
+            self.drag_to_scroll,
+            self.state.is_scroller_grabbed(),
+            &event,
+        ) {
+            if let mouse::Event::ButtonPressed(_)
+            | mouse::Event::WheelScrolled { .. } = mouse_event
+            {
+                self.state.fling = None;
+            }
+
+            let was_dragging = self.state.drag.is_dragging();
+
+            match self.state.drag.update(
+                *mouse_event,
+                cursor_position,
+                is_mouse_over && !is_mouse_over_scrollbar,
+            ) {
+                Some(mouse::drag::Event::Started { origin, position }) => {
+                    self.state.scroll(
+                        position.y - origin.y,
+                        bounds,
+                        content_bounds,
+                    );
+
+                    self.state.drag_velocity = 0.0;
+                    self.state.last_drag_move = Some(Instant::now());
+                }
+                Some(mouse::drag::Event::Moved { delta, .. }) => {
+                    self.state.scroll(delta.y, bounds, content_bounds);
+
+                    let now = Instant::now();
+
+                    if let Some(last_drag_move) = self.state.last_drag_move {
+                        let elapsed =
+                            now.duration_since(last_drag_move).as_secs_f32();
+
+                        if elapsed > 0.0 {
+                            let velocity = (-delta.y / elapsed)
+                                .clamp(-FLING_MAX_VELOCITY, FLING_MAX_VELOCITY);
+
+                            self.state.drag_velocity =
+                                0.8 * velocity + 0.2 * self.state.drag_velocity;
                         }
                     }
+
+                    self.state.last_drag_move = Some(now);
                 }
-                _ => {}
+                Some(mouse::drag::Event::Ended { .. }) => {
+                    // The contents are not flung if the mouse stopped moving
+                    // before being released
+                    let is_moving = self
+                        .state
+                        .last_drag_move
+                        .map(|last_drag_move| {
+                            last_drag_move.elapsed()
+                                < Duration::from_millis(100)
+                        })
+                        .unwrap_or(false);
+
+                    if is_moving
+                        && self.state.drag_velocity.abs() > FLING_MIN_VELOCITY
+                    {
+                        self.state.fling = Some(Fling {
+                            offset: self.state.offset(bounds, content_bounds)
+                                as f32,
+                            velocity: self.state.drag_velocity,
+                            started_at: Instant::now(),
+                        });
+                    }
+
+                    self.state.last_drag_move = None;
+                }
+                None => {}
+            }
+
+            is_dragging = was_dragging || self.state.drag.is_dragging();
+
+            if is_dragging {
+                self.state.last_activity = Some(Instant::now());
+
+                status = event::Status::Captured;
             }
         }
 
-        let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
-            Point::new(
-                cursor_position.x,
-                cursor_position.y
-                    + self.state.offset(bounds, content_bounds) as f32,
-            )
-        } else {
-            // TODO: Make `cursor_position` an `Option<Point>` so we can encode
-            // cursor availability.
-            // This will probably happen naturally once we add multi-window
-            // support.
-            Point::new(cursor_position.x, -1.0)
+        // The children do not see the mouse while the contents are dragged,
+        // so releasing it does not click them
+        let cursor_position =
+            if is_mouse_over && !is_mouse_over_scrollbar && !is_dragging {
+                Point::new(
+                    cursor_position.x,
+                    cursor_position.y
+                        + self.state.offset(bounds, content_bounds) as f32,
+                )
+            } else {
+                // TODO: Make `cursor_position` an `Option<Point>` so we can encode
+                // cursor availability.
+                // This will probably happen naturally once we add multi-window
+                // support.
+                Point::new(cursor_position.x, -1.0)
+            };
+
+        // The contents are scrolled under the fingers too
+        let content_event = match event.clone() {
+            Event::Touch(touch_event) => Event::Touch(translate_touch(
+                touch_event,
+                Vector::new(
+                    0.0,
+                    self.state.offset(bounds, content_bounds) as f32,
+                ),
+            )),
+            event => event,
         };
 
-        self.content.on_event(
-            event,
+        let content_status = self.content.on_event(
+            content_event,
             content,
             cursor_position,
             messages,
             renderer,
             clipboard,
+        );
+
+        // Fingers are tracked even if the contents handle them, but nested
+        // scrollables pan first
+        if let Event::Touch(touch_event) = &event {
+            let touch_event = *touch_event;
+            let can_start = bounds.contains(touch_event.position());
+
+            let delta = match self.state.gestures.update(touch_event, can_start)
+            {
+                Some(touch::Gesture::Pan { delta, .. }) => Some(delta),
+                Some(touch::Gesture::Pinch { delta, .. }) => Some(delta),
+                _ => None,
+            };
+
+            if let (Some(delta), event::Status::Ignored) =
+                (delta, content_status)
+            {
+                let offset = self.state.offset(bounds, content_bounds);
+
+                self.state.scroll(delta.y, bounds, content_bounds);
+
+                if self.state.offset(bounds, content_bounds) != offset {
+                    self.state.last_activity = Some(Instant::now());
+
+                    status = event::Status::Captured;
+                }
+            }
+        }
+
+        // Nested scrollables scroll first, until they reach one of their
+        // edges and stop capturing the wheel
+        if let (
+            event::Status::Ignored,
+            true,
+            Event::Mouse(mouse::Event::WheelScrolled { delta }),
+        ) = (content_status, is_mouse_over, event)
+        {
+            let offset = self.state.offset(bounds, content_bounds);
+
+            self.state.scroll_by(delta, bounds, content_bounds);
+
+            if self.state.offset(bounds, content_bounds) != offset {
+                self.state.last_activity = Some(Instant::now());
+
+                status = event::Status::Captured;
+            }
+        }
+
+        // Focus may also change outside of event handling (e.g. when a new
+        // view is built with a focused `State`), so the last focused bounds
+        // are kept in the `State`
+        let focused = self.content.focused_bounds(content);
+
+        if focused != self.state.last_focused {
+            if let (true, Some(focused)) = (self.scroll_to_focus, focused) {
+                self.state.scroll_into_view(focused, bounds, content_bounds);
+            }
+
+            self.state.last_focused = focused;
+        }
+
+        self.update_anchor(bounds, content);
+
+        status.merge(content_status)
+    }
+
+    fn layout_changed(
+        &mut self,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+    ) {
+        Widget::<Message, Renderer>::layout_changed(
+            &mut self.content,
+            layout.children().next().unwrap(),
+            messages,
         )
     }
 
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        let bounds = layout.bounds();
+        let content = layout.children().next().unwrap();
+        let offset = self
+            .anchored(bounds, content)
+            .offset(bounds, content.bounds());
+
+        self.content
+            .focused_bounds(content)
+            .map(|focused| focused + Vector::new(0.0, -(offset as f32)))
+    }
+
+    fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        messages: &mut Vec<Message>,
+    ) {
+        self.content.focusables(visit, messages)
+    }
+
     fn draw(
         &self,
         renderer: &mut Renderer,
@@ -268,8 +803,15 @@ where
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
-        let offset = self.state.offset(bounds, content_bounds);
-        let scrollbar = renderer.scrollbar(bounds, content_bounds, offset);
+        let state = self.anchored(bounds, content_layout);
+        let offset = state.offset(bounds, content_bounds);
+
+        if state.fling.is_some() {
+            renderer.request_redraw();
+        }
+
+        let scrollbar =
+            self.scrollbar(renderer, bounds, content_bounds, offset);
 
         let is_mouse_over = bounds.contains(cursor_position);
         let is_mouse_over_scrollbar = scrollbar
@@ -277,6 +819,32 @@ where
             .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
             .unwrap_or(false);
 
+        let scrollbar_alpha = match self.auto_hide {
+            Some(auto_hide)
+                if !is_mouse_over_scrollbar && !state.is_scroller_grabbed() =>
+            {
+                match state.last_activity {
+                    Some(last_activity) => {
+                        let elapsed = last_activity.elapsed();
+
+                        // Sleep until the fade out starts, then animate it
+                        if elapsed < auto_hide.delay {
+                            renderer.request_redraw_at(
+                                last_activity + auto_hide.delay,
+                            );
+                        } else if elapsed < auto_hide.delay + auto_hide.fade_out
+                        {
+                            renderer.request_redraw();
+                        }
+
+                        auto_hide.alpha(elapsed)
+                    }
+                    None => 0.0,
+                }
+            }
+            _ => 1.0,
+        };
+
         let content = {
             let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
                 Point::new(cursor_position.x, cursor_position.y + offset as f32)
@@ -294,12 +862,13 @@ where
 
         self::Renderer::draw(
             renderer,
-            &self.state,
+            &state,
             bounds,
             content_layout.bounds(),
             is_mouse_over,
             is_mouse_over_scrollbar,
             scrollbar,
+            scrollbar_alpha,
             offset,
             &self.style,
             content,
@@ -320,6 +889,9 @@ where
         &mut self,
         layout: Layout<'_>,
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        *self.state =
+            self.anchored(layout.bounds(), layout.children().next().unwrap());
+
         let Self { content, state, .. } = self;
 
         content
@@ -335,6 +907,33 @@ where
     }
 }
 
+fn translate_touch(event: touch::Event, translation: Vector) -> touch::Event {
+    match event {
+        touch::Event::FingerPressed { id, position } => {
+            touch::Event::FingerPressed {
+                id,
+                position: position + translation,
+            }
+        }
+        touch::Event::FingerMoved { id, position } => {
+            touch::Event::FingerMoved {
+                id,
+                position: position + translation,
+            }
+        }
+        touch::Event::FingerLifted { id, position } => {
+            touch::Event::FingerLifted {
+                id,
+                position: position + translation,
+            }
+        }
+        touch::Event::FingerLost { id, position } => touch::Event::FingerLost {
+            id,
+            position: position + translation,
+        },
+    }
+}
+
 /// The local state of a [`Scrollable`].
 ///
 /// [`Scrollable`]: struct.Scrollable.html
@@ -342,6 +941,58 @@ where
 pub struct State {
     scroller_grabbed_at: Option<f32>,
     offset: f32,
+    last_activity: Option<Instant>,
+    last_focused: Option<Rectangle>,
+    anchor: Option<Anchor>,
+    gestures: touch::Gestures,
+    drag: mouse::Drag,
+    drag_velocity: f32,
+    last_drag_move: Option<Instant>,
+    fling: Option<Fling>,
+}
+
+/// A child of a [`Scrollable`] anchored to its view.
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Anchor {
+    /// The key of the child, if it has one.
+    key: Option<u64>,
+
+    /// The position of the child, used when it has no key.
+    index: usize,
+
+    /// The position of the top of the child relative to the top of the view.
+    top: f32,
+}
+
+/// The contents of a [`Scrollable`] scrolling on their own after being
+/// released while dragged.
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Fling {
+    offset: f32,
+
+    /// The initial speed of the offset, in units per second.
+    velocity: f32,
+    started_at: Instant,
+}
+
+impl Fling {
+    fn offset_at(&self, elapsed: Duration) -> f32 {
+        let time_constant = FLING_TIME_CONSTANT.as_secs_f32();
+        let decay = (-elapsed.as_secs_f32() / time_constant).exp();
+
+        self.offset + self.velocity * time_constant * (1.0 - decay)
+    }
+
+    fn is_finished(&self, elapsed: Duration) -> bool {
+        let decay =
+            (-elapsed.as_secs_f32() / FLING_TIME_CONSTANT.as_secs_f32()).exp();
+
+        (self.velocity * decay).abs() < FLING_MIN_VELOCITY
+    }
 }
 
 impl State {
@@ -372,6 +1023,35 @@ impl State {
             .min((content_bounds.height - bounds.height) as f32);
     }
 
+    /// Applies a mouse [`ScrollDelta`] to the scrolling position of the
+    /// [`State`], given the bounds of the [`Scrollable`] and its contents.
+    ///
+    /// [`ScrollDelta::Lines`] are produced by stepped mouse wheels and are
+    /// scaled to [`LINE_HEIGHT`] units per line. [`ScrollDelta::Pixels`] are
+    /// produced by trackpads and other precise devices and are applied as-is,
+    /// keeping fractional amounts, for smooth scrolling.
+    ///
+    /// [`ScrollDelta`]: ../../mouse/enum.ScrollDelta.html
+    /// [`ScrollDelta::Lines`]: ../../mouse/enum.ScrollDelta.html#variant.Lines
+    /// [`ScrollDelta::Pixels`]: ../../mouse/enum.ScrollDelta.html#variant.Pixels
+    /// [`LINE_HEIGHT`]: constant.LINE_HEIGHT.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`State`]: struct.State.html
+    pub fn scroll_by(
+        &mut self,
+        delta: mouse::ScrollDelta,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        let delta_y = match delta {
+            // TODO: Configurable speed (?)
+            mouse::ScrollDelta::Lines { y, .. } => y * LINE_HEIGHT,
+            mouse::ScrollDelta::Pixels { y, .. } => y,
+        };
+
+        self.scroll(delta_y, bounds, content_bounds);
+    }
+
     /// Moves the scroll position to a relative amount, given the bounds of
     /// the [`Scrollable`] and its contents.
     ///
@@ -390,6 +1070,32 @@ impl State {
             ((content_bounds.height - bounds.height) * percentage).max(0.0);
     }
 
+    /// Scrolls the least amount needed to fully reveal the given target,
+    /// given the bounds of the [`Scrollable`] and its contents.
+    ///
+    /// The target is expected in the coordinates of the unscrolled contents.
+    /// If it is taller than the [`Scrollable`], its top edge is revealed.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn scroll_into_view(
+        &mut self,
+        target: Rectangle,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        let hidden_content = (content_bounds.height - bounds.height).max(0.0);
+        let offset = self.offset(bounds, content_bounds) as f32;
+
+        let top = target.y - bounds.y;
+        let bottom = top + target.height - bounds.height;
+
+        if top < offset || target.height > bounds.height {
+            self.offset = top.clamp(0.0, hidden_content);
+        } else if bottom > offset {
+            self.offset = bottom.clamp(0.0, hidden_content);
+        }
+    }
+
     /// Returns the current scrolling offset of the [`State`], given the bounds
     /// of the [`Scrollable`] and its contents.
     ///
@@ -425,6 +1131,25 @@ pub struct Scrollbar {
 }
 
 impl Scrollbar {
+    /// Mirrors the [`Scrollbar`] horizontally inside the given bounds.
+    ///
+    /// [`Scrollbar`]: struct.Scrollbar.html
+    fn mirrored(self, bounds: Rectangle) -> Self {
+        let mirror = |rectangle: Rectangle| Rectangle {
+            x: bounds.x + bounds.x + bounds.width
+                - rectangle.x
+                - rectangle.width,
+            ..rectangle
+        };
+
+        Scrollbar {
+            bounds: mirror(self.bounds),
+            scroller: Scroller {
+                bounds: mirror(self.scroller.bounds),
+            },
+        }
+    }
+
     fn is_mouse_over(&self, cursor_position: Point) -> bool {
         self.bounds.contains(cursor_position)
     }
@@ -479,6 +1204,9 @@ pub trait Renderer: column::Renderer + Sized {
     /// Returns the [`Scrollbar`] given the bounds and content bounds of a
     /// [`Scrollable`].
     ///
+    /// The [`Scrollbar`] is placed on the right. A [`Scrollable`] with its
+    /// scrollbar on the left mirrors it, and draws the mirrored one.
+    ///
     /// [`Scrollbar`]: struct.Scrollbar.html
     /// [`Scrollable`]: struct.Scrollable.html
     fn scrollbar(
@@ -497,6 +1225,7 @@ pub trait Renderer: column::Renderer + Sized {
     /// - whether the mouse is over the [`Scrollable`] or not
     /// - whether the mouse is over the [`Scrollbar`] or not
     /// - a optional [`Scrollbar`] to be rendered
+    /// - the opacity of the [`Scrollbar`], from `0.0` (hidden) to `1.0`
     /// - the scrolling offset
     /// - the drawn content
     ///
@@ -511,6 +1240,7 @@ pub trait Renderer: column::Renderer + Sized {
         is_mouse_over: bool,
         is_mouse_over_scrollbar: bool,
         scrollbar: Option<Scrollbar>,
+        scrollbar_alpha: f32,
         offset: u32,
         style: &Self::Style,
         content: Self::Output,
@@ -529,3 +1259,512 @@ where
         Element::new(scrollable)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AutoHide, Fling, Scrollable, Scrollbar, Scroller, State,
+        FLING_TIME_CONSTANT, LINE_HEIGHT,
+    };
+    use crate::renderer::Null;
+    use crate::testing::Harness;
+    use crate::{
+        button, mouse, touch, Button, Column, Event, Length, Point, Rectangle,
+        Size, Text,
+    };
+    use std::time::{Duration, Instant};
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+
+    const CONTENT_BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 1000.0,
+    };
+
+    #[test]
+    fn lines_scroll_in_steps() {
+        let mut state = State::new();
+
+        state.scroll_by(
+            mouse::ScrollDelta::Lines { x: 0.0, y: -2.0 },
+            BOUNDS,
+            CONTENT_BOUNDS,
+        );
+
+        assert_eq!(state.offset, 2.0 * LINE_HEIGHT);
+
+        state.scroll_by(
+            mouse::ScrollDelta::Lines { x: 0.0, y: 1.0 },
+            BOUNDS,
+            CONTENT_BOUNDS,
+        );
+
+        assert_eq!(state.offset, LINE_HEIGHT);
+    }
+
+    #[test]
+    fn pixels_scroll_smoothly() {
+        let mut state = State::new();
+
+        for _ in 0..4 {
+            state.scroll_by(
+                mouse::ScrollDelta::Pixels { x: 0.0, y: -0.75 },
+                BOUNDS,
+                CONTENT_BOUNDS,
+            );
+        }
+
+        assert_eq!(state.offset, 3.0);
+        assert_eq!(state.offset(BOUNDS, CONTENT_BOUNDS), 3);
+    }
+
+    #[test]
+    fn scrolling_is_clamped_to_content() {
+        let mut state = State::new();
+
+        state.scroll_by(
+            mouse::ScrollDelta::Pixels { x: 0.0, y: 10.0 },
+            BOUNDS,
+            CONTENT_BOUNDS,
+        );
+
+        assert_eq!(state.offset, 0.0);
+
+        state.scroll_by(
+            mouse::ScrollDelta::Lines { x: 0.0, y: -100.0 },
+            BOUNDS,
+            CONTENT_BOUNDS,
+        );
+
+        assert_eq!(state.offset, CONTENT_BOUNDS.height - BOUNDS.height);
+    }
+
+    #[test]
+    fn anchored_view_follows_resized_contents() {
+        fn list(
+            state: &mut State,
+            first_height: u16,
+        ) -> Scrollable<'_, (), Null> {
+            Scrollable::new(state)
+                .width(Length::Fill)
+                .height(Length::Units(100))
+                .anchor_to_visible(true)
+                .push(Text::new("").height(Length::Units(first_height)))
+                .push(Text::new("").height(Length::Units(100)))
+                .push(Text::new("").height(Length::Units(100)))
+        }
+
+        let mut state = State::new();
+
+        let mut harness =
+            Harness::new(list(&mut state, 100), Size::new(100.0, 100.0));
+        harness.move_cursor_to(Point::new(10.0, 10.0));
+        harness.event(Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Pixels { x: 0.0, y: -150.0 },
+        }));
+        drop(harness);
+
+        assert_eq!(state.offset, 150.0);
+
+        // The first child grows above the view
+        let mut harness =
+            Harness::new(list(&mut state, 200), Size::new(100.0, 100.0));
+        harness.move_cursor_to(Point::new(10.0, 10.0));
+        drop(harness);
+
+        assert_eq!(state.offset, 250.0);
+    }
+
+    #[test]
+    fn anchored_view_follows_prepended_children() {
+        fn list<'a>(
+            state: &'a mut State,
+            keys: &[&str],
+        ) -> Scrollable<'a, (), Null> {
+            keys.iter().fold(
+                Scrollable::new(state)
+                    .width(Length::Fill)
+                    .height(Length::Units(100))
+                    .anchor_to_visible(true),
+                |scrollable, key| {
+                    scrollable.push_keyed(
+                        *key,
+                        Text::new(*key).height(Length::Units(100)),
+                    )
+                },
+            )
+        }
+
+        let mut state = State::new();
+
+        let mut harness = Harness::new(
+            list(&mut state, &["a", "b", "c"]),
+            Size::new(100.0, 100.0),
+        );
+        harness.move_cursor_to(Point::new(10.0, 10.0));
+        harness.event(Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Pixels { x: 0.0, y: -150.0 },
+        }));
+        drop(harness);
+
+        assert_eq!(state.offset, 150.0);
+
+        // A child is inserted above the view
+        let mut harness = Harness::new(
+            list(&mut state, &["z", "a", "b", "c"]),
+            Size::new(100.0, 100.0),
+        );
+        harness.move_cursor_to(Point::new(10.0, 10.0));
+        drop(harness);
+
+        assert_eq!(state.offset, 250.0);
+    }
+
+    #[test]
+    fn fingers_pan_the_contents() {
+        let mut state = State::new();
+
+        let scrollable = Scrollable::<(), Null>::new(&mut state)
+            .width(Length::Fill)
+            .height(Length::Units(100))
+            .push(Text::new("").height(Length::Units(300)));
+
+        let finger = touch::Finger(0);
+        let touch = |event| Event::Touch(event);
+
+        let mut harness = Harness::new(scrollable, Size::new(100.0, 100.0));
+        harness.events(vec![
+            touch(touch::Event::FingerPressed {
+                id: finger,
+                position: Point::new(10.0, 90.0),
+            }),
+            touch(touch::Event::FingerMoved {
+                id: finger,
+                position: Point::new(10.0, 30.0),
+            }),
+            touch(touch::Event::FingerMoved {
+                id: finger,
+                position: Point::new(10.0, 40.0),
+            }),
+            touch(touch::Event::FingerLifted {
+                id: finger,
+                position: Point::new(10.0, 40.0),
+            }),
+        ]);
+        drop(harness);
+
+        assert_eq!(state.offset, 50.0);
+    }
+
+    #[test]
+    fn dragging_the_contents_scrolls_them_instead_of_clicking() {
+        let mut state = State::new();
+        let mut button = button::State::new();
+
+        let scrollable = Scrollable::<_, Null>::new(&mut state)
+            .width(Length::Fill)
+            .height(Length::Units(100))
+            .drag_to_scroll(true)
+            .push(
+                Button::new(&mut button, Text::new(""))
+                    .width(Length::Fill)
+                    .height(Length::Units(300))
+                    .on_press(()),
+            );
+
+        let mut harness = Harness::new(scrollable, Size::new(100.0, 100.0));
+        harness.click(Point::new(10.0, 50.0));
+        assert_eq!(harness.take_messages(), vec![()]);
+
+        harness.move_cursor_to(Point::new(10.0, 90.0));
+        harness.event(Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        )));
+        harness.move_cursor_to(Point::new(10.0, 30.0));
+        harness.move_cursor_to(Point::new(10.0, 40.0));
+        harness.event(Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Left,
+        )));
+        assert!(harness.into_messages().is_empty());
+
+        assert_eq!(state.offset, 50.0);
+    }
+
+    #[test]
+    fn overflowing_columns_can_be_scrolled() {
+        let mut state = State::new();
+
+        let column = Column::new()
+            .spacing(10)
+            .push(Text::new("").height(Length::Units(50)))
+            .push(Text::new("").height(Length::Units(50)))
+            .push(Text::new("").height(Length::Units(50)));
+
+        let scrollable =
+            Scrollable::<(), Null>::with_column(&mut state, column);
+
+        let mut harness = Harness::new(scrollable, Size::new(100.0, 100.0));
+
+        assert_eq!(
+            harness.snapshot().lines().collect::<Vec<_>>(),
+            vec![
+                "Scrollable (0, 0) 0x100",
+                "  Node (0, 0) 0x170",
+                "    Text (0, 0) 0x50",
+                "    Text (0, 60) 0x50",
+                "    Text (0, 120) 0x50",
+            ]
+        );
+
+        harness.move_cursor_to(Point::new(0.0, 10.0));
+        harness.event(Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Pixels { x: 0.0, y: -1000.0 },
+        }));
+        drop(harness);
+
+        assert_eq!(state.offset, 70.0);
+    }
+
+    #[test]
+    fn padding_is_included_in_the_maximum_offset() {
+        let mut state = State::new();
+
+        let scrollable = Scrollable::<(), Null>::new(&mut state)
+            .width(Length::Fill)
+            .height(Length::Units(100))
+            .padding(10)
+            .push(Text::new("").height(Length::Units(200)));
+
+        let mut harness = Harness::new(scrollable, Size::new(100.0, 100.0));
+        harness.move_cursor_to(Point::new(10.0, 10.0));
+        harness.event(Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Pixels { x: 0.0, y: -1000.0 },
+        }));
+        drop(harness);
+
+        assert_eq!(state.offset, 120.0);
+    }
+
+    #[test]
+    fn nested_scrollables_scroll_inner_first() {
+        let mut outer = State::new();
+        let mut inner = State::new();
+
+        let scrollable = Scrollable::<(), Null>::new(&mut outer)
+            .width(Length::Fill)
+            .height(Length::Units(100))
+            .push(
+                Scrollable::new(&mut inner)
+                    .width(Length::Fill)
+                    .height(Length::Units(50))
+                    .push(Text::new("").height(Length::Units(80))),
+            )
+            .push(Text::new("").height(Length::Units(200)));
+
+        let mut harness = Harness::new(scrollable, Size::new(100.0, 100.0));
+        harness.move_cursor_to(Point::new(10.0, 10.0));
+
+        for _ in 0..3 {
+            harness.event(Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Pixels { x: 0.0, y: -20.0 },
+            }));
+        }
+
+        drop(harness);
+
+        // The inner scrollable reaches its edge on the second event
+        assert_eq!(inner.offset, 30.0);
+        assert_eq!(outer.offset, 20.0);
+    }
+
+    #[test]
+    fn auto_hide_fades_out_after_delay() {
+        let auto_hide = AutoHide {
+            delay: Duration::from_millis(1000),
+            fade_out: Duration::from_millis(500),
+        };
+
+        assert_eq!(auto_hide.alpha(Duration::from_millis(800)), 1.0);
+        assert_eq!(auto_hide.alpha(Duration::from_millis(1250)), 0.5);
+        assert_eq!(auto_hide.alpha(Duration::from_millis(2000)), 0.0);
+    }
+
+    #[test]
+    fn fling_decays_towards_its_final_offset() {
+        let fling = Fling {
+            offset: 100.0,
+            velocity: -1000.0,
+            started_at: Instant::now(),
+        };
+
+        // The offset covers 1 - 1/e of the distance after a time constant
+        let distance = 1000.0 * FLING_TIME_CONSTANT.as_secs_f32();
+        let after_time_constant = 100.0 - distance * (1.0 - (-1.0f32).exp());
+        let after_a_while = 100.0 - distance;
+
+        let error = |elapsed, expected: f32| {
+            (fling.offset_at(elapsed) - expected).abs()
+        };
+
+        assert_eq!(fling.offset_at(Duration::from_secs(0)), 100.0);
+        assert!(error(FLING_TIME_CONSTANT, after_time_constant) < 0.01);
+        assert!(error(Duration::from_secs(10), after_a_while) < 0.01);
+    }
+
+    #[test]
+    fn fling_stops_below_the_minimum_velocity() {
+        let fling = Fling {
+            offset: 0.0,
+            velocity: 1000.0,
+            started_at: Instant::now(),
+        };
+
+        // The velocity decays to 1% of its initial value after ~1.5 seconds
+        assert!(!fling.is_finished(Duration::from_millis(1400)));
+        assert!(fling.is_finished(Duration::from_millis(1600)));
+    }
+
+    #[test]
+    fn auto_hide_scrollbar_is_shown_at_first() {
+        let mut state = State::new();
+        let _ = Scrollable::<(), Null>::new(&mut state)
+            .auto_hide(AutoHide::default());
+
+        // The scrollbar starts fully visible instead of hidden
+        let alpha = state
+            .last_activity
+            .map(|shown_at| AutoHide::default().alpha(shown_at.elapsed()));
+
+        assert_eq!(alpha, Some(1.0));
+    }
+
+    #[test]
+    fn scroll_into_view_reveals_target() {
+        let mut state = State::new();
+        let target = Rectangle {
+            x: 0.0,
+            y: 500.0,
+            width: 100.0,
+            height: 20.0,
+        };
+
+        state.scroll_into_view(target, BOUNDS, CONTENT_BOUNDS);
+        assert_eq!(state.offset, 420.0);
+
+        state.scroll_into_view(
+            Rectangle { y: 450.0, ..target },
+            BOUNDS,
+            CONTENT_BOUNDS,
+        );
+        assert_eq!(state.offset, 420.0);
+
+        state.scroll_into_view(
+            Rectangle { y: 100.0, ..target },
+            BOUNDS,
+            CONTENT_BOUNDS,
+        );
+        assert_eq!(state.offset, 100.0);
+    }
+
+    #[test]
+    fn focused_child_is_scrolled_into_view() {
+        use super::Scrollable;
+        use crate::testing::Harness;
+        use crate::{text_input, Event, Length, Size, TextInput};
+
+        let mut state = State::new();
+        let mut inputs: Vec<_> =
+            (0..10).map(|_| text_input::State::new()).collect();
+        inputs[9] = text_input::State::focused();
+
+        let scrollable = inputs.iter_mut().fold(
+            Scrollable::new(&mut state)
+                .height(Length::Units(100))
+                .scroll_to_focus(true),
+            |scrollable, input| {
+                scrollable.push(TextInput::new(input, "", "", |_| ()))
+            },
+        );
+
+        let mut harness = Harness::new(scrollable, Size::new(100.0, 100.0));
+        harness.event(Event::Mouse(mouse::Event::CursorEntered));
+        drop(harness);
+
+        // Each input is 20 units tall
+        assert_eq!(state.offset, 100.0);
+    }
+
+    #[test]
+    fn focused_child_is_not_scrolled_into_view_by_default() {
+        use super::Scrollable;
+        use crate::testing::Harness;
+        use crate::{text_input, Event, Length, Size, TextInput};
+
+        let mut state = State::new();
+        let mut inputs: Vec<_> =
+            (0..10).map(|_| text_input::State::new()).collect();
+        inputs[9] = text_input::State::focused();
+
+        let scrollable = inputs.iter_mut().fold(
+            Scrollable::new(&mut state).height(Length::Units(100)),
+            |scrollable, input| {
+                scrollable.push(TextInput::new(input, "", "", |_| ()))
+            },
+        );
+
+        let mut harness = Harness::new(scrollable, Size::new(100.0, 100.0));
+        harness.event(Event::Mouse(mouse::Event::CursorEntered));
+        drop(harness);
+
+        assert_eq!(state.offset, 0.0);
+    }
+
+    #[test]
+    fn scrollbar_on_the_left_is_mirrored() {
+        let scrollbar = Scrollbar {
+            bounds: Rectangle {
+                x: 84.0,
+                y: 0.0,
+                width: 16.0,
+                height: 100.0,
+            },
+            scroller: Scroller {
+                bounds: Rectangle {
+                    x: 87.0,
+                    y: 20.0,
+                    width: 10.0,
+                    height: 30.0,
+                },
+            },
+        }
+        .mirrored(BOUNDS);
+
+        assert_eq!(
+            scrollbar.bounds,
+            Rectangle {
+                x: 0.0,
+                y: 0.0,
+                width: 16.0,
+                height: 100.0,
+            }
+        );
+        assert_eq!(
+            scrollbar.scroller.bounds,
+            Rectangle {
+                x: 3.0,
+                y: 20.0,
+                width: 10.0,
+                height: 30.0,
+            }
+        );
+        assert!(scrollbar.is_mouse_over(Point::new(5.0, 50.0)));
+        assert!(!scrollbar.is_mouse_over(Point::new(90.0, 50.0)));
+    }
+}
@@ -4,9 +4,10 @@
 //!
 //! [`Slider`]: struct.Slider.html
 //! [`State`]: struct.State.html
+use crate::focus::Focusable;
 use crate::{
-    layout, mouse, Clipboard, Element, Event, Hasher, Layout, Length, Point,
-    Rectangle, Size, Widget,
+    event, keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
 };
 
 use std::{hash::Hash, ops::RangeInclusive};
@@ -43,9 +44,11 @@ pub struct Slider<'a, T, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     range: RangeInclusive<T>,
     step: T,
+    page_step: Option<T>,
     value: T,
     on_change: Box<dyn Fn(T) -> Message>,
     on_release: Option<Message>,
+    scale: Scale,
     width: Length,
     height: u16,
     style: Renderer::Style,
@@ -75,6 +78,59 @@ where
         value: T,
         on_change: F,
     ) -> Self
+    where
+        F: 'static + Fn(T) -> Message,
+    {
+        Self::with_step(state, range, value, T::from(1), on_change)
+    }
+}
+
+impl<'a, T, Message, Renderer> Slider<'a, T, Message, Renderer>
+where
+    T: num_traits::PrimInt + Into<f64>,
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Slider`] over a range of integers.
+    ///
+    /// It expects the same arguments as [`new`]. The value produced while
+    /// dragging is always a whole number in the range, snapped to the
+    /// [`step`] size, which defaults to 1.
+    ///
+    /// This also works for integer types that cannot be built from a `u8`,
+    /// like `i8`. Like any [`Slider`], the integer type must convert into an
+    /// `f64` without losing precision, so integers of up to 32 bits are
+    /// supported, while `i64`, `u64`, `isize`, and `usize` are not.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    /// [`new`]: #method.new
+    /// [`step`]: #method.step
+    pub fn new_int<F>(
+        state: &'a mut State,
+        range: RangeInclusive<T>,
+        value: T,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(T) -> Message,
+    {
+        Self::with_step(state, range, value, T::one(), on_change)
+    }
+}
+
+impl<'a, T, Message, Renderer> Slider<'a, T, Message, Renderer>
+where
+    T: Copy + std::cmp::PartialOrd,
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn with_step<F>(
+        state: &'a mut State,
+        range: RangeInclusive<T>,
+        value: T,
+        step: T,
+        on_change: F,
+    ) -> Self
     where
         F: 'static + Fn(T) -> Message,
     {
@@ -94,21 +150,23 @@ where
             state,
             value,
             range,
-            step: T::from(1),
+            step,
+            page_step: None,
             on_change: Box::new(on_change),
             on_release: None,
+            scale: Scale::default(),
             width: Length::Fill,
             height: Renderer::DEFAULT_HEIGHT,
             style: Renderer::Style::default(),
         }
     }
 
-    /// Sets the release message of the [`Slider`].
-    /// This is called when the mouse is released from the slider.
+    /// Sets the message produced when a drag of the [`Slider`] ends.
     ///
-    /// Typically, the user's interaction with the slider is finished when this message is produced.
-    /// This is useful if you need to spawn a long-running task from the slider's result, where
-    /// the default on_change message could create too many events.
+    /// Unlike the `on_change` message, which is produced continuously while
+    /// dragging, this message is produced only once, when the mouse button
+    /// is released. This makes it a good place to commit expensive work,
+    /// while keeping `on_change` for cheap live updates.
     ///
     /// [`Slider`]: struct.Slider.html
     pub fn on_release(mut self, on_release: Message) -> Self {
@@ -147,6 +205,82 @@ where
         self.step = step;
         self
     }
+
+    /// Sets the page step of the [`Slider`], used when pressing `PageUp` or
+    /// `PageDown` while it is focused.
+    ///
+    /// By default, it is a tenth of the range, rounded to the step size.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn page_step(mut self, page_step: T) -> Self {
+        self.page_step = Some(page_step);
+        self
+    }
+
+    /// Sets the [`Scale`] mapping the values of the [`Slider`] to the
+    /// position of its handle.
+    ///
+    /// [`Scale`]: enum.Scale.html
+    /// [`Slider`]: struct.Slider.html
+    pub fn scale(mut self, scale: Scale) -> Self {
+        self.scale = scale;
+        self
+    }
+}
+
+/// The mapping between the values of a [`Slider`] and the position of its
+/// handle.
+///
+/// [`Slider`]: struct.Slider.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scale {
+    /// Values are spread evenly along the [`Slider`].
+    ///
+    /// [`Slider`]: struct.Slider.html
+    Linear,
+
+    /// Every ratio between values takes the same space along the
+    /// [`Slider`], like on a frequency or zoom control.
+    ///
+    /// Logarithms are only defined for positive values, so the start of the
+    /// range must be greater than zero. Otherwise, the [`Slider`] falls back
+    /// to a [`Linear`] scale.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    /// [`Linear`]: #variant.Linear
+    Logarithmic,
+}
+
+impl Default for Scale {
+    fn default() -> Self {
+        Scale::Linear
+    }
+}
+
+impl Scale {
+    /// Returns whether the given range can use a logarithmic mapping.
+    fn is_logarithmic(self, start: f64, end: f64) -> bool {
+        self == Scale::Logarithmic && start > 0.0 && end > start
+    }
+
+    /// Returns the position of the given value in the range, from 0 to 1.
+    fn position(self, value: f64, start: f64, end: f64) -> f64 {
+        if self.is_logarithmic(start, end) {
+            (value / start).ln() / (end / start).ln()
+        } else {
+            (value - start) / (end - start)
+        }
+    }
+
+    /// Returns the distance from the start of the range to the value at the
+    /// given position, from 0 to 1.
+    fn offset(self, position: f64, start: f64, end: f64) -> f64 {
+        if self.is_logarithmic(start, end) {
+            start * (end / start).powf(position) - start
+        } else {
+            position * (end - start)
+        }
+    }
 }
 
 /// The local state of a [`Slider`].
@@ -155,6 +289,7 @@ where
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
     is_dragging: bool,
+    is_focused: bool,
 }
 
 impl State {
@@ -164,6 +299,65 @@ impl State {
     pub fn new() -> State {
         State::default()
     }
+
+    /// Creates a new [`State`], representing a focused [`Slider`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Slider`]: struct.Slider.html
+    pub fn focused() -> State {
+        State {
+            is_focused: true,
+            ..State::default()
+        }
+    }
+
+    /// Returns whether the [`Slider`] is currently focused or not.
+    ///
+    /// A focused [`Slider`] jumps to the start and the end of its range when
+    /// pressing `Home` and `End`, and moves by its page step when pressing
+    /// `PageDown` and `PageUp`.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+}
+
+impl Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.is_dragging = false;
+    }
+}
+
+/// Returns the value of a [`Slider`] closest to the given one, rounded to the
+/// step size and clamped to the range.
+///
+/// [`Slider`]: struct.Slider.html
+fn snap<T>(value: f64, start: f64, end: f64, step: f64) -> Option<T>
+where
+    T: num_traits::FromPrimitive,
+{
+    let steps = ((value - start) / step).round();
+    let value = (steps * step + start).max(start).min(end);
+
+    // Avoid floating point errors when every possible value is a whole
+    // number, as `from_f64` truncates for integer types
+    let value = if step.fract() == 0.0 && start.fract() == 0.0 {
+        value.round()
+    } else {
+        value
+    };
+
+    T::from_f64(value)
 }
 
 impl<'a, T, Message, Renderer> Widget<Message, Renderer>
@@ -202,7 +396,7 @@ where
         messages: &mut Vec<Message>,
         _renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
-    ) {
+    ) -> event::Status {
         let mut change = || {
             let bounds = layout.bounds();
             if cursor_position.x <= bounds.x {
@@ -217,10 +411,9 @@ where
                 let percent = f64::from(cursor_position.x - bounds.x)
                     / f64::from(bounds.width);
 
-                let steps = (percent * (end - start) / step).round();
-                let value = steps * step + start;
+                let offset = self.scale.offset(percent, start, end);
 
-                if let Some(value) = T::from_f64(value) {
+                if let Some(value) = snap(start + offset, start, end, step) {
                     messages.push((self.on_change)(value));
                 }
             }
@@ -229,28 +422,68 @@ where
         match event {
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::ButtonPressed(mouse::Button::Left) => {
-                    if layout.bounds().contains(cursor_position) {
+                    let is_over = layout.bounds().contains(cursor_position);
+
+                    if is_over {
                         change();
                         self.state.is_dragging = true;
                     }
+
+                    self.state.is_focused = is_over;
                 }
-                mouse::Event::ButtonReleased(mouse::Button::Left) => {
-                    if self.state.is_dragging {
-                        if let Some(on_release) = self.on_release.clone() {
-                            messages.push(on_release);
-                        }
-                        self.state.is_dragging = false;
+                mouse::Event::ButtonReleased(mouse::Button::Left)
+                    if self.state.is_dragging =>
+                {
+                    if let Some(on_release) = self.on_release.clone() {
+                        messages.push(on_release);
                     }
+                    self.state.is_dragging = false;
                 }
-                mouse::Event::CursorMoved { .. } => {
-                    if self.state.is_dragging {
-                        change();
-                    }
+                mouse::Event::CursorMoved { .. } if self.state.is_dragging => {
+                    change();
                 }
                 _ => {}
             },
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if self.state.is_focused => {
+                let step = self.step.into();
+                let start = (*self.range.start()).into();
+                let end = (*self.range.end()).into();
+                let current = self.value.into();
+
+                let page = match self.page_step {
+                    Some(page_step) => page_step.into(),
+                    None => {
+                        ((end - start) / 10.0 / step).round().max(1.0) * step
+                    }
+                };
+
+                let value = match key_code {
+                    keyboard::KeyCode::Home => start,
+                    keyboard::KeyCode::End => end,
+                    keyboard::KeyCode::PageUp => current + page,
+                    keyboard::KeyCode::PageDown => current - page,
+                    keyboard::KeyCode::Escape => {
+                        self.state.is_focused = false;
+
+                        return event::Status::Ignored;
+                    }
+                    _ => return event::Status::Ignored,
+                };
+
+                if let Some(value) = snap::<T>(value, start, end, step) {
+                    if value.into() != current {
+                        messages.push((self.on_change)(value));
+                    }
+                }
+
+                return event::Status::Captured;
+            }
             _ => {}
         }
+
+        event::Status::Ignored
     }
 
     fn draw(
@@ -260,14 +493,23 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> Renderer::Output {
-        let start = *self.range.start();
-        let end = *self.range.end();
+        let start = (*self.range.start()).into();
+        let end = (*self.range.end()).into();
+        let value = self.value.into();
+
+        // The renderer places the handle linearly, so a logarithmic slider
+        // is drawn from the position of its value instead
+        let (range, value) = if self.scale.is_logarithmic(start, end) {
+            (0.0..=1.0, self.scale.position(value, start, end) as f32)
+        } else {
+            (start as f32..=end as f32, value as f32)
+        };
 
         renderer.draw(
             layout.bounds(),
             cursor_position,
-            start.into() as f32..=end.into() as f32,
-            self.value.into() as f32,
+            range,
+            value,
             self.state.is_dragging,
             &self.style,
         )
@@ -279,6 +521,22 @@ where
 
         self.width.hash(state);
     }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        if self.state.is_focused {
+            Some(layout.bounds())
+        } else {
+            None
+        }
+    }
+
+    fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        _messages: &mut Vec<Message>,
+    ) {
+        visit(&mut *self.state);
+    }
 }
 
 /// The renderer of a [`Slider`].
@@ -333,3 +591,179 @@ where
         Element::new(slider)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+
+    #[test]
+    fn new_int_produces_whole_values() {
+        let mut state = State::new();
+        let slider = Slider::<'_, i8, i8, crate::renderer::Null>::new_int(
+            &mut state,
+            -10..=10,
+            0,
+            |value| value,
+        );
+
+        let mut harness = Harness::new(slider, Size::new(200.0, 100.0));
+
+        harness.click(Point::new(10.0, 1.0));
+        harness.click(Point::new(110.0, 1.0));
+        harness.click(Point::new(199.0, 1.0));
+
+        assert_eq!(harness.into_messages(), vec![-9, 1, 10]);
+    }
+
+    #[test]
+    fn new_int_supports_the_whole_range_of_32_bit_integers() {
+        let mut state = State::new();
+        let slider = Slider::<'_, u32, u32, crate::renderer::Null>::new_int(
+            &mut state,
+            0..=u32::MAX,
+            0,
+            |value| value,
+        );
+
+        let mut harness = Harness::new(slider, Size::new(200.0, 100.0));
+
+        harness.click(Point::new(0.0, 1.0));
+        harness.click(Point::new(100.0, 1.0));
+        harness.click(Point::new(200.0, 1.0));
+
+        assert_eq!(
+            harness.into_messages(),
+            vec![0, u32::MAX / 2 + 1, u32::MAX]
+        );
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Changed(u8),
+        Released,
+    }
+
+    #[test]
+    fn on_release_is_produced_once_when_the_drag_ends() {
+        let mut state = State::new();
+        let slider = Slider::<'_, u8, _, crate::renderer::Null>::new(
+            &mut state,
+            0..=100,
+            0,
+            Message::Changed,
+        )
+        .on_release(Message::Released);
+
+        let mut harness = Harness::new(slider, Size::new(100.0, 100.0));
+
+        // Releasing outside of a drag does nothing
+        harness.event(Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Left,
+        )));
+
+        harness.move_cursor_to(Point::new(10.0, 1.0));
+        harness.event(Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        )));
+        harness.move_cursor_to(Point::new(20.0, 1.0));
+        harness.move_cursor_to(Point::new(30.0, 1.0));
+        harness.event(Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Left,
+        )));
+        harness.move_cursor_to(Point::new(40.0, 1.0));
+
+        assert_eq!(
+            harness.into_messages(),
+            vec![
+                Message::Changed(10),
+                Message::Changed(20),
+                Message::Changed(30),
+                Message::Released,
+            ]
+        );
+    }
+
+    #[test]
+    fn focused_slider_jumps_with_home_end_and_page_keys() {
+        let mut state = State::new();
+        let slider = Slider::<'_, u8, _, crate::renderer::Null>::new(
+            &mut state,
+            0..=100,
+            95,
+            |value| value,
+        );
+
+        let mut harness = Harness::new(slider, Size::new(100.0, 100.0));
+
+        // Keys are ignored until the slider is focused
+        harness.press_key(keyboard::KeyCode::Home);
+        harness.click(Point::new(95.0, 1.0));
+
+        harness.press_key(keyboard::KeyCode::PageUp);
+        harness.press_key(keyboard::KeyCode::PageDown);
+        harness.press_key(keyboard::KeyCode::Home);
+        harness.press_key(keyboard::KeyCode::End);
+        harness.press_key(keyboard::KeyCode::Escape);
+        harness.press_key(keyboard::KeyCode::Home);
+
+        assert_eq!(harness.into_messages(), vec![95, 100, 85, 0, 100]);
+    }
+
+    #[test]
+    fn page_step_can_be_configured() {
+        let mut state = State::focused();
+        let slider = Slider::<'_, f32, _, crate::renderer::Null>::new(
+            &mut state,
+            0.0..=1.0,
+            0.5,
+            |value| value,
+        )
+        .step(0.01)
+        .page_step(0.25);
+
+        let mut harness = Harness::new(slider, Size::new(100.0, 100.0));
+
+        harness.press_key(keyboard::KeyCode::PageUp);
+        harness.press_key(keyboard::KeyCode::PageDown);
+
+        assert_eq!(harness.into_messages(), vec![0.75, 0.25]);
+    }
+
+    #[test]
+    fn logarithmic_scale_spreads_ratios_evenly() {
+        let mut state = State::new();
+        let slider = Slider::<'_, f64, _, crate::renderer::Null>::new(
+            &mut state,
+            10.0..=10000.0,
+            10.0,
+            |value| value,
+        )
+        .scale(Scale::Logarithmic);
+
+        let mut harness = Harness::new(slider, Size::new(300.0, 100.0));
+
+        harness.click(Point::new(100.0, 1.0));
+        harness.click(Point::new(200.0, 1.0));
+
+        assert_eq!(harness.into_messages(), vec![100.0, 1000.0]);
+    }
+
+    #[test]
+    fn logarithmic_scale_falls_back_to_linear_without_positive_range() {
+        let mut state = State::new();
+        let slider = Slider::<'_, f64, _, crate::renderer::Null>::new(
+            &mut state,
+            0.0..=100.0,
+            0.0,
+            |value| value,
+        )
+        .scale(Scale::Logarithmic);
+
+        let mut harness = Harness::new(slider, Size::new(100.0, 100.0));
+
+        harness.click(Point::new(25.0, 1.0));
+
+        assert_eq!(harness.into_messages(), vec![25.0]);
+    }
+}
@@ -0,0 +1,418 @@
+//! Display content on top of each other.
+use std::hash::Hash;
+
+use crate::focus::Focusable;
+use crate::{
+    event, layout, overlay, Align, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
+};
+
+/// A container that places its contents on top of each other.
+///
+/// A [`Stack`] is as big as its biggest child. Children are drawn in the
+/// order they were pushed, so the last child ends up on top. Events reach
+/// the top-most child first, and stop as soon as a child captures them.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{renderer::Null, Align, Text};
+/// #
+/// # type Stack<'a, Message> = iced_native::Stack<'a, Message, Null>;
+/// #
+/// let icon_with_badge: Stack<'_, ()> = Stack::new()
+///     .push(Text::new("Inbox").size(40))
+///     .push_aligned(Text::new("3").size(12), Align::End, Align::Start);
+/// ```
+///
+/// [`Stack`]: struct.Stack.html
+#[allow(missing_debug_implementations)]
+pub struct Stack<'a, Message, Renderer> {
+    width: Length,
+    height: Length,
+    horizontal_alignment: Align,
+    vertical_alignment: Align,
+    children: Vec<Element<'a, Message, Renderer>>,
+    alignments: Vec<Option<(Align, Align)>>,
+}
+
+impl<'a, Message, Renderer> Stack<'a, Message, Renderer> {
+    /// Creates an empty [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn new() -> Self {
+        Self::with_children(Vec::new())
+    }
+
+    /// Creates a [`Stack`] with the given elements, from bottom to top.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn with_children(
+        children: Vec<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Stack {
+            width: Length::Shrink,
+            height: Length::Shrink,
+            horizontal_alignment: Align::Start,
+            vertical_alignment: Align::Start,
+            alignments: vec![None; children.len()],
+            children,
+        }
+    }
+
+    /// Sets the width of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the default horizontal alignment of the contents of the
+    /// [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn align_x(mut self, alignment: Align) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the default vertical alignment of the contents of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn align_y(mut self, alignment: Align) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Adds an element on top of the [`Stack`], using its default alignment.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn push<E>(mut self, child: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.children.push(child.into());
+        self.alignments.push(None);
+        self
+    }
+
+    /// Adds an element on top of the [`Stack`] with its own horizontal and
+    /// vertical alignment.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn push_aligned<E>(
+        mut self,
+        child: E,
+        horizontal_alignment: Align,
+        vertical_alignment: Align,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.children.push(child.into());
+        self.alignments
+            .push(Some((horizontal_alignment, vertical_alignment)));
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Default for Stack<'a, Message, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Stack<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn min_size(&self, renderer: &Renderer) -> Size {
+        self.children.iter().fold(Size::ZERO, |size, child| {
+            let child_size = child.min_size(renderer);
+
+            Size::new(
+                size.width.max(child_size.width),
+                size.height.max(child_size.height),
+            )
+        })
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let intrinsic = self.children.iter().fold(Size::ZERO, |size, child| {
+            let child_size = child.layout(renderer, &limits.loose()).size();
+
+            // Filling children take the size of the stack instead
+            Size::new(
+                if child.width() == Length::Fill {
+                    size.width
+                } else {
+                    size.width.max(child_size.width)
+                },
+                if child.height() == Length::Fill {
+                    size.height
+                } else {
+                    size.height.max(child_size.height)
+                },
+            )
+        });
+
+        let size = limits.resolve(intrinsic);
+
+        let nodes = self
+            .children
+            .iter()
+            .zip(&self.alignments)
+            .map(|(child, alignment)| {
+                let (horizontal_alignment, vertical_alignment) = alignment
+                    .unwrap_or((
+                        self.horizontal_alignment,
+                        self.vertical_alignment,
+                    ));
+
+                let mut node = child
+                    .layout(renderer, &layout::Limits::new(Size::ZERO, size));
+
+                node.align(horizontal_alignment, vertical_alignment, size);
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        let layouts: Vec<_> = layout.children().collect();
+
+        for (child, layout) in self.children.iter_mut().zip(layouts).rev() {
+            let status = child.widget.on_event(
+                event.clone(),
+                layout,
+                cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            );
+
+            if status == event::Status::Captured {
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn layout_changed(
+        &mut self,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+    ) {
+        for (child, layout) in self.children.iter_mut().zip(layout.children()) {
+            child.layout_changed(layout, messages);
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(defaults, &self.children, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.horizontal_alignment.hash(state);
+        self.vertical_alignment.hash(state);
+        self.alignments.hash(state);
+
+        for child in &self.children {
+            child.widget.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let layouts: Vec<_> = layout.children().collect();
+
+        self.children
+            .iter_mut()
+            .zip(layouts)
+            .rev()
+            .filter_map(|(child, layout)| child.widget.overlay(layout))
+            .next()
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.children
+            .iter()
+            .zip(layout.children())
+            .find_map(|(child, layout)| child.focused_bounds(layout))
+    }
+
+    fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        messages: &mut Vec<Message>,
+    ) {
+        for child in &mut self.children {
+            child.focusables(visit, messages);
+        }
+    }
+}
+
+/// The renderer of a [`Stack`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Stack`] in your user interface.
+///
+/// [`Stack`]: struct.Stack.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws a [`Stack`].
+    ///
+    /// It receives:
+    /// - the children of the [`Stack`], from bottom to top
+    /// - the [`Layout`] of the [`Stack`] and its children
+    /// - the cursor position
+    ///
+    /// [`Stack`]: struct.Stack.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        content: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Stack<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        stack: Stack<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(stack)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::testing::Harness;
+    use crate::{button, Button, Text};
+
+    fn layer(width: u16, height: u16) -> Text<Null> {
+        Text::new("")
+            .width(Length::Units(width))
+            .height(Length::Units(height))
+    }
+
+    #[test]
+    fn children_overlap_and_follow_their_alignment() {
+        let stack: Stack<'_, (), Null> = Stack::new()
+            .align_x(Align::Center)
+            .push(layer(100, 60))
+            .push(layer(20, 20))
+            .push_aligned(layer(10, 10), Align::End, Align::End)
+            .push(Text::new("").width(Length::Fill).height(Length::Fill));
+
+        let harness = Harness::new(stack, Size::new(400.0, 400.0));
+
+        assert_eq!(
+            harness.snapshot().lines().collect::<Vec<_>>(),
+            vec![
+                "Stack (0, 0) 100x60",
+                "  Text (0, 0) 100x60",
+                "  Text (40, 0) 20x20",
+                "  Text (90, 50) 10x10",
+                "  Text (0, 0) 100x60",
+            ]
+        );
+    }
+
+    #[test]
+    fn top_most_child_receives_events_first() {
+        let mut bottom = button::State::new();
+        let mut top = button::State::new();
+
+        let stack = Stack::new()
+            .push(
+                Button::<_, Null>::new(&mut bottom, Text::new(""))
+                    .width(Length::Units(100))
+                    .on_press("bottom"),
+            )
+            .push(
+                Button::new(&mut top, Text::new(""))
+                    .width(Length::Units(100))
+                    .on_press("top"),
+            );
+
+        let mut harness = Harness::new(stack, Size::new(200.0, 100.0));
+        harness.click(Point::new(5.0, 5.0));
+
+        assert_eq!(harness.into_messages(), vec!["top"]);
+    }
+
+    #[test]
+    fn ignored_events_reach_the_children_below() {
+        let mut bottom = button::State::new();
+
+        let stack = Stack::new()
+            .push(
+                Button::<_, Null>::new(&mut bottom, Text::new(""))
+                    .width(Length::Units(100))
+                    .on_press("bottom"),
+            )
+            .push(Text::new("").width(Length::Units(100)));
+
+        let mut harness = Harness::new(stack, Size::new(200.0, 100.0));
+        harness.click(Point::new(5.0, 5.0));
+
+        assert_eq!(harness.into_messages(), vec!["bottom"]);
+    }
+}
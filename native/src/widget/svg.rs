@@ -58,6 +58,18 @@ impl Svg {
         self.height = height;
         self
     }
+
+    /// Sets the [`Elements`] of the vector image displayed by the [`Svg`].
+    ///
+    /// By default, the whole image is displayed. Only `iced_wgpu` supports
+    /// choosing elements.
+    ///
+    /// [`Elements`]: enum.Elements.html
+    /// [`Svg`]: struct.Svg.html
+    pub fn elements(mut self, elements: Elements) -> Self {
+        self.handle = self.handle.with_elements(elements);
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Svg
@@ -122,7 +134,9 @@ where
 #[derive(Debug, Clone)]
 pub struct Handle {
     id: u64,
+    data_id: u64,
     data: Arc<Data>,
+    elements: Elements,
 }
 
 impl Handle {
@@ -149,9 +163,39 @@ impl Handle {
         let mut hasher = Hasher::default();
         data.hash(&mut hasher);
 
+        let data_id = hasher.finish();
+
         Handle {
-            id: hasher.finish(),
+            id: data_id,
+            data_id,
             data: Arc::new(data),
+            elements: Elements::All,
+        }
+    }
+
+    /// Returns a [`Handle`] to the same vector image, displaying only the
+    /// given [`Elements`].
+    ///
+    /// The data of the image is shared between both handles, but they are
+    /// rasterized separately.
+    ///
+    /// [`Handle`]: struct.Handle.html
+    /// [`Elements`]: enum.Elements.html
+    pub fn with_elements(self, elements: Elements) -> Handle {
+        let id = if elements == Elements::All {
+            self.data_id
+        } else {
+            let mut hasher = Hasher::default();
+            self.data_id.hash(&mut hasher);
+            elements.hash(&mut hasher);
+
+            hasher.finish()
+        };
+
+        Handle {
+            id,
+            elements,
+            ..self
         }
     }
 
@@ -168,6 +212,13 @@ impl Handle {
     pub fn data(&self) -> &Data {
         &self.data
     }
+
+    /// Returns the [`Elements`] of the vector image to display.
+    ///
+    /// [`Elements`]: enum.Elements.html
+    pub fn elements(&self) -> &Elements {
+        &self.elements
+    }
 }
 
 impl Hash for Handle {
@@ -199,6 +250,35 @@ impl std::fmt::Debug for Data {
     }
 }
 
+/// The elements of a vector image displayed by an [`Svg`].
+///
+/// Elements are chosen by the value of their `id` attribute. They keep their
+/// position in the image, whose dimensions do not change.
+///
+/// Choosing elements is only supported by `iced_wgpu`. `iced_glow` does not
+/// draw vector images yet, with or without [`Elements`].
+///
+/// [`Svg`]: struct.Svg.html
+/// [`Elements`]: enum.Elements.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Elements {
+    /// The whole image is displayed.
+    All,
+
+    /// Only the elements with the given ids are displayed, together with
+    /// their contents.
+    Only(Vec<String>),
+
+    /// Every element is displayed, except the ones with the given ids.
+    Except(Vec<String>),
+}
+
+impl Default for Elements {
+    fn default() -> Self {
+        Elements::All
+    }
+}
+
 /// The renderer of an [`Svg`].
 ///
 /// Your [renderer] will need to implement this trait before being able to use
@@ -227,3 +307,23 @@ where
         Element::new(icon)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn elements_are_part_of_the_identity_of_a_handle() {
+        let handle = Handle::from_memory(&b"<svg></svg>"[..]);
+        let only = handle
+            .clone()
+            .with_elements(Elements::Only(vec![String::from("layer")]));
+        let except = handle
+            .clone()
+            .with_elements(Elements::Except(vec![String::from("layer")]));
+
+        assert_ne!(only.id(), handle.id());
+        assert_ne!(only.id(), except.id());
+        assert_eq!(only.with_elements(Elements::All).id(), handle.id());
+    }
+}
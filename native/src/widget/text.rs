@@ -22,6 +22,7 @@ use std::hash::Hash;
 #[derive(Debug)]
 pub struct Text<Renderer: self::Renderer> {
     content: String,
+    expanded: Option<String>,
     size: Option<u16>,
     color: Option<Color>,
     font: Renderer::Font,
@@ -29,15 +30,25 @@ pub struct Text<Renderer: self::Renderer> {
     height: Length,
     horizontal_alignment: HorizontalAlignment,
     vertical_alignment: VerticalAlignment,
+    tab_width: u16,
 }
 
 impl<Renderer: self::Renderer> Text<Renderer> {
+    /// The default amount of spaces between the tab stops of a [`Text`].
+    ///
+    /// [`Text`]: struct.Text.html
+    pub const DEFAULT_TAB_WIDTH: u16 = 4;
+
     /// Create a new fragment of [`Text`] with the given contents.
     ///
     /// [`Text`]: struct.Text.html
     pub fn new<T: Into<String>>(label: T) -> Self {
+        let content = label.into();
+        let expanded = expand_tabs(&content, Self::DEFAULT_TAB_WIDTH);
+
         Text {
-            content: label.into(),
+            content,
+            expanded,
             size: None,
             color: None,
             font: Default::default(),
@@ -45,6 +56,7 @@ impl<Renderer: self::Renderer> Text<Renderer> {
             height: Length::Shrink,
             horizontal_alignment: HorizontalAlignment::Left,
             vertical_alignment: VerticalAlignment::Top,
+            tab_width: Self::DEFAULT_TAB_WIDTH,
         }
     }
 
@@ -110,6 +122,42 @@ impl<Renderer: self::Renderer> Text<Renderer> {
         self.vertical_alignment = alignment;
         self
     }
+
+    /// Sets the amount of spaces between the tab stops of the [`Text`].
+    ///
+    /// Every tab character is expanded to the spaces needed to reach the
+    /// next tab stop, so columns of text stay aligned. Columns are counted
+    /// in characters, which lines them up exactly with monospaced fonts.
+    ///
+    /// By default, tab stops are [`DEFAULT_TAB_WIDTH`] spaces apart.
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`DEFAULT_TAB_WIDTH`]: #associatedconstant.DEFAULT_TAB_WIDTH
+    pub fn tab_width(mut self, spaces: u16) -> Self {
+        self.tab_width = spaces;
+        self.expanded = expand_tabs(&self.content, spaces);
+        self
+    }
+
+    /// Measures the [`Text`] in the given bounds and returns the minimum
+    /// boundaries that can fit its contents, using its size and font.
+    ///
+    /// [`Text`]: struct.Text.html
+    pub fn measure(&self, renderer: &Renderer, bounds: Size) -> (f32, f32) {
+        renderer.measure(
+            self.displayed(),
+            self.size.unwrap_or(renderer.default_size()),
+            self.font,
+            bounds,
+        )
+    }
+
+    /// Returns the contents of the [`Text`] with its tabs expanded.
+    ///
+    /// [`Text`]: struct.Text.html
+    fn displayed(&self) -> &str {
+        self.expanded.as_deref().unwrap_or(&self.content)
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Text<Renderer>
@@ -124,6 +172,21 @@ where
         self.height
     }
 
+    fn min_size(&self, renderer: &Renderer) -> Size {
+        let content = self.displayed();
+        let size = self.size.unwrap_or(renderer.default_size());
+
+        // The text can wrap between words, but never inside a word
+        let width = content
+            .split_whitespace()
+            .map(|word| renderer.measure_text(word, size, self.font).0)
+            .fold(0.0, f32::max);
+
+        let (_, height) = renderer.measure_text(content, size, self.font);
+
+        Size::new(width, height)
+    }
+
     fn layout(
         &self,
         renderer: &Renderer,
@@ -131,16 +194,25 @@ where
     ) -> layout::Node {
         let limits = limits.width(self.width).height(self.height);
 
-        let size = self.size.unwrap_or(renderer.default_size());
+        let (width, height) = self.measure(renderer, limits.max());
 
-        let bounds = limits.max();
+        let size = limits.resolve(Size::new(width, height));
 
-        let (width, height) =
-            renderer.measure(&self.content, size, self.font, bounds);
+        // The text is drawn aligned inside its bounds, so the baseline moves
+        // along with it
+        let top = match self.vertical_alignment {
+            VerticalAlignment::Top => 0.0,
+            VerticalAlignment::Center => (size.height - height) / 2.0,
+            VerticalAlignment::Bottom => size.height - height,
+        };
 
-        let size = limits.resolve(Size::new(width, height));
+        let ascent = renderer
+            .ascent(self.size.unwrap_or(renderer.default_size()), self.font);
+
+        let mut node = layout::Node::new(size);
+        node.set_baseline(top + ascent);
 
-        layout::Node::new(size)
+        node
     }
 
     fn draw(
@@ -153,7 +225,7 @@ where
         renderer.draw(
             defaults,
             layout.bounds(),
-            &self.content,
+            self.displayed(),
             self.size.unwrap_or(renderer.default_size()),
             self.font,
             self.color,
@@ -170,9 +242,44 @@ where
         self.size.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.tab_width.hash(state);
     }
 }
 
+/// Replaces every tab character of the given content with the spaces needed
+/// to reach the next tab stop, if it has any.
+fn expand_tabs(content: &str, tab_width: u16) -> Option<String> {
+    if !content.contains('\t') {
+        return None;
+    }
+
+    let tab_width = usize::from(tab_width);
+    let mut expanded = String::with_capacity(content.len());
+    let mut column = 0;
+
+    for c in content.chars() {
+        match c {
+            '\t' if tab_width > 0 => {
+                let spaces = tab_width - column % tab_width;
+
+                expanded.extend(std::iter::repeat(' ').take(spaces));
+                column += spaces;
+            }
+            '\t' => {}
+            '\n' => {
+                expanded.push(c);
+                column = 0;
+            }
+            _ => {
+                expanded.push(c);
+                column += 1;
+            }
+        }
+    }
+
+    Some(expanded)
+}
+
 /// The renderer of a [`Text`] fragment.
 ///
 /// Your [renderer] will need to implement this trait before being
@@ -204,6 +311,31 @@ pub trait Renderer: crate::Renderer {
         bounds: Size,
     ) -> (f32, f32);
 
+    /// Returns the distance from the top of a line of [`Text`] to its
+    /// baseline, using the given size and font.
+    ///
+    /// By default, it is 80% of the size, which is close enough for most
+    /// fonts.
+    ///
+    /// [`Text`]: struct.Text.html
+    fn ascent(&self, size: u16, _font: Self::Font) -> f32 {
+        f32::from(size) * 0.8
+    }
+
+    /// Measures the given text in a single unbounded area and returns its
+    /// width and height.
+    ///
+    /// Useful to size a custom layout around some text that should never
+    /// wrap.
+    fn measure_text(
+        &self,
+        content: &str,
+        size: u16,
+        font: Self::Font,
+    ) -> (f32, f32) {
+        self.measure(content, size, font, Size::INFINITY)
+    }
+
     /// Draws a [`Text`] fragment.
     ///
     /// It receives:
@@ -244,6 +376,7 @@ impl<Renderer: self::Renderer> Clone for Text<Renderer> {
     fn clone(&self) -> Self {
         Self {
             content: self.content.clone(),
+            expanded: self.expanded.clone(),
             size: self.size,
             color: self.color,
             font: self.font,
@@ -251,6 +384,30 @@ impl<Renderer: self::Renderer> Clone for Text<Renderer> {
             height: self.height,
             horizontal_alignment: self.horizontal_alignment,
             vertical_alignment: self.vertical_alignment,
+            tab_width: self.tab_width,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tabs_are_expanded_to_the_next_stop() {
+        assert_eq!(expand_tabs("a\tbc\td", 4).as_deref(), Some("a   bc  d"));
+        assert_eq!(
+            expand_tabs("abcd\te\n\tf", 4).as_deref(),
+            Some("abcd    e\n    f")
+        );
+        assert_eq!(expand_tabs("a\tb", 0).as_deref(), Some("ab"));
+        assert_eq!(expand_tabs("no tabs", 4), None);
+    }
+
+    #[test]
+    fn tabs_are_expanded_again_when_the_tab_width_changes() {
+        let text = Text::<crate::renderer::Null>::new("a\tb").tab_width(2);
+
+        assert_eq!(text.displayed(), "a b");
+    }
+}
@@ -5,24 +5,38 @@
 //! [`TextInput`]: struct.TextInput.html
 //! [`State`]: struct.State.html
 mod editor;
+mod mask;
 mod value;
 
 pub mod cursor;
+pub mod suggestions;
 
 pub use cursor::Cursor;
+pub use suggestions::Suggestions;
 pub use value::Value;
 
 use editor::Editor;
+use mask::Mask;
 
+use crate::accessibility::{Accessibility, Role};
+use crate::focus::Focusable;
 use crate::{
-    keyboard, layout,
+    event, keyboard, layout,
     mouse::{self, click},
-    text, Clipboard, Element, Event, Hasher, Layout, Length, Point, Rectangle,
-    Size, Widget,
+    overlay::menu,
+    text, Clipboard, Direction, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
 };
 
+use std::time::{Duration, Instant};
 use std::u32;
 
+/// The time the cursor of a focused [`TextInput`] stays visible, and then
+/// hidden, while it blinks.
+///
+/// [`TextInput`]: struct.TextInput.html
+pub const CURSOR_BLINK_INTERVAL: Duration = Duration::from_millis(530);
+
 /// A field that can be filled with text.
 ///
 /// # Example
@@ -53,16 +67,38 @@ pub struct TextInput<'a, Message, Renderer: self::Renderer> {
     placeholder: String,
     value: Value,
     is_secure: bool,
+    is_clearable: bool,
+    mask: Option<Mask>,
     font: Renderer::Font,
     width: Length,
     max_width: u32,
     padding: u16,
     size: Option<u16>,
+    direction: Direction,
     on_change: Box<dyn Fn(String) -> Message>,
     on_submit: Option<Message>,
+    on_focus: Option<Message>,
+    on_blur: Option<Message>,
+    normalize: Option<Normalize>,
+    accessible_name: Option<String>,
+    helper_text: Option<String>,
+    error: Option<String>,
+    character_limit: Option<usize>,
+    cursor_blink: Option<Duration>,
     style: Renderer::Style,
 }
 
+/// The vertical space between the box of a [`TextInput`] and its [`Footer`].
+///
+/// [`TextInput`]: struct.TextInput.html
+/// [`Footer`]: struct.Footer.html
+const FOOTER_SPACING: f32 = 4.0;
+
+/// Normalizes the value of a [`TextInput`] when it loses focus.
+///
+/// [`TextInput`]: struct.TextInput.html
+type Normalize = Box<dyn Fn(&str) -> String>;
+
 impl<'a, Message, Renderer> TextInput<'a, Message, Renderer>
 where
     Message: Clone,
@@ -92,13 +128,24 @@ where
             placeholder: String::from(placeholder),
             value: Value::new(value),
             is_secure: false,
+            is_clearable: false,
+            mask: None,
             font: Default::default(),
             width: Length::Fill,
             max_width: u32::MAX,
             padding: 0,
             size: None,
+            direction: Direction::LeftToRight,
             on_change: Box::new(on_change),
             on_submit: None,
+            on_focus: None,
+            on_blur: None,
+            normalize: None,
+            accessible_name: None,
+            helper_text: None,
+            error: None,
+            character_limit: None,
+            cursor_blink: Some(CURSOR_BLINK_INTERVAL),
             style: Renderer::Style::default(),
         }
     }
@@ -111,6 +158,36 @@ where
         self
     }
 
+    /// Adds a clear button to the trailing edge of the [`TextInput`].
+    ///
+    /// The button is only shown when the value is not empty. Clicking it
+    /// produces the `on_change` message with an empty value.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn clearable(mut self) -> Self {
+        self.is_clearable = true;
+        self
+    }
+
+    /// Restricts the input of the [`TextInput`] to the given pattern.
+    ///
+    /// Every `#` in the pattern is a placeholder that only accepts a digit,
+    /// while any other character is a literal that is inserted automatically
+    /// as the user types, like in `(###) ###-####`. Pasted content is
+    /// reformatted to fit the pattern.
+    ///
+    /// The messages produced when the [`TextInput`] changes contain the
+    /// formatted value, literals included.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn mask(mut self, pattern: &str) -> Self {
+        let mask = Mask::new(pattern);
+
+        self.value = Value::new(&mask.apply(&self.value.to_string()));
+        self.mask = Some(mask);
+        self
+    }
+
     /// Sets the [`Font`] of the [`Text`].
     ///
     /// [`Text`]: struct.Text.html
@@ -151,6 +228,24 @@ where
         self
     }
 
+    /// Sets the text [`Direction`] of the [`TextInput`].
+    ///
+    /// A right-to-left [`TextInput`] is aligned to the right, places its
+    /// cursor from the right and swaps the logical movement of the arrow keys.
+    /// Use [`Direction::Auto`] to detect the direction from the value.
+    ///
+    /// Mixed-direction values are drawn in visual order, following the
+    /// Unicode Bidirectional Algorithm. The cursor still moves through the
+    /// value in logical order.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    /// [`Direction`]: ../../enum.Direction.html
+    /// [`Direction::Auto`]: ../../enum.Direction.html#variant.Auto
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
     /// Sets the message that should be produced when the [`TextInput`] is
     /// focused and the enter key is pressed.
     ///
@@ -160,6 +255,54 @@ where
         self
     }
 
+    /// Sets the message that should be produced when the [`TextInput`] gains
+    /// focus.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn on_focus(mut self, message: Message) -> Self {
+        self.on_focus = Some(message);
+        self
+    }
+
+    /// Sets the message that should be produced when the [`TextInput`] loses
+    /// focus, either by clicking elsewhere or by pressing the escape key.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn on_blur(mut self, message: Message) -> Self {
+        self.on_blur = Some(message);
+        self
+    }
+
+    /// Sets a function that normalizes the value of the [`TextInput`] when it
+    /// loses focus, like trimming whitespace.
+    ///
+    /// If the normalized value is different, the [`TextInput`] produces the
+    /// `on_change` message with it, right before the `on_blur` message. The
+    /// cursor is moved to the end of the new value.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn normalize_on_blur<F>(mut self, normalize: F) -> Self
+    where
+        F: 'static + Fn(&str) -> String,
+    {
+        self.normalize = Some(Box::new(normalize));
+        self
+    }
+
+    /// Sets the interval at which the cursor of the [`TextInput`] blinks
+    /// while focused, or `None` to keep it always visible.
+    ///
+    /// The cursor stays visible for a whole interval after every key press or
+    /// click, so it does not vanish while typing. By default, it blinks every
+    /// [`CURSOR_BLINK_INTERVAL`].
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    /// [`CURSOR_BLINK_INTERVAL`]: constant.CURSOR_BLINK_INTERVAL.html
+    pub fn cursor_blink(mut self, interval: Option<Duration>) -> Self {
+        self.cursor_blink = interval;
+        self
+    }
+
     /// Sets the style of the [`TextInput`].
     ///
     /// [`TextInput`]: struct.TextInput.html
@@ -168,12 +311,160 @@ where
         self
     }
 
+    /// Sets the name announced for the [`TextInput`] by assistive
+    /// technologies.
+    ///
+    /// By default, the placeholder of the [`TextInput`] is announced.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn accessible_name(mut self, name: impl Into<String>) -> Self {
+        self.accessible_name = Some(name.into());
+        self
+    }
+
+    /// Sets some text describing the [`TextInput`], shown below its box.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn helper_text(mut self, text: impl Into<String>) -> Self {
+        self.helper_text = Some(text.into());
+        self
+    }
+
+    /// Puts the [`TextInput`] in an error state, showing the given message
+    /// below its box in place of the helper text.
+    ///
+    /// The box of the [`TextInput`] is drawn with the error style of its
+    /// style sheet.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn error(mut self, message: impl Into<String>) -> Self {
+        self.error = Some(message.into());
+        self
+    }
+
+    /// Shows a counter of the characters of the value below the box of the
+    /// [`TextInput`], out of the given limit, like `12/140`.
+    ///
+    /// The limit is not enforced. Instead, the [`TextInput`] is put in an
+    /// error state while the value exceeds it.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn character_limit(mut self, limit: usize) -> Self {
+        self.character_limit = Some(limit);
+        self
+    }
+
+    /// Shows the given suggestions in a menu below the box of the
+    /// [`TextInput`] while it is being edited.
+    ///
+    /// See [`Suggestions`] for the details.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    /// [`Suggestions`]: suggestions/struct.Suggestions.html
+    pub fn suggestions(
+        self,
+        suggestions: &'a [String],
+    ) -> Suggestions<'a, Message, Renderer> {
+        Suggestions::new(self, suggestions)
+    }
+
     /// Returns the current [`State`] of the [`TextInput`].
     ///
     /// [`TextInput`]: struct.TextInput.html
     pub fn state(&self) -> &State {
         self.state
     }
+
+    fn clear_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        if self.value.len() == 0 {
+            return None;
+        }
+
+        layout.children().nth(1).map(|clear| clear.bounds())
+    }
+
+    fn has_footer(&self) -> bool {
+        self.helper_text.is_some()
+            || self.error.is_some()
+            || self.character_limit.is_some()
+    }
+
+    /// Returns the layout of the box of the [`TextInput`], which is nested
+    /// next to the [`Footer`] when there is one.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    /// [`Footer`]: struct.Footer.html
+    fn input_layout<'b>(&self, layout: &'b Layout<'b>) -> Layout<'b> {
+        if self.has_footer() {
+            layout.children().next().unwrap()
+        } else {
+            *layout
+        }
+    }
+
+    fn footer<'b>(
+        &'b self,
+        layout: Layout<'_>,
+        text_size: u16,
+    ) -> Option<Footer<'b>> {
+        if !self.has_footer() {
+            return None;
+        }
+
+        let counter =
+            self.character_limit.map(|limit| (self.value.len(), limit));
+
+        Some(Footer {
+            bounds: layout.children().nth(1).unwrap().bounds(),
+            size: footer_size(text_size),
+            text: self.error.as_deref().or(self.helper_text.as_deref()),
+            counter,
+            is_error: self.error.is_some()
+                || matches!(counter, Some((count, limit)) if count > limit),
+        })
+    }
+
+    /// Produces the messages of a change of focus of the [`TextInput`],
+    /// normalizing its value when it loses it.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    fn focus_changed(
+        &mut self,
+        was_focused: bool,
+        messages: &mut Vec<Message>,
+    ) {
+        if was_focused && !self.state.is_focused {
+            if let Some(normalize) = &self.normalize {
+                let value = self.value.to_string();
+                let normalized = normalize(&value);
+
+                if normalized != value {
+                    self.value = Value::new(&normalized);
+                    self.state.move_cursor_to_end();
+
+                    messages.push((self.on_change)(normalized));
+                }
+            }
+        }
+
+        let transition = match (was_focused, self.state.is_focused) {
+            (false, true) => self.on_focus.clone(),
+            (true, false) => self.on_blur.clone(),
+            _ => None,
+        };
+
+        if let Some(message) = transition {
+            messages.push(message);
+        }
+    }
+
+    fn resolved_direction(&self) -> Direction {
+        if self.value.len() == 0 {
+            self.direction.resolve(&self.placeholder)
+        } else {
+            self.direction.resolve(&self.value.to_string())
+        }
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -204,10 +495,56 @@ where
             .max_width(self.max_width)
             .height(Length::Units(text_size));
 
-        let mut text = layout::Node::new(limits.resolve(Size::ZERO));
-        text.move_to(Point::new(padding, padding));
+        let size = limits.resolve(Size::ZERO);
+
+        let input = if self.is_clearable {
+            let clear_width = f32::from(text_size).min(size.width);
+
+            let mut text = layout::Node::new(Size::new(
+                size.width - clear_width,
+                size.height,
+            ));
+            let mut clear =
+                layout::Node::new(Size::new(clear_width, size.height));
+
+            if self.resolved_direction().is_right_to_left() {
+                clear.move_to(Point::new(padding, padding));
+                text.move_to(Point::new(padding + clear_width, padding));
+            } else {
+                text.move_to(Point::new(padding, padding));
+                clear.move_to(Point::new(
+                    padding + size.width - clear_width,
+                    padding,
+                ));
+            }
 
-        layout::Node::with_children(text.size().pad(padding), vec![text])
+            layout::Node::with_children(size.pad(padding), vec![text, clear])
+        } else {
+            let mut text = layout::Node::new(size);
+            text.move_to(Point::new(padding, padding));
+
+            layout::Node::with_children(size.pad(padding), vec![text])
+        };
+
+        if self.has_footer() {
+            let input_size = input.size();
+
+            let mut footer = layout::Node::new(Size::new(
+                input_size.width,
+                f32::from(footer_size(text_size)),
+            ));
+            footer.move_to(Point::new(0.0, input_size.height + FOOTER_SPACING));
+
+            layout::Node::with_children(
+                Size::new(
+                    input_size.width,
+                    input_size.height + FOOTER_SPACING + footer.size().height,
+                ),
+                vec![input, footer],
+            )
+        } else {
+            input
+        }
     }
 
     fn on_event(
@@ -218,8 +555,45 @@ where
         messages: &mut Vec<Message>,
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>,
-    ) {
+    ) -> event::Status {
+        let direction = self.resolved_direction();
+        let event = logical_event(event, direction);
+        let was_focused = self.state.is_focused;
+        let layout = self.input_layout(&layout);
+
+        let is_input = matches!(
+            event,
+            Event::Keyboard(keyboard::Event::KeyPressed { .. })
+                | Event::Keyboard(keyboard::Event::CharacterReceived(_))
+                | Event::Mouse(mouse::Event::ButtonPressed(_))
+        );
+
+        // A focused input handles the keyboard, and the box handles clicks
+        let is_captured = match event {
+            Event::Keyboard(keyboard::Event::KeyPressed { .. })
+            | Event::Keyboard(keyboard::Event::CharacterReceived(_)) => {
+                was_focused
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                layout.bounds().contains(cursor_position)
+            }
+            _ => false,
+        };
+
         match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if matches!(
+                    self.clear_bounds(layout),
+                    Some(bounds) if bounds.contains(cursor_position)
+                ) =>
+            {
+                self.value = Value::new("");
+                self.state.cursor.move_to(0);
+                self.state.is_dragging = false;
+                self.state.is_focused = true;
+
+                messages.push((self.on_change)(String::new()));
+            }
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 let is_clicked = layout.bounds().contains(cursor_position);
 
@@ -234,7 +608,7 @@ where
 
                     match click.kind() {
                         click::Kind::Single => {
-                            if target > 0.0 {
+                            if target > 0.0 || direction.is_right_to_left() {
                                 let value = if self.is_secure {
                                     self.value.secure()
                                 } else {
@@ -247,6 +621,7 @@ where
                                     self.size,
                                     &value,
                                     &self.state,
+                                    direction,
                                     target,
                                 );
 
@@ -265,6 +640,7 @@ where
                                     self.size,
                                     &self.value,
                                     &self.state,
+                                    direction,
                                     target,
                                 );
 
@@ -293,7 +669,7 @@ where
                     let text_layout = layout.children().next().unwrap();
                     let target = x - text_layout.bounds().x;
 
-                    if target > 0.0 {
+                    if target > 0.0 || direction.is_right_to_left() {
                         let value = if self.is_secure {
                             self.value.secure()
                         } else {
@@ -306,6 +682,7 @@ where
                             self.size,
                             &value,
                             &self.state,
+                            direction,
                             target,
                         );
 
@@ -321,18 +698,27 @@ where
                     && self.state.is_pasting.is_none()
                     && !c.is_control() =>
             {
-                let mut editor =
-                    Editor::new(&mut self.value, &mut self.state.cursor);
+                if let Some(mask) = &self.mask {
+                    if mask.insert(&mut self.value, &mut self.state.cursor, c) {
+                        messages.push((self.on_change)(self.value.to_string()));
+                    }
+                } else {
+                    let mut editor =
+                        Editor::new(&mut self.value, &mut self.state.cursor);
 
-                editor.insert(c);
+                    editor.insert(c);
 
-                let message = (self.on_change)(editor.contents());
-                messages.push(message);
+                    let message = (self.on_change)(editor.contents());
+                    messages.push(message);
+                }
             }
             Event::Keyboard(keyboard::Event::KeyPressed {
                 key_code,
                 modifiers,
+                is_repeat,
             }) if self.state.is_focused => match key_code {
+                // Holding the key down submits only once
+                keyboard::KeyCode::Enter if is_repeat => {}
                 keyboard::KeyCode::Enter => {
                     if let Some(on_submit) = self.on_submit.clone() {
                         messages.push(on_submit);
@@ -350,13 +736,24 @@ where
                         }
                     }
 
-                    let mut editor =
-                        Editor::new(&mut self.value, &mut self.state.cursor);
+                    if let Some(mask) = &self.mask {
+                        if mask
+                            .backspace(&mut self.value, &mut self.state.cursor)
+                        {
+                            messages
+                                .push((self.on_change)(self.value.to_string()));
+                        }
+                    } else {
+                        let mut editor = Editor::new(
+                            &mut self.value,
+                            &mut self.state.cursor,
+                        );
 
-                    editor.backspace();
+                        editor.backspace();
 
-                    let message = (self.on_change)(editor.contents());
-                    messages.push(message);
+                        let message = (self.on_change)(editor.contents());
+                        messages.push(message);
+                    }
                 }
                 keyboard::KeyCode::Delete => {
                     if platform::is_jump_modifier_pressed(modifiers)
@@ -374,13 +771,23 @@ where
                         }
                     }
 
-                    let mut editor =
-                        Editor::new(&mut self.value, &mut self.state.cursor);
+                    if let Some(mask) = &self.mask {
+                        if mask.delete(&mut self.value, &mut self.state.cursor)
+                        {
+                            messages
+                                .push((self.on_change)(self.value.to_string()));
+                        }
+                    } else {
+                        let mut editor = Editor::new(
+                            &mut self.value,
+                            &mut self.state.cursor,
+                        );
 
-                    editor.delete();
+                        editor.delete();
 
-                    let message = (self.on_change)(editor.contents());
-                    messages.push(message);
+                        let message = (self.on_change)(editor.contents());
+                        messages.push(message);
+                    }
                 }
                 keyboard::KeyCode::Left => {
                     if platform::is_jump_modifier_pressed(modifiers)
@@ -451,14 +858,23 @@ where
                                 }
                             };
 
-                            let mut editor = Editor::new(
-                                &mut self.value,
-                                &mut self.state.cursor,
-                            );
+                            if let Some(mask) = &self.mask {
+                                mask.paste(
+                                    &mut self.value,
+                                    &mut self.state.cursor,
+                                    &content.to_string(),
+                                );
+                            } else {
+                                let mut editor = Editor::new(
+                                    &mut self.value,
+                                    &mut self.state.cursor,
+                                );
 
-                            editor.paste(content.clone());
+                                editor.paste(content.clone());
+                            }
 
-                            let message = (self.on_change)(editor.contents());
+                            let message =
+                                (self.on_change)(self.value.to_string());
                             messages.push(message);
 
                             self.state.is_pasting = Some(content);
@@ -489,6 +905,19 @@ where
             },
             _ => {}
         }
+
+        // The cursor blinks again from the start whenever it may have moved
+        if self.state.is_focused && (is_input || !was_focused) {
+            self.state.blink_started_at = Some(Instant::now());
+        }
+
+        self.focus_changed(was_focused, messages);
+
+        if is_captured {
+            event::Status::Captured
+        } else {
+            event::Status::Ignored
+        }
     }
 
     fn draw(
@@ -498,20 +927,44 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> Renderer::Output {
+        let size = self.size.unwrap_or(renderer.default_size());
+        let footer = self.footer(layout, size);
+        let layout = self.input_layout(&layout);
+        let clear_bounds = self.clear_bounds(layout);
+
         let bounds = layout.bounds();
         let text_bounds = layout.children().next().unwrap().bounds();
+        let direction = self.resolved_direction();
+
+        let now = Instant::now();
+
+        // The cursor only needs to be drawn again when it appears or vanishes
+        if self.state.is_focused {
+            if let Some(next_blink) =
+                self.state.next_blink(self.cursor_blink, now)
+            {
+                renderer.request_redraw_at(next_blink);
+            }
+        }
+
+        let is_cursor_visible =
+            self.state.is_cursor_visible(self.cursor_blink, now);
 
         if self.is_secure {
             self::Renderer::draw(
                 renderer,
                 bounds,
                 text_bounds,
+                clear_bounds,
+                footer,
                 cursor_position,
                 self.font,
-                self.size.unwrap_or(renderer.default_size()),
+                size,
                 &self.placeholder,
                 &self.value.secure(),
                 &self.state,
+                is_cursor_visible,
+                direction,
                 &self.style,
             )
         } else {
@@ -519,17 +972,32 @@ where
                 renderer,
                 bounds,
                 text_bounds,
+                clear_bounds,
+                footer,
                 cursor_position,
                 self.font,
-                self.size.unwrap_or(renderer.default_size()),
+                size,
                 &self.placeholder,
                 &self.value,
                 &self.state,
+                is_cursor_visible,
+                direction,
                 &self.style,
             )
         }
     }
 
+    fn accessibility(&self) -> Option<Accessibility> {
+        Some(Accessibility {
+            role: Role::TextInput,
+            name: self
+                .accessible_name
+                .clone()
+                .or_else(|| Some(self.placeholder.clone()))
+                .filter(|name| !name.is_empty()),
+        })
+    }
+
     fn hash_layout(&self, state: &mut Hasher) {
         use std::{any::TypeId, hash::Hash};
         struct Marker;
@@ -539,6 +1007,101 @@ where
         self.max_width.hash(state);
         self.padding.hash(state);
         self.size.hash(state);
+        self.is_clearable.hash(state);
+        self.has_footer().hash(state);
+        self.placeholder.hash(state);
+        self.accessible_name.hash(state);
+
+        if self.is_clearable {
+            self.resolved_direction().hash(state);
+        }
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        if self.state.is_focused {
+            Some(layout.bounds())
+        } else {
+            None
+        }
+    }
+
+    fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        messages: &mut Vec<Message>,
+    ) {
+        let was_focused = self.state.is_focused;
+
+        visit(&mut *self.state);
+
+        self.focus_changed(was_focused, messages);
+    }
+}
+
+/// The text shown below the box of a [`TextInput`].
+///
+/// [`TextInput`]: struct.TextInput.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Footer<'a> {
+    /// The bounds of the [`Footer`].
+    ///
+    /// [`Footer`]: struct.Footer.html
+    pub bounds: Rectangle,
+
+    /// The text size of the [`Footer`].
+    ///
+    /// [`Footer`]: struct.Footer.html
+    pub size: u16,
+
+    /// The error message or, if there is none, the helper text.
+    pub text: Option<&'a str>,
+
+    /// The number of characters of the value and the character limit, if
+    /// the [`TextInput`] has a counter.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub counter: Option<(usize, usize)>,
+
+    /// Whether the [`TextInput`] is in an error state, either because it
+    /// has an error message or because its value exceeds the character
+    /// limit.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub is_error: bool,
+}
+
+/// Returns the text size of a [`Footer`], which is a bit smaller than the
+/// text size of the value.
+///
+/// [`Footer`]: struct.Footer.html
+fn footer_size(text_size: u16) -> u16 {
+    text_size - text_size / 5
+}
+
+/// Maps the arrow keys pressed in the given [`Direction`] to their logical
+/// movement.
+///
+/// [`Direction`]: ../../enum.Direction.html
+fn logical_event(event: Event, direction: Direction) -> Event {
+    match event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            modifiers,
+            is_repeat,
+        }) if direction.is_right_to_left() => {
+            let key_code = match key_code {
+                keyboard::KeyCode::Left => keyboard::KeyCode::Right,
+                keyboard::KeyCode::Right => keyboard::KeyCode::Left,
+                key_code => key_code,
+            };
+
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+                is_repeat,
+            })
+        }
+        event => event,
     }
 }
 
@@ -580,31 +1143,45 @@ pub trait Renderer: text::Renderer + Sized {
     /// It receives:
     /// - the bounds of the [`TextInput`]
     /// - the bounds of the text (i.e. the current value)
+    /// - the bounds of the clear button, if it should be displayed
+    /// - the [`Footer`] shown below the box, if any
     /// - the cursor position
     /// - the placeholder to show when the value is empty
     /// - the current [`Value`]
     /// - the current [`State`]
+    /// - whether the cursor is visible, as it blinks while focused
+    /// - the resolved [`Direction`] of the text
     ///
     /// [`TextInput`]: struct.TextInput.html
     /// [`Value`]: struct.Value.html
     /// [`State`]: struct.State.html
+    /// [`Direction`]: ../../enum.Direction.html
+    /// [`Footer`]: struct.Footer.html
     fn draw(
         &mut self,
         bounds: Rectangle,
         text_bounds: Rectangle,
+        clear_bounds: Option<Rectangle>,
+        footer: Option<Footer<'_>>,
         cursor_position: Point,
         font: Self::Font,
         size: u16,
         placeholder: &str,
         value: &Value,
         state: &State,
+        is_cursor_visible: bool,
+        direction: Direction,
         style: &Self::Style,
     ) -> Self::Output;
 
     /// Computes the position of the text cursor at the given X coordinate of
     /// a [`TextInput`].
     ///
+    /// The X coordinate is relative to the left edge of the text bounds. When
+    /// the [`Direction`] is right-to-left, the value starts at the right edge.
+    ///
     /// [`TextInput`]: struct.TextInput.html
+    /// [`Direction`]: ../../enum.Direction.html
     fn find_cursor_position(
         &self,
         text_bounds: Rectangle,
@@ -612,12 +1189,19 @@ pub trait Renderer: text::Renderer + Sized {
         size: Option<u16>,
         value: &Value,
         state: &State,
+        direction: Direction,
         x: f32,
     ) -> usize {
         let size = size.unwrap_or(self.default_size());
 
         let offset = self.offset(text_bounds, font, size, &value, &state);
 
+        let x = if direction.is_right_to_left() {
+            text_bounds.width - x
+        } else {
+            x
+        };
+
         find_cursor_position(
             self,
             &value,
@@ -653,6 +1237,11 @@ pub struct State {
     is_pasting: Option<Value>,
     last_click: Option<mouse::Click>,
     cursor: Cursor,
+    menu: Option<Box<menu::State>>,
+    shows_suggestions: bool,
+    hovered_suggestion: Option<usize>,
+    last_suggestion: Option<usize>,
+    blink_started_at: Option<Instant>,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -670,10 +1259,8 @@ impl State {
     pub fn focused() -> Self {
         Self {
             is_focused: true,
-            is_dragging: false,
-            is_pasting: None,
-            last_click: None,
-            cursor: Cursor::default(),
+            blink_started_at: Some(Instant::now()),
+            ..Self::default()
         }
     }
 
@@ -715,6 +1302,60 @@ impl State {
     pub fn move_cursor_to(&mut self, position: usize) {
         self.cursor.move_to(position);
     }
+
+    /// Returns whether the cursor is visible at the given instant, blinking
+    /// at the given interval, if any.
+    fn is_cursor_visible(&self, blink: Option<Duration>, now: Instant) -> bool {
+        match (blink, self.blink_started_at) {
+            (Some(interval), Some(started_at))
+                if interval > Duration::from_secs(0) =>
+            {
+                let elapsed = now.saturating_duration_since(started_at);
+                let phase = elapsed.as_secs_f32() / interval.as_secs_f32();
+
+                phase as u32 % 2 == 0
+            }
+            _ => true,
+        }
+    }
+
+    /// Returns the instant the cursor appears or vanishes next, after the
+    /// given instant, if it blinks at the given interval.
+    fn next_blink(
+        &self,
+        blink: Option<Duration>,
+        now: Instant,
+    ) -> Option<Instant> {
+        match (blink, self.blink_started_at) {
+            (Some(interval), Some(started_at))
+                if interval > Duration::from_secs(0) =>
+            {
+                let elapsed = now.saturating_duration_since(started_at);
+                let phases = elapsed.as_nanos() / interval.as_nanos() + 1;
+
+                Some(started_at + interval * phases as u32)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+        self.blink_started_at = Some(Instant::now());
+        self.move_cursor_to_end();
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.is_dragging = false;
+        self.is_pasting = None;
+    }
 }
 
 // TODO: Reduce allocations
@@ -796,3 +1437,204 @@ mod platform {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+
+    #[test]
+    fn cursor_blinks_from_the_last_input() {
+        let mut state = State::new();
+
+        {
+            let text_input =
+                TextInput::new(&mut state, "", "iced", |value| value)
+                    .width(Length::Units(200));
+
+            let mut harness = Harness::new(text_input, Size::new(200.0, 100.0));
+            harness.click(Point::new(10.0, 5.0));
+        }
+
+        let started_at = state.blink_started_at.expect("blink start");
+        let at = |millis| started_at + Duration::from_millis(millis);
+        let blink = Some(CURSOR_BLINK_INTERVAL);
+
+        assert!(state.is_cursor_visible(blink, at(0)));
+        assert!(!state.is_cursor_visible(blink, at(600)));
+        assert!(state.is_cursor_visible(blink, at(1100)));
+        assert!(state.is_cursor_visible(None, at(600)));
+
+        assert_eq!(state.next_blink(blink, at(0)), Some(at(530)));
+        assert_eq!(state.next_blink(blink, at(600)), Some(at(1060)));
+        assert_eq!(state.next_blink(None, at(600)), None);
+    }
+
+    #[test]
+    fn clear_button_empties_the_value() {
+        let mut state = State::new();
+        let text_input =
+            TextInput::new(&mut state, "Search", "iced", |value| value)
+                .width(Length::Units(200))
+                .clearable();
+
+        let mut harness = Harness::new(text_input, Size::new(200.0, 100.0));
+
+        // The clear button is as wide as the text size at the trailing edge
+        harness.click(Point::new(190.0, 10.0));
+        harness.click(Point::new(190.0, 10.0));
+
+        assert_eq!(harness.into_messages(), vec![String::new()]);
+        assert!(state.is_focused());
+    }
+
+    #[test]
+    fn helper_text_is_laid_out_below_the_box() {
+        let mut state = State::new();
+        let text_input = TextInput::new(&mut state, "Bio", "", |value| value)
+            .width(Length::Units(100))
+            .helper_text("Tell us about yourself")
+            .character_limit(140);
+
+        let mut harness = Harness::new(text_input, Size::new(200.0, 100.0));
+
+        assert_eq!(
+            harness.snapshot().lines().collect::<Vec<_>>(),
+            vec![
+                "TextInput (0, 0) 100x40",
+                "  Node (0, 0) 100x20",
+                "    Node (0, 0) 100x20",
+                "  Node (0, 24) 100x16",
+            ]
+        );
+
+        // Clicking the footer does not focus the box
+        harness.click(Point::new(10.0, 30.0));
+        harness.click(Point::new(10.0, 30.0));
+        drop(harness);
+
+        assert!(!state.is_focused());
+    }
+
+    #[test]
+    fn focus_transitions_produce_messages_once() {
+        let mut state = State::new();
+        let text_input = TextInput::new(&mut state, "Name", "", |value| value)
+            .width(Length::Units(100))
+            .on_focus(String::from("focus"))
+            .on_blur(String::from("blur"));
+
+        let mut harness = Harness::new(text_input, Size::new(200.0, 100.0));
+
+        harness.click(Point::new(5.0, 5.0));
+        harness.click(Point::new(50.0, 5.0));
+        harness.click(Point::new(150.0, 50.0));
+        harness.click(Point::new(150.0, 50.0));
+
+        assert_eq!(
+            harness.into_messages(),
+            vec![String::from("focus"), String::from("blur")]
+        );
+    }
+
+    #[test]
+    fn holding_enter_submits_once() {
+        let mut state = State::focused();
+        let text_input = TextInput::new(&mut state, "Name", "", |value| value)
+            .on_submit(String::from("submit"));
+
+        let mut harness = Harness::new(text_input, Size::new(200.0, 100.0));
+
+        harness.events((0..3).map(|i| {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Enter,
+                modifiers: keyboard::ModifiersState::default(),
+                is_repeat: i > 0,
+            })
+        }));
+
+        assert_eq!(harness.into_messages(), vec![String::from("submit")]);
+    }
+
+    #[test]
+    fn value_is_normalized_on_blur() {
+        let mut state = State::focused();
+        let text_input =
+            TextInput::new(&mut state, "Name", "  iced ", |value| value)
+                .width(Length::Units(100))
+                .on_blur(String::from("blur"))
+                .normalize_on_blur(|value| value.trim().to_string());
+
+        let mut harness = Harness::new(text_input, Size::new(200.0, 100.0));
+
+        harness.click(Point::new(150.0, 50.0));
+
+        assert_eq!(
+            harness.into_messages(),
+            vec![String::from("iced"), String::from("blur")]
+        );
+        assert!(matches!(
+            state.cursor().state(&Value::new("iced")),
+            cursor::State::Index(4)
+        ));
+    }
+
+    struct Content(&'static str);
+
+    impl Clipboard for Content {
+        fn content(&self) -> Option<String> {
+            Some(String::from(self.0))
+        }
+    }
+
+    #[test]
+    fn mask_inserts_literals_and_skips_them_when_deleting() {
+        let mut state = State::focused();
+        let text_input = TextInput::new(&mut state, "Phone", "", |value| value)
+            .mask("(###) ###-####");
+
+        let mut harness = Harness::new(text_input, Size::new(200.0, 100.0));
+
+        harness.type_text("555a12");
+        harness.press_key(keyboard::KeyCode::Backspace);
+        harness.press_key(keyboard::KeyCode::Backspace);
+
+        assert_eq!(
+            harness.into_messages(),
+            vec!["(5", "(55", "(555", "(555) 1", "(555) 12", "(555) 1", "(555"]
+        );
+        assert!(matches!(
+            state.cursor().state(&Value::new("(555")),
+            cursor::State::Index(4)
+        ));
+    }
+
+    #[test]
+    fn mask_reformats_pasted_content() {
+        let clipboard = Content("555.123.45678");
+
+        let mut state = State::focused();
+        let text_input = TextInput::new(&mut state, "Phone", "", |value| value)
+            .mask("(###) ###-####");
+
+        let mut harness = Harness::new(text_input, Size::new(200.0, 100.0))
+            .clipboard(&clipboard);
+
+        // Digits beyond the capacity of the mask are dropped
+        harness.event(Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::V,
+            modifiers: keyboard::ModifiersState {
+                control: true,
+                logo: true,
+                ..keyboard::ModifiersState::default()
+            },
+            is_repeat: false,
+        }));
+
+        assert_eq!(harness.into_messages(), vec!["(555) 123-4567"]);
+        assert!(matches!(
+            state.cursor().state(&Value::new("(555) 123-4567")),
+            cursor::State::Index(14)
+        ));
+    }
+}
@@ -0,0 +1,190 @@
+use crate::text_input::{Cursor, Value};
+
+use std::ops::Range;
+
+/// A pattern of digit placeholders (`#`) and literal characters.
+#[derive(Debug, Clone)]
+pub struct Mask {
+    slots: Vec<Slot>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Slot {
+    Digit,
+    Literal(char),
+}
+
+impl Mask {
+    pub fn new(pattern: &str) -> Mask {
+        let slots = pattern
+            .chars()
+            .map(|c| match c {
+                '#' => Slot::Digit,
+                _ => Slot::Literal(c),
+            })
+            .collect();
+
+        Mask { slots }
+    }
+
+    /// Reformats the given string to the [`Mask`].
+    ///
+    /// [`Mask`]: struct.Mask.html
+    pub fn apply(&self, string: &str) -> String {
+        self.format(&self.entered(string))
+    }
+
+    /// Formats the given digits.
+    ///
+    /// Literals are only inserted right before the digit that follows them,
+    /// and digits beyond the capacity of the mask are dropped.
+    fn format(&self, digits: &[char]) -> String {
+        let mut digits = digits.iter().copied().peekable();
+        let mut formatted = String::new();
+
+        for slot in &self.slots {
+            if digits.peek().is_none() {
+                break;
+            }
+
+            match slot {
+                Slot::Digit => formatted.extend(digits.next()),
+                Slot::Literal(c) => formatted.push(*c),
+            }
+        }
+
+        formatted
+    }
+
+    pub fn insert(
+        &self,
+        value: &mut Value,
+        cursor: &mut Cursor,
+        character: char,
+    ) -> bool {
+        if !character.is_ascii_digit() {
+            return false;
+        }
+
+        let range = self.selected_digits(value, cursor);
+
+        if range.is_empty()
+            && self.entered(&value.to_string()).len() >= self.capacity()
+        {
+            return false;
+        }
+
+        self.splice(value, cursor, range, &[character]);
+
+        true
+    }
+
+    pub fn paste(&self, value: &mut Value, cursor: &mut Cursor, content: &str) {
+        let range = self.selected_digits(value, cursor);
+
+        self.splice(value, cursor, range, &self.entered(content));
+    }
+
+    pub fn backspace(&self, value: &mut Value, cursor: &mut Cursor) -> bool {
+        let range = match self.selected_digits(value, cursor) {
+            range if !range.is_empty() => range,
+            Range { start: 0, .. } => return false,
+            Range { start, .. } => start - 1..start,
+        };
+
+        self.splice(value, cursor, range, &[]);
+
+        true
+    }
+
+    pub fn delete(&self, value: &mut Value, cursor: &mut Cursor) -> bool {
+        let range = match self.selected_digits(value, cursor) {
+            range if !range.is_empty() => range,
+            Range { start, .. }
+                if start >= self.entered(&value.to_string()).len() =>
+            {
+                return false
+            }
+            Range { start, .. } => start..start + 1,
+        };
+
+        self.splice(value, cursor, range, &[]);
+
+        true
+    }
+
+    /// Returns the digits entered in the given string, skipping the literals
+    /// of the [`Mask`] in their positions.
+    ///
+    /// [`Mask`]: struct.Mask.html
+    fn entered(&self, string: &str) -> Vec<char> {
+        string
+            .chars()
+            .enumerate()
+            .filter(|(i, c)| match self.slots.get(*i) {
+                Some(Slot::Literal(literal)) if literal == c => false,
+                _ => c.is_ascii_digit(),
+            })
+            .map(|(_, c)| c)
+            .collect()
+    }
+
+    fn capacity(&self) -> usize {
+        self.slots
+            .iter()
+            .filter(|slot| matches!(slot, Slot::Digit))
+            .count()
+    }
+
+    /// Returns the range of digits covered by the selection, or an empty
+    /// range at the cursor if nothing is selected.
+    fn selected_digits(&self, value: &Value, cursor: &Cursor) -> Range<usize> {
+        let (left, right) = cursor.selection(value).unwrap_or_else(|| {
+            let end = cursor.end(value);
+
+            (end, end)
+        });
+
+        let digits_until =
+            |index: usize| self.entered(&value.until(index).to_string()).len();
+
+        digits_until(left)..digits_until(right)
+    }
+
+    fn splice(
+        &self,
+        value: &mut Value,
+        cursor: &mut Cursor,
+        range: Range<usize>,
+        inserted: &[char],
+    ) {
+        let mut digits = self.entered(&value.to_string());
+        let start = range.start.min(digits.len());
+        let end = range.end.min(digits.len());
+
+        let _ = digits.splice(start..end, inserted.iter().copied());
+        digits.truncate(self.capacity());
+
+        *value = Value::new(&self.format(&digits));
+
+        cursor.move_to(
+            self.position_after((start + inserted.len()).min(digits.len())),
+        );
+    }
+
+    /// Returns the position right after the given amount of digits, once
+    /// formatted.
+    fn position_after(&self, amount: usize) -> usize {
+        if amount == 0 {
+            return 0;
+        }
+
+        self.slots
+            .iter()
+            .enumerate()
+            .filter(|(_, slot)| matches!(slot, Slot::Digit))
+            .nth(amount - 1)
+            .map(|(i, _)| i + 1)
+            .unwrap_or(0)
+    }
+}
@@ -0,0 +1,412 @@
+//! Suggest values to fill a [`TextInput`] with.
+//!
+//! [`TextInput`]: ../struct.TextInput.html
+use crate::accessibility::Accessibility;
+use crate::focus::Focusable;
+use crate::{
+    event, keyboard, layout, mouse,
+    overlay::{self, menu, Menu},
+    text_input::{self, TextInput, Value},
+    Clipboard, Element, Event, Hasher, Layout, Length, Point, Rectangle, Size,
+    Widget,
+};
+
+use std::time::Instant;
+
+/// A [`TextInput`] showing a menu of suggestions while it is being edited.
+///
+/// The suggestions containing the value of the [`TextInput`], ignoring case,
+/// are shown in a menu below its box. The arrow keys move through the
+/// suggestions, and pressing `Enter` or clicking one of them fills the
+/// [`TextInput`] with it, producing its `on_change` message. Pressing
+/// `Escape` hides the menu until the value is edited again.
+///
+/// Use [`TextInput::suggestions`] to build one.
+///
+/// [`TextInput`]: ../struct.TextInput.html
+/// [`TextInput::suggestions`]: ../struct.TextInput.html#method.suggestions
+#[allow(missing_debug_implementations)]
+pub struct Suggestions<'a, Message, Renderer: text_input::Renderer> {
+    text_input: TextInput<'a, Message, Renderer>,
+    suggestions: &'a [String],
+    on_selected: Option<Box<dyn Fn(String) -> Message>>,
+    matching: Vec<&'a String>,
+    query: String,
+}
+
+impl<'a, Message, Renderer> Suggestions<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text_input::Renderer,
+{
+    /// Creates new [`Suggestions`] for the given [`TextInput`].
+    ///
+    /// [`Suggestions`]: struct.Suggestions.html
+    /// [`TextInput`]: ../struct.TextInput.html
+    pub fn new(
+        text_input: TextInput<'a, Message, Renderer>,
+        suggestions: &'a [String],
+    ) -> Self {
+        Suggestions {
+            text_input,
+            suggestions,
+            on_selected: None,
+            matching: Vec::new(),
+            query: String::new(),
+        }
+    }
+
+    /// Sets the message produced when one of the [`Suggestions`] is
+    /// selected, right after the `on_change` message of the [`TextInput`].
+    ///
+    /// [`Suggestions`]: struct.Suggestions.html
+    /// [`TextInput`]: ../struct.TextInput.html
+    pub fn on_suggestion_selected<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(String) -> Message,
+    {
+        self.on_selected = Some(Box::new(f));
+        self
+    }
+
+    /// Returns whether the menu of [`Suggestions`] is shown.
+    ///
+    /// [`Suggestions`]: struct.Suggestions.html
+    fn is_shown(&self) -> bool {
+        let state = &self.text_input.state;
+
+        state.is_focused
+            && state.shows_suggestions
+            && matching(self.suggestions, &self.text_input.value.to_string())
+                .next()
+                .is_some()
+    }
+
+    /// Returns whether the given key is handled by the menu of
+    /// [`Suggestions`] instead of the [`TextInput`].
+    ///
+    /// [`Suggestions`]: struct.Suggestions.html
+    /// [`TextInput`]: ../struct.TextInput.html
+    fn is_menu_key(&self, key_code: keyboard::KeyCode) -> bool {
+        match key_code {
+            keyboard::KeyCode::Up
+            | keyboard::KeyCode::Down
+            | keyboard::KeyCode::Escape => self.is_shown(),
+            keyboard::KeyCode::Enter => {
+                self.text_input.state.hovered_suggestion.is_some()
+                    && self.is_shown()
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the matching suggestion at the given index, if any.
+    fn nth_matching(&self, index: usize) -> Option<&'a String> {
+        matching(self.suggestions, &self.text_input.value.to_string())
+            .nth(index)
+    }
+
+    /// Fills the [`TextInput`] with the given suggestion.
+    ///
+    /// [`TextInput`]: ../struct.TextInput.html
+    fn select(&mut self, suggestion: &str, messages: &mut Vec<Message>) {
+        let text_input = &mut self.text_input;
+
+        text_input.value = Value::new(suggestion);
+        text_input.state.move_cursor_to_end();
+        text_input.state.blink_started_at = Some(Instant::now());
+        text_input.state.shows_suggestions = false;
+        text_input.state.hovered_suggestion = None;
+
+        messages.push((text_input.on_change)(suggestion.to_string()));
+
+        if let Some(on_selected) = &self.on_selected {
+            messages.push(on_selected(suggestion.to_string()));
+        }
+    }
+}
+
+/// Returns the suggestions matching the given query.
+///
+/// Every suggestion matches an empty query.
+fn matching<'a, 'b>(
+    suggestions: &'a [String],
+    query: &'b str,
+) -> impl Iterator<Item = &'a String> + 'b
+where
+    'a: 'b,
+{
+    suggestions.iter().filter(move |suggestion| {
+        query.is_empty() || menu::highlight_range(suggestion, query).is_some()
+    })
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Suggestions<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text_input::Renderer + menu::Renderer,
+{
+    fn width(&self) -> Length {
+        Widget::<Message, Renderer>::width(&self.text_input)
+    }
+
+    fn height(&self) -> Length {
+        Widget::<Message, Renderer>::height(&self.text_input)
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.text_input.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        match event {
+            // The menu records the clicked suggestion before the click
+            // reaches the box
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if self.text_input.state.last_suggestion.is_some() =>
+            {
+                let selected = self
+                    .text_input
+                    .state
+                    .last_suggestion
+                    .take()
+                    .and_then(|index| self.nth_matching(index));
+
+                if let Some(suggestion) = selected {
+                    self.select(suggestion, messages);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if self.is_menu_key(key_code) => {
+                let count = matching(
+                    self.suggestions,
+                    &self.text_input.value.to_string(),
+                )
+                .count();
+                let hovered = self.text_input.state.hovered_suggestion;
+
+                match key_code {
+                    keyboard::KeyCode::Down => {
+                        self.text_input.state.hovered_suggestion = Some(
+                            hovered
+                                .map_or(0, |index| (index + 1).min(count - 1)),
+                        );
+                    }
+                    keyboard::KeyCode::Up => {
+                        self.text_input.state.hovered_suggestion =
+                            Some(hovered.map_or(count - 1, |index| {
+                                index.saturating_sub(1)
+                            }));
+                    }
+                    keyboard::KeyCode::Enter => {
+                        if let Some(suggestion) =
+                            hovered.and_then(|index| self.nth_matching(index))
+                        {
+                            self.select(suggestion, messages);
+                        }
+                    }
+                    _ => {
+                        self.text_input.state.shows_suggestions = false;
+                        self.text_input.state.hovered_suggestion = None;
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        let previous_value = self.text_input.value.to_string();
+
+        let status = self.text_input.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+
+        let state = &mut self.text_input.state;
+
+        if self.text_input.value.to_string() != previous_value {
+            state.shows_suggestions = true;
+            state.hovered_suggestion = None;
+        }
+
+        if !state.is_focused {
+            state.shows_suggestions = false;
+            state.hovered_suggestion = None;
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.text_input
+            .draw(renderer, defaults, layout, cursor_position)
+    }
+
+    fn accessibility(&self) -> Option<Accessibility> {
+        Widget::<Message, Renderer>::accessibility(&self.text_input)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        Widget::<Message, Renderer>::hash_layout(&self.text_input, state)
+    }
+
+    fn min_size(&self, renderer: &Renderer) -> Size {
+        Widget::<Message, Renderer>::min_size(&self.text_input, renderer)
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        Widget::<Message, Renderer>::focused_bounds(&self.text_input, layout)
+    }
+
+    fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        messages: &mut Vec<Message>,
+    ) {
+        Widget::<Message, Renderer>::focusables(
+            &mut self.text_input,
+            visit,
+            messages,
+        )
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !self.is_shown() {
+            return None;
+        }
+
+        let text_input = &mut self.text_input;
+        let bounds = text_input.input_layout(&layout).bounds();
+
+        self.query = text_input.value.to_string();
+        self.matching = matching(self.suggestions, &self.query).collect();
+
+        let text_input::State {
+            menu,
+            hovered_suggestion,
+            last_suggestion,
+            ..
+        } = &mut *text_input.state;
+
+        // The state of the menu is only allocated once suggestions are shown
+        let mut menu = Menu::new(
+            menu.get_or_insert_with(Box::default),
+            &self.matching,
+            hovered_suggestion,
+            last_suggestion,
+        )
+        .highlight(&self.query)
+        .width(bounds.width.round() as u16)
+        .padding(text_input.padding)
+        .font(text_input.font);
+
+        if let Some(size) = text_input.size {
+            menu = menu.text_size(size);
+        }
+
+        Some(menu.overlay(Point::new(bounds.x, bounds.y), bounds.height))
+    }
+}
+
+impl<'a, Message, Renderer> From<Suggestions<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text_input::Renderer + menu::Renderer,
+{
+    fn from(
+        suggestions: Suggestions<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(suggestions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+    use crate::text_input::State;
+
+    fn fruits() -> Vec<String> {
+        vec!["Apple", "Apricot", "Banana"]
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn suggestions_are_navigated_with_the_arrow_keys() {
+        let fruits = fruits();
+        let mut state = State::focused();
+        let text_input = TextInput::new(&mut state, "Fruit", "", |value| value)
+            .suggestions(&fruits)
+            .on_suggestion_selected(|fruit| format!("Selected {}", fruit));
+
+        let mut harness = Harness::new(text_input, Size::new(200.0, 200.0));
+
+        harness.type_text("ap");
+        harness.press_key(keyboard::KeyCode::Down);
+        harness.press_key(keyboard::KeyCode::Down);
+        harness.press_key(keyboard::KeyCode::Down);
+        harness.press_key(keyboard::KeyCode::Up);
+        harness.press_key(keyboard::KeyCode::Enter);
+
+        assert_eq!(
+            harness.into_messages(),
+            vec!["a", "ap", "Apple", "Selected Apple"]
+        );
+        assert!(state.is_focused());
+    }
+
+    #[test]
+    fn clicked_suggestion_fills_the_value() {
+        let fruits = fruits();
+        let mut state = State::focused();
+        let text_input = TextInput::new(&mut state, "Fruit", "", |value| value)
+            .suggestions(&fruits);
+
+        let mut harness = Harness::new(text_input, Size::new(200.0, 200.0));
+
+        harness.type_text("an");
+
+        // The only matching suggestion is right below the box
+        harness.click(Point::new(10.0, 30.0));
+
+        // The menu is hidden until the value is edited again
+        harness.press_key(keyboard::KeyCode::Down);
+        harness.press_key(keyboard::KeyCode::Enter);
+
+        assert_eq!(harness.into_messages(), vec!["a", "an", "Banana"]);
+        assert!(state.is_focused());
+    }
+}
@@ -1,3 +1,6 @@
+use crate::Direction;
+
+use unicode_bidi::{BidiInfo, Level};
 use unicode_segmentation::UnicodeSegmentation;
 
 /// The value of a [`TextInput`].
@@ -120,6 +123,35 @@ impl Value {
         let _ = self.graphemes.splice(start..end, std::iter::empty());
     }
 
+    /// Returns the contents of the [`Value`] in visual order, as they are
+    /// drawn from left to right.
+    ///
+    /// The runs of text are reordered following the Unicode Bidirectional
+    /// Algorithm, flowing in the given base [`Direction`]. Therefore, a
+    /// right-to-left word keeps its reading order inside a left-to-right
+    /// value, and the other way around.
+    ///
+    /// [`Value`]: struct.Value.html
+    /// [`Direction`]: ../../enum.Direction.html
+    pub fn visual(&self, direction: Direction) -> String {
+        let text = self.to_string();
+
+        let level = if direction.is_right_to_left() {
+            Level::rtl()
+        } else {
+            Level::ltr()
+        };
+
+        let bidi = BidiInfo::new(&text, Some(level));
+
+        bidi.paragraphs
+            .iter()
+            .map(|paragraph| {
+                bidi.reorder_line(paragraph, paragraph.range.clone())
+            })
+            .collect()
+    }
+
     /// Returns a new [`Value`] with all its graphemes replaced with the
     /// dot ('•') character.
     ///
@@ -132,3 +164,22 @@ impl Value {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn visual_order_follows_the_bidirectional_algorithm() {
+        let hebrew = Value::new("שלום");
+        let mixed = Value::new("שלום abc");
+
+        assert_eq!(hebrew.visual(Direction::RightToLeft), "םולש");
+        assert_eq!(mixed.visual(Direction::RightToLeft), "abc םולש");
+        assert_eq!(mixed.visual(Direction::LeftToRight), "םולש abc");
+        assert_eq!(
+            Value::new("abc 123").visual(Direction::RightToLeft),
+            "abc 123"
+        );
+    }
+}
@@ -0,0 +1,526 @@
+//! Display a floating tip next to some content while it is hovered.
+use std::hash::Hash;
+
+use crate::event;
+use crate::focus::Focusable;
+use crate::mouse;
+use crate::overlay;
+use crate::{
+    container, layout, text, Clipboard, Element, Event, Hasher, Layout, Length,
+    Point, Rectangle, Size, Text, Vector, Widget,
+};
+
+/// An element that shows a tip next to its content while the content is
+/// hovered.
+///
+/// The tip can be a simple text or any element, like an image preview. It is
+/// displayed in an overlay, so it can go beyond the bounds of its content,
+/// but it is always kept inside the viewport.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{renderer::Null, tooltip, Text};
+/// #
+/// # type Tooltip<'a, Message> = iced_native::Tooltip<'a, Message, Null>;
+/// #
+/// let mut state = tooltip::State::new();
+///
+/// let tooltip: Tooltip<'_, ()> = Tooltip::new(
+///     &mut state,
+///     Text::new("Hover me!"),
+///     "Hello!",
+///     tooltip::Position::Bottom,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Tooltip<'a, Message, Renderer: container::Renderer> {
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+    tooltip: Element<'a, Message, Renderer>,
+    position: Position,
+    gap: u16,
+    padding: u16,
+    style: <Renderer as container::Renderer>::Style,
+}
+
+/// The position of the tip of a [`Tooltip`], relative to its content.
+///
+/// [`Tooltip`]: struct.Tooltip.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Position {
+    /// The tip is centered above the content.
+    Top,
+
+    /// The tip is centered below the content.
+    Bottom,
+
+    /// The tip is centered on the left of the content.
+    Left,
+
+    /// The tip is centered on the right of the content.
+    Right,
+
+    /// The tip is placed right above the cursor and follows it.
+    FollowCursor,
+}
+
+/// The local state of a [`Tooltip`].
+///
+/// [`Tooltip`]: struct.Tooltip.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    cursor: Option<Vector>,
+}
+
+impl State {
+    /// Creates a new [`State`] with the tip of the [`Tooltip`] hidden.
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the tip of the [`Tooltip`] with this [`State`] is
+    /// shown.
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    /// [`State`]: struct.State.html
+    pub fn is_shown(&self) -> bool {
+        self.cursor.is_some()
+    }
+}
+
+impl<'a, Message, Renderer> Tooltip<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: container::Renderer + text::Renderer + 'a,
+{
+    /// Creates a new [`Tooltip`] with the given [`State`], content, text of
+    /// the tip, and [`Position`].
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    /// [`State`]: struct.State.html
+    /// [`Position`]: enum.Position.html
+    pub fn new(
+        state: &'a mut State,
+        content: impl Into<Element<'a, Message, Renderer>>,
+        tooltip: impl Into<String>,
+        position: Position,
+    ) -> Self {
+        Self::with_content(state, content, Text::new(tooltip), position)
+    }
+}
+
+impl<'a, Message, Renderer> Tooltip<'a, Message, Renderer>
+where
+    Renderer: container::Renderer,
+{
+    /// Creates a new [`Tooltip`] with the given [`State`], content, element
+    /// to show as the tip, and [`Position`].
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    /// [`State`]: struct.State.html
+    /// [`Position`]: enum.Position.html
+    pub fn with_content(
+        state: &'a mut State,
+        content: impl Into<Element<'a, Message, Renderer>>,
+        tooltip: impl Into<Element<'a, Message, Renderer>>,
+        position: Position,
+    ) -> Self {
+        Tooltip {
+            state,
+            content: content.into(),
+            tooltip: tooltip.into(),
+            position,
+            gap: 0,
+            padding: 5,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the distance between the tip and the content of the [`Tooltip`].
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn gap(mut self, gap: u16) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets the padding around the tip of the [`Tooltip`].
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the style of the tip of the [`Tooltip`].
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as container::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Tooltip<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: container::Renderer + 'a,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn min_size(&self, renderer: &Renderer) -> Size {
+        self.content.min_size(renderer)
+    }
+
+    fn max_size(&self) -> Size {
+        self.content.max_size()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                self.state.cursor = if layout.bounds().contains(cursor_position)
+                {
+                    Some(cursor_position - layout.position())
+                } else {
+                    None
+                };
+            }
+            Event::Mouse(mouse::Event::CursorLeft) => {
+                self.state.cursor = None;
+            }
+            _ => {}
+        }
+
+        self.content.widget.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn layout_changed(
+        &mut self,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+    ) {
+        self.content.layout_changed(layout, messages)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.content
+            .draw(renderer, defaults, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let Tooltip {
+            state,
+            content,
+            tooltip,
+            position,
+            gap,
+            padding,
+            style,
+        } = self;
+
+        content.overlay(layout).or_else(move || {
+            let cursor = state.cursor?;
+
+            Some(overlay::Element::new(
+                layout.position(),
+                Box::new(Overlay {
+                    tooltip,
+                    target: layout.bounds().size(),
+                    cursor,
+                    position: *position,
+                    gap: *gap,
+                    padding: *padding,
+                    style,
+                }),
+            ))
+        })
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.content.focused_bounds(layout)
+    }
+
+    fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        messages: &mut Vec<Message>,
+    ) {
+        self.content.focusables(visit, messages)
+    }
+}
+
+struct Overlay<'a, 'b, Message, Renderer: container::Renderer> {
+    tooltip: &'b Element<'a, Message, Renderer>,
+    target: Size,
+    cursor: Vector,
+    position: Position,
+    gap: u16,
+    padding: u16,
+    style: &'b <Renderer as container::Renderer>::Style,
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Overlay<'a, 'b, Message, Renderer>
+where
+    Renderer: container::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let padding = f32::from(self.padding);
+
+        let limits = layout::Limits::new(Size::ZERO, bounds).pad(padding);
+
+        let mut tooltip = self.tooltip.layout(renderer, &limits.loose());
+        tooltip.move_to(Point::new(padding, padding));
+
+        let size = tooltip.size().pad(padding);
+
+        let target = Rectangle {
+            x: position.x,
+            y: position.y,
+            width: self.target.width,
+            height: self.target.height,
+        };
+
+        let mut node = layout::Node::with_children(size, vec![tooltip]);
+
+        node.move_to(place(
+            self.position,
+            target,
+            position + self.cursor,
+            size,
+            f32::from(self.gap),
+            bounds,
+        ));
+
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        container::Renderer::draw(
+            renderer,
+            defaults,
+            layout.bounds(),
+            cursor_position,
+            self.style,
+            None,
+            false,
+            self.tooltip,
+            layout.children().next().unwrap(),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher, position: Point) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+        (self.target.width as u32).hash(state);
+        (self.target.height as u32).hash(state);
+        (self.cursor.x as u32).hash(state);
+        (self.cursor.y as u32).hash(state);
+        self.position.hash(state);
+        self.gap.hash(state);
+        self.padding.hash(state);
+        self.tooltip.hash_layout(state);
+    }
+
+    fn on_event(
+        &mut self,
+        _event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+    }
+}
+
+/// Returns the position of a tip of the given size shown next to the target
+/// bounds, kept inside the viewport.
+fn place(
+    position: Position,
+    target: Rectangle,
+    cursor: Point,
+    size: Size,
+    gap: f32,
+    viewport: Size,
+) -> Point {
+    let (x, y) = match position {
+        Position::Top => (
+            target.center_x() - size.width / 2.0,
+            target.y - gap - size.height,
+        ),
+        Position::Bottom => (
+            target.center_x() - size.width / 2.0,
+            target.y + target.height + gap,
+        ),
+        Position::Left => (
+            target.x - gap - size.width,
+            target.center_y() - size.height / 2.0,
+        ),
+        Position::Right => (
+            target.x + target.width + gap,
+            target.center_y() - size.height / 2.0,
+        ),
+        Position::FollowCursor => (cursor.x, cursor.y - gap - size.height),
+    };
+
+    // Keep the tip inside the viewport, favoring its top-left corner when
+    // it does not fit
+    Point::new(
+        x.min(viewport.width - size.width).max(0.0),
+        y.min(viewport.height - size.height).max(0.0),
+    )
+}
+
+impl<'a, Message, Renderer> From<Tooltip<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: container::Renderer + 'a,
+{
+    fn from(
+        tooltip: Tooltip<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(tooltip)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TARGET: Rectangle = Rectangle {
+        x: 100.0,
+        y: 100.0,
+        width: 50.0,
+        height: 20.0,
+    };
+
+    const VIEWPORT: Size = Size {
+        width: 300.0,
+        height: 200.0,
+    };
+
+    #[test]
+    fn tip_is_centered_next_to_the_target() {
+        let size = Size::new(30.0, 10.0);
+        let cursor = Point::new(110.0, 110.0);
+
+        assert_eq!(
+            place(Position::Top, TARGET, cursor, size, 5.0, VIEWPORT),
+            Point::new(110.0, 85.0)
+        );
+        assert_eq!(
+            place(Position::Bottom, TARGET, cursor, size, 5.0, VIEWPORT),
+            Point::new(110.0, 125.0)
+        );
+        assert_eq!(
+            place(Position::Left, TARGET, cursor, size, 5.0, VIEWPORT),
+            Point::new(65.0, 105.0)
+        );
+        assert_eq!(
+            place(Position::Right, TARGET, cursor, size, 5.0, VIEWPORT),
+            Point::new(155.0, 105.0)
+        );
+        assert_eq!(
+            place(Position::FollowCursor, TARGET, cursor, size, 5.0, VIEWPORT),
+            Point::new(110.0, 95.0)
+        );
+    }
+
+    #[test]
+    fn big_tip_is_kept_inside_the_viewport() {
+        let cursor = Point::new(110.0, 110.0);
+
+        assert_eq!(
+            place(
+                Position::Right,
+                TARGET,
+                cursor,
+                Size::new(200.0, 150.0),
+                0.0,
+                VIEWPORT
+            ),
+            Point::new(100.0, 35.0)
+        );
+        assert_eq!(
+            place(
+                Position::Bottom,
+                TARGET,
+                cursor,
+                Size::new(400.0, 100.0),
+                0.0,
+                VIEWPORT
+            ),
+            Point::new(0.0, 100.0)
+        );
+    }
+}
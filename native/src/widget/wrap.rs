@@ -0,0 +1,449 @@
+//! Distribute content horizontally, wrapping it into new lines.
+use std::hash::Hash;
+
+use crate::focus::Focusable;
+use crate::{
+    event, layout, overlay, Align, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Spacing, Widget,
+};
+
+/// A container that distributes its contents horizontally, starting a new
+/// line whenever the next child does not fit in the available width.
+///
+/// A [`Wrap`] is useful for toolbars, lists of tags and groups of buttons
+/// that need to adapt to the width of the window.
+///
+/// Every line is as tall as its tallest child. Lines are aligned
+/// horizontally inside the [`Wrap`] as a whole, including the last one,
+/// which may only be partially filled.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{renderer::Null, Align, Text};
+/// #
+/// # type Wrap<'a, Message> = iced_native::Wrap<'a, Message, Null>;
+/// #
+/// let tags: Wrap<'_, ()> = Wrap::new()
+///     .spacing(10)
+///     .line_spacing(5)
+///     .align_x(Align::Center)
+///     .push(Text::new("rust"))
+///     .push(Text::new("gui"))
+///     .push(Text::new("reactive"));
+/// ```
+///
+/// [`Wrap`]: struct.Wrap.html
+#[allow(missing_debug_implementations)]
+pub struct Wrap<'a, Message, Renderer> {
+    spacing: Spacing,
+    line_spacing: Spacing,
+    padding: Spacing,
+    width: Length,
+    height: Length,
+    horizontal_alignment: Align,
+    align_items: Align,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Wrap<'a, Message, Renderer> {
+    /// Creates an empty [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn new() -> Self {
+        Self::with_children(Vec::new())
+    }
+
+    /// Creates a [`Wrap`] with the given elements.
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn with_children(
+        children: Vec<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Wrap {
+            spacing: Spacing::Units(0),
+            line_spacing: Spacing::Units(0),
+            padding: Spacing::Units(0),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            horizontal_alignment: Align::Start,
+            align_items: Align::Start,
+            children,
+        }
+    }
+
+    /// Sets the horizontal spacing _between_ the elements of a line of the
+    /// [`Wrap`].
+    ///
+    /// A [`Spacing::Relative`] amount is relative to the available width.
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    /// [`Spacing::Relative`]: ../../enum.Spacing.html#variant.Relative
+    pub fn spacing(mut self, spacing: impl Into<Spacing>) -> Self {
+        self.spacing = spacing.into();
+        self
+    }
+
+    /// Sets the vertical spacing _between_ the lines of the [`Wrap`].
+    ///
+    /// A [`Spacing::Relative`] amount is relative to the available height.
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    /// [`Spacing::Relative`]: ../../enum.Spacing.html#variant.Relative
+    pub fn line_spacing(mut self, line_spacing: impl Into<Spacing>) -> Self {
+        self.line_spacing = line_spacing.into();
+        self
+    }
+
+    /// Sets the padding of the [`Wrap`].
+    ///
+    /// A [`Spacing::Relative`] amount is relative to the available width.
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    /// [`Spacing::Relative`]: ../../enum.Spacing.html#variant.Relative
+    pub fn padding(mut self, padding: impl Into<Spacing>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the horizontal alignment of the lines of the [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn align_x(mut self, alignment: Align) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the vertical alignment of the contents of the [`Wrap`] inside
+    /// their line.
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn align_items(mut self, align: Align) -> Self {
+        self.align_items = align;
+        self
+    }
+
+    /// Adds an element to the [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn push<E>(mut self, child: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.children.push(child.into());
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Default for Wrap<'a, Message, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Wrap<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let padding = self.padding.resolve(limits.max().width);
+        let spacing = self.spacing.resolve(limits.max().width);
+        let line_spacing = self.line_spacing.resolve(limits.max().height);
+
+        let limits = limits.pad(padding);
+        let available = limits.max();
+
+        let mut nodes: Vec<layout::Node> =
+            Vec::with_capacity(self.children.len());
+
+        // The range of nodes of every line, with its width and height
+        let mut lines: Vec<(usize, usize, Size)> = Vec::new();
+        let mut line = (0, Size::ZERO);
+
+        for child in &self.children {
+            let node = child
+                .layout(renderer, &layout::Limits::new(Size::ZERO, available));
+            let size = node.size();
+
+            let (start, line_size) = &mut line;
+
+            if nodes.len() > *start
+                && line_size.width + spacing + size.width > available.width
+            {
+                lines.push((*start, nodes.len(), *line_size));
+                line = (nodes.len(), size);
+            } else if nodes.len() > *start {
+                line_size.width += spacing + size.width;
+                line_size.height = line_size.height.max(size.height);
+            } else {
+                *line_size = size;
+            }
+
+            nodes.push(node);
+        }
+
+        if !nodes.is_empty() {
+            lines.push((line.0, nodes.len(), line.1));
+        }
+
+        let intrinsic = Size::new(
+            lines
+                .iter()
+                .map(|(_, _, size)| size.width)
+                .fold(0.0, f32::max),
+            lines.iter().map(|(_, _, size)| size.height).sum::<f32>()
+                + line_spacing * lines.len().saturating_sub(1) as f32,
+        );
+
+        let size = limits.resolve(intrinsic);
+        let mut y = padding;
+
+        for (start, end, line_size) in lines {
+            let mut x = padding
+                + match self.horizontal_alignment {
+                    Align::Start | Align::Baseline => 0.0,
+                    Align::Center => (size.width - line_size.width) / 2.0,
+                    Align::End => size.width - line_size.width,
+                };
+
+            for node in &mut nodes[start..end] {
+                let width = node.size().width;
+
+                node.move_to(Point::new(x, y));
+                node.align(
+                    Align::Start,
+                    self.align_items,
+                    Size::new(width, line_size.height),
+                );
+
+                x += width + spacing;
+            }
+
+            y += line_size.height + line_spacing;
+        }
+
+        layout::Node::with_children(size.pad(padding), nodes)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .map(|(child, layout)| {
+                child.widget.on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    messages,
+                    renderer,
+                    clipboard,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn layout_changed(
+        &mut self,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+    ) {
+        for (child, layout) in self.children.iter_mut().zip(layout.children()) {
+            child.layout_changed(layout, messages);
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(defaults, &self.children, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.horizontal_alignment.hash(state);
+        self.align_items.hash(state);
+        self.spacing.hash(state);
+        self.line_spacing.hash(state);
+        self.padding.hash(state);
+
+        for child in &self.children {
+            child.widget.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .filter_map(|(child, layout)| child.widget.overlay(layout))
+            .next()
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.children
+            .iter()
+            .zip(layout.children())
+            .find_map(|(child, layout)| child.focused_bounds(layout))
+    }
+
+    fn focusables(
+        &mut self,
+        visit: &mut dyn FnMut(&mut dyn Focusable),
+        messages: &mut Vec<Message>,
+    ) {
+        for child in &mut self.children {
+            child.focusables(visit, messages);
+        }
+    }
+}
+
+/// The renderer of a [`Wrap`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Wrap`] in your user interface.
+///
+/// [`Wrap`]: struct.Wrap.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws a [`Wrap`].
+    ///
+    /// It receives:
+    /// - the children of the [`Wrap`]
+    /// - the [`Layout`] of the [`Wrap`] and its children
+    /// - the cursor position
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        content: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Wrap<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        wrap: Wrap<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(wrap)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::testing::Harness;
+    use crate::Text;
+
+    fn item(width: u16, height: u16) -> Text<Null> {
+        Text::new("")
+            .width(Length::Units(width))
+            .height(Length::Units(height))
+    }
+
+    #[test]
+    fn children_wrap_into_new_lines() {
+        let wrap: Wrap<'_, (), Null> = Wrap::new()
+            .spacing(10)
+            .line_spacing(5)
+            .align_items(Align::End)
+            .push(item(40, 20))
+            .push(item(40, 10))
+            .push(item(40, 20))
+            .push(item(60, 20));
+
+        let harness = Harness::new(wrap, Size::new(100.0, 400.0));
+
+        assert_eq!(
+            harness.snapshot().lines().collect::<Vec<_>>(),
+            vec![
+                "Wrap (0, 0) 90x70",
+                "  Text (0, 0) 40x20",
+                "  Text (50, 10) 40x10",
+                "  Text (0, 25) 40x20",
+                "  Text (0, 50) 60x20",
+            ]
+        );
+    }
+
+    #[test]
+    fn partial_lines_follow_the_alignment() {
+        let wrap: Wrap<'_, (), Null> = Wrap::new()
+            .width(Length::Fill)
+            .padding(10)
+            .align_x(Align::Center)
+            .push(item(50, 20))
+            .push(item(50, 20))
+            .push(item(40, 20));
+
+        let harness = Harness::new(wrap, Size::new(120.0, 100.0));
+
+        assert_eq!(
+            harness.snapshot().lines().collect::<Vec<_>>(),
+            vec![
+                "Wrap (0, 0) 120x60",
+                "  Text (10, 10) 50x20",
+                "  Text (60, 10) 50x20",
+                "  Text (40, 30) 40x20",
+            ]
+        );
+    }
+}
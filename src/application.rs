@@ -1,5 +1,5 @@
 use crate::window;
-use crate::{Color, Command, Element, Executor, Settings, Subscription};
+use crate::{Color, Command, Element, Executor, Settings, Subscription, Theme};
 
 /// An interactive cross-platform application.
 ///
@@ -185,6 +185,23 @@ pub trait Application: Sized {
         Color::WHITE
     }
 
+    /// Returns the [`Theme`] of the [`Application`].
+    ///
+    /// Widgets without an explicit style are drawn using the style sheets of
+    /// the [`Theme`]. It can change at runtime (i.e. switching between light
+    /// and dark modes).
+    ///
+    /// Currently, the theme only has an effect in native platforms.
+    ///
+    /// By default, it returns [`Theme::default()`].
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`Theme`]: struct.Theme.html
+    /// [`Theme::default()`]: struct.Theme.html
+    fn theme(&self) -> Theme {
+        Theme::default()
+    }
+
     /// Returns the scale factor of the [`Application`].
     ///
     /// It can be used to dynamically control the size of the UI at runtime
@@ -259,6 +276,10 @@ where
     fn view(&mut self) -> Element<'_, Self::Message> {
         self.0.view()
     }
+
+    fn prepare(&self, renderer: &mut Self::Renderer) {
+        renderer.set_theme(self.0.theme());
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -189,6 +189,7 @@ pub mod executor;
 pub mod keyboard;
 pub mod mouse;
 pub mod settings;
+pub mod touch;
 pub mod widget;
 pub mod window;
 
@@ -233,7 +234,14 @@ pub use result::Result;
 pub use sandbox::Sandbox;
 pub use settings::Settings;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub use renderer::Theme;
+
+#[cfg(target_arch = "wasm32")]
+pub use runtime::Theme;
+
 pub use runtime::{
-    futures, Align, Background, Color, Command, Font, HorizontalAlignment,
-    Length, Point, Rectangle, Size, Subscription, Vector, VerticalAlignment,
+    color_palette, futures, Align, Background, Color, Command, ContentFit,
+    Direction, Font, HorizontalAlignment, Length, Point, Rectangle, Size,
+    Spacing, Subscription, Vector, VerticalAlignment,
 };
@@ -1,5 +1,5 @@
 use crate::{
-    Application, Color, Command, Element, Error, Settings, Subscription,
+    Application, Color, Command, Element, Error, Settings, Subscription, Theme,
 };
 
 /// A sandboxed [`Application`].
@@ -136,6 +136,20 @@ pub trait Sandbox {
         Color::WHITE
     }
 
+    /// Returns the [`Theme`] of the [`Sandbox`].
+    ///
+    /// Widgets without an explicit style are drawn using the style sheets of
+    /// the [`Theme`].
+    ///
+    /// By default, it returns [`Theme::default()`].
+    ///
+    /// [`Sandbox`]: trait.Sandbox.html
+    /// [`Theme`]: struct.Theme.html
+    /// [`Theme::default()`]: struct.Theme.html
+    fn theme(&self) -> Theme {
+        Theme::default()
+    }
+
     /// Returns the scale factor of the [`Sandbox`].
     ///
     /// It can be used to dynamically control the size of the UI at runtime
@@ -201,6 +215,10 @@ where
         T::background_color(self)
     }
 
+    fn theme(&self) -> Theme {
+        T::theme(self)
+    }
+
     fn scale_factor(&self) -> f64 {
         T::scale_factor(self)
     }
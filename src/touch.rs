@@ -0,0 +1,5 @@
+//! Listen and react to touch events.
+pub use crate::runtime::touch::{Event, Finger};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::runtime::touch::{gesture, Gesture, Gestures};
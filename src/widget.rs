@@ -19,8 +19,12 @@
 #[cfg(not(target_arch = "wasm32"))]
 mod platform {
     pub use crate::renderer::widget::{
-        button, checkbox, container, pane_grid, pick_list, progress_bar, radio,
-        rule, scrollable, slider, text_input, Column, Row, Space, Text,
+        button, checkbox, component, container, context_menu, keyed,
+        linked_text, modal, mouse_area, multi_pick_list, on_layout, pane_grid,
+        pick_list, popover, progress_bar, radio, responsive, rich_text, rule,
+        scrollable, slider, text_input, tooltip, AspectRatio, Column, Grid,
+        LinkedText, MouseArea, OnLayout, Popover, Responsive, Row, Space,
+        Stack, Stateful, Text, Wrap,
     };
 
     #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
@@ -33,21 +37,31 @@ mod platform {
     #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
     pub mod image {
         //! Display images in your user interface.
-        pub use crate::runtime::image::{Handle, Image};
+        pub use crate::runtime::image::{Handle, Image, Status};
+
+        #[cfg(all(feature = "image", not(feature = "glow")))]
+        #[cfg_attr(
+            docsrs,
+            doc(cfg(all(feature = "image", not(feature = "glow"))))
+        )]
+        pub use crate::renderer::widget::image::decode;
     }
 
     #[cfg_attr(docsrs, doc(cfg(feature = "svg")))]
     pub mod svg {
         //! Display vector graphics in your user interface.
-        pub use crate::runtime::svg::{Handle, Svg};
+        pub use crate::runtime::svg::{Elements, Handle, Svg};
     }
 
     #[doc(no_inline)]
     pub use {
-        button::Button, checkbox::Checkbox, container::Container, image::Image,
-        pane_grid::PaneGrid, pick_list::PickList, progress_bar::ProgressBar,
-        radio::Radio, rule::Rule, scrollable::Scrollable, slider::Slider,
-        svg::Svg, text_input::TextInput,
+        button::Button, checkbox::Checkbox, container::Container,
+        context_menu::ContextMenu, image::Image, modal::Modal,
+        multi_pick_list::MultiPickList, pane_grid::PaneGrid,
+        pick_list::PickList, progress_bar::ProgressBar, radio::Radio,
+        radio::RadioGroup, rich_text::RichText, rule::Rule,
+        scrollable::Scrollable, slider::Slider, svg::Svg,
+        text_input::TextInput, tooltip::Tooltip,
     };
 
     #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
@@ -45,6 +45,11 @@ pub trait StyleSheet {
         }
     }
 
+    /// Produces the style of a button that is toggled on.
+    fn toggled(&self) -> Style {
+        self.pressed()
+    }
+
     fn disabled(&self) -> Style {
         let active = self.active();
 
@@ -63,6 +68,10 @@ pub trait StyleSheet {
             ..active
         }
     }
+
+    fn follows_theme(&self) -> bool {
+        false
+    }
 }
 
 struct Default;
@@ -78,6 +87,18 @@ impl StyleSheet for Default {
             text_color: Color::BLACK,
         }
     }
+
+    fn toggled(&self) -> Style {
+        Style {
+            background: Some(Background::Color([0.75, 0.75, 0.75].into())),
+            border_color: [0.55, 0.55, 0.55].into(),
+            ..self.pressed()
+        }
+    }
+
+    fn follows_theme(&self) -> bool {
+        true
+    }
 }
 
 impl std::default::Default for Box<dyn StyleSheet> {
@@ -86,6 +107,12 @@ impl std::default::Default for Box<dyn StyleSheet> {
     }
 }
 
+impl std::default::Default for &'static dyn StyleSheet {
+    fn default() -> Self {
+        &Default
+    }
+}
+
 impl<T> From<T> for Box<dyn StyleSheet>
 where
     T: 'static + StyleSheet,
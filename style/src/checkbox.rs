@@ -16,6 +16,15 @@ pub trait StyleSheet {
     fn active(&self, is_checked: bool) -> Style;
 
     fn hovered(&self, is_checked: bool) -> Style;
+
+    /// Produces the color of the ring drawn around a focused checkbox.
+    fn focus_ring_color(&self) -> Color {
+        Color::from_rgba(0.2, 0.4, 0.9, 0.6)
+    }
+
+    fn follows_theme(&self) -> bool {
+        false
+    }
 }
 
 struct Default;
@@ -37,6 +46,10 @@ impl StyleSheet for Default {
             ..self.active(is_checked)
         }
     }
+
+    fn follows_theme(&self) -> bool {
+        true
+    }
 }
 
 impl std::default::Default for Box<dyn StyleSheet> {
@@ -45,6 +58,12 @@ impl std::default::Default for Box<dyn StyleSheet> {
     }
 }
 
+impl std::default::Default for &'static dyn StyleSheet {
+    fn default() -> Self {
+        &Default
+    }
+}
+
 impl<T> From<T> for Box<dyn StyleSheet>
 where
     T: 'static + StyleSheet,
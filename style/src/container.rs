@@ -1,5 +1,5 @@
 //! Decorate content and apply alignment.
-use iced_core::{Background, Color};
+use iced_core::{Background, Color, Vector};
 
 /// The appearance of a container.
 #[derive(Debug, Clone, Copy)]
@@ -9,6 +9,9 @@ pub struct Style {
     pub border_radius: u16,
     pub border_width: u16,
     pub border_color: Color,
+    pub shadow_offset: Vector,
+    pub shadow_blur_radius: u16,
+    pub shadow_color: Color,
 }
 
 impl std::default::Default for Style {
@@ -19,6 +22,9 @@ impl std::default::Default for Style {
             border_radius: 0,
             border_width: 0,
             border_color: Color::TRANSPARENT,
+            shadow_offset: Vector::new(0.0, 0.0),
+            shadow_blur_radius: 0,
+            shadow_color: Color::TRANSPARENT,
         }
     }
 }
@@ -27,6 +33,10 @@ impl std::default::Default for Style {
 pub trait StyleSheet {
     /// Produces the style of a container.
     fn style(&self) -> Style;
+
+    fn follows_theme(&self) -> bool {
+        false
+    }
 }
 
 struct Default;
@@ -39,8 +49,15 @@ impl StyleSheet for Default {
             border_radius: 0,
             border_width: 0,
             border_color: Color::TRANSPARENT,
+            shadow_offset: Vector::new(0.0, 0.0),
+            shadow_blur_radius: 0,
+            shadow_color: Color::TRANSPARENT,
         }
     }
+
+    fn follows_theme(&self) -> bool {
+        true
+    }
 }
 
 impl std::default::Default for Box<dyn StyleSheet> {
@@ -49,6 +66,12 @@ impl std::default::Default for Box<dyn StyleSheet> {
     }
 }
 
+impl std::default::Default for &'static dyn StyleSheet {
+    fn default() -> Self {
+        &Default
+    }
+}
+
 impl<T> From<T> for Box<dyn StyleSheet>
 where
     T: 'static + StyleSheet,
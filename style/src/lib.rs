@@ -8,6 +8,7 @@ pub mod button;
 pub mod checkbox;
 pub mod container;
 pub mod menu;
+pub mod modal;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
@@ -15,3 +16,4 @@ pub mod rule;
 pub mod scrollable;
 pub mod slider;
 pub mod text_input;
+pub mod theme;
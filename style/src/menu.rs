@@ -11,6 +11,17 @@ pub struct Style {
     pub selected_background: Background,
 }
 
+impl Style {
+    /// Returns the color of the parts of the options that match a query.
+    ///
+    /// It is the color of the selected background, made opaque.
+    pub fn highlighted_text_color(&self) -> Color {
+        match self.selected_background {
+            Background::Color(color) => Color { a: 1.0, ..color },
+        }
+    }
+}
+
 impl std::default::Default for Style {
     fn default() -> Self {
         Self {
@@ -0,0 +1,62 @@
+//! Display content on top of a dimmed backdrop.
+use iced_core::{Background, Color};
+
+/// The appearance of a modal.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub backdrop: Background,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            backdrop: Background::Color(Color {
+                a: 0.5,
+                ..Color::BLACK
+            }),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a modal.
+pub trait StyleSheet {
+    /// Produces the style of a modal.
+    fn style(&self) -> Style;
+
+    fn follows_theme(&self) -> bool {
+        false
+    }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style::default()
+    }
+
+    fn follows_theme(&self) -> bool {
+        true
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl std::default::Default for &'static dyn StyleSheet {
+    fn default() -> Self {
+        &Default
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -33,6 +33,16 @@ pub trait StyleSheet {
 
     /// Produces the style of a container.
     fn hovered(&self) -> Style;
+
+    /// Produces the color of the dropdown arrow of a pick list with the
+    /// given style, which is the color of its text by default.
+    fn icon_color(&self, style: &Style) -> Color {
+        style.text_color
+    }
+
+    fn follows_theme(&self) -> bool {
+        false
+    }
 }
 
 struct Default;
@@ -52,6 +62,10 @@ impl StyleSheet for Default {
             ..self.active()
         }
     }
+
+    fn follows_theme(&self) -> bool {
+        true
+    }
 }
 
 impl std::default::Default for Box<dyn StyleSheet> {
@@ -60,6 +74,12 @@ impl std::default::Default for Box<dyn StyleSheet> {
     }
 }
 
+impl std::default::Default for &'static dyn StyleSheet {
+    fn default() -> Self {
+        &Default
+    }
+}
+
 impl<T> From<T> for Box<dyn StyleSheet>
 where
     T: 'static + StyleSheet,
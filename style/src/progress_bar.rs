@@ -12,6 +12,10 @@ pub struct Style {
 /// A set of rules that dictate the style of a progress bar.
 pub trait StyleSheet {
     fn style(&self) -> Style;
+
+    fn follows_theme(&self) -> bool {
+        false
+    }
 }
 
 struct Default;
@@ -24,6 +28,10 @@ impl StyleSheet for Default {
             border_radius: 5,
         }
     }
+
+    fn follows_theme(&self) -> bool {
+        true
+    }
 }
 
 impl std::default::Default for Box<dyn StyleSheet> {
@@ -32,6 +40,12 @@ impl std::default::Default for Box<dyn StyleSheet> {
     }
 }
 
+impl std::default::Default for &'static dyn StyleSheet {
+    fn default() -> Self {
+        &Default
+    }
+}
+
 impl<T> From<T> for Box<dyn StyleSheet>
 where
     T: 'static + StyleSheet,
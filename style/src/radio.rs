@@ -15,6 +15,15 @@ pub trait StyleSheet {
     fn active(&self) -> Style;
 
     fn hovered(&self) -> Style;
+
+    /// Produces the color of the ring drawn around a focused radio button.
+    fn focus_ring_color(&self) -> Color {
+        Color::from_rgba(0.2, 0.4, 0.9, 0.6)
+    }
+
+    fn follows_theme(&self) -> bool {
+        false
+    }
 }
 
 struct Default;
@@ -35,6 +44,10 @@ impl StyleSheet for Default {
             ..self.active()
         }
     }
+
+    fn follows_theme(&self) -> bool {
+        true
+    }
 }
 
 impl std::default::Default for Box<dyn StyleSheet> {
@@ -43,6 +56,12 @@ impl std::default::Default for Box<dyn StyleSheet> {
     }
 }
 
+impl std::default::Default for &'static dyn StyleSheet {
+    fn default() -> Self {
+        &Default
+    }
+}
+
 impl<T> From<T> for Box<dyn StyleSheet>
 where
     T: 'static + StyleSheet,
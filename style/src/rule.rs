@@ -85,6 +85,10 @@ pub struct Style {
 pub trait StyleSheet {
     /// Produces the style of a rule.
     fn style(&self) -> Style;
+
+    fn follows_theme(&self) -> bool {
+        false
+    }
 }
 
 struct Default;
@@ -98,6 +102,10 @@ impl StyleSheet for Default {
             fill_mode: FillMode::Percent(90.0),
         }
     }
+
+    fn follows_theme(&self) -> bool {
+        true
+    }
 }
 
 impl std::default::Default for Box<dyn StyleSheet> {
@@ -106,6 +114,12 @@ impl std::default::Default for Box<dyn StyleSheet> {
     }
 }
 
+impl std::default::Default for &'static dyn StyleSheet {
+    fn default() -> Self {
+        &Default
+    }
+}
+
 impl<T> From<T> for Box<dyn StyleSheet>
 where
     T: 'static + StyleSheet,
@@ -20,8 +20,23 @@ pub struct Handle {
 /// The shape of the handle of a slider.
 #[derive(Debug, Clone, Copy)]
 pub enum HandleShape {
-    Circle { radius: u16 },
-    Rectangle { width: u16, border_radius: u16 },
+    Circle {
+        radius: u16,
+    },
+    Rectangle {
+        width: u16,
+        border_radius: u16,
+    },
+
+    /// A handle with the given dimensions, centered on the rail.
+    ///
+    /// Use it to build pill or square handles, or any rounded rectangle
+    /// that does not span the whole height of the slider.
+    Custom {
+        width: u16,
+        height: u16,
+        border_radius: u16,
+    },
 }
 
 /// A set of rules that dictate the style of a slider.
@@ -34,6 +49,10 @@ pub trait StyleSheet {
 
     /// Produces the style of a slider that is being dragged.
     fn dragging(&self) -> Style;
+
+    fn follows_theme(&self) -> bool {
+        false
+    }
 }
 
 struct Default;
@@ -77,6 +96,10 @@ impl StyleSheet for Default {
             ..active
         }
     }
+
+    fn follows_theme(&self) -> bool {
+        true
+    }
 }
 
 impl std::default::Default for Box<dyn StyleSheet> {
@@ -85,6 +108,12 @@ impl std::default::Default for Box<dyn StyleSheet> {
     }
 }
 
+impl std::default::Default for &'static dyn StyleSheet {
+    fn default() -> Self {
+        &Default
+    }
+}
+
 impl<T> From<T> for Box<dyn StyleSheet>
 where
     T: 'static + StyleSheet,
@@ -39,6 +39,29 @@ pub trait StyleSheet {
     fn hovered(&self) -> Style {
         self.focused()
     }
+
+    /// Produces the style of a text input in an error state.
+    fn error(&self) -> Style {
+        Style {
+            border_color: self.error_color(),
+            ..self.focused()
+        }
+    }
+
+    /// Returns the color of the helper text and the character counter shown
+    /// below a text input.
+    fn helper_color(&self) -> Color {
+        self.placeholder_color()
+    }
+
+    /// Returns the color of the error message shown below a text input.
+    fn error_color(&self) -> Color {
+        Color::from_rgb(0.8, 0.2, 0.2)
+    }
+
+    fn follows_theme(&self) -> bool {
+        false
+    }
 }
 
 struct Default;
@@ -71,6 +94,10 @@ impl StyleSheet for Default {
     fn selection_color(&self) -> Color {
         Color::from_rgb(0.8, 0.8, 1.0)
     }
+
+    fn follows_theme(&self) -> bool {
+        true
+    }
 }
 
 impl std::default::Default for Box<dyn StyleSheet> {
@@ -79,6 +106,12 @@ impl std::default::Default for Box<dyn StyleSheet> {
     }
 }
 
+impl std::default::Default for &'static dyn StyleSheet {
+    fn default() -> Self {
+        &Default
+    }
+}
+
 impl<T> From<T> for Box<dyn StyleSheet>
 where
     T: 'static + StyleSheet,
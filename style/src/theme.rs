@@ -0,0 +1,90 @@
+//! Share a set of style sheets between all the widgets of an application.
+//!
+//! Every style sheet trait has a `follows_theme` method telling whether the
+//! style sheet should be replaced by the one of the current [`Theme`] when
+//! drawing. Only the default style sheets return `true`, so any style set
+//! explicitly on a widget overrides the [`Theme`] for that widget only.
+//!
+//! [`Theme`]: struct.Theme.html
+use crate::{
+    button, checkbox, container, modal, pick_list, progress_bar, radio, rule,
+    scrollable, slider, text_input,
+};
+
+/// The default style sheets of the built-in widgets.
+///
+/// Widgets without an explicit style use the style sheets of the current
+/// [`Theme`]. The style sheets are borrowed for the whole lifetime of the
+/// application, so building a [`Theme`] does not allocate and it can be
+/// produced on every frame.
+///
+/// Any style sheet left out when building a [`Theme`] can be taken from the
+/// default one:
+///
+/// ```
+/// # use iced_style::{button, Background, Color};
+/// # use iced_style::theme::Theme;
+/// #
+/// struct Button;
+///
+/// impl button::StyleSheet for Button {
+///     fn active(&self) -> button::Style {
+///         button::Style {
+///             background: Some(Background::Color(Color::BLACK)),
+///             text_color: Color::WHITE,
+///             ..button::Style::default()
+///         }
+///     }
+/// }
+///
+/// let theme = Theme {
+///     button: &Button,
+///     ..Theme::default()
+/// };
+/// ```
+///
+/// [`Theme`]: struct.Theme.html
+#[derive(Clone, Copy, Default)]
+pub struct Theme {
+    /// The style sheet of a button.
+    pub button: &'static dyn button::StyleSheet,
+
+    /// The style sheet of a checkbox.
+    pub checkbox: &'static dyn checkbox::StyleSheet,
+
+    /// The style sheet of a container.
+    pub container: &'static dyn container::StyleSheet,
+
+    /// The style sheet of a modal.
+    pub modal: &'static dyn modal::StyleSheet,
+
+    /// The style sheet of a pick list.
+    ///
+    /// The menu of a pick list does not follow the theme yet; it is always
+    /// styled by the style sheet of the pick list itself.
+    pub pick_list: &'static dyn pick_list::StyleSheet,
+
+    /// The style sheet of a progress bar.
+    pub progress_bar: &'static dyn progress_bar::StyleSheet,
+
+    /// The style sheet of a radio button.
+    pub radio: &'static dyn radio::StyleSheet,
+
+    /// The style sheet of a rule.
+    pub rule: &'static dyn rule::StyleSheet,
+
+    /// The style sheet of a scrollable.
+    pub scrollable: &'static dyn scrollable::StyleSheet,
+
+    /// The style sheet of a slider.
+    pub slider: &'static dyn slider::StyleSheet,
+
+    /// The style sheet of a text input.
+    pub text_input: &'static dyn text_input::StyleSheet,
+}
+
+impl std::fmt::Debug for Theme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Theme").finish()
+    }
+}
@@ -1,5 +1,5 @@
 //! Style your widgets.
-use crate::{bumpalo, Align, Background, Color, Length};
+use crate::{bumpalo, Align, Background, Color, Length, Spacing};
 
 use std::collections::BTreeMap;
 
@@ -13,10 +13,10 @@ pub enum Rule {
     Row,
 
     /// Padding of the container
-    Padding(u16),
+    Padding(Spacing),
 
     /// Spacing between elements
-    Spacing(u16),
+    Spacing(Spacing),
 }
 
 impl Rule {
@@ -27,8 +27,8 @@ impl Rule {
         match self {
             Rule::Column => String::from("c"),
             Rule::Row => String::from("r"),
-            Rule::Padding(padding) => format!("p-{}", padding),
-            Rule::Spacing(spacing) => format!("s-{}", spacing),
+            Rule::Padding(padding) => format!("p-{}", amount_class(*padding)),
+            Rule::Spacing(spacing) => format!("s-{}", amount_class(*spacing)),
         }
     }
 
@@ -51,12 +51,12 @@ impl Rule {
             }
             Rule::Padding(padding) => bumpalo::format!(
                 in bump,
-                ".{} {{ box-sizing: border-box; padding: {}px }}",
+                ".{} {{ box-sizing: border-box; padding: {} }}",
                 class,
-                padding
+                amount(*padding)
             )
             .into_bump_str(),
-            Rule::Spacing(spacing) => bumpalo::format!(
+            Rule::Spacing(Spacing::Units(spacing)) => bumpalo::format!(
                 in bump,
                 ".c.{} > * {{ margin-bottom: {}px }} \
                  .r.{} > * {{ margin-right: {}px }} \
@@ -70,10 +70,31 @@ impl Rule {
                 class
             )
             .into_bump_str(),
+            // Percentage margins are always relative to the width, while
+            // gaps are relative to the size along the axis of the container
+            Rule::Spacing(spacing) => bumpalo::format!(
+                in bump,
+                ".c.{} {{ row-gap: {} }} .r.{} {{ column-gap: {} }}",
+                class,
+                amount(*spacing),
+                class,
+                amount(*spacing)
+            )
+            .into_bump_str(),
         }
     }
 }
 
+/// Returns the part of a class name identifying the given [`Spacing`].
+///
+/// [`Spacing`]: ../enum.Spacing.html
+fn amount_class(spacing: Spacing) -> String {
+    match spacing {
+        Spacing::Units(units) => units.to_string(),
+        Spacing::Relative(ratio) => format!("r{}", ratio.to_bits()),
+    }
+}
+
 /// A cascading style sheet.
 #[derive(Debug)]
 pub struct Css<'a> {
@@ -143,6 +164,16 @@ pub fn length(length: Length) -> String {
     }
 }
 
+/// Returns the style value for the given [`Spacing`].
+///
+/// [`Spacing`]: ../enum.Spacing.html
+pub fn amount(spacing: Spacing) -> String {
+    match spacing {
+        Spacing::Units(units) => format!("{}px", units),
+        Spacing::Relative(ratio) => format!("{}%", ratio * 100.0),
+    }
+}
+
 /// Returns the style value for the given maximum length in units.
 pub fn max_length(units: u32) -> String {
     use std::u32;
@@ -187,5 +218,6 @@ pub fn align(align: Align) -> &'static str {
         Align::Start => "flex-start",
         Align::Center => "center",
         Align::End => "flex-end",
+        Align::Baseline => "baseline",
     }
 }
@@ -74,10 +74,12 @@ pub use dodrio;
 pub use element::Element;
 pub use hasher::Hasher;
 pub use iced_core::{
-    keyboard, mouse, Align, Background, Color, Font, HorizontalAlignment,
-    Length, Point, Rectangle, Size, Vector, VerticalAlignment,
+    color_palette, keyboard, mouse, touch, Align, Background, Color,
+    ContentFit, Direction, Font, HorizontalAlignment, Length, Point, Rectangle,
+    Size, Spacing, Vector, VerticalAlignment,
 };
 pub use iced_futures::{executor, futures, Command};
+pub use iced_style::theme::Theme;
 pub use subscription::Subscription;
 
 #[doc(no_inline)]
@@ -4,7 +4,7 @@
 //!
 //! [`Button`]: struct.Button.html
 //! [`State`]: struct.State.html
-use crate::{css, Background, Bus, Css, Element, Length, Widget};
+use crate::{css, Background, Bus, Css, Element, Length, Spacing, Widget};
 
 pub use iced_style::button::{Style, StyleSheet};
 
@@ -142,10 +142,14 @@ where
         use dodrio::builder::*;
 
         // TODO: State-based styling
-        let style = self.style.active();
+        let style = if self.on_press.is_some() {
+            self.style.active()
+        } else {
+            self.style.disabled()
+        };
 
-        let padding_class =
-            style_sheet.insert(bump, css::Rule::Padding(self.padding));
+        let padding_class = style_sheet
+            .insert(bump, css::Rule::Padding(Spacing::Units(self.padding)));
 
         let background = match style.background {
             None => String::from("none"),
@@ -184,6 +188,8 @@ where
             node = node.on("click", move |_root, _vdom, _event| {
                 event_bus.publish(on_press.clone());
             });
+        } else {
+            node = node.attr("disabled", "");
         }
 
         node.finish()
@@ -1,5 +1,5 @@
 //! Show toggle controls using checkboxes.
-use crate::{css, Bus, Css, Element, Length, Widget};
+use crate::{css, Bus, Css, Element, Length, Spacing, Widget};
 
 pub use iced_style::checkbox::{Style, StyleSheet};
 
@@ -11,20 +11,22 @@ use std::rc::Rc;
 /// # Example
 ///
 /// ```
-/// # use iced_web::Checkbox;
+/// # use iced_web::{checkbox, Checkbox};
 ///
 /// pub enum Message {
 ///     CheckboxToggled(bool),
 /// }
 ///
+/// let mut state = checkbox::State::new();
 /// let is_checked = true;
 ///
-/// Checkbox::new(is_checked, "Toggle me!", Message::CheckboxToggled);
+/// Checkbox::new(&mut state, is_checked, "Toggle me!", Message::CheckboxToggled);
 /// ```
 ///
 /// ![Checkbox drawn by Coffee's renderer](https://github.com/hecrj/coffee/blob/bda9818f823dfcb8a7ad0ff4940b4d4b387b5208/images/ui/checkbox.png?raw=true)
 #[allow(missing_debug_implementations)]
-pub struct Checkbox<Message> {
+pub struct Checkbox<'a, Message> {
+    _state: &'a mut State,
     is_checked: bool,
     on_toggle: Rc<dyn Fn(bool) -> Message>,
     label: String,
@@ -33,10 +35,11 @@ pub struct Checkbox<Message> {
     style: Box<dyn StyleSheet>,
 }
 
-impl<Message> Checkbox<Message> {
+impl<'a, Message> Checkbox<'a, Message> {
     /// Creates a new [`Checkbox`].
     ///
     /// It expects:
+    ///   * some local [`State`]
     ///   * a boolean describing whether the [`Checkbox`] is checked or not
     ///   * the label of the [`Checkbox`]
     ///   * a function that will be called when the [`Checkbox`] is toggled. It
@@ -44,11 +47,18 @@ impl<Message> Checkbox<Message> {
     ///     `Message`.
     ///
     /// [`Checkbox`]: struct.Checkbox.html
-    pub fn new<F>(is_checked: bool, label: impl Into<String>, f: F) -> Self
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        is_checked: bool,
+        label: impl Into<String>,
+        f: F,
+    ) -> Self
     where
         F: 'static + Fn(bool) -> Message,
     {
         Checkbox {
+            _state: state,
             is_checked,
             on_toggle: Rc::new(f),
             label: label.into(),
@@ -83,7 +93,22 @@ impl<Message> Checkbox<Message> {
     }
 }
 
-impl<Message> Widget<Message> for Checkbox<Message>
+/// The local state of a [`Checkbox`].
+///
+/// [`Checkbox`]: struct.Checkbox.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State;
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State
+    }
+}
+
+impl<'a, Message> Widget<Message> for Checkbox<'a, Message>
 where
     Message: 'static,
 {
@@ -105,7 +130,8 @@ where
 
         let row_class = style_sheet.insert(bump, css::Rule::Row);
 
-        let spacing_class = style_sheet.insert(bump, css::Rule::Spacing(5));
+        let spacing_class =
+            style_sheet.insert(bump, css::Rule::Spacing(Spacing::Units(5)));
 
         let (label, input) = if let Some(id) = &self.id {
             let id = String::from_str_in(id, bump).into_bump_str();
@@ -144,11 +170,11 @@ where
     }
 }
 
-impl<'a, Message> From<Checkbox<Message>> for Element<'a, Message>
+impl<'a, Message> From<Checkbox<'a, Message>> for Element<'a, Message>
 where
     Message: 'static,
 {
-    fn from(checkbox: Checkbox<Message>) -> Element<'a, Message> {
+    fn from(checkbox: Checkbox<'a, Message>) -> Element<'a, Message> {
         Element::new(checkbox)
     }
 }
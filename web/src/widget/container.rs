@@ -1,5 +1,5 @@
 //! Decorate content and apply alignment.
-use crate::{bumpalo, css, Align, Bus, Css, Element, Length, Widget};
+use crate::{bumpalo, css, Align, Bus, Css, Element, Length, Spacing, Widget};
 
 pub use iced_style::container::{Style, StyleSheet};
 
@@ -8,7 +8,7 @@ pub use iced_style::container::{Style, StyleSheet};
 /// It is normally used for alignment purposes.
 #[allow(missing_debug_implementations)]
 pub struct Container<'a, Message> {
-    padding: u16,
+    padding: Spacing,
     width: Length,
     height: Length,
     max_width: u32,
@@ -30,7 +30,7 @@ impl<'a, Message> Container<'a, Message> {
         use std::u32;
 
         Container {
-            padding: 0,
+            padding: Spacing::Units(0),
             width: Length::Shrink,
             height: Length::Shrink,
             max_width: u32::MAX,
@@ -44,9 +44,12 @@ impl<'a, Message> Container<'a, Message> {
 
     /// Sets the padding of the [`Container`].
     ///
+    /// A [`Spacing::Relative`] amount is relative to the available width.
+    ///
     /// [`Container`]: struct.Column.html
-    pub fn padding(mut self, units: u16) -> Self {
-        self.padding = units;
+    /// [`Spacing::Relative`]: ../enum.Spacing.html#variant.Relative
+    pub fn padding(mut self, padding: impl Into<Spacing>) -> Self {
+        self.padding = padding.into();
         self
     }
 
@@ -137,7 +140,7 @@ where
                 "style",
                 bumpalo::format!(
                     in bump,
-                    "width: {}; height: {}; max-width: {}; align-items: {}; justify-content: {}; background: {}; color: {}; border-width: {}px; border-color: {}; border-radius: {}px",
+                    "width: {}; height: {}; max-width: {}; align-items: {}; justify-content: {}; background: {}; color: {}; border-width: {}px; border-color: {}; border-radius: {}px; box-shadow: {}px {}px {}px {}",
                     css::length(self.width),
                     css::length(self.height),
                     css::max_length(self.max_width),
@@ -147,7 +150,11 @@ where
                     style.text_color.map(css::color).unwrap_or(String::from("inherit")),
                     style.border_width,
                     css::color(style.border_color),
-                    style.border_radius
+                    style.border_radius,
+                    style.shadow_offset.x,
+                    style.shadow_offset.y,
+                    style.shadow_blur_radius,
+                    css::color(style.shadow_color)
                 )
                 .into_bump_str(),
             )
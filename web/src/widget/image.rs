@@ -30,6 +30,9 @@ pub struct Image {
 
     /// The height of the image
     pub height: Length,
+
+    /// The border radius of the image
+    pub border_radius: u16,
 }
 
 impl Image {
@@ -42,6 +45,7 @@ impl Image {
             alt: Default::default(),
             width: Length::Shrink,
             height: Length::Shrink,
+            border_radius: 0,
         }
     }
 
@@ -61,6 +65,14 @@ impl Image {
         self
     }
 
+    /// Sets the border radius of the [`Image`].
+    ///
+    /// [`Image`]: struct.Image.html
+    pub fn border_radius(mut self, border_radius: u16) -> Self {
+        self.border_radius = border_radius;
+        self
+    }
+
     /// Sets the alt text of the [`Image`].
     ///
     /// [`Image`]: struct.Image.html
@@ -105,6 +117,18 @@ impl<Message> Widget<Message> for Image {
             }
         }
 
+        if self.border_radius > 0 {
+            image = image.attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "border-radius: {}px",
+                    self.border_radius
+                )
+                .into_bump_str(),
+            );
+        }
+
         // TODO: Complete styling
 
         image.finish()
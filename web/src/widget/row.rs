@@ -1,4 +1,4 @@
-use crate::{css, Align, Bus, Css, Element, Length, Widget};
+use crate::{css, Align, Bus, Css, Element, Length, Spacing, Widget};
 
 use dodrio::bumpalo;
 use std::u32;
@@ -10,8 +10,8 @@ use std::u32;
 /// [`Row`]: struct.Row.html
 #[allow(missing_debug_implementations)]
 pub struct Row<'a, Message> {
-    spacing: u16,
-    padding: u16,
+    spacing: Spacing,
+    padding: Spacing,
     width: Length,
     height: Length,
     max_width: u32,
@@ -33,8 +33,8 @@ impl<'a, Message> Row<'a, Message> {
     /// [`Row`]: struct.Row.html
     pub fn with_children(children: Vec<Element<'a, Message>>) -> Self {
         Row {
-            spacing: 0,
-            padding: 0,
+            spacing: Spacing::Units(0),
+            padding: Spacing::Units(0),
             width: Length::Fill,
             height: Length::Shrink,
             max_width: u32::MAX,
@@ -49,16 +49,23 @@ impl<'a, Message> Row<'a, Message> {
     /// Custom margins per element do not exist in Iced. You should use this
     /// method instead! While less flexible, it helps you keep spacing between
     /// elements consistent.
-    pub fn spacing(mut self, units: u16) -> Self {
-        self.spacing = units;
+    ///
+    /// A [`Spacing::Relative`] amount is relative to the available width.
+    ///
+    /// [`Spacing::Relative`]: ../enum.Spacing.html#variant.Relative
+    pub fn spacing(mut self, spacing: impl Into<Spacing>) -> Self {
+        self.spacing = spacing.into();
         self
     }
 
     /// Sets the padding of the [`Row`].
     ///
+    /// A [`Spacing::Relative`] amount is relative to the available width.
+    ///
     /// [`Row`]: struct.Row.html
-    pub fn padding(mut self, units: u16) -> Self {
-        self.padding = units;
+    /// [`Spacing::Relative`]: ../enum.Spacing.html#variant.Relative
+    pub fn padding(mut self, padding: impl Into<Spacing>) -> Self {
+        self.padding = padding.into();
         self
     }
 
@@ -69,6 +69,53 @@ where
         value: T,
         on_change: F,
     ) -> Self
+    where
+        F: 'static + Fn(T) -> Message,
+    {
+        Self::with_step(state, range, value, T::from(1), on_change)
+    }
+}
+
+impl<'a, T, Message> Slider<'a, T, Message>
+where
+    T: num_traits::PrimInt + Into<f64>,
+{
+    /// Creates a new [`Slider`] over a range of integers.
+    ///
+    /// It expects the same arguments as [`new`]. The value produced while
+    /// dragging is always a whole number in the range, snapped to the
+    /// [`step`] size, which defaults to 1.
+    ///
+    /// This also works for integer types that cannot be built from a `u8`,
+    /// like `i8`.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    /// [`new`]: #method.new
+    /// [`step`]: #method.step
+    pub fn new_int<F>(
+        state: &'a mut State,
+        range: RangeInclusive<T>,
+        value: T,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(T) -> Message,
+    {
+        Self::with_step(state, range, value, T::one(), on_change)
+    }
+}
+
+impl<'a, T, Message> Slider<'a, T, Message>
+where
+    T: Copy + std::cmp::PartialOrd,
+{
+    fn with_step<F>(
+        state: &'a mut State,
+        range: RangeInclusive<T>,
+        value: T,
+        step: T,
+        on_change: F,
+    ) -> Self
     where
         F: 'static + Fn(T) -> Message,
     {
@@ -88,7 +135,7 @@ where
             _state: state,
             value,
             range,
-            step: T::from(1),
+            step,
             on_change: Rc::new(Box::new(on_change)),
             width: Length::Fill,
             style: Default::default(),
@@ -4,7 +4,9 @@
 //!
 //! [`TextInput`]: struct.TextInput.html
 //! [`State`]: struct.State.html
-use crate::{bumpalo, css, Bus, Css, Element, Length, Widget};
+use crate::{
+    bumpalo, css, Bus, Css, Direction, Element, Length, Spacing, Widget,
+};
 
 pub use iced_style::text_input::{Style, StyleSheet};
 
@@ -40,6 +42,7 @@ pub struct TextInput<'a, Message> {
     max_width: u32,
     padding: u16,
     size: Option<u16>,
+    direction: Direction,
     on_change: Rc<Box<dyn Fn(String) -> Message>>,
     on_submit: Option<Message>,
     style_sheet: Box<dyn StyleSheet>,
@@ -74,6 +77,7 @@ impl<'a, Message> TextInput<'a, Message> {
             max_width: u32::MAX,
             padding: 0,
             size: None,
+            direction: Direction::LeftToRight,
             on_change: Rc::new(Box::new(on_change)),
             on_submit: None,
             style_sheet: Default::default(),
@@ -120,6 +124,15 @@ impl<'a, Message> TextInput<'a, Message> {
         self
     }
 
+    /// Sets the text [`Direction`] of the [`TextInput`].
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    /// [`Direction`]: ../../enum.Direction.html
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
     /// Sets the message that should be produced when the [`TextInput`] is
     /// focused and the enter key is pressed.
     ///
@@ -154,8 +167,8 @@ where
         let class = {
             use dodrio::bumpalo::collections::String;
 
-            let padding_class =
-                style_sheet.insert(bump, css::Rule::Padding(self.padding));
+            let padding_class = style_sheet
+                .insert(bump, css::Rule::Padding(Spacing::Units(self.padding)));
 
             String::from_str_in(&padding_class, bump).into_bump_str()
         };
@@ -201,6 +214,14 @@ where
             .attr("placeholder", placeholder)
             .attr("value", value)
             .attr("type", if self.is_secure { "password" } else { "text" })
+            .attr(
+                "dir",
+                match self.direction {
+                    Direction::LeftToRight => "ltr",
+                    Direction::RightToLeft => "rtl",
+                    Direction::Auto => "auto",
+                },
+            )
             .on("input", move |_root, _vdom, event| {
                 let text_input = match event.target().and_then(|t| {
                     t.dyn_into::<web_sys::HtmlInputElement>().ok()
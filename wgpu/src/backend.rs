@@ -7,10 +7,11 @@ use iced_graphics::font;
 use iced_graphics::layer::Layer;
 use iced_graphics::{Primitive, Viewport};
 use iced_native::mouse;
+use iced_native::rich_text;
 use iced_native::{Font, HorizontalAlignment, Size, VerticalAlignment};
 
 #[cfg(any(feature = "image", feature = "svg"))]
-use crate::image;
+use crate::image_pipeline;
 
 /// A [`wgpu`] graphics backend for [`iced`].
 ///
@@ -23,7 +24,7 @@ pub struct Backend {
     triangle_pipeline: triangle::Pipeline,
 
     #[cfg(any(feature = "image", feature = "svg"))]
-    image_pipeline: image::Pipeline,
+    image_pipeline: image_pipeline::Pipeline,
 
     default_text_size: u16,
 }
@@ -33,8 +34,12 @@ impl Backend {
     ///
     /// [`Backend`]: struct.Backend.html
     pub fn new(device: &wgpu::Device, settings: Settings) -> Self {
-        let text_pipeline =
-            text::Pipeline::new(device, settings.format, settings.default_font);
+        let text_pipeline = text::Pipeline::new(
+            device,
+            settings.format,
+            settings.default_font,
+            settings.text_shaper,
+        );
         let quad_pipeline = quad::Pipeline::new(device, settings.format);
         let triangle_pipeline = triangle::Pipeline::new(
             device,
@@ -43,7 +48,8 @@ impl Backend {
         );
 
         #[cfg(any(feature = "image", feature = "svg"))]
-        let image_pipeline = image::Pipeline::new(device, settings.format);
+        let image_pipeline =
+            image_pipeline::Pipeline::new(device, settings.format);
 
         Self {
             quad_pipeline,
@@ -123,6 +129,7 @@ impl Backend {
                 transformation,
                 scale_factor,
                 bounds,
+                layer.blend_mode,
                 target,
             );
         }
@@ -141,6 +148,7 @@ impl Backend {
                 scaled,
                 scale_factor,
                 &layer.meshes,
+                layer.blend_mode,
             );
         }
 
@@ -163,8 +171,12 @@ impl Backend {
             }
         }
 
-        if !layer.text.is_empty() {
+        if !layer.text.is_empty() || !layer.rich_text.is_empty() {
             for text in layer.text.iter() {
+                if self.text_pipeline.queue_shaped(text, scale_factor) {
+                    continue;
+                }
+
                 // Target physical coordinates directly to avoid blurry text
                 let text = wgpu_glyph::Section {
                     // TODO: We `round` here to avoid rerasterizing text when
@@ -200,30 +212,56 @@ impl Backend {
                             z: 0.0,
                         },
                     }],
-                    layout: wgpu_glyph::Layout::default()
-                        .h_align(match text.horizontal_alignment {
-                            HorizontalAlignment::Left => {
-                                wgpu_glyph::HorizontalAlign::Left
-                            }
-                            HorizontalAlignment::Center => {
-                                wgpu_glyph::HorizontalAlign::Center
-                            }
-                            HorizontalAlignment::Right => {
-                                wgpu_glyph::HorizontalAlign::Right
+                    layout: layout(
+                        text.horizontal_alignment,
+                        text.vertical_alignment,
+                    ),
+                    ..Default::default()
+                };
+
+                self.text_pipeline.queue(text);
+            }
+
+            for text in layer.rich_text.iter() {
+                let text = wgpu_glyph::Section {
+                    screen_position: (
+                        (text.bounds.x * scale_factor).round(),
+                        (text.bounds.y * scale_factor).round(),
+                    ),
+                    bounds: (
+                        (text.bounds.width * scale_factor).ceil(),
+                        (text.bounds.height * scale_factor).ceil(),
+                    ),
+                    text: text
+                        .spans
+                        .iter()
+                        .map(|span| {
+                            let size =
+                                span.size.map(f32::from).unwrap_or(text.size);
+
+                            wgpu_glyph::Text {
+                                text: &span.content,
+                                scale: wgpu_glyph::ab_glyph::PxScale {
+                                    x: size * scale_factor,
+                                    y: size * scale_factor,
+                                },
+                                font_id: self
+                                    .text_pipeline
+                                    .find_font(span.font),
+                                extra: wgpu_glyph::Extra {
+                                    color: span
+                                        .color
+                                        .map(|color| color.into_linear())
+                                        .unwrap_or(text.color),
+                                    z: 0.0,
+                                },
                             }
                         })
-                        .v_align(match text.vertical_alignment {
-                            VerticalAlignment::Top => {
-                                wgpu_glyph::VerticalAlign::Top
-                            }
-                            VerticalAlignment::Center => {
-                                wgpu_glyph::VerticalAlign::Center
-                            }
-                            VerticalAlignment::Bottom => {
-                                wgpu_glyph::VerticalAlign::Bottom
-                            }
-                        }),
-                    ..Default::default()
+                        .collect(),
+                    layout: layout(
+                        text.horizontal_alignment,
+                        text.vertical_alignment,
+                    ),
                 };
 
                 self.text_pipeline.queue(text);
@@ -246,10 +284,35 @@ impl Backend {
     }
 }
 
+fn layout(
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+) -> wgpu_glyph::Layout<wgpu_glyph::BuiltInLineBreaker> {
+    wgpu_glyph::Layout::default()
+        .h_align(match horizontal_alignment {
+            HorizontalAlignment::Left => wgpu_glyph::HorizontalAlign::Left,
+            HorizontalAlignment::Center => wgpu_glyph::HorizontalAlign::Center,
+            HorizontalAlignment::Right => wgpu_glyph::HorizontalAlign::Right,
+        })
+        .v_align(match vertical_alignment {
+            VerticalAlignment::Top => wgpu_glyph::VerticalAlign::Top,
+            VerticalAlignment::Center => wgpu_glyph::VerticalAlign::Center,
+            VerticalAlignment::Bottom => wgpu_glyph::VerticalAlign::Bottom,
+        })
+}
+
 impl iced_graphics::Backend for Backend {
     fn trim_measurements(&mut self) {
         self.text_pipeline.trim_measurement_cache()
     }
+
+    #[cfg(feature = "image")]
+    fn image_dimensions(
+        &self,
+        handle: &iced_native::image::Handle,
+    ) -> Option<(u32, u32)> {
+        Some(self.image_pipeline.dimensions(handle))
+    }
 }
 
 impl backend::Text for Backend {
@@ -270,6 +333,19 @@ impl backend::Text for Backend {
     ) -> (f32, f32) {
         self.text_pipeline.measure(contents, size, font, bounds)
     }
+
+    fn ascent(&self, size: f32, font: Font) -> f32 {
+        self.text_pipeline.ascent(size, font)
+    }
+
+    fn measure_spans(
+        &self,
+        spans: &[rich_text::Span<Font>],
+        size: f32,
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.text_pipeline.measure_spans(spans, size, bounds)
+    }
 }
 
 #[cfg(feature = "image")]
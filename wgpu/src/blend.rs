@@ -0,0 +1,72 @@
+use iced_graphics::BlendMode;
+
+/// Returns the color state of a pipeline drawing to the given format with
+/// the given [`BlendMode`].
+///
+/// The fragment shaders produce colors with straight alpha.
+///
+/// [`BlendMode`]: ../../iced_graphics/enum.BlendMode.html
+pub fn color_state(
+    format: wgpu::TextureFormat,
+    mode: BlendMode,
+) -> wgpu::ColorStateDescriptor {
+    let (src_factor, dst_factor) = match mode {
+        BlendMode::Normal => (
+            wgpu::BlendFactor::SrcAlpha,
+            wgpu::BlendFactor::OneMinusSrcAlpha,
+        ),
+        BlendMode::Multiply => {
+            (wgpu::BlendFactor::DstColor, wgpu::BlendFactor::Zero)
+        }
+        BlendMode::Additive => {
+            (wgpu::BlendFactor::SrcAlpha, wgpu::BlendFactor::One)
+        }
+        BlendMode::Screen => {
+            (wgpu::BlendFactor::OneMinusDstColor, wgpu::BlendFactor::One)
+        }
+    };
+
+    wgpu::ColorStateDescriptor {
+        format,
+        color_blend: wgpu::BlendDescriptor {
+            src_factor,
+            dst_factor,
+            operation: wgpu::BlendOperation::Add,
+        },
+        alpha_blend: wgpu::BlendDescriptor {
+            src_factor: wgpu::BlendFactor::One,
+            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+            operation: wgpu::BlendOperation::Add,
+        },
+        write_mask: wgpu::ColorWrite::ALL,
+    }
+}
+
+/// The render pipelines of a primitive for each [`BlendMode`], built the
+/// first time each mode is used.
+///
+/// [`BlendMode`]: ../../iced_graphics/enum.BlendMode.html
+#[derive(Debug)]
+pub struct Pipelines {
+    pipelines: [Option<wgpu::RenderPipeline>; BlendMode::ALL.len()],
+}
+
+impl Pipelines {
+    pub fn new() -> Self {
+        Pipelines {
+            pipelines: [None, None, None, None],
+        }
+    }
+
+    /// Returns the pipeline for the given [`BlendMode`], building it with
+    /// the given function if it is used for the first time.
+    ///
+    /// [`BlendMode`]: ../../iced_graphics/enum.BlendMode.html
+    pub fn get(
+        &mut self,
+        mode: BlendMode,
+        build: impl FnOnce(BlendMode) -> wgpu::RenderPipeline,
+    ) -> &wgpu::RenderPipeline {
+        self.pipelines[mode.index()].get_or_insert_with(|| build(mode))
+    }
+}
@@ -1,7 +1,7 @@
 mod atlas;
 
 #[cfg(feature = "image")]
-mod raster;
+pub mod raster;
 
 #[cfg(feature = "svg")]
 mod vector;
@@ -207,6 +207,21 @@ impl Pipeline {
                                     format: wgpu::VertexFormat::Uint,
                                     offset: 4 * 8,
                                 },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 6,
+                                    format: wgpu::VertexFormat::Float2,
+                                    offset: 4 * 9,
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 7,
+                                    format: wgpu::VertexFormat::Float2,
+                                    offset: 4 * 11,
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 8,
+                                    format: wgpu::VertexFormat::Float,
+                                    offset: 4 * 13,
+                                },
                             ],
                         },
                     ],
@@ -308,7 +323,11 @@ impl Pipeline {
         for image in images {
             match &image {
                 #[cfg(feature = "image")]
-                layer::Image::Raster { handle, bounds } => {
+                layer::Image::Raster {
+                    handle,
+                    bounds,
+                    border_radius,
+                } => {
                     if let Some(atlas_entry) = raster_cache.upload(
                         handle,
                         device,
@@ -318,6 +337,7 @@ impl Pipeline {
                         add_instances(
                             [bounds.x, bounds.y],
                             [bounds.width, bounds.height],
+                            *border_radius,
                             atlas_entry,
                             instances,
                         );
@@ -341,6 +361,7 @@ impl Pipeline {
                         add_instances(
                             [bounds.x, bounds.y],
                             size,
+                            0.0,
                             atlas_entry,
                             instances,
                         );
@@ -493,6 +514,9 @@ struct Instance {
     _position_in_atlas: [f32; 2],
     _size_in_atlas: [f32; 2],
     _layer: u32,
+    _image_position: [f32; 2],
+    _image_size: [f32; 2],
+    _border_radius: f32,
 }
 
 impl Instance {
@@ -508,12 +532,21 @@ struct Uniforms {
 fn add_instances(
     image_position: [f32; 2],
     image_size: [f32; 2],
+    border_radius: f32,
     entry: &atlas::Entry,
     instances: &mut Vec<Instance>,
 ) {
     match entry {
         atlas::Entry::Contiguous(allocation) => {
-            add_instance(image_position, image_size, allocation, instances);
+            add_instance(
+                image_position,
+                image_size,
+                image_position,
+                image_size,
+                border_radius,
+                allocation,
+                instances,
+            );
         }
         atlas::Entry::Fragmented { fragments, size } => {
             let scaling_x = image_size[0] / size.0 as f32;
@@ -536,7 +569,15 @@ fn add_instances(
                     fragment_height as f32 * scaling_y,
                 ];
 
-                add_instance(position, size, allocation, instances);
+                add_instance(
+                    position,
+                    size,
+                    image_position,
+                    image_size,
+                    border_radius,
+                    allocation,
+                    instances,
+                );
             }
         }
     }
@@ -546,6 +587,9 @@ fn add_instances(
 fn add_instance(
     position: [f32; 2],
     size: [f32; 2],
+    image_position: [f32; 2],
+    image_size: [f32; 2],
+    border_radius: f32,
     allocation: &atlas::Allocation,
     instances: &mut Vec<Instance>,
 ) {
@@ -565,6 +609,9 @@ fn add_instance(
             (height as f32 - 1.0) / atlas::SIZE as f32,
         ],
         _layer: layer as u32,
+        _image_position: image_position,
+        _image_size: image_size,
+        _border_radius: border_radius,
     };
 
     instances.push(instance);
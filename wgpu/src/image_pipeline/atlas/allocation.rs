@@ -1,4 +1,4 @@
-use crate::image::atlas::{self, allocator};
+use crate::image_pipeline::atlas::{self, allocator};
 
 #[derive(Debug)]
 pub enum Allocation {
@@ -1,4 +1,4 @@
-use crate::image::atlas;
+use crate::image_pipeline::atlas;
 
 #[derive(Debug)]
 pub enum Entry {
@@ -1,4 +1,4 @@
-use crate::image::atlas::Allocator;
+use crate::image_pipeline::atlas::Allocator;
 
 #[derive(Debug)]
 pub enum Layer {
@@ -1,4 +1,4 @@
-use crate::image::atlas::{self, Atlas};
+use crate::image_pipeline::atlas::{self, Atlas};
 use iced_native::image;
 use std::collections::{HashMap, HashSet};
 
@@ -11,36 +11,8 @@ pub enum Memory {
 }
 
 impl Memory {
-    pub fn dimensions(&self) -> (u32, u32) {
-        match self {
-            Memory::Host(image) => image.dimensions(),
-            Memory::Device(entry) => entry.size(),
-            Memory::NotFound => (1, 1),
-            Memory::Invalid => (1, 1),
-        }
-    }
-}
-
-#[derive(Debug)]
-pub struct Cache {
-    map: HashMap<u64, Memory>,
-    hits: HashSet<u64>,
-}
-
-impl Cache {
-    pub fn new() -> Self {
-        Self {
-            map: HashMap::new(),
-            hits: HashSet::new(),
-        }
-    }
-
-    pub fn load(&mut self, handle: &image::Handle) -> &mut Memory {
-        if self.contains(handle) {
-            return self.get(handle).unwrap();
-        }
-
-        let memory = match handle.data() {
+    pub fn load(handle: &image::Handle) -> Memory {
+        match handle.data() {
             image::Data::Path(path) => {
                 if let Ok(image) = ::image::open(path) {
                     Memory::Host(image.to_bgra())
@@ -70,7 +42,39 @@ impl Cache {
                     Memory::Invalid
                 }
             }
-        };
+        }
+    }
+
+    pub fn dimensions(&self) -> (u32, u32) {
+        match self {
+            Memory::Host(image) => image.dimensions(),
+            Memory::Device(entry) => entry.size(),
+            Memory::NotFound => (1, 1),
+            Memory::Invalid => (1, 1),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Cache {
+    map: HashMap<u64, Memory>,
+    hits: HashSet<u64>,
+}
+
+impl Cache {
+    pub fn new() -> Self {
+        Self {
+            map: HashMap::new(),
+            hits: HashSet::new(),
+        }
+    }
+
+    pub fn load(&mut self, handle: &image::Handle) -> &mut Memory {
+        if self.contains(handle) {
+            return self.get(handle).unwrap();
+        }
+
+        let memory = Memory::load(handle);
 
         self.insert(handle, memory);
         self.get(handle).unwrap()
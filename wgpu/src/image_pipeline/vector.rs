@@ -1,5 +1,6 @@
-use crate::image::atlas::{self, Atlas};
+use crate::image_pipeline::atlas::{self, Atlas};
 use iced_native::svg;
+use resvg::usvg;
 use std::collections::{HashMap, HashSet};
 
 use zerocopy::AsBytes;
@@ -45,7 +46,10 @@ impl Cache {
             return self.svgs.get(&handle.id()).unwrap();
         }
 
-        let opt = resvg::Options::default();
+        let mut opt = resvg::Options::default();
+
+        // Groups are flattened by default, losing their ids
+        opt.usvg.keep_named_groups = *handle.elements() != svg::Elements::All;
 
         let svg = match handle.data() {
             svg::Data::Path(path) => {
@@ -62,6 +66,10 @@ impl Cache {
             }
         };
 
+        if let Svg::Loaded(tree) = &svg {
+            filter(tree, handle.elements());
+        }
+
         let _ = self.svgs.insert(handle.id(), svg);
         self.svgs.get(&handle.id()).unwrap()
     }
@@ -153,6 +161,37 @@ impl Cache {
     }
 }
 
+/// Detaches the nodes of the tree that are not part of the given elements.
+fn filter(tree: &usvg::Tree, elements: &svg::Elements) {
+    let hidden: Vec<usvg::Node> = match elements {
+        svg::Elements::All => return,
+        svg::Elements::Except(ids) => {
+            ids.iter().filter_map(|id| tree.node_by_id(id)).collect()
+        }
+        svg::Elements::Only(ids) => {
+            let shown: Vec<usvg::Node> =
+                ids.iter().filter_map(|id| tree.node_by_id(id)).collect();
+
+            // The ancestors of a shown node, including the root, and its
+            // descendants are kept. Definitions are kept for references.
+            tree.root()
+                .descendants()
+                .filter(|node| !tree.is_in_defs(node))
+                .filter(|node| {
+                    !shown.iter().any(|shown| {
+                        node.ancestors().any(|node| node == *shown)
+                            || shown.ancestors().any(|shown| shown == *node)
+                    })
+                })
+                .collect()
+        }
+    };
+
+    for mut node in hidden {
+        node.detach();
+    }
+}
+
 impl std::fmt::Debug for Svg {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
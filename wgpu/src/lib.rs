@@ -12,6 +12,7 @@
 //! - Text, which is rendered using [`wgpu_glyph`]. No shaping at all.
 //! - Quads or rectangles, with rounded borders and a solid background color.
 //! - Clip areas, useful to implement scrollables or hide overflowing content.
+//! - Blend modes, applied to the quads and meshes of a layer.
 //! - Images and SVG, loaded from memory or the file system.
 //! - Meshes of triangles, useful to draw geometry freely.
 //!
@@ -33,11 +34,12 @@ pub mod widget;
 pub mod window;
 
 mod backend;
+mod blend;
 mod quad;
 mod text;
 
 pub use iced_graphics::{
-    Antialiasing, Color, Defaults, Error, Primitive, Viewport,
+    Antialiasing, BlendMode, Color, Defaults, Error, Primitive, Theme, Viewport,
 };
 pub use wgpu;
 
@@ -50,7 +52,7 @@ pub use widget::*;
 pub(crate) use iced_graphics::Transformation;
 
 #[cfg(any(feature = "image", feature = "svg"))]
-mod image;
+mod image_pipeline;
 
 /// A [`wgpu`] graphics renderer for [`iced`].
 ///
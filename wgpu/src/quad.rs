@@ -1,5 +1,5 @@
-use crate::Transformation;
-use iced_graphics::layer;
+use crate::{blend, Transformation};
+use iced_graphics::{layer, BlendMode};
 use iced_native::Rectangle;
 
 use std::mem;
@@ -8,7 +8,11 @@ use zerocopy::AsBytes;
 
 #[derive(Debug)]
 pub struct Pipeline {
-    pipeline: wgpu::RenderPipeline,
+    pipelines: blend::Pipelines,
+    layout: wgpu::PipelineLayout,
+    vs_module: wgpu::ShaderModule,
+    fs_module: wgpu::ShaderModule,
+    format: wgpu::TextureFormat,
     constants: wgpu::BindGroup,
     constants_buffer: wgpu::Buffer,
     vertices: wgpu::Buffer,
@@ -65,93 +69,13 @@ impl Pipeline {
         let fs_module = device
             .create_shader_module(wgpu::include_spirv!("shader/quad.frag.spv"));
 
-        let pipeline =
-            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-                label: Some("iced_wgpu::quad pipeline"),
-                layout: Some(&layout),
-                vertex_stage: wgpu::ProgrammableStageDescriptor {
-                    module: &vs_module,
-                    entry_point: "main",
-                },
-                fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
-                    module: &fs_module,
-                    entry_point: "main",
-                }),
-                rasterization_state: Some(wgpu::RasterizationStateDescriptor {
-                    front_face: wgpu::FrontFace::Cw,
-                    cull_mode: wgpu::CullMode::None,
-                    ..Default::default()
-                }),
-                primitive_topology: wgpu::PrimitiveTopology::TriangleList,
-                color_states: &[wgpu::ColorStateDescriptor {
-                    format,
-                    color_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::SrcAlpha,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
-                    alpha_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::One,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
-                    write_mask: wgpu::ColorWrite::ALL,
-                }],
-                depth_stencil_state: None,
-                vertex_state: wgpu::VertexStateDescriptor {
-                    index_format: wgpu::IndexFormat::Uint16,
-                    vertex_buffers: &[
-                        wgpu::VertexBufferDescriptor {
-                            stride: mem::size_of::<Vertex>() as u64,
-                            step_mode: wgpu::InputStepMode::Vertex,
-                            attributes: &[wgpu::VertexAttributeDescriptor {
-                                shader_location: 0,
-                                format: wgpu::VertexFormat::Float2,
-                                offset: 0,
-                            }],
-                        },
-                        wgpu::VertexBufferDescriptor {
-                            stride: mem::size_of::<layer::Quad>() as u64,
-                            step_mode: wgpu::InputStepMode::Instance,
-                            attributes: &[
-                                wgpu::VertexAttributeDescriptor {
-                                    shader_location: 1,
-                                    format: wgpu::VertexFormat::Float2,
-                                    offset: 0,
-                                },
-                                wgpu::VertexAttributeDescriptor {
-                                    shader_location: 2,
-                                    format: wgpu::VertexFormat::Float2,
-                                    offset: 4 * 2,
-                                },
-                                wgpu::VertexAttributeDescriptor {
-                                    shader_location: 3,
-                                    format: wgpu::VertexFormat::Float4,
-                                    offset: 4 * (2 + 2),
-                                },
-                                wgpu::VertexAttributeDescriptor {
-                                    shader_location: 4,
-                                    format: wgpu::VertexFormat::Float4,
-                                    offset: 4 * (2 + 2 + 4),
-                                },
-                                wgpu::VertexAttributeDescriptor {
-                                    shader_location: 5,
-                                    format: wgpu::VertexFormat::Float,
-                                    offset: 4 * (2 + 2 + 4 + 4),
-                                },
-                                wgpu::VertexAttributeDescriptor {
-                                    shader_location: 6,
-                                    format: wgpu::VertexFormat::Float,
-                                    offset: 4 * (2 + 2 + 4 + 4 + 1),
-                                },
-                            ],
-                        },
-                    ],
-                },
-                sample_count: 1,
-                sample_mask: !0,
-                alpha_to_coverage_enabled: false,
-            });
+        let mut pipelines = blend::Pipelines::new();
+
+        let _ = pipelines.get(BlendMode::Normal, |mode| {
+            build_pipeline(
+                device, &layout, &vs_module, &fs_module, format, mode,
+            )
+        });
 
         let vertices =
             device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
@@ -175,7 +99,11 @@ impl Pipeline {
         });
 
         Pipeline {
-            pipeline,
+            pipelines,
+            layout,
+            vs_module,
+            fs_module,
+            format,
             constants,
             constants_buffer,
             vertices,
@@ -193,10 +121,18 @@ impl Pipeline {
         transformation: Transformation,
         scale: f32,
         bounds: Rectangle<u32>,
+        blend_mode: BlendMode,
         target: &wgpu::TextureView,
     ) {
         let uniforms = Uniforms::new(transformation, scale);
 
+        let (layout, vs_module, fs_module, format) =
+            (&self.layout, &self.vs_module, &self.fs_module, self.format);
+
+        let pipeline = self.pipelines.get(blend_mode, |mode| {
+            build_pipeline(device, layout, vs_module, fs_module, format, mode)
+        });
+
         {
             let mut constants_buffer = staging_belt.write_buffer(
                 encoder,
@@ -245,7 +181,7 @@ impl Pipeline {
                         depth_stencil_attachment: None,
                     });
 
-                render_pass.set_pipeline(&self.pipeline);
+                render_pass.set_pipeline(pipeline);
                 render_pass.set_bind_group(0, &self.constants, &[]);
                 render_pass.set_index_buffer(self.indices.slice(..));
                 render_pass.set_vertex_buffer(0, self.vertices.slice(..));
@@ -270,6 +206,89 @@ impl Pipeline {
     }
 }
 
+fn build_pipeline(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    vs_module: &wgpu::ShaderModule,
+    fs_module: &wgpu::ShaderModule,
+    format: wgpu::TextureFormat,
+    mode: BlendMode,
+) -> wgpu::RenderPipeline {
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some("iced_wgpu::quad pipeline"),
+        layout: Some(layout),
+        vertex_stage: wgpu::ProgrammableStageDescriptor {
+            module: vs_module,
+            entry_point: "main",
+        },
+        fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+            module: fs_module,
+            entry_point: "main",
+        }),
+        rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+            front_face: wgpu::FrontFace::Cw,
+            cull_mode: wgpu::CullMode::None,
+            ..Default::default()
+        }),
+        primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+        color_states: &[blend::color_state(format, mode)],
+        depth_stencil_state: None,
+        vertex_state: wgpu::VertexStateDescriptor {
+            index_format: wgpu::IndexFormat::Uint16,
+            vertex_buffers: &[
+                wgpu::VertexBufferDescriptor {
+                    stride: mem::size_of::<Vertex>() as u64,
+                    step_mode: wgpu::InputStepMode::Vertex,
+                    attributes: &[wgpu::VertexAttributeDescriptor {
+                        shader_location: 0,
+                        format: wgpu::VertexFormat::Float2,
+                        offset: 0,
+                    }],
+                },
+                wgpu::VertexBufferDescriptor {
+                    stride: mem::size_of::<layer::Quad>() as u64,
+                    step_mode: wgpu::InputStepMode::Instance,
+                    attributes: &[
+                        wgpu::VertexAttributeDescriptor {
+                            shader_location: 1,
+                            format: wgpu::VertexFormat::Float2,
+                            offset: 0,
+                        },
+                        wgpu::VertexAttributeDescriptor {
+                            shader_location: 2,
+                            format: wgpu::VertexFormat::Float2,
+                            offset: 4 * 2,
+                        },
+                        wgpu::VertexAttributeDescriptor {
+                            shader_location: 3,
+                            format: wgpu::VertexFormat::Float4,
+                            offset: 4 * (2 + 2),
+                        },
+                        wgpu::VertexAttributeDescriptor {
+                            shader_location: 4,
+                            format: wgpu::VertexFormat::Float4,
+                            offset: 4 * (2 + 2 + 4),
+                        },
+                        wgpu::VertexAttributeDescriptor {
+                            shader_location: 5,
+                            format: wgpu::VertexFormat::Float,
+                            offset: 4 * (2 + 2 + 4 + 4),
+                        },
+                        wgpu::VertexAttributeDescriptor {
+                            shader_location: 6,
+                            format: wgpu::VertexFormat::Float,
+                            offset: 4 * (2 + 2 + 4 + 4 + 1),
+                        },
+                    ],
+                },
+            ],
+        },
+        sample_count: 1,
+        sample_mask: !0,
+        alpha_to_coverage_enabled: false,
+    })
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, AsBytes)]
 pub struct Vertex {
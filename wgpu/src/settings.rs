@@ -1,5 +1,6 @@
 //! Configure a renderer.
 pub use crate::Antialiasing;
+pub use iced_graphics::shaping::Shaper;
 
 /// The settings of a [`Renderer`].
 ///
@@ -22,7 +23,19 @@ pub struct Settings {
     pub default_text_size: u16,
 
     /// The antialiasing strategy that will be used for triangle primitives.
+    ///
+    /// Text is not affected by this setting. Glyphs are always rasterized
+    /// into a grayscale coverage cache, so text never shows the color
+    /// fringes of subpixel antialiasing, even on displays with unusual
+    /// subpixel layouts or in screenshots.
     pub antialiasing: Option<Antialiasing>,
+
+    /// The text shaping engine that will be used to lay out text.
+    ///
+    /// If `None` is provided, text will be laid out glyph by glyph, which
+    /// breaks ligatures and complex scripts. Shaped text is not wrapped and
+    /// rich text is never shaped.
+    pub text_shaper: Option<&'static dyn Shaper>,
 }
 
 impl Default for Settings {
@@ -32,6 +45,7 @@ impl Default for Settings {
             default_font: None,
             default_text_size: 20,
             antialiasing: None,
+            text_shaper: None,
         }
     }
 }
@@ -1,12 +1,42 @@
 #version 450
 
 layout(location = 0) in vec3 v_Uv;
+layout(location = 1) in vec2 v_Pos;
+layout(location = 2) in vec2 v_Image_Pos;
+layout(location = 3) in vec2 v_Image_Scale;
+layout(location = 4) in float v_BorderRadius;
 
 layout(set = 0, binding = 1) uniform sampler u_Sampler;
 layout(set = 1, binding = 0) uniform texture2DArray u_Texture;
 
 layout(location = 0) out vec4 o_Color;
 
+float distance(in vec2 frag_coord, in vec2 position, in vec2 size, float radius)
+{
+    vec2 inner_size = size - vec2(radius, radius) * 2.0;
+    vec2 top_left = position + vec2(radius, radius);
+    vec2 bottom_right = top_left + inner_size;
+
+    vec2 top_left_distance = top_left - frag_coord;
+    vec2 bottom_right_distance = frag_coord - bottom_right;
+
+    vec2 distance = vec2(
+        max(max(top_left_distance.x, bottom_right_distance.x), 0),
+        max(max(top_left_distance.y, bottom_right_distance.y), 0)
+    );
+
+    return sqrt(distance.x * distance.x + distance.y * distance.y);
+}
+
 void main() {
-    o_Color = texture(sampler2DArray(u_Texture, u_Sampler), v_Uv);
+    vec4 color = texture(sampler2DArray(u_Texture, u_Sampler), v_Uv);
+
+    float radius = min(v_BorderRadius, min(v_Image_Scale.x, v_Image_Scale.y) / 2.0);
+
+    float d = distance(v_Pos, v_Image_Pos, v_Image_Scale, radius);
+
+    float radius_alpha =
+        1.0 - smoothstep(max(radius - 0.5, 0), radius + 0.5, d);
+
+    o_Color = vec4(color.xyz, color.w * radius_alpha);
 }
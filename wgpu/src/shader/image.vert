@@ -6,15 +6,26 @@ layout(location = 2) in vec2 i_Scale;
 layout(location = 3) in vec2 i_Atlas_Pos;
 layout(location = 4) in vec2 i_Atlas_Scale;
 layout(location = 5) in uint i_Layer;
+layout(location = 6) in vec2 i_Image_Pos;
+layout(location = 7) in vec2 i_Image_Scale;
+layout(location = 8) in float i_BorderRadius;
 
 layout (set = 0, binding = 0) uniform Globals {
     mat4 u_Transform;
 };
 
 layout(location = 0) out vec3 o_Uv;
+layout(location = 1) out vec2 o_Pos;
+layout(location = 2) out vec2 o_Image_Pos;
+layout(location = 3) out vec2 o_Image_Scale;
+layout(location = 4) out float o_BorderRadius;
 
 void main() {
     o_Uv = vec3(v_Pos * i_Atlas_Scale + i_Atlas_Pos, i_Layer);
+    o_Pos = i_Pos + v_Pos * i_Scale;
+    o_Image_Pos = i_Image_Pos;
+    o_Image_Scale = i_Image_Scale;
+    o_BorderRadius = i_BorderRadius;
 
     mat4 i_Transform = mat4(
         vec4(i_Scale.x, 0.0, 0.0, 0.0),
@@ -1,6 +1,8 @@
 use crate::Transformation;
 use iced_graphics::font;
-use std::{cell::RefCell, collections::HashMap};
+use iced_graphics::layer;
+use iced_graphics::shaping::{self, Metrics, Shaper};
+use std::{borrow::Cow, cell::RefCell, collections::HashMap};
 use wgpu_glyph::ab_glyph;
 
 #[derive(Debug)]
@@ -8,6 +10,7 @@ pub struct Pipeline {
     draw_brush: RefCell<wgpu_glyph::GlyphBrush<()>>,
     draw_font_map: RefCell<HashMap<String, wgpu_glyph::FontId>>,
     measure_brush: RefCell<glyph_brush::GlyphBrush<()>>,
+    shaping: Option<RefCell<shaping::Engine>>,
 }
 
 impl Pipeline {
@@ -15,6 +18,7 @@ impl Pipeline {
         device: &wgpu::Device,
         format: wgpu::TextureFormat,
         default_font: Option<&[u8]>,
+        shaper: Option<&'static dyn Shaper>,
     ) -> Self {
         let default_font = default_font.map(|slice| slice.to_vec());
 
@@ -31,16 +35,29 @@ impl Pipeline {
         let default_font =
             default_font.unwrap_or_else(|| font::FALLBACK.to_vec());
 
-        let font = ab_glyph::FontArc::try_from_vec(default_font)
-            .unwrap_or_else(|_| {
-                log::warn!(
-                    "System font failed to load. Falling back to \
-                    embedded font..."
-                );
+        let (font, bytes) =
+            match ab_glyph::FontArc::try_from_vec(default_font.clone()) {
+                Ok(font) => (font, Cow::Owned(default_font)),
+                Err(_) => {
+                    log::warn!(
+                        "System font failed to load. Falling back to \
+                        embedded font..."
+                    );
 
-                ab_glyph::FontArc::try_from_slice(font::FALLBACK)
-                    .expect("Load fallback font")
-            });
+                    (
+                        ab_glyph::FontArc::try_from_slice(font::FALLBACK)
+                            .expect("Load fallback font"),
+                        Cow::Borrowed(font::FALLBACK),
+                    )
+                }
+            };
+
+        let shaping = shaper.map(|shaper| {
+            let mut engine = shaping::Engine::new(shaper);
+            engine.add_font(bytes, font.clone());
+
+            RefCell::new(engine)
+        });
 
         let draw_brush =
             wgpu_glyph::GlyphBrushBuilder::using_font(font.clone())
@@ -55,13 +72,55 @@ impl Pipeline {
             draw_brush: RefCell::new(draw_brush),
             draw_font_map: RefCell::new(HashMap::new()),
             measure_brush: RefCell::new(measure_brush),
+            shaping,
         }
     }
 
+    /// Returns the distance from the top of a line of text to its baseline.
+    pub fn ascent(&self, size: f32, font: iced_native::Font) -> f32 {
+        use wgpu_glyph::GlyphCruncher;
+
+        let font_id = self.find_font(font);
+        let measure_brush = self.measure_brush.borrow();
+
+        Metrics::of(&measure_brush.fonts()[font_id.0], size).ascent
+    }
+
     pub fn queue(&mut self, section: wgpu_glyph::Section<'_>) {
         self.draw_brush.borrow_mut().queue(section);
     }
 
+    /// Shapes and queues the given text, if a `Shaper` was provided.
+    ///
+    /// Returns whether the text was queued.
+    pub fn queue_shaped(
+        &mut self,
+        text: &layer::Text<'_>,
+        scale_factor: f32,
+    ) -> bool {
+        let shaping = match &self.shaping {
+            Some(shaping) => shaping,
+            None => return false,
+        };
+
+        let font_id = self.find_font(text.font);
+        let (glyphs, bounds) =
+            shaping
+                .borrow_mut()
+                .section_glyphs(text, scale_factor, font_id.0);
+
+        self.draw_brush.borrow_mut().queue_pre_positioned(
+            glyphs,
+            vec![wgpu_glyph::Extra {
+                color: text.color,
+                z: 0.0,
+            }],
+            bounds,
+        );
+
+        true
+    }
+
     pub fn draw_queued(
         &mut self,
         device: &wgpu::Device,
@@ -95,6 +154,12 @@ impl Pipeline {
 
         let wgpu_glyph::FontId(font_id) = self.find_font(font);
 
+        if let Some(shaping) = &self.shaping {
+            let size = shaping.borrow_mut().measure(content, size, font_id);
+
+            return (size.width.ceil(), size.height.ceil());
+        }
+
         let section = wgpu_glyph::Section {
             bounds: (bounds.width, bounds.height),
             text: vec![wgpu_glyph::Text {
@@ -115,11 +180,46 @@ impl Pipeline {
         }
     }
 
+    pub fn measure_spans(
+        &self,
+        spans: &[iced_native::rich_text::Span<iced_native::Font>],
+        size: f32,
+        bounds: iced_native::Size,
+    ) -> (f32, f32) {
+        use wgpu_glyph::GlyphCruncher;
+
+        let section = wgpu_glyph::Section {
+            bounds: (bounds.width, bounds.height),
+            text: spans
+                .iter()
+                .map(|span| wgpu_glyph::Text {
+                    text: &span.content,
+                    scale: span.size.map(f32::from).unwrap_or(size).into(),
+                    font_id: self.find_font(span.font),
+                    extra: wgpu_glyph::Extra::default(),
+                })
+                .collect(),
+            ..Default::default()
+        };
+
+        if let Some(bounds) =
+            self.measure_brush.borrow_mut().glyph_bounds(section)
+        {
+            (bounds.width().ceil(), bounds.height().ceil())
+        } else {
+            (0.0, 0.0)
+        }
+    }
+
     pub fn trim_measurement_cache(&mut self) {
         // TODO: We should probably use a `GlyphCalculator` for this. However,
         // it uses a lifetimed `GlyphCalculatorGuard` with side-effects on drop.
         // This makes stuff quite inconvenient. A manual method for trimming the
         // cache would make our lives easier.
+        if let Some(shaping) = &self.shaping {
+            shaping.borrow_mut().trim();
+        }
+
         loop {
             let action = self
                 .measure_brush
@@ -152,6 +252,12 @@ impl Pipeline {
 
                 let _ = self.measure_brush.borrow_mut().add_font(font.clone());
 
+                if let Some(shaping) = &self.shaping {
+                    shaping
+                        .borrow_mut()
+                        .add_font(Cow::Borrowed(bytes), font.clone());
+                }
+
                 let font_id = self.draw_brush.borrow_mut().add_font(font);
 
                 let _ = self
@@ -1,6 +1,6 @@
 //! Draw meshes of triangles.
-use crate::{settings, Transformation};
-use iced_graphics::layer;
+use crate::{blend, settings, Transformation};
+use iced_graphics::{layer, BlendMode};
 use std::mem;
 use zerocopy::AsBytes;
 
@@ -14,7 +14,12 @@ const INDEX_BUFFER_SIZE: usize = 10_000;
 
 #[derive(Debug)]
 pub(crate) struct Pipeline {
-    pipeline: wgpu::RenderPipeline,
+    pipelines: blend::Pipelines,
+    layout: wgpu::PipelineLayout,
+    vs_module: wgpu::ShaderModule,
+    fs_module: wgpu::ShaderModule,
+    format: wgpu::TextureFormat,
+    sample_count: u32,
     blit: Option<msaa::Blit>,
     constants_layout: wgpu::BindGroupLayout,
     constants: wgpu::BindGroup,
@@ -131,69 +136,30 @@ impl Pipeline {
             "shader/triangle.frag.spv"
         ));
 
-        let pipeline =
-            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-                label: Some("iced_wgpu::triangle pipeline"),
-                layout: Some(&layout),
-                vertex_stage: wgpu::ProgrammableStageDescriptor {
-                    module: &vs_module,
-                    entry_point: "main",
-                },
-                fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
-                    module: &fs_module,
-                    entry_point: "main",
-                }),
-                rasterization_state: Some(wgpu::RasterizationStateDescriptor {
-                    front_face: wgpu::FrontFace::Cw,
-                    cull_mode: wgpu::CullMode::None,
-                    ..Default::default()
-                }),
-                primitive_topology: wgpu::PrimitiveTopology::TriangleList,
-                color_states: &[wgpu::ColorStateDescriptor {
-                    format,
-                    color_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::SrcAlpha,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
-                    alpha_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::One,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
-                    write_mask: wgpu::ColorWrite::ALL,
-                }],
-                depth_stencil_state: None,
-                vertex_state: wgpu::VertexStateDescriptor {
-                    index_format: wgpu::IndexFormat::Uint32,
-                    vertex_buffers: &[wgpu::VertexBufferDescriptor {
-                        stride: mem::size_of::<Vertex2D>() as u64,
-                        step_mode: wgpu::InputStepMode::Vertex,
-                        attributes: &[
-                            // Position
-                            wgpu::VertexAttributeDescriptor {
-                                shader_location: 0,
-                                format: wgpu::VertexFormat::Float2,
-                                offset: 0,
-                            },
-                            // Color
-                            wgpu::VertexAttributeDescriptor {
-                                shader_location: 1,
-                                format: wgpu::VertexFormat::Float4,
-                                offset: 4 * 2,
-                            },
-                        ],
-                    }],
-                },
-                sample_count: u32::from(
-                    antialiasing.map(|a| a.sample_count()).unwrap_or(1),
-                ),
-                sample_mask: !0,
-                alpha_to_coverage_enabled: false,
-            });
+        let sample_count =
+            u32::from(antialiasing.map(|a| a.sample_count()).unwrap_or(1));
+
+        let mut pipelines = blend::Pipelines::new();
+
+        let _ = pipelines.get(BlendMode::Normal, |mode| {
+            build_pipeline(
+                device,
+                &layout,
+                &vs_module,
+                &fs_module,
+                format,
+                sample_count,
+                mode,
+            )
+        });
 
         Pipeline {
-            pipeline,
+            pipelines,
+            layout,
+            vs_module,
+            fs_module,
+            format,
+            sample_count,
             blit: antialiasing.map(|a| msaa::Blit::new(device, format, a)),
             constants_layout,
             constants: constant_bind_group,
@@ -224,6 +190,7 @@ impl Pipeline {
         transformation: Transformation,
         scale_factor: f32,
         meshes: &[layer::Mesh<'_>],
+        blend_mode: BlendMode,
     ) {
         // This looks a bit crazy, but we are just counting how many vertices
         // and indices we will need to handle.
@@ -339,6 +306,33 @@ impl Pipeline {
         }
 
         {
+            let (layout, vs_module, fs_module, format, sample_count) = (
+                &self.layout,
+                &self.vs_module,
+                &self.fs_module,
+                self.format,
+                self.sample_count,
+            );
+
+            // With multisampling, the meshes are blended when resolved
+            let mode = if self.blit.is_some() {
+                BlendMode::Normal
+            } else {
+                blend_mode
+            };
+
+            let pipeline = self.pipelines.get(mode, |mode| {
+                build_pipeline(
+                    device,
+                    layout,
+                    vs_module,
+                    fs_module,
+                    format,
+                    sample_count,
+                    mode,
+                )
+            });
+
             let (attachment, resolve_target, load) =
                 if let Some(blit) = &mut self.blit {
                     let (attachment, resolve_target) =
@@ -365,7 +359,7 @@ impl Pipeline {
                     depth_stencil_attachment: None,
                 });
 
-            render_pass.set_pipeline(&self.pipeline);
+            render_pass.set_pipeline(pipeline);
 
             for (i, (vertex_offset, index_offset, indices)) in
                 offsets.into_iter().enumerate()
@@ -403,11 +397,66 @@ impl Pipeline {
         }
 
         if let Some(blit) = &mut self.blit {
-            blit.draw(encoder, target);
+            blit.draw(device, encoder, target, blend_mode);
         }
     }
 }
 
+fn build_pipeline(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    vs_module: &wgpu::ShaderModule,
+    fs_module: &wgpu::ShaderModule,
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+    mode: BlendMode,
+) -> wgpu::RenderPipeline {
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some("iced_wgpu::triangle pipeline"),
+        layout: Some(layout),
+        vertex_stage: wgpu::ProgrammableStageDescriptor {
+            module: vs_module,
+            entry_point: "main",
+        },
+        fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+            module: fs_module,
+            entry_point: "main",
+        }),
+        rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+            front_face: wgpu::FrontFace::Cw,
+            cull_mode: wgpu::CullMode::None,
+            ..Default::default()
+        }),
+        primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+        color_states: &[blend::color_state(format, mode)],
+        depth_stencil_state: None,
+        vertex_state: wgpu::VertexStateDescriptor {
+            index_format: wgpu::IndexFormat::Uint32,
+            vertex_buffers: &[wgpu::VertexBufferDescriptor {
+                stride: mem::size_of::<Vertex2D>() as u64,
+                step_mode: wgpu::InputStepMode::Vertex,
+                attributes: &[
+                    // Position
+                    wgpu::VertexAttributeDescriptor {
+                        shader_location: 0,
+                        format: wgpu::VertexFormat::Float2,
+                        offset: 0,
+                    },
+                    // Color
+                    wgpu::VertexAttributeDescriptor {
+                        shader_location: 1,
+                        format: wgpu::VertexFormat::Float4,
+                        offset: 4 * 2,
+                    },
+                ],
+            }],
+        },
+        sample_count,
+        sample_mask: !0,
+        alpha_to_coverage_enabled: false,
+    })
+}
+
 #[repr(C)]
 #[derive(Debug, Clone, Copy, AsBytes)]
 struct Uniforms {
@@ -1,9 +1,13 @@
-use crate::settings;
+use crate::{blend, settings};
+use iced_graphics::BlendMode;
 
 #[derive(Debug)]
 pub struct Blit {
     format: wgpu::TextureFormat,
-    pipeline: wgpu::RenderPipeline,
+    pipelines: blend::Pipelines,
+    layout: wgpu::PipelineLayout,
+    vs_module: wgpu::ShaderModule,
+    fs_module: wgpu::ShaderModule,
     constants: wgpu::BindGroup,
     texture_layout: wgpu::BindGroupLayout,
     sample_count: u32,
@@ -77,51 +81,20 @@ impl Blit {
             "../shader/blit.frag.spv"
         ));
 
-        let pipeline =
-            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-                label: Some("iced_wgpu::triangle::msaa pipeline"),
-                layout: Some(&layout),
-                vertex_stage: wgpu::ProgrammableStageDescriptor {
-                    module: &vs_module,
-                    entry_point: "main",
-                },
-                fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
-                    module: &fs_module,
-                    entry_point: "main",
-                }),
-                rasterization_state: Some(wgpu::RasterizationStateDescriptor {
-                    front_face: wgpu::FrontFace::Cw,
-                    cull_mode: wgpu::CullMode::None,
-                    ..Default::default()
-                }),
-                primitive_topology: wgpu::PrimitiveTopology::TriangleList,
-                color_states: &[wgpu::ColorStateDescriptor {
-                    format,
-                    color_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::SrcAlpha,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
-                    alpha_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::One,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
-                    write_mask: wgpu::ColorWrite::ALL,
-                }],
-                depth_stencil_state: None,
-                vertex_state: wgpu::VertexStateDescriptor {
-                    index_format: wgpu::IndexFormat::Uint16,
-                    vertex_buffers: &[],
-                },
-                sample_count: 1,
-                sample_mask: !0,
-                alpha_to_coverage_enabled: false,
-            });
+        let mut pipelines = blend::Pipelines::new();
+
+        let _ = pipelines.get(BlendMode::Normal, |mode| {
+            build_pipeline(
+                device, &layout, &vs_module, &fs_module, format, mode,
+            )
+        });
 
         Blit {
             format,
-            pipeline,
+            pipelines,
+            layout,
+            vs_module,
+            fs_module,
             constants: constant_bind_group,
             texture_layout,
             sample_count: antialiasing.sample_count(),
@@ -166,10 +139,19 @@ impl Blit {
     }
 
     pub fn draw(
-        &self,
+        &mut self,
+        device: &wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
         target: &wgpu::TextureView,
+        blend_mode: BlendMode,
     ) {
+        let (layout, vs_module, fs_module, format) =
+            (&self.layout, &self.vs_module, &self.fs_module, self.format);
+
+        let pipeline = self.pipelines.get(blend_mode, |mode| {
+            build_pipeline(device, layout, vs_module, fs_module, format, mode)
+        });
+
         let mut render_pass =
             encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 color_attachments: &[
@@ -185,7 +167,7 @@ impl Blit {
                 depth_stencil_attachment: None,
             });
 
-        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_pipeline(pipeline);
         render_pass.set_bind_group(0, &self.constants, &[]);
         render_pass.set_bind_group(
             1,
@@ -196,6 +178,43 @@ impl Blit {
     }
 }
 
+fn build_pipeline(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    vs_module: &wgpu::ShaderModule,
+    fs_module: &wgpu::ShaderModule,
+    format: wgpu::TextureFormat,
+    mode: BlendMode,
+) -> wgpu::RenderPipeline {
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some("iced_wgpu::triangle::msaa pipeline"),
+        layout: Some(layout),
+        vertex_stage: wgpu::ProgrammableStageDescriptor {
+            module: vs_module,
+            entry_point: "main",
+        },
+        fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+            module: fs_module,
+            entry_point: "main",
+        }),
+        rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+            front_face: wgpu::FrontFace::Cw,
+            cull_mode: wgpu::CullMode::None,
+            ..Default::default()
+        }),
+        primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+        color_states: &[blend::color_state(format, mode)],
+        depth_stencil_state: None,
+        vertex_state: wgpu::VertexStateDescriptor {
+            index_format: wgpu::IndexFormat::Uint16,
+            vertex_buffers: &[],
+        },
+        sample_count: 1,
+        sample_mask: !0,
+        alpha_to_coverage_enabled: false,
+    })
+}
+
 #[derive(Debug)]
 struct Targets {
     attachment: wgpu::TextureView,
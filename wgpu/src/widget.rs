@@ -12,14 +12,19 @@ use crate::Renderer;
 pub mod button;
 pub mod checkbox;
 pub mod container;
+pub mod context_menu;
+pub mod modal;
+pub mod multi_pick_list;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod rich_text;
 pub mod rule;
 pub mod scrollable;
 pub mod slider;
 pub mod text_input;
+pub mod tooltip;
 
 #[doc(no_inline)]
 pub use button::Button;
@@ -28,13 +33,21 @@ pub use checkbox::Checkbox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use context_menu::ContextMenu;
+#[doc(no_inline)]
+pub use modal::Modal;
+#[doc(no_inline)]
+pub use multi_pick_list::MultiPickList;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
 #[doc(no_inline)]
 pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
-pub use radio::Radio;
+pub use radio::{Radio, RadioGroup};
+#[doc(no_inline)]
+pub use rich_text::RichText;
 #[doc(no_inline)]
 pub use rule::Rule;
 #[doc(no_inline)]
@@ -43,6 +56,8 @@ pub use scrollable::Scrollable;
 pub use slider::Slider;
 #[doc(no_inline)]
 pub use text_input::TextInput;
+#[doc(no_inline)]
+pub use tooltip::Tooltip;
 
 #[cfg(feature = "canvas")]
 #[cfg_attr(docsrs, doc(cfg(feature = "canvas")))]
@@ -52,13 +67,56 @@ pub mod canvas;
 #[doc(no_inline)]
 pub use canvas::Canvas;
 
-pub use iced_native::Space;
+#[cfg(feature = "image")]
+#[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+pub mod image;
+
+pub use iced_native::{
+    component, keyed, linked_text, mouse_area, on_layout, popover, responsive,
+    Space,
+};
+
+/// An element that keeps its content at a fixed aspect ratio.
+pub type AspectRatio<'a, Message> =
+    iced_native::AspectRatio<'a, Message, Renderer>;
 
 /// A container that distributes its contents vertically.
 pub type Column<'a, Message> = iced_native::Column<'a, Message, Renderer>;
 
+/// A container that distributes its contents in rows and aligned columns.
+pub type Grid<'a, Message> = iced_native::Grid<'a, Message, Renderer>;
+
+/// An element displaying a reusable component with its own state and events.
+pub type Stateful<'a, C, Message> =
+    iced_native::Stateful<'a, C, Message, Renderer>;
+
+/// A paragraph of text with clickable links.
+pub type LinkedText<Message> = iced_native::LinkedText<Message, Renderer>;
+
+/// An element that tracks whether the mouse is hovering or pressing its
+/// content.
+pub type MouseArea<'a, Message> = iced_native::MouseArea<'a, Message, Renderer>;
+
+/// An element that produces a message with the bounds of its content whenever
+/// they change.
+pub type OnLayout<'a, Message> = iced_native::OnLayout<'a, Message, Renderer>;
+
+/// An element that displays a popup anchored to its content.
+pub type Popover<'a, Message> = iced_native::Popover<'a, Message, Renderer>;
+
+/// An element that builds its content from the size available to it.
+pub type Responsive<'a, Message> =
+    iced_native::Responsive<'a, Message, Renderer>;
+
 /// A container that distributes its contents horizontally.
 pub type Row<'a, Message> = iced_native::Row<'a, Message, Renderer>;
 
+/// A container that places its contents on top of each other.
+pub type Stack<'a, Message> = iced_native::Stack<'a, Message, Renderer>;
+
 /// A paragraph of text.
 pub type Text = iced_native::Text<Renderer>;
+
+/// A container that distributes its contents horizontally, wrapping them into
+/// new lines.
+pub type Wrap<'a, Message> = iced_native::Wrap<'a, Message, Renderer>;
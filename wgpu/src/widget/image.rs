@@ -0,0 +1,59 @@
+//! Display images in your user interface.
+use crate::image_pipeline::raster::Memory;
+
+use futures::executor::ThreadPool;
+
+pub use iced_graphics::image::{Handle, Image, Status};
+
+/// The amount of threads decoding images in the background.
+const DECODING_THREADS: usize = 2;
+
+lazy_static::lazy_static! {
+    static ref DECODING_POOL: ThreadPool = ThreadPool::builder()
+        .pool_size(DECODING_THREADS)
+        .name_prefix("iced_wgpu::image::decode-")
+        .create()
+        .expect("Create image decoding thread pool");
+}
+
+/// Decodes the image of the given [`Handle`] in the background.
+///
+/// The resulting [`Status`] contains a [`Handle`] with the decoded pixels,
+/// which can be drawn without stalling the user interface. Turn the future
+/// into a `Command` to receive the [`Status`] as a message once it is ready.
+///
+/// Images are decoded by a small pool of threads shared by the whole
+/// application, so decoding many images at once queues them instead of
+/// spawning a thread for each of them.
+///
+/// This is only available with the `wgpu` renderer, since the `glow`
+/// renderer cannot draw images yet.
+///
+/// [`Handle`]: struct.Handle.html
+/// [`Status`]: enum.Status.html
+pub async fn decode(handle: Handle) -> Status {
+    if handle.is_decoded() {
+        return Status::Ready(handle);
+    }
+
+    let (sender, receiver) = futures::channel::oneshot::channel();
+
+    DECODING_POOL.spawn_ok(async move {
+        let status = match Memory::load(&handle) {
+            Memory::Host(image) => {
+                let (width, height) = image.dimensions();
+
+                Status::Ready(Handle::from_pixels(
+                    width,
+                    height,
+                    image.into_raw(),
+                ))
+            }
+            _ => Status::Failed,
+        };
+
+        let _ = sender.send(status);
+    });
+
+    receiver.await.unwrap_or(Status::Failed)
+}
@@ -0,0 +1,10 @@
+//! Display content on top of a dimmed backdrop, capturing all input.
+use crate::Renderer;
+
+pub use iced_graphics::modal::{Style, StyleSheet};
+
+/// An element that can show some content centered in the viewport, on top of
+/// a backdrop covering everything else.
+///
+/// This is an alias of an `iced_native` modal with a default `Renderer`.
+pub type Modal<'a, Message> = iced_native::Modal<'a, Message, Renderer>;
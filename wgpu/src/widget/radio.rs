@@ -1,10 +1,17 @@
 //! Create choices using radio buttons.
 use crate::Renderer;
 
-pub use iced_graphics::radio::{Style, StyleSheet};
+pub use iced_graphics::radio::{Orientation, State, Style, StyleSheet};
 
 /// A circular button representing a choice.
 ///
 /// This is an alias of an `iced_native` radio button with an
 /// `iced_wgpu::Renderer`.
 pub type Radio<Message> = iced_native::Radio<Message, Renderer>;
+
+/// A group of radio buttons that can be navigated with the arrow keys.
+///
+/// This is an alias of an `iced_native` radio group with an
+/// `iced_wgpu::Renderer`.
+pub type RadioGroup<'a, Message> =
+    iced_native::RadioGroup<'a, Message, Renderer>;
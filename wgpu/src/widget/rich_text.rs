@@ -0,0 +1,12 @@
+//! Write paragraphs of text made of differently styled spans.
+use crate::Renderer;
+
+pub use iced_graphics::rich_text::Span;
+
+/// A paragraph of text made of multiple [`Span`]s.
+///
+/// This is an alias of an `iced_native` rich text with an
+/// `iced_wgpu::Renderer`.
+///
+/// [`Span`]: struct.Span.html
+pub type RichText = iced_native::RichText<Renderer>;
@@ -0,0 +1,10 @@
+//! Display a floating tip next to some content while it is hovered.
+use crate::Renderer;
+
+pub use iced_graphics::tooltip::{Position, State, Style, StyleSheet};
+
+/// An element that shows a tip next to its content while the content is
+/// hovered.
+///
+/// This is an alias of an `iced_native` tooltip with a default `Renderer`.
+pub type Tooltip<'a, Message> = iced_native::Tooltip<'a, Message, Renderer>;
@@ -1,3 +1,4 @@
+use crate::window::screenshot;
 use crate::{Backend, Color, Error, Renderer, Settings, Viewport};
 
 use futures::task::SpawnExt;
@@ -73,6 +74,19 @@ impl Compositor {
     pub fn create_backend(&self) -> Backend {
         Backend::new(&self.device, self.settings)
     }
+
+    fn submit(&mut self, encoder: wgpu::CommandEncoder) {
+        self.staging_belt.finish();
+        self.queue.submit(Some(encoder.finish()));
+
+        // Recall staging buffers
+        self.local_pool
+            .spawner()
+            .spawn(self.staging_belt.recall())
+            .expect("Recall staging belt");
+
+        self.local_pool.run_until_stalled();
+    }
 }
 
 impl iced_graphics::window::Compositor for Compositor {
@@ -135,26 +149,7 @@ impl iced_graphics::window::Compositor for Compositor {
             },
         );
 
-        let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            color_attachments: &[wgpu::RenderPassColorAttachmentDescriptor {
-                attachment: &frame.output.view,
-                resolve_target: None,
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Clear({
-                        let [r, g, b, a] = background_color.into_linear();
-
-                        wgpu::Color {
-                            r: f64::from(r),
-                            g: f64::from(g),
-                            b: f64::from(b),
-                            a: f64::from(a),
-                        }
-                    }),
-                    store: true,
-                },
-            }],
-            depth_stencil_attachment: None,
-        });
+        clear(&mut encoder, &frame.output.view, background_color);
 
         let mouse_interaction = renderer.backend_mut().draw(
             &mut self.device,
@@ -166,18 +161,201 @@ impl iced_graphics::window::Compositor for Compositor {
             overlay,
         );
 
-        // Submit work
-        self.staging_belt.finish();
-        self.queue.submit(Some(encoder.finish()));
+        self.submit(encoder);
 
-        // Recall staging buffers
-        self.local_pool
-            .spawner()
-            .spawn(self.staging_belt.recall())
-            .expect("Recall staging belt");
+        mouse_interaction
+    }
 
-        self.local_pool.run_until_stalled();
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        output: &<Self::Renderer as iced_native::Renderer>::Output,
+        overlay: &[T],
+    ) -> Result<Vec<u8>, screenshot::Error> {
+        let size = viewport.physical_size();
 
-        mouse_interaction
+        if size.width == 0 || size.height == 0 {
+            return Err(screenshot::Error::EmptyViewport);
+        }
+
+        let extent = wgpu::Extent3d {
+            width: size.width,
+            height: size.height,
+            depth: 1,
+        };
+
+        let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("iced_wgpu screenshot texture"),
+            size: extent,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: self.settings.format,
+            usage: wgpu::TextureUsage::OUTPUT_ATTACHMENT
+                | wgpu::TextureUsage::COPY_SRC,
+        });
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let row_length = 4 * size.width;
+        let padded_row_length = padded_row_length(row_length);
+
+        let buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu screenshot buffer"),
+            size: u64::from(padded_row_length * size.height),
+            usage: wgpu::BufferUsage::COPY_DST | wgpu::BufferUsage::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = self.device.create_command_encoder(
+            &wgpu::CommandEncoderDescriptor {
+                label: Some("iced_wgpu screenshot encoder"),
+            },
+        );
+
+        clear(&mut encoder, &view, background_color);
+
+        let _ = renderer.backend_mut().draw(
+            &self.device,
+            &mut self.staging_belt,
+            &mut encoder,
+            &view,
+            viewport,
+            output,
+            overlay,
+        );
+
+        encoder.copy_texture_to_buffer(
+            wgpu::TextureCopyView {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+            },
+            wgpu::BufferCopyView {
+                buffer: &buffer,
+                layout: wgpu::TextureDataLayout {
+                    offset: 0,
+                    bytes_per_row: padded_row_length,
+                    rows_per_image: size.height,
+                },
+            },
+            extent,
+        );
+
+        self.submit(encoder);
+
+        let slice = buffer.slice(..);
+        let mapping = slice.map_async(wgpu::MapMode::Read);
+
+        self.device.poll(wgpu::Maintain::Wait);
+        futures::executor::block_on(mapping)
+            .map_err(|_| screenshot::Error::ReadFailed)?;
+
+        let is_bgra = matches!(
+            self.settings.format,
+            wgpu::TextureFormat::Bgra8Unorm
+                | wgpu::TextureFormat::Bgra8UnormSrgb
+        );
+
+        let pixels = unpad_rows(
+            &slice.get_mapped_range(),
+            row_length as usize,
+            padded_row_length as usize,
+            is_bgra,
+        );
+
+        buffer.unmap();
+
+        Ok(pixels)
+    }
+}
+
+/// Returns the length of a row of a texture copy, which needs to be aligned
+/// to `wgpu::COPY_BYTES_PER_ROW_ALIGNMENT`.
+fn padded_row_length(row_length: u32) -> u32 {
+    let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+
+    row_length + (align - row_length % align) % align
+}
+
+/// Strips the padding of the given rows of pixels and converts them to RGBA.
+fn unpad_rows(
+    data: &[u8],
+    row_length: usize,
+    padded_row_length: usize,
+    is_bgra: bool,
+) -> Vec<u8> {
+    let mut pixels =
+        Vec::with_capacity(row_length * (data.len() / padded_row_length));
+
+    for row in data.chunks_exact(padded_row_length) {
+        for pixel in row[..row_length].chunks_exact(4) {
+            if is_bgra {
+                pixels.extend_from_slice(&[
+                    pixel[2], pixel[1], pixel[0], pixel[3],
+                ]);
+            } else {
+                pixels.extend_from_slice(pixel);
+            }
+        }
+    }
+
+    pixels
+}
+
+fn clear(
+    encoder: &mut wgpu::CommandEncoder,
+    target: &wgpu::TextureView,
+    background_color: Color,
+) {
+    let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        color_attachments: &[wgpu::RenderPassColorAttachmentDescriptor {
+            attachment: target,
+            resolve_target: None,
+            ops: wgpu::Operations {
+                load: wgpu::LoadOp::Clear({
+                    let [r, g, b, a] = background_color.into_linear();
+
+                    wgpu::Color {
+                        r: f64::from(r),
+                        g: f64::from(g),
+                        b: f64::from(b),
+                        a: f64::from(a),
+                    }
+                }),
+                store: true,
+            },
+        }],
+        depth_stencil_attachment: None,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows_are_padded_to_the_copy_alignment() {
+        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+
+        assert_eq!(padded_row_length(4), align);
+        assert_eq!(padded_row_length(align), align);
+        assert_eq!(padded_row_length(align + 4), align * 2);
+    }
+
+    #[test]
+    fn padding_is_stripped_and_bgra_is_swapped() {
+        let data = [
+            1, 2, 3, 4, 0, 0, 0, 0, //
+            5, 6, 7, 8, 0, 0, 0, 0,
+        ];
+
+        assert_eq!(
+            unpad_rows(&data, 4, 8, false),
+            vec![1, 2, 3, 4, 5, 6, 7, 8]
+        );
+        assert_eq!(unpad_rows(&data, 4, 8, true), vec![3, 2, 1, 4, 7, 6, 5, 8]);
     }
 }
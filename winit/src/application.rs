@@ -9,6 +9,9 @@ use iced_graphics::window;
 use iced_graphics::Viewport;
 use iced_native::program::{self, Program};
 
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
 /// An interactive, native cross-platform application.
 ///
 /// This trait is the main entrypoint of Iced. Once implemented, you can run
@@ -116,10 +119,7 @@ where
     E: Executor + 'static,
     C: window::Compositor<Renderer = A::Renderer> + 'static,
 {
-    use winit::{
-        event,
-        event_loop::{ControlFlow, EventLoop},
-    };
+    use winit::{event, event_loop::EventLoop};
 
     let mut debug = Debug::new();
     debug.startup_started();
@@ -155,6 +155,7 @@ where
     let mut cursor_position = winit::dpi::PhysicalPosition::new(-1.0, -1.0);
     let mut mouse_interaction = mouse::Interaction::default();
     let mut modifiers = winit::event::ModifiersState::default();
+    let mut pressed_keys = HashSet::new();
 
     let physical_size = window.inner_size();
     let mut viewport = Viewport::with_physical_size(
@@ -184,7 +185,7 @@ where
 
     event_loop.run(move |event, _, control_flow| match event {
         event::Event::MainEventsCleared => {
-            if state.is_queue_empty() {
+            if !state.needs_update() {
                 return;
             }
 
@@ -304,9 +305,6 @@ where
 
                 mouse_interaction = new_mouse_interaction;
             }
-
-            // TODO: Handle animations!
-            // Maybe we can use `ControlFlow::WaitUntil` for this.
         }
         event::Event::WindowEvent {
             event: window_event,
@@ -328,17 +326,47 @@ where
                 &window_event,
                 viewport.scale_factor(),
                 modifiers,
+                &mut pressed_keys,
             ) {
                 state.queue_event(event.clone());
                 runtime.broadcast(event);
             }
         }
         _ => {
-            *control_flow = ControlFlow::Wait;
+            *control_flow = self::control_flow(&state);
         }
     })
 }
 
+/// The time between the frames of an animating user interface.
+const FRAME: Duration = Duration::from_millis(16);
+
+/// Returns the control flow to use while waiting for new events.
+///
+/// A [`State`] with queued messages is updated again right away. An
+/// animating one is updated again on the next frame, even if no event
+/// arrives. Otherwise, it is updated once any requested redraw is due.
+///
+/// [`State`]: ../program/struct.State.html
+pub fn control_flow<P>(
+    state: &program::State<P>,
+) -> winit::event_loop::ControlFlow
+where
+    P: Program + 'static,
+{
+    use winit::event_loop::ControlFlow;
+
+    if !state.is_queue_empty() {
+        ControlFlow::Poll
+    } else if state.is_animating() {
+        ControlFlow::WaitUntil(Instant::now() + FRAME)
+    } else if let Some(redraw_at) = state.redraw_at() {
+        ControlFlow::WaitUntil(redraw_at)
+    } else {
+        ControlFlow::Wait
+    }
+}
+
 /// Handles a `WindowEvent` and mutates the provided control flow, keyboard
 /// modifiers, viewport, and resized flag accordingly.
 pub fn handle_window_event(
@@ -4,14 +4,20 @@
 //! [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
 use crate::{
     keyboard::{self, KeyCode, ModifiersState},
-    mouse, window, Event, Mode, Point,
+    mouse, touch, window, Event, Mode, Point,
 };
 
+use std::collections::HashSet;
+
 /// Converts a winit window event into an iced event.
+///
+/// The `pressed_keys` are used to tell apart the repeats of a key that is
+/// held down from new presses, and are updated accordingly.
 pub fn window_event(
     event: &winit::event::WindowEvent<'_>,
     scale_factor: f64,
     modifiers: winit::event::ModifiersState,
+    pressed_keys: &mut HashSet<KeyCode>,
 ) -> Option<Event> {
     use winit::event::WindowEvent;
 
@@ -68,6 +74,8 @@ pub fn window_event(
                 }))
             }
             winit::event::MouseScrollDelta::PixelDelta(position) => {
+                let position = position.to_logical::<f64>(scale_factor);
+
                 Some(Event::Mouse(mouse::Event::WheelScrolled {
                     delta: mouse::ScrollDelta::Pixels {
                         x: position.x as f32,
@@ -96,9 +104,12 @@ pub fn window_event(
                     keyboard::Event::KeyPressed {
                         key_code,
                         modifiers,
+                        is_repeat: !pressed_keys.insert(key_code),
                     }
                 }
                 winit::event::ElementState::Released => {
+                    let _ = pressed_keys.remove(&key_code);
+
                     keyboard::Event::KeyReleased {
                         key_code,
                         modifiers,
@@ -106,6 +117,12 @@ pub fn window_event(
                 }
             }
         })),
+        WindowEvent::Focused(false) => {
+            // Keys released while the window is unfocused are never reported
+            pressed_keys.clear();
+
+            None
+        }
         WindowEvent::ModifiersChanged(new_modifiers) => Some(Event::Keyboard(
             keyboard::Event::ModifiersChanged(modifiers_state(*new_modifiers)),
         )),
@@ -118,6 +135,9 @@ pub fn window_event(
         WindowEvent::HoveredFileCancelled => {
             Some(Event::Window(window::Event::FilesHoveredLeft))
         }
+        WindowEvent::Touch(touch) => {
+            Some(Event::Touch(touch_event(*touch, scale_factor)))
+        }
         _ => None,
     }
 }
@@ -175,6 +195,33 @@ pub fn mouse_button(mouse_button: winit::event::MouseButton) -> mouse::Button {
     }
 }
 
+/// Converts a `Touch` from [`winit`] to an [`iced_native`] touch event.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
+pub fn touch_event(
+    touch: winit::event::Touch,
+    scale_factor: f64,
+) -> touch::Event {
+    let id = touch::Finger(touch.id);
+    let position = cursor_position(touch.location, scale_factor);
+
+    match touch.phase {
+        winit::event::TouchPhase::Started => {
+            touch::Event::FingerPressed { id, position }
+        }
+        winit::event::TouchPhase::Moved => {
+            touch::Event::FingerMoved { id, position }
+        }
+        winit::event::TouchPhase::Ended => {
+            touch::Event::FingerLifted { id, position }
+        }
+        winit::event::TouchPhase::Cancelled => {
+            touch::Event::FingerLost { id, position }
+        }
+    }
+}
+
 /// Converts some `ModifiersState` from [`winit`] to an [`iced_native`]
 /// modifiers state.
 ///